@@ -1,10 +1,11 @@
 use bevy::{
     prelude::{Bundle, Component, GlobalTransform, Transform},
     render::{
-        camera::{Camera, CameraProjection, DepthCalculation, WindowOrigin},
+        camera::{Camera, CameraProjection, DepthCalculation, RenderTarget, WindowOrigin},
         primitives::Frustum,
         view::VisibleEntities,
     },
+    window::WindowId,
 };
 
 use super::ortho::UIOrthographicProjection;
@@ -57,4 +58,13 @@ impl UICameraBundle {
             marker: CameraUiKayak,
         }
     }
+
+    /// Renders the UI of the given window, rather than the primary one
+    ///
+    /// Each window with a UI needs its own camera. The UI drawn by this camera is the one given the
+    /// same window with `BevyContext::with_window`.
+    pub fn with_window(mut self, window: WindowId) -> Self {
+        self.camera.target = RenderTarget::Window(window);
+        self
+    }
 }
@@ -1,7 +1,4 @@
-use bevy::{
-    prelude::{CoreStage, Plugin},
-    render::camera::CameraTypePlugin,
-};
+use bevy::prelude::{CoreStage, Plugin};
 
 mod camera;
 mod ortho;
@@ -16,7 +13,6 @@ impl Plugin for KayakUICameraPlugin {
         app.add_system_to_stage(
             CoreStage::PostUpdate,
             bevy::render::camera::camera_system::<UIOrthographicProjection>,
-        )
-        .add_plugin(CameraTypePlugin::<CameraUiKayak>::default());
+        );
     }
 }
@@ -1,12 +1,15 @@
 use bevy::{
     core_pipeline::node::MAIN_PASS_DRIVER,
-    prelude::{Commands, Plugin, Res},
+    prelude::{Assets, Commands, Entity, GlobalTransform, Plugin, Query, Res, With},
     render::{
-        camera::ActiveCamera,
+        camera::{Camera, ExtractedCamera, RenderTarget},
         render_graph::{EmptyNode, RenderGraph, SlotInfo, SlotType},
         render_phase::{DrawFunctions, RenderPhase},
+        texture::Image,
+        view::{ExtractedView, VisibleEntities},
         RenderApp, RenderStage,
     },
+    window::{WindowId, Windows},
 };
 
 use crate::{
@@ -44,7 +47,8 @@ impl Plugin for BevyKayakUIRenderPlugin {
         let render_app = app.sub_app_mut(RenderApp);
         render_app
             .init_resource::<DrawFunctions<TransparentUI>>()
-            .add_system_to_stage(RenderStage::Extract, extract_core_pipeline_camera_phases);
+            .init_resource::<ExtractedUICameras>()
+            .add_system_to_stage(RenderStage::Extract, extract_ui_cameras);
         // .add_system_to_stage(RenderStage::PhaseSort, sort_phase_system::<TransparentUI>);
 
         let pass_node_ui = MainPassUINode::new(&mut render_app.world);
@@ -79,13 +83,59 @@ impl Plugin for BevyKayakUIRenderPlugin {
     }
 }
 
-pub fn extract_core_pipeline_camera_phases(
+/// The UI cameras extracted this frame, along with the window each of them renders to
+#[derive(Debug, Default, Clone)]
+pub struct ExtractedUICameras {
+    pub cameras: Vec<(Entity, WindowId)>,
+}
+
+impl ExtractedUICameras {
+    /// Returns the window the given camera renders to, if it's a UI camera
+    pub fn window(&self, camera: Entity) -> Option<WindowId> {
+        self.cameras
+            .iter()
+            .find_map(|(entity, window)| (*entity == camera).then(|| *window))
+    }
+}
+
+/// Extracts every UI camera, so that each window with a UI is drawn by its own camera
+pub fn extract_ui_cameras(
     mut commands: Commands,
-    active_camera: Res<ActiveCamera<CameraUiKayak>>,
+    windows: Res<Windows>,
+    images: Res<Assets<Image>>,
+    query: Query<(Entity, &Camera, &GlobalTransform, &VisibleEntities), With<CameraUiKayak>>,
 ) {
-    if let Some(entity) = active_camera.get() {
-        commands
-            .get_or_spawn(entity)
-            .insert(RenderPhase::<TransparentUI>::default());
+    let mut cameras = Vec::new();
+    for (entity, camera, transform, visible_entities) in query.iter() {
+        let window = match camera.target {
+            RenderTarget::Window(window) => window,
+            // The UI is only drawn to windows, since it's laid out (and receives input) per window
+            _ => continue,
+        };
+        let size = match camera.target.get_physical_size(&windows, &images) {
+            Some(size) => size,
+            None => continue,
+        };
+
+        commands.get_or_spawn(entity).insert_bundle((
+            ExtractedCamera {
+                target: camera.target.clone(),
+                physical_size: Some(size),
+            },
+            ExtractedView {
+                projection: camera.projection_matrix,
+                transform: *transform,
+                width: size.x.max(1),
+                height: size.y.max(1),
+                near: camera.near,
+                far: camera.far,
+            },
+            visible_entities.clone(),
+            CameraUiKayak,
+            RenderPhase::<TransparentUI>::default(),
+        ));
+        cameras.push((entity, window));
     }
+
+    commands.insert_resource(ExtractedUICameras { cameras });
 }
@@ -1,10 +1,10 @@
+use bevy::ecs::world::World;
 use bevy::render::{
     render_graph::{Node, NodeRunError, RenderGraphContext, SlotValue},
     renderer::RenderContext,
 };
-use bevy::{ecs::world::World, render::camera::ActiveCamera};
 
-use crate::CameraUiKayak;
+use super::ExtractedUICameras;
 
 pub struct UIPassDriverNode;
 
@@ -15,11 +15,14 @@ impl Node for UIPassDriverNode {
         _render_context: &mut RenderContext,
         world: &World,
     ) -> Result<(), NodeRunError> {
-        if let Some(camera_ui) = world.resource::<ActiveCamera<CameraUiKayak>>().get() {
-            graph.run_sub_graph(
-                super::draw_ui_graph::NAME,
-                vec![SlotValue::Entity(camera_ui)],
-            )?;
+        // Each window with a UI has its own camera
+        if let Some(extracted) = world.get_resource::<ExtractedUICameras>() {
+            for (camera_ui, _) in extracted.cameras.iter() {
+                graph.run_sub_graph(
+                    super::draw_ui_graph::NAME,
+                    vec![SlotValue::Entity(*camera_ui)],
+                )?;
+            }
         }
 
         Ok(())
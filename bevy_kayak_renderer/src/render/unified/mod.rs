@@ -1,16 +1,12 @@
 use bevy::{
-    prelude::{Assets, Commands, HandleUntyped, Plugin, Res},
+    prelude::{Assets, HandleUntyped, Plugin},
     reflect::TypeUuid,
     render::{render_phase::DrawFunctions, render_resource::Shader, RenderApp, RenderStage},
-    window::Windows,
 };
 
-use crate::{
-    render::{
-        ui_pass::TransparentUI,
-        unified::pipeline::{DrawUI, QuadMeta, UnifiedPipeline},
-    },
-    WindowSize,
+use crate::render::{
+    ui_pass::TransparentUI,
+    unified::pipeline::{DrawUI, QuadMeta, UnifiedPipeline},
 };
 
 use self::pipeline::ImageBindGroups;
@@ -36,7 +32,6 @@ impl Plugin for UnifiedRenderPlugin {
             .init_resource::<ImageBindGroups>()
             .init_resource::<UnifiedPipeline>()
             .init_resource::<QuadMeta>()
-            .add_system_to_stage(RenderStage::Extract, extract_baseline)
             .add_system_to_stage(RenderStage::Prepare, pipeline::prepare_quads)
             .add_system_to_stage(RenderStage::Queue, pipeline::queue_quads);
 
@@ -50,20 +45,3 @@ impl Plugin for UnifiedRenderPlugin {
             .add(draw_quad);
     }
 }
-
-pub struct Dpi(f32);
-
-pub fn extract_baseline(
-    mut commands: Commands,
-    windows: Res<Windows>,
-    window_size: Res<WindowSize>,
-) {
-    let dpi = if let Some(window) = windows.get_primary() {
-        window.scale_factor() as f32
-    } else {
-        1.0
-    };
-
-    commands.insert_resource(*window_size);
-    commands.insert_resource(Dpi(dpi));
-}
@@ -26,10 +26,11 @@ use bevy::{
         },
         renderer::{RenderDevice, RenderQueue},
         texture::{BevyDefault, GpuImage, Image},
-        view::{ViewUniformOffset, ViewUniforms},
+        view::{ExtractedView, ViewUniformOffset, ViewUniforms},
     },
     sprite::Rect,
     utils::HashMap,
+    window::WindowId,
 };
 use bytemuck::{Pod, Zeroable};
 use kayak_font::{
@@ -37,9 +38,9 @@ use kayak_font::{
     KayakFont,
 };
 
-use super::{Dpi, UNIFIED_SHADER_HANDLE};
-use crate::render::ui_pass::TransparentUI;
-use crate::{Corner, WindowSize};
+use super::UNIFIED_SHADER_HANDLE;
+use crate::render::{ui_pass::TransparentUI, ExtractedUICameras};
+use crate::Corner;
 
 pub struct UnifiedPipeline {
     view_layout: BindGroupLayout,
@@ -326,8 +327,16 @@ pub struct ExtractedQuad {
     pub image: Option<Handle<Image>>,
     pub uv_min: Option<Vec2>,
     pub uv_max: Option<Vec2>,
+    /// A transform applied to the vertices of the quad (in physical pixels)
+    pub transform: Option<Mat4>,
 }
 
+/// The window an extracted quad is drawn in
+///
+/// Quads are only drawn by the UI camera of their window.
+#[derive(Debug, Component, Clone, Copy, PartialEq, Eq)]
+pub struct UIWindow(pub WindowId);
+
 #[repr(C)]
 #[derive(Copy, Clone, Pod, Zeroable)]
 struct QuadVertex {
@@ -446,20 +455,25 @@ pub fn prepare_quads(
         ];
 
         extracted_sprite.vertex_index = i;
+        let transform = extracted_sprite.transform.unwrap_or(Mat4::IDENTITY);
         for (index, vertex_position) in QUAD_VERTEX_POSITIONS.iter().enumerate() {
             let world = Mat4::from_scale_rotation_translation(
                 sprite_rect.size().extend(1.0),
                 Quat::default(),
                 sprite_rect.min.extend(0.0),
             );
-            let final_position = (world * Vec3::from(*vertex_position).extend(1.0)).truncate();
+            let position = (world * Vec3::from(*vertex_position).extend(1.0)).truncate();
+            let final_position = transform.transform_point3(position);
+            // The shader finds the position within the quad by subtracting the rect's minimum from
+            // the vertex position, so the minimum is moved along with each transformed vertex
+            let rect_min = final_position.truncate() - (position.truncate() - sprite_rect.min);
             sprite_meta.vertices.push(QuadVertex {
                 position: final_position.into(),
                 color,
                 uv: uvs[index],
                 pos_size: [
-                    sprite_rect.min.x,
-                    sprite_rect.min.y,
+                    rect_min.x,
+                    rect_min.y,
                     sprite_rect.size().x,
                     sprite_rect.size().y,
                 ],
@@ -477,8 +491,9 @@ pub fn queue_quads(
     mut sprite_meta: ResMut<QuadMeta>,
     view_uniforms: Res<ViewUniforms>,
     quad_pipeline: Res<UnifiedPipeline>,
-    mut extracted_sprites: Query<(Entity, &ExtractedQuad)>,
-    mut views: Query<&mut RenderPhase<TransparentUI>>,
+    mut extracted_sprites: Query<(Entity, &ExtractedQuad, &UIWindow)>,
+    mut views: Query<(Entity, &mut RenderPhase<TransparentUI>)>,
+    cameras: Res<ExtractedUICameras>,
     mut image_bind_groups: ResMut<ImageBindGroups>,
     unified_pipeline: Res<UnifiedPipeline>,
     gpu_images: Res<RenderAssets<Image>>,
@@ -506,8 +521,17 @@ pub fn queue_quads(
         }));
 
         let draw_quad = draw_functions.read().get_id::<DrawUI>().unwrap();
-        for mut transparent_phase in views.iter_mut() {
-            for (entity, quad) in extracted_sprites.iter_mut() {
+        for (view, mut transparent_phase) in views.iter_mut() {
+            let view_window = match cameras.window(view) {
+                Some(window) => window,
+                None => continue,
+            };
+            for (entity, quad, quad_window) in extracted_sprites.iter_mut() {
+                if quad_window.0 != view_window {
+                    // Each window only draws its own UI
+                    continue;
+                }
+
                 if let Some(image_handle) = quad.image.as_ref() {
                     if let Some(gpu_image) = gpu_images.get(&image_handle) {
                         image_bind_groups
@@ -551,8 +575,7 @@ pub struct DrawUI {
         SRes<PipelineCache>,
         SRes<FontTextureCache>,
         SRes<ImageBindGroups>,
-        SRes<WindowSize>,
-        SRes<Dpi>,
+        SQuery<Read<ExtractedView>>,
         SQuery<Read<ViewUniformOffset>>,
         SQuery<Read<ExtractedQuad>>,
     )>,
@@ -580,8 +603,7 @@ impl Draw<TransparentUI> for DrawUI {
             pipelines,
             font_texture_cache,
             image_bind_groups,
-            window_size,
-            dpi,
+            extracted_views,
             views,
             quads,
         ) = self.params.get(world);
@@ -591,7 +613,11 @@ impl Draw<TransparentUI> for DrawUI {
         let extracted_quad = quads.get(item.entity).unwrap();
 
         if extracted_quad.quad_type == UIQuadType::Clip {
-            let window_size = (window_size.0 * dpi.0, window_size.1 * dpi.0);
+            // Clips are bounded by the (physical) size of the window being drawn
+            let window_size = match extracted_views.get(view) {
+                Ok(extracted_view) => (extracted_view.width as f32, extracted_view.height as f32),
+                Err(_) => return,
+            };
             let x = extracted_quad.rect.min.x as u32;
             let y = extracted_quad.rect.min.y as u32;
             let mut width = extracted_quad.rect.width() as u32;
@@ -84,7 +84,8 @@ fn fragment(in: VertexOutput) -> [[location(0)]] vec4<f32> {
         return vec4<f32>(in.color.rgb, a);
     }
     if (quad_type.t == 2) {
-        var bs = min(in.border_radius, min(in.size.x, in.size.y));
+        // The mask is computed at twice the size of the quad, so the radius is doubled too
+        var bs = min(in.border_radius * 2.0, min(in.size.x, in.size.y));
         var mask = sdRoundBox(
             in.pos.xy * 2.0 - (in.size.xy),
             in.size.xy,
@@ -1,5 +1,6 @@
 use std::sync::{Arc, RwLock};
 
+use bevy::{prelude::Component, window::WindowId};
 use kayak_core::context::KayakContext;
 
 /// A wrapper around `KayakContext` to be used in Bevy integrations
@@ -12,14 +13,48 @@ use kayak_core::context::KayakContext;
 ///   // ...
 /// }
 /// ```
+///
+/// # Multiple windows
+///
+/// Inserted as a resource, the context renders the UI of the primary window. The UIs of other
+/// windows are added by spawning an entity with a context (given the window using
+/// [`with_window`](Self::with_window)) as a component, along with a UI camera for that window.
+///
+/// Each context has its own widget tree, and receives only the input of its window, so focus and
+/// hover are tracked separately for each window. Keyboard input goes to the focused window, while
+/// mouse input goes to the window under the cursor.
+///
+/// ```
+/// use bevy::{prelude::*, window::WindowId};
+/// use bevy_kayak_ui::{BevyContext, UICameraBundle};
+///
+/// fn setup_second_window(mut commands: Commands) {
+///   # let window = WindowId::new();
+///   commands.spawn_bundle(UICameraBundle::new().with_window(window));
+///
+///   let context = BevyContext::new(|context| {
+///     render! {
+///       <>
+///         // ...
+///       </>
+///     }
+///   });
+///   commands.spawn().insert(context.with_window(window));
+/// }
+/// ```
+#[derive(Component)]
 pub struct BevyContext {
     pub kayak_context: Arc<RwLock<KayakContext>>,
+    window: WindowId,
 }
 
 impl BevyContext {
     /// Create a new `BevyContext`
     ///
-    /// This takes a function that will setup the `KayakContext` and its widget tree.
+    /// This takes a function that will setup the `KayakContext` and its widget tree. With the
+    /// `clipboard` feature enabled, the context is first given a
+    /// [`PlatformClipboard`](crate::PlatformClipboard), which that function can replace using
+    /// `KayakContext::set_clipboard`.
     ///
     /// ```
     /// use bevy::prelude::*;
@@ -41,11 +76,29 @@ impl BevyContext {
         let kayak_context = Arc::new(RwLock::new(KayakContext::new()));
 
         if let Ok(mut kayak_context) = kayak_context.write() {
+            #[cfg(feature = "clipboard")]
+            kayak_context.set_clipboard(Some(Box::new(crate::PlatformClipboard)));
             f(&mut kayak_context);
             kayak_context.widget_manager.dirty(true);
         }
 
-        Self { kayak_context }
+        Self {
+            kayak_context,
+            window: WindowId::primary(),
+        }
+    }
+
+    /// Renders this context in (and feeds it the input of) the given window
+    ///
+    /// By default, the context belongs to the primary window.
+    pub fn with_window(mut self, window: WindowId) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Returns the window this context is rendered in
+    pub fn window(&self) -> WindowId {
+        self.window
     }
 
     /// Returns true if the cursor is currently over a valid widget
@@ -0,0 +1,42 @@
+use std::cell::RefCell;
+
+use kayak_core::{ClipboardError, ClipboardProvider};
+
+thread_local! {
+    /// The platform clipboard, which is kept open since some platforms (such as X11) only keep
+    /// the copied text around for as long as it is
+    static CLIPBOARD: RefCell<Option<arboard::Clipboard>> = RefCell::new(None);
+}
+
+/// A [`ClipboardProvider`] backed by the clipboard of the platform
+///
+/// This is installed in every [`BevyContext`](crate::BevyContext) when the `clipboard` feature
+/// is enabled (as it is by default).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PlatformClipboard;
+
+impl PlatformClipboard {
+    fn with_clipboard<T>(
+        f: impl FnOnce(&mut arboard::Clipboard) -> Result<T, arboard::Error>,
+    ) -> Result<T, ClipboardError> {
+        CLIPBOARD.with(|clipboard| {
+            let mut clipboard = clipboard.borrow_mut();
+            if clipboard.is_none() {
+                *clipboard =
+                    Some(arboard::Clipboard::new().map_err(|_| ClipboardError::Unavailable)?);
+            }
+            let clipboard = clipboard.as_mut().unwrap();
+            f(clipboard).map_err(|error| ClipboardError::Failed(error.to_string()))
+        })
+    }
+}
+
+impl ClipboardProvider for PlatformClipboard {
+    fn get_text(&mut self) -> Result<String, ClipboardError> {
+        Self::with_clipboard(|clipboard| clipboard.get_text())
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<(), ClipboardError> {
+        Self::with_clipboard(|clipboard| clipboard.set_text(text.to_string()))
+    }
+}
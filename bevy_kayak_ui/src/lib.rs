@@ -1,16 +1,25 @@
+use std::sync::{Arc, RwLock};
+
 use bevy::{
+    ecs::system::SystemState,
     input::{
         keyboard::KeyboardInput,
         mouse::{MouseButtonInput, MouseScrollUnit, MouseWheel},
         ElementState,
     },
     math::Vec2,
-    prelude::{EventReader, IntoExclusiveSystem, MouseButton, Plugin, Res, World},
+    prelude::{EventReader, IntoExclusiveSystem, MouseButton, Plugin, Res, Time, World},
     render::color::Color,
-    window::{CursorMoved, ReceivedCharacter, WindowCreated, WindowResized, Windows},
+    utils::HashMap,
+    window::{
+        CursorLeft, CursorMoved, ReceivedCharacter, WindowCreated, WindowFocused, WindowId,
+        WindowResized, Windows,
+    },
 };
 
 mod bevy_context;
+#[cfg(feature = "clipboard")]
+mod clipboard;
 mod cursor;
 mod key;
 mod render;
@@ -18,8 +27,10 @@ mod render;
 use crate::cursor::convert_cursor_icon;
 pub use bevy_context::BevyContext;
 pub use bevy_kayak_renderer::camera::*;
-use kayak_core::{bind, Binding, InputEvent, MutableBound};
-pub use render::font::FontMapping;
+#[cfg(feature = "clipboard")]
+pub use clipboard::PlatformClipboard;
+use kayak_core::{bind, context::KayakContext, Binding, InputEvent, MutableBound};
+pub use render::font::{EmojiMapping, FontMapping};
 pub use render::image::ImageManager;
 
 #[derive(Default)]
@@ -28,6 +39,7 @@ pub struct BevyKayakUIPlugin;
 impl Plugin for BevyKayakUIPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.insert_resource(bind(WindowSize::default()))
+            .init_resource::<InputRouting>()
             .add_plugin(bevy_kayak_renderer::BevyKayakRendererPlugin)
             .add_plugin(render::BevyKayakUIExtractPlugin)
             .add_system(update_window_size)
@@ -40,108 +52,209 @@ pub(crate) fn to_bevy_color(color: &kayak_core::color::Color) -> Color {
     Color::rgba(color.r, color.g, color.b, color.a)
 }
 
+/// Returns the window and `KayakContext` of every UI: the given primary context (taken from the
+/// `BevyContext` resource), followed by those spawned as components for other windows
+fn collect_contexts(
+    world: &mut World,
+    primary: Option<&BevyContext>,
+) -> Vec<(WindowId, Arc<RwLock<KayakContext>>)> {
+    let mut contexts: Vec<_> = primary
+        .map(|context| (context.window(), context.kayak_context.clone()))
+        .into_iter()
+        .collect();
+    let mut query = world.query::<&BevyContext>();
+    contexts.extend(
+        query
+            .iter(world)
+            .map(|context| (context.window(), context.kayak_context.clone())),
+    );
+    contexts
+}
+
 pub fn update(world: &mut World) {
-    if let Some(bevy_context) = world.remove_resource::<BevyContext>() {
-        if let Ok(mut context) = bevy_context.kayak_context.write() {
+    let bevy_context = world.remove_resource::<BevyContext>();
+    for (window_id, kayak_context) in collect_contexts(world, bevy_context.as_ref()) {
+        if let Ok(mut context) = kayak_context.write() {
+            if let Some(time) = world.get_resource::<Time>() {
+                context.set_delta_time(time.delta_seconds());
+            }
+            // Widgets reading the window size are only re-rendered when it actually changes
+            if let Some(window) = world
+                .get_resource::<Windows>()
+                .and_then(|windows| windows.get(window_id))
+            {
+                context.set_window_size(window.width(), window.height());
+            }
             context.set_global(std::mem::take(world));
             context.render();
             *world = context.remove_global::<World>().unwrap();
 
             if let Some(ref mut windows) = world.get_resource_mut::<Windows>() {
-                if let Some(window) = windows.get_primary_mut() {
+                if let Some(window) = windows.get_mut(window_id) {
                     window.set_cursor_icon(convert_cursor_icon(context.cursor_icon()));
                 }
             }
         }
+    }
 
+    if let Some(bevy_context) = bevy_context {
         world.insert_resource(bevy_context);
     }
 }
 
+/// Tracks which window each kind of input is routed to
+#[derive(Default)]
+struct InputRouting {
+    /// The window the cursor was last moved within, which receives mouse input
+    hovered: Option<WindowId>,
+    /// The window with keyboard focus, which receives keyboard input
+    focused: Option<WindowId>,
+    /// The window the left mouse button was pressed in, which also receives its release
+    pressed: Option<WindowId>,
+}
+
 pub fn process_events(world: &mut World) {
-    let window_size = if let Some(windows) = world.get_resource::<Windows>() {
-        if let Some(window) = windows.get_primary() {
-            Vec2::new(window.width(), window.height())
-        } else {
-            panic!("Couldn't find primary window!");
-        }
-    } else {
-        panic!("Couldn't find primary window!");
-    };
+    let bevy_context = world.remove_resource::<BevyContext>();
+    let contexts = collect_contexts(world, bevy_context.as_ref());
 
-    if let Some(bevy_context) = world.remove_resource::<BevyContext>() {
-        if let Ok(mut context) = bevy_context.kayak_context.write() {
-            let mut input_events = Vec::new();
+    let window_heights: HashMap<WindowId, f32> = world
+        .get_resource::<Windows>()
+        .map(|windows| {
+            windows
+                .iter()
+                .map(|window| (window.id(), window.height()))
+                .collect()
+        })
+        .unwrap_or_default();
 
-            context.set_global(std::mem::take(world));
-            context.query_world::<(
-                EventReader<CursorMoved>,
-                EventReader<MouseButtonInput>,
-                EventReader<MouseWheel>,
-                EventReader<ReceivedCharacter>,
-                EventReader<KeyboardInput>,
-            ), _, _>(
-                |(
-                    mut cursor_moved_events,
-                    mut mouse_button_input_events,
-                    mut mouse_wheel_events,
-                    mut char_input_events,
-                    mut keyboard_input_events,
-                )| {
-                    if let Some(event) = cursor_moved_events.iter().last() {
-                        // Currently, we can only handle a single MouseMoved event at a time so everything but the last needs to be skipped
-                        input_events.push(InputEvent::MouseMoved((
-                            event.position.x as f32,
-                            window_size.y - event.position.y as f32,
-                        )));
-                    }
+    let mut routing = world.remove_resource::<InputRouting>().unwrap_or_default();
+    let mut input_events: HashMap<WindowId, Vec<InputEvent>> = HashMap::default();
+    {
+        let mut system_state = SystemState::<(
+            EventReader<WindowFocused>,
+            EventReader<CursorMoved>,
+            EventReader<CursorLeft>,
+            EventReader<MouseButtonInput>,
+            EventReader<MouseWheel>,
+            EventReader<ReceivedCharacter>,
+            EventReader<KeyboardInput>,
+        )>::new(world);
+        let (
+            mut window_focused_events,
+            mut cursor_moved_events,
+            mut cursor_left_events,
+            mut mouse_button_input_events,
+            mut mouse_wheel_events,
+            mut char_input_events,
+            mut keyboard_input_events,
+        ) = system_state.get_mut(world);
 
-                    for event in mouse_button_input_events.iter() {
-                        match event.button {
-                            MouseButton::Left => {
-                                if event.state == ElementState::Pressed {
-                                    input_events.push(InputEvent::MouseLeftPress);
-                                } else if event.state == ElementState::Released {
-                                    input_events.push(InputEvent::MouseLeftRelease);
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
+        for event in window_focused_events.iter() {
+            if event.focused {
+                routing.focused = Some(event.id);
+            } else if routing.focused == Some(event.id) {
+                routing.focused = None;
+            }
+        }
 
-                    for MouseWheel { x, y, unit } in mouse_wheel_events.iter() {
-                        input_events.push(InputEvent::Scroll {
-                            dx: *x,
-                            dy: *y,
-                            is_line: matches!(unit, MouseScrollUnit::Line),
-                        })
-                    }
+        // Currently, we can only handle a single MouseMoved event at a time so everything but the
+        // last one in each window needs to be skipped
+        let mut cursor_positions: HashMap<WindowId, Vec2> = HashMap::default();
+        for event in cursor_moved_events.iter() {
+            cursor_positions.insert(event.id, event.position);
+            routing.hovered = Some(event.id);
+        }
+        for (window_id, position) in cursor_positions {
+            let height = window_heights.get(&window_id).copied().unwrap_or_default();
+            input_events
+                .entry(window_id)
+                .or_default()
+                .push(InputEvent::MouseMoved((position.x, height - position.y)));
+        }
+        for event in cursor_left_events.iter() {
+            if routing.hovered == Some(event.id) {
+                routing.hovered = None;
+            }
+        }
 
-                    for event in char_input_events.iter() {
-                        input_events.push(InputEvent::CharEvent { c: event.char });
+        let pointer_window = routing
+            .hovered
+            .or(routing.focused)
+            .unwrap_or_else(WindowId::primary);
+        for event in mouse_button_input_events.iter() {
+            match event.button {
+                MouseButton::Left => {
+                    if event.state == ElementState::Pressed {
+                        routing.pressed = Some(pointer_window);
+                        input_events
+                            .entry(pointer_window)
+                            .or_default()
+                            .push(InputEvent::MouseLeftPress);
+                    } else if event.state == ElementState::Released {
+                        // Drags end in the window they started in, even if released over another
+                        let window_id = routing.pressed.take().unwrap_or(pointer_window);
+                        input_events
+                            .entry(window_id)
+                            .or_default()
+                            .push(InputEvent::MouseLeftRelease);
                     }
+                }
+                _ => {}
+            }
+        }
 
-                    for event in keyboard_input_events.iter() {
-                        if let Some(key_code) = event.key_code {
-                            let kayak_key_code = key::convert_virtual_key_code(key_code);
-                            input_events.push(InputEvent::Keyboard {
-                                key: kayak_key_code,
-                                is_pressed: matches!(event.state, ElementState::Pressed),
-                            });
-                        }
-                    }
-                },
-            );
+        for MouseWheel { x, y, unit } in mouse_wheel_events.iter() {
+            input_events
+                .entry(pointer_window)
+                .or_default()
+                .push(InputEvent::Scroll {
+                    dx: *x,
+                    dy: *y,
+                    is_line: matches!(unit, MouseScrollUnit::Line),
+                });
+        }
 
-            context.process_events(input_events);
-            *world = context.remove_global::<World>().unwrap()
+        for event in char_input_events.iter() {
+            input_events
+                .entry(event.id)
+                .or_default()
+                .push(InputEvent::CharEvent { c: event.char });
         }
 
+        let keyboard_window = routing.focused.unwrap_or_else(WindowId::primary);
+        for event in keyboard_input_events.iter() {
+            if let Some(key_code) = event.key_code {
+                let kayak_key_code = key::convert_virtual_key_code(key_code);
+                input_events
+                    .entry(keyboard_window)
+                    .or_default()
+                    .push(InputEvent::Keyboard {
+                        key: kayak_key_code,
+                        is_pressed: matches!(event.state, ElementState::Pressed),
+                    });
+            }
+        }
+    }
+    world.insert_resource(routing);
+
+    for (window_id, kayak_context) in contexts {
+        if let Ok(mut context) = kayak_context.write() {
+            let events = input_events.remove(&window_id).unwrap_or_default();
+            context.set_global(std::mem::take(world));
+            context.process_events(events);
+            *world = context.remove_global::<World>().unwrap();
+        }
+    }
+
+    if let Some(bevy_context) = bevy_context {
         world.insert_resource(bevy_context);
     }
 }
 
 /// Tracks the bevy window size.
+///
+/// This is the size of the most recently resized window. With multiple windows, widgets should use
+/// `KayakContextRef::window_size` instead, which is the size of the window their UI is rendered in.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct WindowSize(pub f32, pub f32);
 
@@ -0,0 +1,57 @@
+use bevy::{prelude::Handle, render::texture::Image, utils::HashMap};
+
+/// A resource used to provide color images for emoji that aren't contained in a `KayakFont`
+///
+/// Any emoji without a registered image is rendered using the font's missing glyph instead.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_kayak_ui::EmojiMapping;
+///
+/// fn setup_ui(
+///   asset_server: Res<AssetServer>,
+///   mut emoji_mapping: ResMut<EmojiMapping>
+/// ) {
+///   emoji_mapping.add("👋", asset_server.load("emoji/waving_hand.png"));
+///   emoji_mapping.add("🏳️‍🌈", asset_server.load("emoji/rainbow_flag.png"));
+///   // ...
+/// }
+/// ```
+#[derive(Default)]
+pub struct EmojiMapping {
+    images: HashMap<String, Handle<Image>>,
+}
+
+impl EmojiMapping {
+    /// Add an image for the given emoji
+    ///
+    /// The emoji should be the full grapheme cluster (including any joiners, modifiers, or
+    /// variation selectors) as it appears in the text.
+    pub fn add(&mut self, emoji: impl Into<String>, handle: Handle<Image>) {
+        self.images.insert(emoji.into(), handle);
+    }
+
+    /// Remove the image for the given emoji
+    pub fn remove(&mut self, emoji: &str) -> Option<Handle<Image>> {
+        self.images.remove(emoji)
+    }
+
+    /// Get the image for the given emoji
+    ///
+    /// If there's no image for the exact emoji, this falls back to the image for the emoji without
+    /// its variation selectors (i.e. `"❤️"` will fall back to `"❤"`).
+    pub fn get(&self, emoji: &str) -> Option<Handle<Image>> {
+        self.images
+            .get(emoji)
+            .or_else(|| {
+                let stripped: String = emoji
+                    .chars()
+                    .filter(|c| !matches!(c, '\u{FE0E}' | '\u{FE0F}'))
+                    .collect();
+                self.images.get(&stripped)
+            })
+            .cloned()
+    }
+}
@@ -1,9 +1,10 @@
 use bevy::{
     math::Vec2,
     prelude::{Assets, Res},
+    render::color::Color,
     sprite::Rect,
 };
-use kayak_core::render_primitive::RenderPrimitive;
+use kayak_core::{render_primitive::RenderPrimitive, text_range_rects};
 use kayak_font::KayakFont;
 
 use crate::to_bevy_color;
@@ -12,26 +13,29 @@ use bevy_kayak_renderer::{
     Corner,
 };
 
-use super::font_mapping::FontMapping;
+use super::{emoji_mapping::EmojiMapping, font_mapping::FontMapping};
 
 pub fn extract_texts(
     render_primitive: &RenderPrimitive,
     fonts: &Res<Assets<KayakFont>>,
     font_mapping: &Res<FontMapping>,
+    emoji_mapping: &Res<EmojiMapping>,
     _dpi: f32,
 ) -> Vec<ExtractQuadBundle> {
     let mut extracted_texts = Vec::new();
-    let (background_color, text_layout, layout, font, properties) = match render_primitive {
-        RenderPrimitive::Text {
-            color,
-            text_layout,
-            layout,
-            font,
-            properties,
-            ..
-        } => (color, text_layout, layout, font, *properties),
-        _ => panic!(""),
-    };
+    let (background_color, highlights, text_layout, layout, font, properties) =
+        match render_primitive {
+            RenderPrimitive::Text {
+                color,
+                highlights,
+                text_layout,
+                layout,
+                font,
+                properties,
+                ..
+            } => (color, highlights, text_layout, layout, font, *properties),
+            _ => panic!(""),
+        };
 
     let font_handle = font_mapping.get_handle(font.clone()).unwrap();
     let font = match fonts.get(font_handle.clone()) {
@@ -39,6 +43,34 @@ pub fn extract_texts(
         None => return Vec::new(),
     };
 
+    // Highlights are drawn first so that they appear behind the glyphs
+    let top_left = Vec2::new(layout.posx, layout.posy);
+    for (range, color) in highlights {
+        for rect in text_range_rects(text_layout, range.clone()) {
+            let position = top_left + Vec2::new(rect.posx, rect.posy);
+            extracted_texts.push(ExtractQuadBundle {
+                extracted_quad: ExtractedQuad {
+                    rect: Rect {
+                        min: position,
+                        max: position + Vec2::new(rect.width, rect.height),
+                    },
+                    color: to_bevy_color(color),
+                    vertex_index: 0,
+                    char_id: 0,
+                    z_index: layout.z_index,
+                    font_handle: None,
+                    quad_type: UIQuadType::Quad,
+                    type_index: 0,
+                    border_radius: Corner::default(),
+                    image: None,
+                    uv_max: None,
+                    uv_min: None,
+                    transform: None,
+                },
+            });
+        }
+    }
+
     let base_position = Vec2::new(layout.posx, layout.posy + properties.font_size);
 
     for glyph_rect in text_layout.glyphs() {
@@ -64,9 +96,62 @@ pub fn extract_texts(
                 image: None,
                 uv_max: None,
                 uv_min: None,
+                transform: None,
             },
         });
     }
 
+    // Emojis missing from the font are rendered with their mapped image, or with the font's missing
+    // glyph if none was provided
+    for emoji_rect in text_layout.emojis() {
+        if let Some(image) = emoji_mapping.get(&emoji_rect.content) {
+            let position = top_left + Vec2::from(emoji_rect.position);
+            extracted_texts.push(ExtractQuadBundle {
+                extracted_quad: ExtractedQuad {
+                    rect: Rect {
+                        min: position,
+                        max: position + Vec2::from(emoji_rect.size),
+                    },
+                    // The image keeps its own colors, but fades along with the text (the alpha
+                    // of the text color already includes the opacity of its widget)
+                    color: Color::rgba(1.0, 1.0, 1.0, background_color.a),
+                    vertex_index: 0,
+                    char_id: 0,
+                    z_index: layout.z_index,
+                    font_handle: None,
+                    quad_type: UIQuadType::Image,
+                    type_index: 0,
+                    border_radius: Corner::default(),
+                    image: Some(image.clone_weak()),
+                    uv_max: None,
+                    uv_min: None,
+                    transform: None,
+                },
+            });
+        } else if let Some(glyph_rect) = &emoji_rect.fallback {
+            let position = base_position + Vec2::from(glyph_rect.position);
+            extracted_texts.push(ExtractQuadBundle {
+                extracted_quad: ExtractedQuad {
+                    font_handle: Some(font_handle.clone()),
+                    rect: Rect {
+                        min: position,
+                        max: position + Vec2::from(glyph_rect.size),
+                    },
+                    color: to_bevy_color(background_color),
+                    vertex_index: 0,
+                    char_id: font.get_char_id(glyph_rect.content).unwrap(),
+                    z_index: layout.z_index,
+                    quad_type: UIQuadType::Text,
+                    type_index: 0,
+                    border_radius: Corner::default(),
+                    image: None,
+                    uv_max: None,
+                    uv_min: None,
+                    transform: None,
+                },
+            });
+        }
+    }
+
     extracted_texts
 }
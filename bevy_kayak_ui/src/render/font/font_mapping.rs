@@ -86,20 +86,23 @@ impl FontMapping {
             .and_then(|font_id| Some(font_id.clone()))
     }
 
+    /// Adds the fonts that have loaded since the last call to each of the given contexts
     pub(crate) fn add_loaded_to_kayak(
         &mut self,
         fonts: &Res<Assets<KayakFont>>,
-        context: &BevyContext,
+        contexts: &[&BevyContext],
     ) {
-        if let Ok(mut kayak_context) = context.kayak_context.write() {
-            let new_fonts = self.new_fonts.drain(..).collect::<Vec<_>>();
-            for font_key in new_fonts {
-                let font_handle = self.font_handles.get(&font_key).unwrap();
-                if let Some(font) = fonts.get(font_handle) {
-                    kayak_context.set_asset(font_key, font.clone());
-                } else {
-                    self.new_fonts.push(font_key);
+        let new_fonts = self.new_fonts.drain(..).collect::<Vec<_>>();
+        for font_key in new_fonts {
+            let font_handle = self.font_handles.get(&font_key).unwrap();
+            if let Some(font) = fonts.get(font_handle) {
+                for context in contexts {
+                    if let Ok(mut kayak_context) = context.kayak_context.write() {
+                        kayak_context.set_asset(font_key.clone(), font.clone());
+                    }
                 }
+            } else {
+                self.new_fonts.push(font_key);
             }
         }
     }
@@ -1,11 +1,13 @@
-use bevy::prelude::{Assets, Plugin, Res, ResMut};
+use bevy::prelude::{Added, Assets, Plugin, Query, Res, ResMut};
 use kayak_font::KayakFont;
 
+mod emoji_mapping;
 mod extract;
 mod font_mapping;
 
 use crate::BevyContext;
 
+pub use emoji_mapping::*;
 pub use extract::extract_texts;
 pub use font_mapping::*;
 
@@ -15,6 +17,7 @@ pub struct TextRendererPlugin;
 impl Plugin for TextRendererPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.init_resource::<FontMapping>()
+            .init_resource::<EmojiMapping>()
             .add_system(process_loaded_fonts);
     }
 }
@@ -23,11 +26,23 @@ fn process_loaded_fonts(
     mut font_mapping: ResMut<FontMapping>,
     fonts: Res<Assets<KayakFont>>,
     bevy_context: Option<Res<BevyContext>>,
+    window_contexts: Query<&BevyContext>,
+    added_window_contexts: Query<(), Added<BevyContext>>,
 ) {
-    if let Some(context) = bevy_context {
-        if context.is_added() {
-            font_mapping.mark_all_as_new();
-        }
-        font_mapping.add_loaded_to_kayak(&fonts, &context);
+    let is_added = bevy_context
+        .as_ref()
+        .map_or(false, |context| context.is_added());
+    if is_added || !added_window_contexts.is_empty() {
+        // New contexts need every font, including those already given to the others
+        font_mapping.mark_all_as_new();
+    }
+
+    let contexts: Vec<&BevyContext> = bevy_context
+        .as_deref()
+        .into_iter()
+        .chain(window_contexts.iter())
+        .collect();
+    if !contexts.is_empty() {
+        font_mapping.add_loaded_to_kayak(&fonts, &contexts);
     }
 }
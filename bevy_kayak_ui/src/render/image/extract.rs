@@ -12,12 +12,13 @@ pub fn extract_images(
     image_manager: &Res<ImageManager>,
     dpi: f32,
 ) -> Vec<ExtractQuadBundle> {
-    let (border_radius, layout, handle) = match render_command {
+    let (border_radius, layout, handle, uv) = match render_command {
         RenderPrimitive::Image {
             border_radius,
             layout,
             handle,
-        } => (*border_radius, layout, handle),
+            uv,
+        } => (*border_radius, layout, handle, *uv),
         _ => panic!(""),
     };
 
@@ -43,8 +44,11 @@ pub fn extract_images(
             image: image_manager
                 .get_handle(handle)
                 .and_then(|a| Some(a.clone_weak())),
-            uv_max: None,
-            uv_min: None,
+            // The region is given from the top-left corner, while textures are sampled from the
+            // bottom-left one
+            uv_max: uv.map(|(_, min_y, max_x, _)| Vec2::new(max_x, 1.0 - min_y)),
+            uv_min: uv.map(|(min_x, _, _, max_y)| Vec2::new(min_x, 1.0 - max_y)),
+            transform: None,
         },
     }]
 }
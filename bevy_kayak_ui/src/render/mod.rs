@@ -1,16 +1,16 @@
-use crate::{BevyContext, FontMapping, ImageManager};
+use crate::{BevyContext, EmojiMapping, FontMapping, ImageManager};
 use bevy::{
-    math::Vec2,
-    prelude::{Assets, Commands, Plugin, Res},
+    math::{Mat4, Vec2},
+    prelude::{Assets, Commands, Plugin, Query, Res},
     render::{color::Color, texture::Image, RenderApp, RenderStage},
     sprite::Rect,
     window::Windows,
 };
 use bevy_kayak_renderer::{
-    render::unified::pipeline::{ExtractQuadBundle, ExtractedQuad, UIQuadType},
+    render::unified::pipeline::{ExtractQuadBundle, ExtractedQuad, UIQuadType, UIWindow},
     Corner,
 };
-use kayak_core::render_primitive::RenderPrimitive;
+use kayak_core::{render_primitive::RenderPrimitive, styles::TransformMatrix};
 use kayak_font::KayakFont;
 
 pub mod font;
@@ -33,77 +33,132 @@ impl Plugin for BevyKayakUIExtractPlugin {
 pub fn extract(
     mut commands: Commands,
     context: Option<Res<BevyContext>>,
+    window_contexts: Query<&BevyContext>,
     fonts: Res<Assets<KayakFont>>,
     font_mapping: Res<FontMapping>,
+    emoji_mapping: Res<EmojiMapping>,
     image_manager: Res<ImageManager>,
     images: Res<Assets<Image>>,
     windows: Res<Windows>,
 ) {
-    if context.is_none() {
-        return;
-    }
-
-    let context = context.unwrap();
-
-    let render_primitives = if let Ok(context) = context.kayak_context.read() {
-        context.widget_manager.build_render_primitives()
-    } else {
-        vec![]
-    };
+    for context in context.as_deref().into_iter().chain(window_contexts.iter()) {
+        let window = context.window();
+        let render_primitives = if let Ok(context) = context.kayak_context.read() {
+            context.widget_manager.build_render_primitives()
+        } else {
+            vec![]
+        };
 
-    // dbg!(&render_primitives);
+        // dbg!(&render_primitives);
 
-    let dpi = if let Some(window) = windows.get_primary() {
-        window.scale_factor() as f32
-    } else {
-        1.0
-    };
+        let dpi = if let Some(window) = windows.get(window) {
+            window.scale_factor() as f32
+        } else {
+            1.0
+        };
 
-    let mut extracted_quads = Vec::new();
-    for render_primitive in render_primitives {
-        match render_primitive {
-            RenderPrimitive::Text { .. } => {
-                let text_quads = font::extract_texts(&render_primitive, &fonts, &font_mapping, dpi);
-                extracted_quads.extend(text_quads);
-            }
-            RenderPrimitive::Image { .. } => {
-                let image_quads = image::extract_images(&render_primitive, &image_manager, dpi);
-                extracted_quads.extend(image_quads);
-            }
-            RenderPrimitive::Quad { .. } => {
-                let quad_quads = quad::extract_quads(&render_primitive, 1.0);
-                extracted_quads.extend(quad_quads);
-            }
-            RenderPrimitive::NinePatch { .. } => {
-                let nine_patch_quads =
-                    nine_patch::extract_nine_patch(&render_primitive, &image_manager, &images, dpi);
-                extracted_quads.extend(nine_patch_quads);
-            }
-            RenderPrimitive::Clip { layout } => {
-                extracted_quads.push(ExtractQuadBundle {
-                    extracted_quad: ExtractedQuad {
-                        rect: Rect {
-                            min: Vec2::new(layout.posx, layout.posy) * dpi,
-                            max: Vec2::new(layout.posx + layout.width, layout.posy + layout.height)
-                                * dpi,
+        let mut extracted_quads = Vec::new();
+        let mut transform = None;
+        for render_primitive in render_primitives {
+            let first_quad = extracted_quads.len();
+            match render_primitive {
+                RenderPrimitive::Text { .. } => {
+                    let text_quads = font::extract_texts(
+                        &render_primitive,
+                        &fonts,
+                        &font_mapping,
+                        &emoji_mapping,
+                        dpi,
+                    );
+                    extracted_quads.extend(text_quads);
+                }
+                RenderPrimitive::Image { .. } => {
+                    let image_quads = image::extract_images(&render_primitive, &image_manager, dpi);
+                    extracted_quads.extend(image_quads);
+                }
+                RenderPrimitive::Quad { .. } => {
+                    let quad_quads = quad::extract_quads(&render_primitive, 1.0);
+                    extracted_quads.extend(quad_quads);
+                }
+                RenderPrimitive::NinePatch { .. } => {
+                    let nine_patch_quads = nine_patch::extract_nine_patch(
+                        &render_primitive,
+                        &image_manager,
+                        &images,
+                        dpi,
+                    );
+                    extracted_quads.extend(nine_patch_quads);
+                }
+                RenderPrimitive::Clip { layout } => {
+                    extracted_quads.push(ExtractQuadBundle {
+                        extracted_quad: ExtractedQuad {
+                            rect: Rect {
+                                min: Vec2::new(layout.posx, layout.posy) * dpi,
+                                max: Vec2::new(
+                                    layout.posx + layout.width,
+                                    layout.posy + layout.height,
+                                ) * dpi,
+                            },
+                            color: Color::default(),
+                            vertex_index: 0,
+                            char_id: 0,
+                            z_index: layout.z_index,
+                            font_handle: None,
+                            quad_type: UIQuadType::Clip,
+                            type_index: 0,
+                            border_radius: Corner::default(),
+                            image: None,
+                            uv_min: None,
+                            uv_max: None,
+                            transform: None,
                         },
-                        color: Color::default(),
-                        vertex_index: 0,
-                        char_id: 0,
-                        z_index: layout.z_index,
-                        font_handle: None,
-                        quad_type: UIQuadType::Clip,
-                        type_index: 0,
-                        border_radius: Corner::default(),
-                        image: None,
-                        uv_min: None,
-                        uv_max: None,
-                    },
-                });
+                    });
+                }
+                RenderPrimitive::Transform { matrix } => {
+                    transform = if matrix.is_identity() {
+                        None
+                    } else {
+                        Some(to_bevy_transform(&matrix, dpi))
+                    };
+                }
+                _ => {}
+            }
+
+            for bundle in &mut extracted_quads[first_quad..] {
+                // Clips are applied as (axis-aligned) scissor rects, which are already transformed
+                if bundle.extracted_quad.quad_type != UIQuadType::Clip {
+                    bundle.extracted_quad.transform = transform;
+                }
             }
-            _ => {}
         }
+
+        // Each quad is only drawn by the UI camera of its window
+        commands.spawn_batch(
+            extracted_quads
+                .into_iter()
+                .map(move |bundle| (bundle.extracted_quad, UIWindow(window))),
+        );
     }
+}
 
-    commands.spawn_batch(extracted_quads);
+/// Converts a transform matrix (in logical pixels) to a matrix in physical pixels
+fn to_bevy_transform(matrix: &TransformMatrix, dpi: f32) -> Mat4 {
+    Mat4::from_cols_array(&[
+        matrix.a,
+        matrix.b,
+        0.0,
+        0.0,
+        matrix.c,
+        matrix.d,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        1.0,
+        0.0,
+        matrix.tx * dpi,
+        matrix.ty * dpi,
+        0.0,
+        1.0,
+    ])
 }
@@ -9,7 +9,7 @@ use bevy_kayak_renderer::{
     render::unified::pipeline::{ExtractQuadBundle, ExtractedQuad, UIQuadType},
     Corner,
 };
-use kayak_core::render_primitive::RenderPrimitive;
+use kayak_core::{render_primitive::RenderPrimitive, styles::SliceCenter};
 
 pub fn extract_nine_patch(
     render_primitive: &RenderPrimitive,
@@ -19,12 +19,13 @@ pub fn extract_nine_patch(
 ) -> Vec<ExtractQuadBundle> {
     let mut extracted_quads = Vec::new();
 
-    let (layout, handle, border) = match render_primitive {
+    let (layout, handle, border, center) = match render_primitive {
         RenderPrimitive::NinePatch {
             layout,
             handle,
             border,
-        } => (layout, handle, border),
+            center,
+        } => (layout, handle, border, *center),
         _ => panic!(""),
     };
 
@@ -64,6 +65,7 @@ pub fn extract_nine_patch(
         image: image_handle,
         uv_max: None,
         uv_min: None,
+        transform: None,
     };
 
     // TOP
@@ -226,24 +228,55 @@ pub fn extract_nine_patch(
     let middle_pos_y = layout.posy + border.top;
     let middle_size_x = layout.width - (border.left + border.right);
     let middle_size_y = layout.height - (border.top + border.bottom);
-    let middle_quad = ExtractQuadBundle {
-        extracted_quad: ExtractedQuad {
-            rect: Rect {
-                min: Vec2::new(middle_pos_x, middle_pos_y),
-                max: Vec2::new(middle_pos_x + middle_size_x, middle_pos_y + middle_size_y),
+    let middle_uv_min = Vec2::new(border.left / image_size.x, border.top / image_size.y);
+    let middle_uv_max = Vec2::new(
+        (image_size.x - border.right) / image_size.x,
+        (image_size.y - border.bottom) / image_size.y,
+    );
+    let tile_size = Vec2::new(
+        image_size.x - (border.left + border.right),
+        image_size.y - (border.top + border.bottom),
+    );
+
+    if center == SliceCenter::Tile && tile_size.x > 0.0 && tile_size.y > 0.0 {
+        // Repeat the center at its original size, cutting off the last tile along each axis
+        let mut tile_y = 0.0;
+        while tile_y < middle_size_y {
+            let height = tile_size.y.min(middle_size_y - tile_y);
+            let mut tile_x = 0.0;
+            while tile_x < middle_size_x {
+                let width = tile_size.x.min(middle_size_x - tile_x);
+                let min = Vec2::new(middle_pos_x + tile_x, middle_pos_y + tile_y);
+                let fraction = Vec2::new(width, height) / tile_size;
+                extracted_quads.push(ExtractQuadBundle {
+                    extracted_quad: ExtractedQuad {
+                        rect: Rect {
+                            min,
+                            max: min + Vec2::new(width, height),
+                        },
+                        uv_min: Some(middle_uv_min),
+                        uv_max: Some(middle_uv_min + (middle_uv_max - middle_uv_min) * fraction),
+                        ..extracted_quad_template.clone()
+                    },
+                });
+                tile_x += tile_size.x;
+            }
+            tile_y += tile_size.y;
+        }
+    } else {
+        let middle_quad = ExtractQuadBundle {
+            extracted_quad: ExtractedQuad {
+                rect: Rect {
+                    min: Vec2::new(middle_pos_x, middle_pos_y),
+                    max: Vec2::new(middle_pos_x + middle_size_x, middle_pos_y + middle_size_y),
+                },
+                uv_min: Some(middle_uv_min),
+                uv_max: Some(middle_uv_max),
+                ..extracted_quad_template.clone()
             },
-            uv_min: Some(Vec2::new(
-                border.left / image_size.x,
-                border.top / image_size.y,
-            )),
-            uv_max: Some(Vec2::new(
-                (image_size.x - border.right) / image_size.x,
-                (image_size.y - border.bottom) / image_size.y,
-            )),
-            ..extracted_quad_template.clone()
-        },
-    };
-    extracted_quads.push(middle_quad);
+        };
+        extracted_quads.push(middle_quad);
+    }
 
     extracted_quads
 }
@@ -54,6 +54,7 @@ pub fn extract_quads(render_primitive: &RenderPrimitive, dpi: f32) -> Vec<Extrac
                 image: None,
                 uv_max: None,
                 uv_min: None,
+                transform: None,
             },
         },
         ExtractQuadBundle {
@@ -72,15 +73,19 @@ pub fn extract_quads(render_primitive: &RenderPrimitive, dpi: f32) -> Vec<Extrac
                 font_handle: None,
                 quad_type: UIQuadType::Quad,
                 type_index: 0,
+                // Shrink the inner radii so the border keeps a consistent thickness around corners
                 border_radius: Corner {
-                    top_left: border_radius.top_left,
-                    top_right: border_radius.top_right,
-                    bottom_left: border_radius.bottom_left,
-                    bottom_right: border_radius.bottom_right,
+                    top_left: (border_radius.top_left - border.top.max(border.left)).max(0.0),
+                    top_right: (border_radius.top_right - border.top.max(border.right)).max(0.0),
+                    bottom_left: (border_radius.bottom_left - border.bottom.max(border.left))
+                        .max(0.0),
+                    bottom_right: (border_radius.bottom_right - border.bottom.max(border.right))
+                        .max(0.0),
                 },
                 image: None,
                 uv_max: None,
                 uv_min: None,
+                transform: None,
             },
         },
     ]
@@ -0,0 +1,109 @@
+use bevy::{
+    prelude::{App as BevyApp, AssetServer, Commands, Res, ResMut},
+    window::WindowDescriptor,
+    DefaultPlugins,
+};
+use kayak_ui::bevy::{BevyContext, BevyKayakUIPlugin, FontMapping, UICameraBundle};
+use kayak_ui::core::{
+    constructor, render, rsx,
+    styles::{LayoutType, LayoutWrap, Style, StyleProp, Units},
+    use_state, widget, EventType, Index, OnEvent, VecTracker,
+};
+use kayak_ui::widgets::{App, Chip, Element, Window};
+
+#[widget]
+fn ChipList() {
+    let (tags, set_tags, ..) = use_state!(vec![
+        ("Rust".to_string(), true),
+        ("Bevy".to_string(), false),
+        ("UI".to_string(), false),
+        ("Game Development".to_string(), true),
+        ("Open Source".to_string(), false),
+        ("Layout".to_string(), false),
+        ("Widgets".to_string(), false),
+        ("Rendering".to_string(), false),
+    ]);
+
+    let list_styles = Style {
+        col_between: StyleProp::Value(Units::Pixels(8.0)),
+        height: StyleProp::Value(Units::Auto),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        layout_wrap: StyleProp::Value(LayoutWrap::Wrap),
+        row_between: StyleProp::Value(Units::Pixels(8.0)),
+        ..Default::default()
+    };
+
+    rsx! {
+        <Window position={(50.0, 50.0)} size={(300.0, 300.0)} title={"Chip Example".to_string()}>
+            <Element styles={Some(list_styles)}>
+                {VecTracker::from(tags.clone().into_iter().enumerate().map(|(index, (label, selected))| {
+                    let cloned_tags = tags.clone();
+                    let cloned_set_tags = set_tags.clone();
+                    let on_click = OnEvent::new(move |_, event| match event.event_type {
+                        EventType::Click(..) => {
+                            let mut tags = cloned_tags.clone();
+                            tags[index].1 = !tags[index].1;
+                            cloned_set_tags(tags);
+                        }
+                        _ => {}
+                    });
+
+                    let cloned_tags = tags.clone();
+                    let cloned_set_tags = set_tags.clone();
+                    let on_remove = OnEvent::new(move |_, event| match event.event_type {
+                        EventType::Click(..) => {
+                            let mut tags = cloned_tags.clone();
+                            tags.remove(index);
+                            cloned_set_tags(tags);
+                        }
+                        _ => {}
+                    });
+
+                    constructor! {
+                        <Chip
+                            label={label}
+                            selected={selected}
+                            removable={true}
+                            on_click={Some(on_click)}
+                            on_remove={Some(on_remove)}
+                        />
+                    }
+                }))}
+            </Element>
+        </Window>
+    }
+}
+
+fn startup(
+    mut commands: Commands,
+    mut font_mapping: ResMut<FontMapping>,
+    asset_server: Res<AssetServer>,
+) {
+    commands.spawn_bundle(UICameraBundle::new());
+
+    font_mapping.set_default(asset_server.load("roboto.kayak_font"));
+
+    let context = BevyContext::new(|context| {
+        render! {
+            <App>
+                <ChipList />
+            </App>
+        }
+    });
+
+    commands.insert_resource(context);
+}
+
+fn main() {
+    BevyApp::new()
+        .insert_resource(WindowDescriptor {
+            width: 1270.0,
+            height: 720.0,
+            title: String::from("UI Example"),
+            ..Default::default()
+        })
+        .add_plugins(DefaultPlugins)
+        .add_plugin(BevyKayakUIPlugin)
+        .add_startup_system(startup)
+        .run();
+}
@@ -0,0 +1,60 @@
+use bevy::{
+    prelude::{App as BevyApp, AssetServer, Commands, Res, ResMut},
+    window::WindowDescriptor,
+    DefaultPlugins,
+};
+use kayak_ui::bevy::{BevyContext, BevyKayakUIPlugin, EmojiMapping, FontMapping, UICameraBundle};
+use kayak_ui::core::{
+    render,
+    styles::{Style, StyleProp},
+    Index,
+};
+use kayak_ui::widgets::{App, Text, Window};
+
+fn startup(
+    mut commands: Commands,
+    mut font_mapping: ResMut<FontMapping>,
+    mut emoji_mapping: ResMut<EmojiMapping>,
+    asset_server: Res<AssetServer>,
+) {
+    commands.spawn_bundle(UICameraBundle::new());
+
+    font_mapping.set_default(asset_server.load("roboto.kayak_font"));
+    // Emoji missing from the font are drawn using these images instead
+    emoji_mapping.add("✅", asset_server.load("kenny/iconCheck_blue.png"));
+    emoji_mapping.add("❌", asset_server.load("kenny/iconCross_brown.png"));
+
+    let context = BevyContext::new(|context| {
+        // Emoji fade along with the rest of their text
+        let faded_styles = Style {
+            opacity: StyleProp::Value(0.4),
+            ..Default::default()
+        };
+
+        render! {
+            <App>
+                <Window position={(50.0, 50.0)} size={(300.0, 200.0)} title={"Emoji Example".to_string()}>
+                    <Text size={24.0} content={"Saved ✅".to_string()} />
+                    <Text size={24.0} content={"Not saved ❌".to_string()} />
+                    <Text styles={Some(faded_styles)} size={24.0} content={"Faded ✅❌".to_string()} />
+                </Window>
+            </App>
+        }
+    });
+
+    commands.insert_resource(context);
+}
+
+fn main() {
+    BevyApp::new()
+        .insert_resource(WindowDescriptor {
+            width: 1270.0,
+            height: 720.0,
+            title: String::from("UI Example"),
+            ..Default::default()
+        })
+        .add_plugins(DefaultPlugins)
+        .add_plugin(BevyKayakUIPlugin)
+        .add_startup_system(startup)
+        .run();
+}
@@ -9,7 +9,7 @@ use kayak_ui::{
     core::{
         render, rsx,
         styles::{Style, StyleProp, Units},
-        use_state, widget, Color, EventType, Handler, Index, OnEvent,
+        use_state, widget, Color, EventType, Index, OnChange, OnEvent,
     },
     widgets::{App, Background, Button, Fold, If, Text, Window},
 };
@@ -87,7 +87,7 @@ fn FolderTree() {
     };
 
     let (tried, set_tried, ..) = use_state!(false);
-    let on_toggle_c = Some(Handler::new(move |_| {
+    let on_toggle_c = Some(OnChange::new(move |_| {
         set_tried(true);
     }));
 
@@ -118,7 +118,7 @@ fn FolderTree() {
                     </Background>
                 </Fold>
                 // === Folder C === //
-                <Fold label={"Folder C".to_string()} open={Some(true)} on_change={on_toggle_c} styles={fold_c_styles}>
+                <Fold label={"Folder C".to_string()} open={Some(true)} on_toggle={on_toggle_c} styles={fold_c_styles}>
                     <Background styles={Some(fold_c_child_styles)}>
                         <Text size={12.0} content={"Can't close me!".to_string()}>{}</Text>
                         <If condition={tried}>
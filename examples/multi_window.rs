@@ -0,0 +1,104 @@
+use bevy::{
+    prelude::{App as BevyApp, AssetServer, Commands, EventWriter, Res, ResMut},
+    window::{CreateWindow, WindowDescriptor, WindowId},
+    DefaultPlugins,
+};
+use kayak_ui::bevy::{BevyContext, BevyKayakUIPlugin, FontMapping, UICameraBundle};
+use kayak_ui::core::{
+    render, rsx,
+    styles::{Style, StyleProp, Units},
+    use_state, widget, EventType, Index, OnChange, OnEvent, WidgetProps,
+};
+use kayak_ui::widgets::{App, Button, ChangeEvent, Text, TextBox, Window};
+
+#[derive(WidgetProps, Clone, Debug, Default, PartialEq)]
+struct CounterProps {
+    title: String,
+}
+
+/// A counter with its own state, so each window counts separately
+#[widget]
+fn Counter(props: CounterProps) {
+    let text_styles = Style {
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Pixels(28.0)),
+        ..Default::default()
+    };
+
+    let (count, set_count, ..) = use_state!(0i32);
+    let (value, set_value, ..) = use_state!(String::new());
+    let on_click = OnEvent::new(move |_, event| {
+        if let EventType::Click(..) = event.event_type {
+            set_count(count + 1);
+        }
+    });
+    // Typing only goes to the focused window
+    let on_change = OnChange::new(move |event: ChangeEvent| set_value(event.value));
+
+    rsx! {
+        <Window draggable={true} position={(50.0, 50.0)} size={(300.0, 300.0)} title={props.title.clone()}>
+            <Text styles={Some(text_styles)} size={24.0} content={format!("Current Count: {}", count)} />
+            <Button on_event={Some(on_click)}>
+                <Text line_height={Some(40.0)} size={24.0} content={"Count!".to_string()} />
+            </Button>
+            <TextBox value={value} on_change={Some(on_change)} />
+        </Window>
+    }
+}
+
+fn startup(
+    mut commands: Commands,
+    mut font_mapping: ResMut<FontMapping>,
+    asset_server: Res<AssetServer>,
+    mut create_window_events: EventWriter<CreateWindow>,
+) {
+    font_mapping.set_default(asset_server.load("roboto.kayak_font"));
+
+    // The primary window's UI is a resource, drawn by a camera for the primary window
+    commands.spawn_bundle(UICameraBundle::new());
+    let context = BevyContext::new(|context| {
+        render! {
+            <App>
+                <Counter title={"Primary Window".to_string()} />
+            </App>
+        }
+    });
+    commands.insert_resource(context);
+
+    // Other windows have their UI (and camera) spawned for them
+    let window_id = WindowId::new();
+    create_window_events.send(CreateWindow {
+        id: window_id,
+        descriptor: WindowDescriptor {
+            width: 640.0,
+            height: 480.0,
+            title: String::from("Second Window"),
+            ..Default::default()
+        },
+    });
+    commands.spawn_bundle(UICameraBundle::new().with_window(window_id));
+    let second_context = BevyContext::new(|context| {
+        render! {
+            <App>
+                <Counter title={"Second Window".to_string()} />
+            </App>
+        }
+    });
+    commands
+        .spawn()
+        .insert(second_context.with_window(window_id));
+}
+
+fn main() {
+    BevyApp::new()
+        .insert_resource(WindowDescriptor {
+            width: 1270.0,
+            height: 720.0,
+            title: String::from("UI Example"),
+            ..Default::default()
+        })
+        .add_plugins(DefaultPlugins)
+        .add_plugin(BevyKayakUIPlugin)
+        .add_startup_system(startup)
+        .run();
+}
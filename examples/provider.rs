@@ -275,7 +275,7 @@ fn startup(
     let context = BevyContext::new(|context| {
         render! {
             <App>
-                <TooltipProvider size={(350.0, 350.0)} position={(0.0, 0.0)}>
+                <TooltipProvider size={Some((350.0, 350.0))} position={Some((0.0, 0.0))}>
                     <Window size={(350.0, 350.0)} position={(0.0, 0.0)} title={"Provider Example".to_string()}>
                         <ThemeProvider initial_theme={Theme::vampire()}>
                             <ThemeDemo is_root={true} />
@@ -0,0 +1,182 @@
+use bevy::{
+    prelude::{App as BevyApp, AssetServer, Commands, Local, Res, ResMut},
+    window::WindowDescriptor,
+    DefaultPlugins,
+};
+use kayak_ui::bevy::{BevyContext, BevyKayakUIPlugin, FontMapping, UICameraBundle};
+use kayak_ui::core::{
+    bind, constructor, render, rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, Binding, Bound, Handler, Index, MutableBound, VecTracker,
+};
+use kayak_ui::widgets::{App, Element, If, Static, Text};
+
+/// The number of frames to average the layout time over, for each configuration
+const SAMPLES: usize = 120;
+/// The number of frames skipped after switching configurations, while the rows are remounted
+const WARMUP: usize = 10;
+
+/// Whether the rows are currently wrapped in a [`Static`] widget, so their layouts are cached
+#[derive(Clone, PartialEq)]
+struct StaticLayouts(pub bool);
+
+/// A large region that never changes, such as the chrome around an app
+#[widget]
+fn Rows() {
+    let row_styles = Style {
+        layout_type: StyleProp::Value(LayoutType::Row),
+        height: StyleProp::Value(Units::Pixels(20.0)),
+        ..Default::default()
+    };
+
+    rsx! {
+        <>
+            {VecTracker::from((0..200).map(|row| {
+                constructor! {
+                    <Element styles={Some(row_styles.clone())}>
+                        <Text content={format!("Row {}", row)} size={14.0} />
+                        <Text content={"Some static content".to_string()} size={14.0} />
+                    </Element>
+                }
+            }))}
+        </>
+    }
+}
+
+/// A counter that changes every frame, so the layout is calculated every frame
+#[widget]
+fn Ticker() {
+    let (frame, set_frame, ..) = use_state!(0u32);
+    context.on_tick(Some(Handler::new(move |_| set_frame(frame + 1))));
+
+    rsx! {
+        <Text content={format!("Frame {}", frame)} size={24.0} />
+    }
+}
+
+#[widget]
+fn Screen() {
+    let static_layouts =
+        context.query_world::<Res<Binding<StaticLayouts>>, _, _>(move |static_layouts| {
+            static_layouts.clone()
+        });
+    context.bind(&static_layouts);
+    let is_static = static_layouts.get().0;
+
+    let label = if is_static {
+        "Static: on"
+    } else {
+        "Static: off"
+    };
+
+    rsx! {
+        <>
+            <Text line_height={Some(40.0)} size={24.0} content={label.to_string()} />
+            <Ticker />
+            <If condition={is_static}>
+                <Static>
+                    <Rows />
+                </Static>
+            </If>
+            <If condition={!is_static}>
+                <Element>
+                    <Rows />
+                </Element>
+            </If>
+        </>
+    }
+}
+
+/// The layout times measured so far by [`report_layout_time`]
+#[derive(Default)]
+struct LayoutReport {
+    /// The layout times (in milliseconds) measured in the current configuration
+    samples: Vec<f32>,
+    /// The number of frames measured in the current configuration, including skipped ones
+    frames: usize,
+    /// The average layout time (in milliseconds) with and without static layouts, once measured
+    averages: (Option<f32>, Option<f32>),
+}
+
+/// Measures the average time taken to calculate the layout with and without static layouts,
+/// switching between them every few frames, and prints the difference
+fn report_layout_time(
+    context: Option<Res<BevyContext>>,
+    static_layouts: Res<Binding<StaticLayouts>>,
+    mut report: Local<LayoutReport>,
+) {
+    let context = match context {
+        Some(context) => context,
+        None => return,
+    };
+    let is_static = static_layouts.get().0;
+
+    report.frames += 1;
+    if report.frames > WARMUP {
+        if let Ok(context) = context.kayak_context.read() {
+            report
+                .samples
+                .push(context.layout_time().as_secs_f32() * 1000.0);
+        }
+    }
+    if report.samples.len() < SAMPLES {
+        return;
+    }
+
+    let average = report.samples.iter().sum::<f32>() / report.samples.len() as f32;
+    if is_static {
+        report.averages.0 = Some(average);
+    } else {
+        report.averages.1 = Some(average);
+    }
+    if let (Some(with_static), Some(without_static)) = report.averages {
+        println!(
+            "Average layout time: {:.3}ms with static layouts, {:.3}ms without ({:.3}ms saved)",
+            with_static,
+            without_static,
+            without_static - with_static,
+        );
+    }
+
+    // Measure the other configuration next
+    report.samples.clear();
+    report.frames = 0;
+    static_layouts.set(StaticLayouts(!is_static));
+}
+
+fn startup(
+    mut commands: Commands,
+    mut font_mapping: ResMut<FontMapping>,
+    asset_server: Res<AssetServer>,
+) {
+    commands.spawn_bundle(UICameraBundle::new());
+
+    font_mapping.set_default(asset_server.load("roboto.kayak_font"));
+
+    commands.insert_resource(bind(StaticLayouts(true)));
+
+    let context = BevyContext::new(|context| {
+        render! {
+            <App>
+                <Screen />
+            </App>
+        }
+    });
+
+    commands.insert_resource(context);
+}
+
+fn main() {
+    BevyApp::new()
+        .insert_resource(WindowDescriptor {
+            width: 1270.0,
+            height: 720.0,
+            title: String::from("UI Example"),
+            ..Default::default()
+        })
+        .add_plugins(DefaultPlugins)
+        .add_plugin(BevyKayakUIPlugin)
+        .add_startup_system(startup)
+        .add_system(report_layout_time)
+        .run();
+}
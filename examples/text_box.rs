@@ -9,9 +9,9 @@ use kayak_ui::bevy::{BevyContext, BevyKayakUIPlugin, FontMapping, UICameraBundle
 use kayak_ui::core::{
     render, rsx,
     styles::{Style, StyleProp, Units},
-    widget, Index,
+    widget, Index, OnChange,
 };
-use kayak_ui::widgets::{App, OnChange, TextBox, Window};
+use kayak_ui::widgets::{App, ChangeEvent, TextBox, Window};
 
 #[widget]
 fn TextBoxExample() {
@@ -29,15 +29,15 @@ fn TextBoxExample() {
         ..input_styles.clone()
     };
 
-    let on_change = OnChange::new(move |event| {
+    let on_change = OnChange::new(move |event: ChangeEvent| {
         set_value(event.value);
     });
 
-    let on_change_empty = OnChange::new(move |event| {
+    let on_change_empty = OnChange::new(move |event: ChangeEvent| {
         set_empty_value(event.value);
     });
 
-    let on_change_red = OnChange::new(move |event| {
+    let on_change_red = OnChange::new(move |event: ChangeEvent| {
         set_red_value(event.value);
     });
 
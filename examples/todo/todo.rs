@@ -7,9 +7,9 @@ use kayak_ui::bevy::{BevyContext, BevyKayakUIPlugin, FontMapping, UICameraBundle
 use kayak_ui::core::{
     render, rsx,
     styles::{LayoutType, Style, StyleProp, Units},
-    use_state, widget, EventType, Handler, Index, OnEvent,
+    use_state, widget, EventType, Handler, Index, OnChange, OnEvent,
 };
-use kayak_ui::widgets::{App, Element, OnChange, TextBox, Window};
+use kayak_ui::widgets::{App, ChangeEvent, Element, TextBox, Window};
 
 mod add_button;
 mod card;
@@ -53,7 +53,7 @@ fn TodoApp() {
         ..Style::default()
     };
 
-    let on_change = OnChange::new(move |event| {
+    let on_change = OnChange::new(move |event: ChangeEvent| {
         set_new_todo_value(event.value);
     });
 
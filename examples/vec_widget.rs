@@ -4,7 +4,7 @@ use bevy::{
     DefaultPlugins,
 };
 use kayak_ui::bevy::{BevyContext, BevyKayakUIPlugin, FontMapping, UICameraBundle};
-use kayak_ui::core::{constructor, render, Index, VecTracker};
+use kayak_ui::core::{constructor, render, Index};
 use kayak_ui::widgets::{App, Text};
 
 fn startup(
@@ -23,11 +23,11 @@ fn startup(
         ];
         render! {
             <App>
-                {VecTracker::from(data.iter().map(|data| {
+                {data.iter().map(|data| {
                     constructor! {
-                        <Text content={data.clone().to_string()} size={16.0} />
+                        <Text key={data} content={data.clone().to_string()} size={16.0} />
                     }
-                }))}
+                })}
             </App>
         }
     });
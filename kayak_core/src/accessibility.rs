@@ -0,0 +1,298 @@
+use crate::layout_cache::Rect;
+use crate::styles::Display;
+use crate::widget_manager::WidgetManager;
+use crate::Index;
+
+/// The semantic role of a widget, describing what it is to assistive technologies
+///
+/// These mirror the roles found in [WAI-ARIA](https://www.w3.org/TR/wai-aria/#role_definitions)
+/// (and in AccessKit), so they can be mapped one-to-one by an integration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// An important message that's announced as soon as it's shown
+    Alert,
+    /// A widget that triggers an action when clicked
+    Button,
+    /// A widget that can be checked or unchecked
+    Checkbox,
+    /// A window that's shown above the rest of the app, such as a modal
+    Dialog,
+    /// A container for a set of related widgets
+    Group,
+    /// A heading for a section of content
+    Heading,
+    /// An image
+    Image,
+    /// A piece of static text
+    Label,
+    /// A widget that navigates somewhere when clicked
+    Link,
+    /// A list of items
+    List,
+    /// An item within a [`List`](Self::List)
+    ListItem,
+    /// A list of choices that's usually shown from a [`MenuBar`](Self::MenuBar) or a button
+    Menu,
+    /// A bar of menus, usually shown at the top of a window
+    MenuBar,
+    /// A choice within a [`Menu`](Self::Menu)
+    MenuItem,
+    /// A widget that shows the progress of a task
+    ProgressBar,
+    /// A container whose content can be scrolled
+    ScrollView,
+    /// A widget for choosing a value within a range
+    Slider,
+    /// A message that's announced once the user is idle, such as the result of an action
+    Status,
+    /// A tab within a [`TabList`](Self::TabList)
+    Tab,
+    /// A list of tabs, which selects the [`TabPanel`](Self::TabPanel) being shown
+    TabList,
+    /// The content shown for a [`Tab`](Self::Tab)
+    TabPanel,
+    /// A table of rows and columns
+    Table,
+    /// A cell within a [`TableRow`](Self::TableRow)
+    TableCell,
+    /// A row within a [`Table`](Self::Table)
+    TableRow,
+    /// A field for entering text
+    TextBox,
+    /// A popup describing another widget, shown when it's hovered or focused
+    Tooltip,
+    /// A window, such as one that can be dragged around the app
+    Window,
+}
+
+/// The accessibility preferences of the user, usually taken from their OS settings
+///
+/// These are set by the integration using
+/// [`KayakContext::set_system_preferences`](crate::KayakContext::set_system_preferences), or
+/// overridden (such as for testing or an in-app setting) using
+/// [`KayakContext::override_preferences`](crate::KayakContext::override_preferences). Widgets read
+/// them using [`KayakContextRef::preferences`](crate::KayakContextRef::preferences).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserPreferences {
+    /// If true, the user prefers to minimize non-essential motion, such as transitions
+    pub reduced_motion: bool,
+    /// If true, the user prefers colors with a higher contrast
+    pub high_contrast: bool,
+}
+
+/// The accessibility information of a widget
+///
+/// This is set by a widget using
+/// [`KayakContextRef::set_accessibility`](crate::KayakContextRef::set_accessibility), and is
+/// collected into the [`AccessNode`] tree returned by
+/// [`KayakContext::accessibility_tree`](crate::KayakContext::accessibility_tree).
+///
+/// # Examples
+///
+/// ```
+/// use kayak_core::{Accessibility, Role};
+///
+/// let accessibility = Accessibility::new(Role::Checkbox)
+///     .with_name("Show grid")
+///     .with_checked(true);
+///
+/// assert_eq!(Some("Show grid".to_string()), accessibility.name);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Accessibility {
+    /// The role of the widget
+    pub role: Role,
+    /// The name announced for the widget, such as the label of a button
+    pub name: Option<String>,
+    /// A longer description of the widget, announced after its name
+    pub description: Option<String>,
+    /// The current value of the widget, such as the text of a text box
+    pub value: Option<String>,
+    /// Whether the widget is checked, for widgets that can be checked
+    pub checked: Option<bool>,
+    /// Whether the widget is expanded, for widgets that show or hide other content
+    pub expanded: Option<bool>,
+    /// The IDs of the widgets that describe this one, such as its tooltip
+    pub described_by: Vec<Index>,
+    /// The IDs of the widgets that this one describes, such as the consumer of a tooltip
+    ///
+    /// This is the inverse of [`described_by`](Self::described_by), for widgets that know what
+    /// they describe rather than the other way around. When the accessibility tree is built, each
+    /// of these widgets has this one added to its `described_by`.
+    pub describes: Vec<Index>,
+}
+
+impl Accessibility {
+    /// Creates new accessibility information with the given role and nothing else
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            name: None,
+            description: None,
+            value: None,
+            checked: None,
+            expanded: None,
+            described_by: Vec::new(),
+            describes: Vec::new(),
+        }
+    }
+
+    /// Sets the name announced for the widget
+    pub fn with_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the description announced after the name of the widget
+    pub fn with_description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the current value of the widget
+    pub fn with_value<S: Into<String>>(mut self, value: S) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets whether the widget is checked
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Sets whether the widget is expanded
+    pub fn with_expanded(mut self, expanded: bool) -> Self {
+        self.expanded = Some(expanded);
+        self
+    }
+
+    /// Adds a widget that describes this one
+    pub fn with_described_by(mut self, id: Index) -> Self {
+        self.described_by.push(id);
+        self
+    }
+
+    /// Adds a widget that this one describes
+    pub fn with_describes(mut self, id: Index) -> Self {
+        self.describes.push(id);
+        self
+    }
+}
+
+/// A widget within the accessibility tree
+///
+/// The accessibility tree only contains widgets that set their [`Accessibility`]. Each node's
+/// children are the nearest such widgets among its descendants, so purely visual or layout widgets
+/// are skipped over.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessNode {
+    /// The ID of the widget
+    pub id: Index,
+    /// The accessibility information set by the widget
+    pub accessibility: Accessibility,
+    /// The computed rect of the widget, if it has been laid out
+    pub rect: Option<Rect>,
+    /// Whether the widget is currently focused
+    pub is_focused: bool,
+    /// Whether the widget is disabled, including by a disabled ancestor
+    pub is_disabled: bool,
+    /// The nodes of the widget's descendants, in order
+    pub children: Vec<AccessNode>,
+}
+
+impl AccessNode {
+    /// Builds the accessibility tree, starting from the root of the widget tree
+    pub(crate) fn build_tree(widget_manager: &WidgetManager) -> Vec<Self> {
+        let mut nodes = match widget_manager.tree.root_node {
+            Some(root) => Self::build(widget_manager, root),
+            None => return Vec::new(),
+        };
+
+        // Link the widgets that describe others from the described side as well
+        let links: Vec<(Index, Index)> = nodes
+            .iter()
+            .flat_map(|node| node.iter())
+            .flat_map(|node| {
+                node.accessibility
+                    .describes
+                    .iter()
+                    .map(move |described| (node.id, *described))
+            })
+            .collect();
+        for (id, described) in links {
+            if let Some(node) = nodes
+                .iter_mut()
+                .find_map(|node| node.find_by_id_mut(described))
+            {
+                if !node.accessibility.described_by.contains(&id) {
+                    node.accessibility.described_by.push(id);
+                }
+            }
+        }
+
+        nodes
+    }
+
+    /// Builds the nodes for the given widget, or for its descendants if it has no accessibility
+    /// information
+    pub(crate) fn build(widget_manager: &WidgetManager, id: Index) -> Vec<Self> {
+        if widget_manager.display(id) != Display::Visible {
+            // Hidden widgets aren't shown, so they aren't announced either
+            return Vec::new();
+        }
+
+        let children = widget_manager
+            .tree
+            .children
+            .get(&id)
+            .map(|children| {
+                children
+                    .iter()
+                    .flat_map(|child| Self::build(widget_manager, *child))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        match widget_manager.get_accessibility(id) {
+            Some(accessibility) => vec![Self {
+                id,
+                accessibility: accessibility.clone(),
+                rect: widget_manager.get_layout(&id).copied(),
+                is_focused: widget_manager.focus_tree.current() == Some(id),
+                is_disabled: widget_manager.is_disabled(id),
+                children,
+            }],
+            None => children,
+        }
+    }
+
+    /// Returns an iterator over this node and all of its descendants, depth-first
+    pub fn iter(&self) -> impl Iterator<Item = &AccessNode> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    /// Finds the first node with the given role, searching depth-first (including this one)
+    pub fn find(&self, role: Role) -> Option<&AccessNode> {
+        self.iter().find(|node| node.accessibility.role == role)
+    }
+
+    /// Finds the node of the widget with the given ID (including this one)
+    pub fn find_by_id(&self, id: Index) -> Option<&AccessNode> {
+        self.iter().find(|node| node.id == id)
+    }
+
+    fn find_by_id_mut(&mut self, id: Index) -> Option<&mut AccessNode> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.children
+            .iter_mut()
+            .find_map(|child| child.find_by_id_mut(id))
+    }
+}
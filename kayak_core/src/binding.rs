@@ -1,7 +1,8 @@
 use std::time::Instant;
 
 pub use crate::flo_binding::{
-    bind, computed, notify, Binding, Bound, Changeable, ComputedBinding, MutableBound, Releasable,
+    bind, computed, notify, BindRef, Binding, Bound, Changeable, ComputedBinding, MutableBound,
+    Releasable,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -29,3 +30,51 @@ impl Debouncer {
         return false;
     }
 }
+
+/// The state of a binding being set using [`KayakContext::set_throttled`](crate::KayakContext::set_throttled)
+pub(crate) struct ThrottledUpdate {
+    last_updated: Instant,
+    interval: f32,
+    /// The latest value given while throttled, which is applied once the interval has passed
+    pending: Option<Box<dyn FnOnce() + Send + Sync>>,
+}
+
+impl ThrottledUpdate {
+    pub(crate) fn new(interval: f32) -> Self {
+        Self {
+            last_updated: Instant::now(),
+            interval,
+            pending: None,
+        }
+    }
+
+    /// Returns true if the interval has passed since the last update
+    pub(crate) fn is_ready(&self) -> bool {
+        self.last_updated.elapsed().as_secs_f32() >= self.interval
+    }
+
+    /// Replaces the pending update, coalescing it with any previous one
+    pub(crate) fn defer<F: FnOnce() + Send + Sync + 'static>(&mut self, interval: f32, apply: F) {
+        self.interval = interval;
+        self.pending = Some(Box::new(apply));
+    }
+
+    /// Applies the pending update if the interval has passed
+    ///
+    /// Returns false if there is nothing left to apply and the interval has passed, meaning this
+    /// update no longer needs to be tracked.
+    pub(crate) fn flush(&mut self) -> bool {
+        if !self.is_ready() {
+            return true;
+        }
+
+        match self.pending.take() {
+            Some(apply) => {
+                apply();
+                self.last_updated = Instant::now();
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt::{Debug, Display, Formatter};
+
+/// An error returned when the clipboard couldn't be accessed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardError {
+    /// No clipboard has been provided by the integration
+    Unavailable,
+    /// The clipboard failed to read or write its contents
+    Failed(String),
+}
+
+impl Display for ClipboardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unavailable => write!(f, "No clipboard is available"),
+            Self::Failed(reason) => write!(f, "Couldn't access the clipboard: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for ClipboardError {}
+
+/// A source of clipboard contents, such as the platform clipboard
+///
+/// Integrations implement this trait to connect the [`Clipboard`] of a
+/// [`KayakContext`](crate::KayakContext) to the clipboard of their platform, using
+/// [`KayakContext::set_clipboard`](crate::KayakContext::set_clipboard).
+pub trait ClipboardProvider: Send + Sync {
+    /// Returns the text currently on the clipboard
+    fn get_text(&mut self) -> Result<String, ClipboardError>;
+
+    /// Replaces the contents of the clipboard with the given text
+    fn set_text(&mut self, text: &str) -> Result<(), ClipboardError>;
+}
+
+/// A clipboard that keeps its contents in memory
+///
+/// This is useful for tests or for platforms without a clipboard, allowing text to be copied and
+/// pasted within the app itself.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MemoryClipboard {
+    text: String,
+}
+
+impl ClipboardProvider for MemoryClipboard {
+    fn get_text(&mut self) -> Result<String, ClipboardError> {
+        Ok(self.text.clone())
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<(), ClipboardError> {
+        self.text = text.to_string();
+        Ok(())
+    }
+}
+
+/// Access to the clipboard of a [`KayakContext`](crate::KayakContext)
+///
+/// This can be retrieved using [`KayakContextRef::clipboard`](crate::KayakContextRef::clipboard),
+/// including from within an [`OnEvent`](crate::OnEvent) handler. Until the integration provides
+/// a clipboard, every method returns [`ClipboardError::Unavailable`].
+#[derive(Default)]
+pub struct Clipboard {
+    provider: Option<Box<dyn ClipboardProvider>>,
+}
+
+impl Clipboard {
+    /// Returns true if a clipboard has been provided by the integration
+    pub fn is_available(&self) -> bool {
+        self.provider.is_some()
+    }
+
+    /// Returns the text currently on the clipboard
+    pub fn get_text(&mut self) -> Result<String, ClipboardError> {
+        match self.provider {
+            Some(ref mut provider) => provider.get_text(),
+            None => Err(ClipboardError::Unavailable),
+        }
+    }
+
+    /// Replaces the contents of the clipboard with the given text
+    pub fn set_text(&mut self, text: &str) -> Result<(), ClipboardError> {
+        match self.provider {
+            Some(ref mut provider) => provider.set_text(text),
+            None => Err(ClipboardError::Unavailable),
+        }
+    }
+
+    pub(crate) fn set_provider(&mut self, provider: Option<Box<dyn ClipboardProvider>>) {
+        self.provider = provider;
+    }
+}
+
+impl Debug for Clipboard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Clipboard")
+            .field("is_available", &self.is_available())
+            .finish()
+    }
+}
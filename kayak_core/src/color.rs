@@ -45,4 +45,144 @@ impl Color {
     pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
         Self { r, g, b, a }
     }
+
+    /// Creates a new color from the given HSL values
+    ///
+    /// # Arguments
+    ///
+    /// * `hue`: The hue, in degrees (0.0 - 360.0)
+    /// * `saturation`: The saturation, 0.0 - 1.0
+    /// * `lightness`: The lightness, 0.0 - 1.0
+    /// * `alpha`: Transparency, 0.0 - 1.0
+    ///
+    pub fn hsla(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = lightness - chroma / 2.0;
+
+        Self::new(r + m, g + m, b + m, alpha)
+    }
+
+    /// Creates a new color from the given HSV values
+    ///
+    /// # Arguments
+    ///
+    /// * `hue`: The hue, in degrees (0.0 - 360.0)
+    /// * `saturation`: The saturation, 0.0 - 1.0
+    /// * `value`: The value (brightness), 0.0 - 1.0
+    /// * `alpha`: Transparency, 0.0 - 1.0
+    ///
+    pub fn hsva(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
+        let lightness = value * (1.0 - saturation / 2.0);
+        let saturation = if lightness <= 0.0 || lightness >= 1.0 {
+            0.0
+        } else {
+            (value - lightness) / lightness.min(1.0 - lightness)
+        };
+
+        Self::hsla(hue, saturation, lightness, alpha)
+    }
+
+    /// Returns the hue (in degrees), saturation, value, and alpha of this color
+    pub fn to_hsva(&self) -> (f32, f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let delta = max - min;
+
+        let hue = if delta <= 0.0 {
+            0.0
+        } else if max == self.r {
+            60.0 * ((self.g - self.b) / delta).rem_euclid(6.0)
+        } else if max == self.g {
+            60.0 * ((self.b - self.r) / delta + 2.0)
+        } else {
+            60.0 * ((self.r - self.g) / delta + 4.0)
+        };
+        let saturation = if max <= 0.0 { 0.0 } else { delta / max };
+
+        (hue, saturation, max, self.a)
+    }
+
+    /// Parses a color from a hex string
+    ///
+    /// Accepts the forms `RGB`, `RGBA`, `RRGGBB`, and `RRGGBBAA`, optionally prefixed by `#`.
+    /// Returns `None` if the string is not a valid hex color.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !hex.is_ascii() {
+            return None;
+        }
+
+        let channel = |start: usize, len: usize| -> Option<f32> {
+            let value = u8::from_str_radix(&hex[start..start + len], 16).ok()?;
+            // Expand shorthand values (e.g. `F` -> `FF`)
+            let value = if len == 1 { value * 17 } else { value };
+            Some(value as f32 / 255.0)
+        };
+
+        match hex.len() {
+            3 | 4 => Some(Self::new(
+                channel(0, 1)?,
+                channel(1, 1)?,
+                channel(2, 1)?,
+                if hex.len() == 4 { channel(3, 1)? } else { 1.0 },
+            )),
+            6 | 8 => Some(Self::new(
+                channel(0, 2)?,
+                channel(2, 2)?,
+                channel(4, 2)?,
+                if hex.len() == 8 { channel(6, 2)? } else { 1.0 },
+            )),
+            _ => None,
+        }
+    }
+
+    /// Returns this color as a hex string of the form `#RRGGBBAA`
+    pub fn to_hex(&self) -> String {
+        let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        format!(
+            "#{:02X}{:02X}{:02X}{:02X}",
+            channel(self.r),
+            channel(self.g),
+            channel(self.b),
+            channel(self.a)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Color;
+
+    #[test]
+    fn color_should_parse_hex() {
+        assert_eq!(Some(Color::WHITE), Color::from_hex("#FFF"));
+        assert_eq!(Some(Color::BLACK), Color::from_hex("000000"));
+        assert_eq!(Some(Color::TRANSPARENT), Color::from_hex("#00000000"));
+        assert_eq!(None, Color::from_hex("#GGGGGG"));
+        assert_eq!(None, Color::from_hex("#12345"));
+    }
+
+    #[test]
+    fn color_should_convert_to_hex() {
+        let color = Color::new(1.0, 0.0, 0.2, 1.0);
+        assert_eq!("#FF0033FF", color.to_hex());
+        assert_eq!(Some(color), Color::from_hex(&color.to_hex()));
+    }
+
+    #[test]
+    fn color_should_convert_hsva() {
+        let color = Color::hsva(120.0, 1.0, 1.0, 1.0);
+        assert_eq!(Color::new(0.0, 1.0, 0.0, 1.0), color);
+        assert_eq!((120.0, 1.0, 1.0, 1.0), color.to_hsva());
+    }
 }
@@ -1,15 +1,37 @@
 use crate::assets::Assets;
+use crate::binding::ThrottledUpdate;
 use crate::layout_dispatcher::LayoutEventDispatcher;
-use crate::{Binding, Changeable, CursorIcon, KayakContextRef};
-use std::collections::HashMap;
+use crate::{
+    AccessNode, BindRef, Binding, Changeable, Clipboard, ClipboardProvider, CursorIcon, Event,
+    Handler, KayakContextRef, KeyboardEvent, KeyboardModifiers, LayoutSnapshot, Platform,
+    ResizeEvent, Shortcut, ShortcutConflict, TextMeasurement, UserPreferences,
+};
+use kayak_font::{KayakFont, TextProperties};
+use std::collections::{HashMap, HashSet};
+use std::panic::{self, AssertUnwindSafe};
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 use crate::event_dispatcher::EventDispatcher;
+use crate::render_error::CaughtPanic;
 use crate::{
-    multi_state::MultiState, widget_manager::WidgetManager, Index, InputEvent, MutableBound,
-    Releasable,
+    multi_state::MultiState, widget_manager::WidgetManager, Bound, Index, InputEvent, KeyCode,
+    MutableBound, Releasable,
 };
 
+/// The time a widget's size must stay the same before it's reported as resized (in seconds)
+const RESIZE_DEBOUNCE: f32 = 0.1;
+
+/// The state of a callback set with [`KayakContext::set_resize`]
+struct ResizeObserver {
+    handler: Handler<ResizeEvent>,
+    /// The size that was last reported to the callback, if any
+    reported: Option<(f32, f32)>,
+    /// The size that's waiting to be reported, along with the [elapsed](KayakContext::elapsed)
+    /// time at which it's due
+    pending: Option<((f32, f32), f32)>,
+}
+
 /// The context in which all widgets are contained
 ///
 /// This manages everything from rendering widgets to processing events.
@@ -20,13 +42,48 @@ use crate::{
 /// control when to render, dispatch events, load assets, etc.
 pub struct KayakContext {
     assets: Assets,
+    /// The callbacks fired when a click lands outside of a widget's subtree, mapped by widget ID
+    click_outside_handlers: HashMap<crate::Index, Handler>,
+    /// The clipboard given by [`set_clipboard`](Self::set_clipboard)
+    clipboard: Clipboard,
     pub(crate) current_effect_index: usize,
+    /// The callbacks fired when the Escape key is pressed, in the order they were added
+    escape_handlers: Vec<(crate::Index, Handler)>,
+    /// The widgets capturing key presses while focused, rather than letting them trigger shortcuts
+    key_captors: HashSet<crate::Index>,
+    /// The registered keyboard shortcuts, along with the ID of the widget that registered them
+    shortcuts: Vec<(crate::Index, Shortcut, Handler)>,
+    /// The callbacks fired at the start of every frame, mapped by widget ID
+    tick_handlers: HashMap<crate::Index, Handler<f32>>,
+    /// When the last frame was rendered
+    last_tick: Option<Instant>,
+    /// The time given by [`set_delta_time`](Self::set_delta_time) for the next frame, if any
+    next_delta_time: Option<f32>,
+    /// The time between the last two frames (in seconds)
+    delta_time: f32,
+    /// The total time of all frames rendered so far (in seconds)
+    elapsed: f32,
+    /// The callbacks scheduled with [`set_timeout`](Self::set_timeout), mapped by widget ID and key,
+    /// along with the [elapsed](Self::elapsed) time at which they're due
+    timeouts: HashMap<(crate::Index, String), (f32, Handler)>,
+    /// The callbacks fired when the size of a widget changes, mapped by widget ID
+    resize_observers: HashMap<crate::Index, ResizeObserver>,
+    /// The logical size of the window given by [`set_window_size`](Self::set_window_size), if any
+    window_size: Binding<Option<(f32, f32)>>,
+    /// The preferences given by [`set_system_preferences`](Self::set_system_preferences)
+    system_preferences: UserPreferences,
+    /// The preferences given by [`override_preferences`](Self::override_preferences), if any
+    preference_overrides: Option<UserPreferences>,
+    /// The preferences that are currently in effect
+    preferences: Binding<UserPreferences>,
     pub(crate) current_state_index: usize,
     /// Processes and dispatches all events
     event_dispatcher: EventDispatcher,
     global_bindings: HashMap<crate::Index, Vec<crate::flo_binding::Uuid>>,
     global_state: resources::Resources,
     pub(crate) last_state_type_id: Option<std::any::TypeId>,
+    /// The number of widgets currently being rendered, nested within one another
+    render_depth: usize,
     // TODO: Make widget_manager private.
     /// The widget manager containing information about the widget tree and layout
     ///
@@ -44,9 +101,29 @@ pub struct KayakContext {
     widget_state_lifetimes:
         HashMap<crate::Index, HashMap<crate::flo_binding::Uuid, Box<dyn crate::Releasable>>>,
     widget_states: HashMap<crate::Index, resources::Resources>,
+    /// The bindings being set using [`set_throttled`](Self::set_throttled), mapped by binding ID
+    throttled_updates: HashMap<crate::flo_binding::Uuid, ThrottledUpdate>,
     cursor_icon: CursorIcon,
 }
 
+/// Keeps a value created by [`KayakContext::computed`] alive for as long as its widget is bound to it
+///
+/// Otherwise, the value would stop tracking its dependencies once the widget is done rendering.
+struct ComputedLifetime<T> {
+    _computed: BindRef<T>,
+    lifetime: Box<dyn Releasable>,
+}
+
+impl<T> Releasable for ComputedLifetime<T> {
+    fn keep_alive(&mut self) {
+        self.lifetime.keep_alive();
+    }
+
+    fn done(&mut self) {
+        self.lifetime.done();
+    }
+}
+
 impl std::fmt::Debug for KayakContext {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("KayakContext").finish()
@@ -58,18 +135,36 @@ impl KayakContext {
     pub fn new() -> Self {
         Self {
             assets: Assets::default(),
+            click_outside_handlers: HashMap::new(),
+            clipboard: Clipboard::default(),
             current_effect_index: 0,
+            escape_handlers: Vec::new(),
+            key_captors: HashSet::new(),
+            shortcuts: Vec::new(),
+            tick_handlers: HashMap::new(),
+            last_tick: None,
+            next_delta_time: None,
+            delta_time: 0.0,
+            elapsed: 0.0,
+            timeouts: HashMap::new(),
+            resize_observers: HashMap::new(),
+            window_size: Binding::new(None),
+            system_preferences: UserPreferences::default(),
+            preference_overrides: None,
+            preferences: Binding::new(UserPreferences::default()),
             current_state_index: 0,
             cursor_icon: CursorIcon::Default,
             event_dispatcher: EventDispatcher::new(),
             global_bindings: HashMap::new(),
             global_state: resources::Resources::default(),
             last_state_type_id: None,
+            render_depth: 0,
             widget_effects: HashMap::new(),
             widget_manager: WidgetManager::new(),
             widget_providers: HashMap::new(),
             widget_state_lifetimes: HashMap::new(),
             widget_states: HashMap::new(),
+            throttled_updates: HashMap::new(),
         }
     }
 
@@ -405,6 +500,65 @@ impl KayakContext {
         }
     }
 
+    /// Creates a read-only value derived from other bound values
+    ///
+    /// The given function is called lazily to calculate the value. Any [`Bound`] values read within
+    /// it become its dependencies: changing one of them recalculates the value the next time it's
+    /// read and re-renders the given widget.
+    ///
+    /// Like [`create_state`](Self::create_state), the order in which this method is called matters.
+    ///
+    /// # Arguments
+    ///
+    /// * `widget_id`: The ID of the widget
+    /// * `calculate`: The function calculating the value
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn MyWidget() {
+    ///   let count = context.create_state::<u32>(0).unwrap();
+    ///
+    ///   let count_clone = count.clone();
+    ///   let doubled = context.computed(move || count_clone.get() * 2);
+    ///   println!("Doubled: {}", doubled.get());
+    /// }
+    /// ```
+    pub fn computed<T, F>(&mut self, widget_id: Index, calculate: F) -> BindRef<T>
+    where
+        T: resources::Resource + Clone + PartialEq,
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        // A state is used to give this computed value the same key every time the widget renders
+        let key = self
+            .create_state(widget_id, crate::flo_binding::Uuid::new_v4())
+            .unwrap()
+            .get();
+
+        let computed = BindRef::from(crate::computed(calculate));
+        let dirty_nodes = self.widget_manager.dirty_nodes.clone();
+        let lifetime = computed.when_changed(crate::notify(move || {
+            if let Ok(mut dirty_nodes) = dirty_nodes.lock() {
+                dirty_nodes.insert(widget_id);
+            }
+        }));
+
+        // Replace the value computed during the previous render
+        Self::remove_state_lifetime(&mut self.widget_state_lifetimes, widget_id, key);
+        Self::insert_state_lifetime(
+            &mut self.widget_state_lifetimes,
+            widget_id,
+            key,
+            Box::new(ComputedLifetime {
+                _computed: computed.clone(),
+                lifetime,
+            }),
+        );
+
+        computed
+    }
+
     fn get_state<T: resources::Resource + Clone + PartialEq>(&self, widget_id: Index) -> Option<T> {
         if self.widget_states.contains_key(&widget_id) {
             let states = self.widget_states.get(&widget_id).unwrap();
@@ -542,7 +696,15 @@ impl KayakContext {
     }
 
     /// Re-render all widgets that need rendering (i.e., marked dirty)
+    ///
+    /// Before any widgets are rendered, the callbacks set with [`set_tick`](Self::set_tick) are
+    /// fired, so that state changed by them is rendered in the same frame. The callbacks set with
+    /// [`set_resize`](Self::set_resize) are fired once layout has been calculated.
     pub fn render(&mut self) {
+        self.flush_throttled();
+        self.dispatch_tick();
+        self.dispatch_timeouts();
+
         let dirty_nodes: Vec<_> =
             if let Ok(mut dirty_nodes) = self.widget_manager.dirty_nodes.lock() {
                 dirty_nodes.drain(..).collect()
@@ -550,19 +712,67 @@ impl KayakContext {
                 panic!("Couldn't get lock on dirty nodes!")
             };
         for node_index in dirty_nodes {
-            let mut widget = self.widget_manager.take(node_index);
-            let mut context = KayakContextRef::new(self, Some(node_index));
-            widget.render(&mut context);
-            self.widget_manager.repossess(widget);
+            let widget = self.widget_manager.take(node_index);
+            let parent = self.widget_manager.tree.get_parent(node_index);
+            self.render_widget(widget, parent);
             self.widget_manager.dirty_render_nodes.insert(node_index);
         }
 
         // self.widget_manager.dirty_nodes.clear();
         self.widget_manager.render(&mut self.assets);
         LayoutEventDispatcher::dispatch(self);
+        self.dispatch_resize();
         self.update_cursor();
     }
 
+    /// Returns the time taken to calculate the layout during the last [`render`](Self::render)
+    ///
+    /// This is useful for profiling, such as to measure the savings of marking a subtree as static
+    /// with [`KayakContextRef::set_static`].
+    pub fn layout_time(&self) -> Duration {
+        self.widget_manager.layout_time()
+    }
+
+    /// Renders the given widget (taken from the widget manager) and returns it to the widget manager
+    ///
+    /// If the widget panics, the panic is reported to the nearest error boundary among its
+    /// ancestors, starting from the given parent. Otherwise, the panic is resumed: while nested
+    /// within the render of another widget, it's passed up for that render to continue the search.
+    pub(crate) fn render_widget(&mut self, mut widget: crate::BoxedWidget, parent: Option<Index>) {
+        let id = widget.get_id();
+        self.render_depth += 1;
+        let result = {
+            let mut context = KayakContextRef::new(self, Some(id));
+            panic::catch_unwind(AssertUnwindSafe(|| widget.render(&mut context)))
+        };
+        self.render_depth -= 1;
+        let name = widget.get_name();
+        // The widget is returned before anything else, so it isn't lost while the panic unwinds
+        self.widget_manager.repossess(widget);
+
+        let payload = match result {
+            Ok(()) => return,
+            Err(payload) => payload,
+        };
+        let caught = CaughtPanic::new(id, name, payload);
+        let mut current = parent;
+        while let Some(index) = current {
+            if let Some(handler) = self.widget_manager.get_error_boundary(index).cloned() {
+                handler.call(caught.error);
+                return;
+            }
+            // Widgets rendered for the first time aren't in the tree yet, which ends the search
+            // early, so their ancestors further up the stack continue it
+            current = self.widget_manager.tree.get_parent(index);
+        }
+
+        if self.render_depth > 0 {
+            panic::resume_unwind(Box::new(caught));
+        } else {
+            panic::resume_unwind(caught.payload);
+        }
+    }
+
     /// Processes the given input events
     ///
     /// Events are processed in three phases: Capture, Target, Propagate. These phases are based on their
@@ -583,10 +793,529 @@ impl KayakContext {
     ///   firing the bubbled event along the way. At any point, the bubbling can be stopped by calling
     ///   [`event.stop_propagation()`](Event::stop_propagation). Not every event can be propagated, in which case,
     ///   they will only fire for their specified target.
-    pub fn process_events(&mut self, input_events: Vec<InputEvent>) {
+    ///
+    /// Returns the events generated from the input, each with the widget it targeted (before propagation).
+    pub fn process_events(&mut self, input_events: Vec<InputEvent>) -> Vec<Event> {
+        let is_press = input_events.contains(&InputEvent::MouseLeftPress);
+        let is_release = input_events.contains(&InputEvent::MouseLeftRelease);
         let mut dispatcher = self.event_dispatcher.to_owned();
-        dispatcher.process_events(input_events, self);
+        let events = dispatcher.process_events(input_events, self);
+        let unfocused_key_presses = dispatcher.take_unfocused_key_presses();
         self.event_dispatcher.merge(dispatcher);
+
+        if is_release {
+            // The captor has already received the `MouseUp` event
+            self.event_dispatcher.end_pointer_capture();
+        }
+
+        if is_press {
+            self.dispatch_click_outside();
+        }
+        for key_press in unfocused_key_presses {
+            // Without a focused widget, there's no key event to run the default action for
+            if !self.dispatch_shortcut(key_press, None) && key_press.key() == KeyCode::Escape {
+                self.dispatch_escape();
+            }
+        }
+
+        events
+    }
+
+    /// Registers a keyboard shortcut for the given widget
+    ///
+    /// Shortcuts are fired before the focused widget receives the key press, in which case the
+    /// `KeyDown` event is not sent at all. The exception is a focused widget capturing key presses
+    /// (see [`set_captures_keys`](Self::set_captures_keys)), which receives any key press without
+    /// Control, Alt, or Meta held instead of the shortcut.
+    ///
+    /// Registering a shortcut the widget already registered replaces its callback.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The ID of the widget
+    /// * `shortcut`: The key combination
+    /// * `handler`: The callback to fire
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ShortcutConflict`] if a different widget already registered the same shortcut.
+    /// The existing shortcut is kept.
+    pub fn register_shortcut(
+        &mut self,
+        index: Index,
+        shortcut: Shortcut,
+        handler: Handler,
+    ) -> Result<(), ShortcutConflict> {
+        self.prune_shortcuts();
+
+        let existing = self
+            .shortcuts
+            .iter_mut()
+            .find(|(_, existing, _)| *existing == shortcut);
+        match existing {
+            Some((owner, _, existing)) if *owner == index => {
+                *existing = handler;
+                Ok(())
+            }
+            Some((owner, ..)) => Err(ShortcutConflict {
+                shortcut,
+                owner: *owner,
+            }),
+            None => {
+                self.shortcuts.push((index, shortcut, handler));
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes a keyboard shortcut registered by the given widget
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The ID of the widget
+    /// * `shortcut`: The key combination
+    ///
+    pub fn unregister_shortcut(&mut self, index: Index, shortcut: Shortcut) {
+        self.shortcuts
+            .retain(|(owner, existing, _)| *owner != index || *existing != shortcut);
+    }
+
+    /// Sets whether the given widget captures key presses while focused, rather than letting them
+    /// trigger shortcuts
+    ///
+    /// This is meant for text inputs, so that typing doesn't trigger shortcuts bound to plain keys.
+    /// Shortcuts using Control, Alt, or Meta are still fired.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The ID of the widget
+    /// * `captures_keys`: Whether the widget captures key presses
+    ///
+    pub fn set_captures_keys(&mut self, index: Index, captures_keys: bool) {
+        if captures_keys {
+            self.key_captors.insert(index);
+        } else {
+            self.key_captors.remove(&index);
+        }
+    }
+
+    /// Fires the shortcut matching the given key press, if any
+    ///
+    /// Returns true if a shortcut was fired.
+    pub(crate) fn dispatch_shortcut(
+        &mut self,
+        event: KeyboardEvent,
+        focused: Option<Index>,
+    ) -> bool {
+        if self.shortcuts.is_empty() {
+            return false;
+        }
+
+        self.prune_shortcuts();
+        let handler = self
+            .shortcuts
+            .iter()
+            .find(|(_, shortcut, _)| shortcut.matches(&event))
+            .filter(|(_, shortcut, _)| {
+                shortcut.is_command()
+                    || !focused.map_or(false, |focused| self.key_captors.contains(&focused))
+            })
+            .map(|(_, _, handler)| handler.clone());
+
+        if let Some(handler) = handler {
+            handler.call(());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes the shortcuts and key captors of widgets that have since been removed
+    fn prune_shortcuts(&mut self) {
+        let tree = &self.widget_manager.tree;
+        self.shortcuts.retain(|(index, ..)| tree.contains(*index));
+        self.key_captors.retain(|index| tree.contains(*index));
+    }
+
+    /// Sets the callback fired when the Escape key is pressed while the given widget is open
+    ///
+    /// Only the most recently added callback is fired for each press, so that Escape closes only
+    /// the topmost overlay. Replacing an existing callback keeps its original position. Passing
+    /// `None` removes the current callback, if any.
+    ///
+    /// The callback is fired as the default action of the `KeyDown` event, so the focused widget can
+    /// prevent it by calling [`event.prevent_default()`](crate::Event::prevent_default).
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The ID of the widget
+    /// * `handler`: The callback to fire
+    ///
+    pub fn set_close_on_escape(&mut self, index: Index, handler: Option<Handler>) {
+        let existing = self.escape_handlers.iter().position(|(id, _)| *id == index);
+        match (handler, existing) {
+            (Some(handler), Some(existing)) => self.escape_handlers[existing].1 = handler,
+            (Some(handler), None) => self.escape_handlers.push((index, handler)),
+            (None, Some(existing)) => {
+                self.escape_handlers.remove(existing);
+            }
+            (None, None) => {}
+        }
+    }
+
+    /// Fires the most recently added Escape callback
+    pub(crate) fn dispatch_escape(&mut self) {
+        let tree = &self.widget_manager.tree;
+
+        // Widgets that have since been removed should no longer be notified
+        self.escape_handlers
+            .retain(|(index, _)| tree.contains(*index));
+
+        if let Some((_, handler)) = self.escape_handlers.last() {
+            handler.clone().call(());
+        }
+    }
+
+    /// Sets the callback fired at the start of every frame for the given widget
+    ///
+    /// The callback is given the time since the previous frame (in seconds), so that animations
+    /// can advance at the same speed regardless of the frame rate. Passing `None` removes the
+    /// current callback, if any. Only one callback may be set per widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The ID of the widget
+    /// * `handler`: The callback to fire
+    ///
+    pub fn set_tick(&mut self, index: Index, handler: Option<Handler<f32>>) {
+        match handler {
+            Some(handler) => {
+                self.tick_handlers.insert(index, handler);
+            }
+            None => {
+                self.tick_handlers.remove(&index);
+            }
+        }
+    }
+
+    /// Sets the time since the last frame (in seconds), to be used for the next frame
+    ///
+    /// Integrations should call this with the delta time of their own clock before every
+    /// [`render`](Self::render), so that widgets are animated in step with the rest of the app. When
+    /// it's not called, the time between calls to `render` is measured instead.
+    pub fn set_delta_time(&mut self, delta_time: f32) {
+        self.next_delta_time = Some(delta_time.max(0.0));
+    }
+
+    /// Returns the time between the last two frames (in seconds)
+    ///
+    /// This is zero until the second frame is rendered.
+    pub fn delta_time(&self) -> f32 {
+        self.delta_time
+    }
+
+    /// Returns the total time of all frames rendered so far (in seconds)
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Schedules a callback to be fired once the given delay (in seconds) has passed
+    ///
+    /// Each timeout is identified by the widget that scheduled it and a key. Scheduling a timeout
+    /// with the same key as a pending one replaces it, restarting the delay. This makes it easy to
+    /// debounce an action, such as searching as the user types. The timeout is cancelled if the
+    /// widget is removed before it fires.
+    ///
+    /// Timeouts are checked at the start of each [`render`](Self::render), after the callbacks set
+    /// with [`set_tick`](Self::set_tick) and before any widgets are rendered, so state changed by
+    /// them is rendered in the same frame. This means a timeout fires on the first frame after its
+    /// delay has passed. Delays are measured in frame time (see [`elapsed`](Self::elapsed)).
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The ID of the widget
+    /// * `key`: The key identifying the timeout within the widget
+    /// * `delay`: The time to wait before firing the callback (in seconds)
+    /// * `handler`: The callback to fire
+    ///
+    pub fn set_timeout(&mut self, index: Index, key: &str, delay: f32, handler: Handler) {
+        let due = self.elapsed + delay.max(0.0);
+        self.timeouts
+            .insert((index, key.to_string()), (due, handler));
+    }
+
+    /// Cancels the pending timeout with the given key, if any
+    pub fn clear_timeout(&mut self, index: Index, key: &str) {
+        self.timeouts.remove(&(index, key.to_string()));
+    }
+
+    /// Returns true if the timeout with the given key is still pending
+    pub fn has_timeout(&self, index: Index, key: &str) -> bool {
+        self.timeouts.contains_key(&(index, key.to_string()))
+    }
+
+    /// Fires the callbacks scheduled with [`set_timeout`](Self::set_timeout) that are now due
+    fn dispatch_timeouts(&mut self) {
+        if self.timeouts.is_empty() {
+            return;
+        }
+
+        // Widgets that have since been removed should no longer be notified
+        let tree = &self.widget_manager.tree;
+        self.timeouts.retain(|(index, _), _| tree.contains(*index));
+
+        let elapsed = self.elapsed;
+        let mut due: Vec<_> = self
+            .timeouts
+            .iter()
+            .filter(|(_, (time, _))| *time <= elapsed)
+            .map(|(key, (time, _))| (key.clone(), *time))
+            .collect();
+        // Timeouts that were due first are fired first
+        due.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        for (key, _) in due {
+            if let Some((_, handler)) = self.timeouts.remove(&key) {
+                handler.call(());
+            }
+        }
+    }
+
+    /// Fires the callbacks set with [`set_tick`](Self::set_tick) with the time since the last frame
+    fn dispatch_tick(&mut self) {
+        let now = Instant::now();
+        let measured = self
+            .last_tick
+            .map(|last_tick| now.duration_since(last_tick).as_secs_f32())
+            .unwrap_or_default();
+        self.last_tick = Some(now);
+        let delta_time = self.next_delta_time.take().unwrap_or(measured);
+        self.delta_time = delta_time;
+        self.elapsed += delta_time;
+
+        if self.tick_handlers.is_empty() {
+            return;
+        }
+
+        // Widgets that have since been removed should no longer be notified
+        let tree = &self.widget_manager.tree;
+        self.tick_handlers.retain(|index, _| tree.contains(*index));
+
+        let handlers: Vec<_> = self.tick_handlers.values().cloned().collect();
+        for handler in handlers {
+            handler.call(delta_time);
+        }
+    }
+
+    /// Sets the clipboard that widgets copy to and paste from
+    ///
+    /// Integrations should call this with a provider backed by the clipboard of their platform, if
+    /// any. Passing `None` removes the current provider, making the clipboard unavailable.
+    pub fn set_clipboard(&mut self, provider: Option<Box<dyn ClipboardProvider>>) {
+        self.clipboard.set_provider(provider);
+    }
+
+    /// Returns the clipboard that widgets copy to and paste from
+    pub fn clipboard(&mut self) -> &mut Clipboard {
+        &mut self.clipboard
+    }
+
+    /// Sets the logical size of the window the UI is rendered in (in pixels)
+    ///
+    /// Integrations should call this whenever the window is created or resized. Widgets that read
+    /// the size using [`KayakContextRef::window_size`] are re-rendered when it changes.
+    pub fn set_window_size(&mut self, width: f32, height: f32) {
+        if self.window_size.get() != Some((width, height)) {
+            self.window_size.set(Some((width, height)));
+        }
+    }
+
+    /// Returns the logical size of the window the UI is rendered in (in pixels)
+    ///
+    /// This is `None` until the integration sets it using [`set_window_size`](Self::set_window_size).
+    pub fn window_size(&self) -> Option<(f32, f32)> {
+        self.window_size.get()
+    }
+
+    /// Returns the binding containing the logical size of the window (in pixels)
+    pub(crate) fn window_size_binding(&self) -> Binding<Option<(f32, f32)>> {
+        self.window_size.clone()
+    }
+
+    /// Returns the platform the app is running on
+    pub fn platform(&self) -> Platform {
+        Platform::current()
+    }
+
+    /// Sets the accessibility preferences of the user, as reported by the OS
+    ///
+    /// Integrations should call this with the platform's settings, where they're available. Since
+    /// winit doesn't report them, apps using the Bevy integration can query the platform and call
+    /// this themselves. Widgets that read the preferences using [`KayakContextRef::preferences`]
+    /// are re-rendered when they change. These are ignored while
+    /// [overridden](Self::override_preferences).
+    pub fn set_system_preferences(&mut self, preferences: UserPreferences) {
+        self.system_preferences = preferences;
+        self.update_preferences();
+    }
+
+    /// Overrides the accessibility preferences reported by the OS
+    ///
+    /// This is useful for testing how widgets respond to the preferences, or for an in-app
+    /// setting. Passing `None` removes the override, returning to the system preferences.
+    pub fn override_preferences(&mut self, preferences: Option<UserPreferences>) {
+        self.preference_overrides = preferences;
+        self.update_preferences();
+    }
+
+    /// Returns the accessibility preferences that are currently in effect
+    pub fn preferences(&self) -> UserPreferences {
+        self.preferences.get()
+    }
+
+    /// Returns the binding containing the accessibility preferences that are currently in effect
+    pub(crate) fn preferences_binding(&self) -> Binding<UserPreferences> {
+        self.preferences.clone()
+    }
+
+    /// Updates the preferences in effect, only notifying the bound widgets if they changed
+    fn update_preferences(&mut self) {
+        let preferences = self.preference_overrides.unwrap_or(self.system_preferences);
+        if self.preferences.get() != preferences {
+            self.preferences.set(preferences);
+        }
+    }
+
+    /// Sets the callback fired when the size of the given widget changes
+    ///
+    /// Unlike an [`OnLayout`](crate::OnLayout) handler, which fires whenever the widget's rect
+    /// changes, this only fires when its width or height changes. The first size is reported as
+    /// soon as the widget has been laid out. Later changes are debounced: the new size is only
+    /// reported once it has stayed the same for a short time (0.1 seconds), so that the callback
+    /// isn't fired on every frame while the window is being resized. Sizes are reported after
+    /// layout, so state changed by the callback is rendered in the next frame.
+    ///
+    /// Setting a new callback keeps track of the size last reported to the previous one, so this
+    /// can safely be called on every render. Passing `None` removes the current callback, if any.
+    /// Only one callback may be set per widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The ID of the widget
+    /// * `handler`: The callback to fire
+    ///
+    pub fn set_resize(&mut self, index: Index, handler: Option<Handler<ResizeEvent>>) {
+        match handler {
+            Some(handler) => {
+                self.resize_observers
+                    .entry(index)
+                    .and_modify(|observer| observer.handler = handler.clone())
+                    .or_insert(ResizeObserver {
+                        handler,
+                        reported: None,
+                        pending: None,
+                    });
+            }
+            None => {
+                self.resize_observers.remove(&index);
+            }
+        }
+    }
+
+    /// Fires the callbacks set with [`set_resize`](Self::set_resize) for widgets whose size changed
+    fn dispatch_resize(&mut self) {
+        if self.resize_observers.is_empty() {
+            return;
+        }
+
+        // Widgets that have since been removed should no longer be notified
+        let tree = &self.widget_manager.tree;
+        self.resize_observers
+            .retain(|index, _| tree.contains(*index));
+
+        let elapsed = self.elapsed;
+        let layout_cache = &self.widget_manager.layout_cache;
+        let mut events = Vec::new();
+        for (index, observer) in self.resize_observers.iter_mut() {
+            let size = match layout_cache.rect.get(index) {
+                Some(rect) => (rect.width, rect.height),
+                None => continue,
+            };
+
+            match observer.reported {
+                None => observer.pending = Some((size, elapsed)),
+                Some(reported) if reported == size => observer.pending = None,
+                Some(_) => {
+                    // Restart the delay whenever the size changes again
+                    if observer.pending.map(|(pending, _)| pending) != Some(size) {
+                        observer.pending = Some((size, elapsed + RESIZE_DEBOUNCE));
+                    }
+                }
+            }
+
+            if let Some((size, due)) = observer.pending {
+                if due <= elapsed {
+                    let event = ResizeEvent {
+                        previous: observer.reported,
+                        size,
+                    };
+                    observer.reported = Some(size);
+                    observer.pending = None;
+                    events.push((observer.handler.clone(), event));
+                }
+            }
+        }
+
+        for (handler, event) in events {
+            handler.call(event);
+        }
+    }
+
+    /// Sets the callback fired when a click lands outside of the given widget's subtree
+    ///
+    /// Passing `None` removes the current callback, if any. Only one callback may be set per widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The ID of the widget
+    /// * `handler`: The callback to fire
+    ///
+    pub fn set_click_outside(&mut self, index: Index, handler: Option<Handler>) {
+        match handler {
+            Some(handler) => {
+                self.click_outside_handlers.insert(index, handler);
+            }
+            None => {
+                self.click_outside_handlers.remove(&index);
+            }
+        }
+    }
+
+    /// Fires the callback of every widget whose subtree does not contain the widget under the cursor
+    fn dispatch_click_outside(&mut self) {
+        if self.click_outside_handlers.is_empty() {
+            return;
+        }
+
+        let (x, y) = self.last_mouse_position();
+        let target = self.widget_at(x, y);
+        let tree = &self.widget_manager.tree;
+
+        // Widgets that have since been removed should no longer be notified
+        self.click_outside_handlers
+            .retain(|index, _| tree.contains(*index));
+
+        let handlers: Vec<Handler> = self
+            .click_outside_handlers
+            .iter()
+            .filter(|(index, _)| match target {
+                Some(target) => target != **index && !tree.is_descendant(target, **index),
+                None => true,
+            })
+            .map(|(_, handler)| handler.clone())
+            .collect();
+        for handler in handlers {
+            handler.call(());
+        }
     }
 
     #[allow(dead_code)]
@@ -608,6 +1337,15 @@ impl KayakContext {
         self.widget_manager.focus_tree.current()
     }
 
+    /// Checks if the widget with the given ID is focused and should visibly indicate it
+    ///
+    /// This is like the `:focus-visible` pseudo-class in CSS: it's only true when focus was moved
+    /// to the widget using the keyboard (such as by pressing `Tab`), not when the widget was
+    /// clicked. Widgets can use this to only show a focus ring when it helps to navigate.
+    pub fn is_focus_visible(&self, index: Index) -> bool {
+        self.is_focused(index) && self.widget_manager.focus_tree.is_focus_visible()
+    }
+
     /// Gets whether the widget with the given ID can be focused
     ///
     /// The values are:
@@ -636,6 +1374,19 @@ impl KayakContext {
         self.widget_manager.set_focusable(focusable, index, false);
     }
 
+    /// Sets whether the widget with the given ID (and all of its descendants) is disabled
+    ///
+    /// Disabled widgets can't be focused and ignore all mouse and keyboard events. Events targeting
+    /// them still propagate to any enabled ancestors.
+    pub fn set_disabled(&mut self, index: Index, is_disabled: bool) {
+        self.widget_manager.set_disabled(index, is_disabled);
+    }
+
+    /// Returns true if the widget with the given ID, or any of its ancestors, is disabled
+    pub fn is_disabled(&self, index: Index) -> bool {
+        self.widget_manager.is_disabled(index)
+    }
+
     /// Get the last calculated mouse position.
     ///
     /// Calling this from a widget will return the last mouse position at the time the widget was rendered.
@@ -643,6 +1394,28 @@ impl KayakContext {
         self.event_dispatcher.current_mouse_position()
     }
 
+    /// Get the change in mouse position caused by the last mouse movement
+    ///
+    /// This is in the same coordinate space as [`last_mouse_position`](Self::last_mouse_position).
+    pub fn mouse_delta(&self) -> (f32, f32) {
+        self.event_dispatcher.mouse_delta()
+    }
+
+    /// Get the modifier keys that are currently pressed
+    ///
+    /// These are tracked from the keyboard input, regardless of which widget is focused.
+    pub fn keyboard_modifiers(&self) -> KeyboardModifiers {
+        self.event_dispatcher.keyboard_modifiers()
+    }
+
+    /// Get the smoothed velocity of the mouse, in pixels per second
+    ///
+    /// This is in the same coordinate space as [`last_mouse_position`](Self::last_mouse_position)
+    /// and decays to zero once the mouse stops moving.
+    pub fn mouse_velocity(&self) -> (f32, f32) {
+        self.event_dispatcher.mouse_velocity()
+    }
+
     /// Query the Bevy `World` with the given `SystemParam`
     ///
     /// The function passed to this method will be called with the retrieved value from `World`. If
@@ -737,11 +1510,120 @@ impl KayakContext {
         self.assets.set_asset(key, asset);
     }
 
+    /// Measures the given text content using the font with the given name
+    ///
+    /// See [`measure_text_with_properties`](crate::measure_text_with_properties) for details.
+    ///
+    /// Returns `None` if the font hasn't been loaded yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `content`: The text to measure
+    /// * `font`: The name of the font (such as [`DEFAULT_FONT`](crate::DEFAULT_FONT))
+    /// * `properties`: The font size, line height, letter spacing, and maximum size of the text
+    ///
+    pub fn measure_text(
+        &mut self,
+        content: &str,
+        font: &str,
+        properties: TextProperties,
+    ) -> Option<TextMeasurement> {
+        let font = self.assets.get_asset::<KayakFont, _>(font).get()?;
+        Some(crate::measure_text_with_properties(
+            content, &font, properties,
+        ))
+    }
+
+    /// Sets the value of a binding, throttling how often it's actually updated
+    ///
+    /// This is meant for high-frequency updates, such as those made while hovering or dragging. The
+    /// first value is set immediately. Any value given within `interval` seconds of the last update
+    /// is held back instead, replacing any other held back value, so that rapid updates coalesce
+    /// into a single one.
+    ///
+    /// The trailing (most recent) value is never dropped: it's applied on the first
+    /// [`render`](Self::render) once the interval has passed. Use
+    /// [`cancel_throttled`](Self::cancel_throttled) before setting the binding directly, so that
+    /// a held back value doesn't overwrite it.
+    ///
+    /// # Arguments
+    ///
+    /// * `binding`: The binding to set
+    /// * `value`: The new value
+    /// * `interval`: The minimum time between updates (in seconds)
+    ///
+    pub fn set_throttled<T: 'static + Clone + PartialEq + Send + Sync>(
+        &mut self,
+        binding: &Binding<T>,
+        value: T,
+        interval: f32,
+    ) {
+        match self.throttled_updates.get_mut(&binding.id) {
+            Some(update) if !update.is_ready() => {
+                let binding = binding.clone();
+                update.defer(interval, move || binding.set(value));
+            }
+            _ => {
+                // Only start throttling once the value actually changes
+                if binding.set_if_changed(value) {
+                    self.throttled_updates
+                        .insert(binding.id, ThrottledUpdate::new(interval));
+                }
+            }
+        }
+    }
+
+    /// Discards any value held back by [`set_throttled`](Self::set_throttled) for the given binding
+    ///
+    /// The next throttled value given for the binding will be set immediately.
+    pub fn cancel_throttled<T>(&mut self, binding: &Binding<T>) {
+        self.throttled_updates.remove(&binding.id);
+    }
+
+    /// Applies the values held back by [`set_throttled`](Self::set_throttled) whose interval has passed
+    fn flush_throttled(&mut self) {
+        self.throttled_updates.retain(|_, update| update.flush());
+    }
+
     /// Get the ID of the widget that was last clicked
     pub fn get_last_clicked_widget(&self) -> Binding<Index> {
         self.event_dispatcher.last_clicked.clone()
     }
 
+    /// Get the ID of the widget currently under the cursor, if any
+    pub fn get_hovered_widget(&self) -> Binding<Option<Index>> {
+        self.event_dispatcher.last_hovered.clone()
+    }
+
+    /// Returns the ID of the topmost widget at the given point (in pixels), if any
+    ///
+    /// See [`WidgetManager::widget_at`](crate::widget_manager::WidgetManager::widget_at) for
+    /// details on how widgets are tested.
+    pub fn widget_at(&self, x: f32, y: f32) -> Option<Index> {
+        self.widget_manager.widget_at((x, y))
+    }
+
+    /// Takes a snapshot of the current layout of the entire widget tree
+    ///
+    /// This uses the layout from the last call to [`render`](Self::render), which does not require
+    /// a window or renderer. Returns `None` if no widgets have been added yet.
+    pub fn snapshot(&self) -> Option<LayoutSnapshot> {
+        let root = self.widget_manager.tree.root_node?;
+        Some(LayoutSnapshot::new(&self.widget_manager, root))
+    }
+
+    /// Builds the accessibility tree of the entire widget tree
+    ///
+    /// The tree contains every widget that has set its [`Accessibility`](crate::Accessibility)
+    /// (see [`KayakContextRef::set_accessibility`]), which integrations can pass on to assistive
+    /// technologies (such as through AccessKit). The returned nodes are the top-most of those
+    /// widgets. Rects are taken from the layout of the last call to [`render`](Self::render), and
+    /// each widget's [`describes`](crate::Accessibility::describes) links are added to the
+    /// [`described_by`](crate::Accessibility::described_by) of the widgets they describe.
+    pub fn accessibility_tree(&self) -> Vec<AccessNode> {
+        AccessNode::build_tree(&self.widget_manager)
+    }
+
     /// Returns true if the cursor is currently over a valid widget
     ///
     /// For the purposes of this method, a valid widget is one which has the means to display a visual component on its own.
@@ -772,6 +1654,18 @@ impl KayakContext {
         self.event_dispatcher.capture_cursor(index)
     }
 
+    /// Captures all cursor events for the given index until the mouse is released
+    ///
+    /// Every mouse event (including the final `MouseUp`) is sent to the given widget, regardless of
+    /// what's under the cursor. This keeps a drag tracking the cursor even if it's moved outside the
+    /// widget. Unlike [`capture_cursor`](Self::capture_cursor), the capture is released
+    /// automatically once the mouse button is released.
+    ///
+    /// Returns the index of the previous captor.
+    pub fn capture_pointer(&mut self, index: Index) -> Option<Index> {
+        self.event_dispatcher.capture_pointer(index)
+    }
+
     /// Releases the captured cursor
     ///
     /// Returns true if successful.
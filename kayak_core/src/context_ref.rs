@@ -1,6 +1,12 @@
 use std::path::PathBuf;
 
-use crate::{Binding, Changeable, Index, KayakContext, WidgetTree};
+use kayak_font::{KayakFont, TextProperties};
+
+use crate::{
+    Accessibility, BindRef, Binding, Bound, Changeable, Clipboard, Handler, Index, KayakContext,
+    KeyboardModifiers, Platform, RenderError, ResizeEvent, Shortcut, ShortcutConflict,
+    TextMeasurement, UserPreferences, WidgetTree,
+};
 
 /// A temporary struct used to provide limited access to the containing [`KayakContext`]
 ///
@@ -149,6 +155,25 @@ impl<'a> KayakContextRef<'a> {
             .create_state(self.current_id.unwrap_or_default(), initial_state)
     }
 
+    /// Creates a read-only value derived from other bound values
+    ///
+    /// Any [`Bound`] values read while calculating the value become its dependencies. Changing one
+    /// of them recalculates the value and re-renders the current widget. See
+    /// [`KayakContext::computed`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `calculate`: The function calculating the value
+    ///
+    pub fn computed<T, F>(&mut self, calculate: F) -> BindRef<T>
+    where
+        T: resources::Resource + Clone + PartialEq,
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        self.context
+            .computed(self.current_id.unwrap_or_default(), calculate)
+    }
+
     /// Creates a callback that runs as a side-effect of one of its dependencies being changed.
     ///
     /// All dependencies must be implement the [Changeable](crate::Changeable) trait, which means it will generally
@@ -274,6 +299,26 @@ impl<'a> KayakContextRef<'a> {
         self.context.current_focus()
     }
 
+    /// Returns true if the given widget or one of its descendants is currently focused
+    ///
+    /// This is useful for handling [`FocusOut`](crate::EventType::FocusOut), which is also sent
+    /// when focus only moves between two descendants of a widget.
+    pub fn contains_focus(&self, id: Index) -> bool {
+        match self.context.current_focus() {
+            Some(focus) => focus == id || self.context.widget_manager.tree.is_descendant(focus, id),
+            None => false,
+        }
+    }
+
+    /// Checks if the widget with the given ID is focused and should visibly indicate it
+    ///
+    /// This is only true when focus was moved to the widget using the keyboard. It's already
+    /// updated when the [`Focus`](crate::EventType::Focus) event is sent, so it can be checked
+    /// from within the widget's `on_event` handler. See [`KayakContext::is_focus_visible`].
+    pub fn is_focus_visible(&self, id: Index) -> bool {
+        self.context.is_focus_visible(id)
+    }
+
     /// Gets whether the widget with the given ID can be focused
     ///
     /// The values are:
@@ -304,6 +349,21 @@ impl<'a> KayakContextRef<'a> {
         }
     }
 
+    /// Sets whether the current widget (and all of its descendants) is disabled
+    ///
+    /// Disabled widgets can't be focused and ignore all mouse and keyboard events. Events targeting
+    /// them still propagate to any enabled ancestors.
+    pub fn set_disabled(&mut self, is_disabled: bool) {
+        if let Some(id) = self.current_id {
+            self.context.set_disabled(id, is_disabled);
+        }
+    }
+
+    /// Returns true if the widget with the given ID, or any of its ancestors, is disabled
+    pub fn is_disabled(&self, id: Index) -> bool {
+        self.context.is_disabled(id)
+    }
+
     /// Query the Bevy `World` with the given `SystemParam`
     ///
     /// The function passed to this method will be called with the retrieved value from `World`. If
@@ -375,6 +435,30 @@ impl<'a> KayakContextRef<'a> {
         self.context.get_asset(key)
     }
 
+    /// Sets the value of a binding, throttling how often it's actually updated
+    ///
+    /// The trailing value is never dropped. See [`KayakContext::set_throttled`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `binding`: The binding to set
+    /// * `value`: The new value
+    /// * `interval`: The minimum time between updates (in seconds)
+    ///
+    pub fn set_throttled<T: 'static + Clone + PartialEq + Send + Sync>(
+        &mut self,
+        binding: &Binding<T>,
+        value: T,
+        interval: f32,
+    ) {
+        self.context.set_throttled(binding, value, interval);
+    }
+
+    /// Discards any value held back by [`set_throttled`](Self::set_throttled) for the given binding
+    pub fn cancel_throttled<T>(&mut self, binding: &Binding<T>) {
+        self.context.cancel_throttled(binding);
+    }
+
     /// Stores an asset along with a key to access it
     ///
     /// # Arguments
@@ -397,11 +481,383 @@ impl<'a> KayakContextRef<'a> {
         self.context.last_mouse_position()
     }
 
+    /// Get the change in mouse position caused by the last mouse movement
+    ///
+    /// This is in the same coordinate space as [`last_mouse_position`](Self::last_mouse_position).
+    pub fn mouse_delta(&self) -> (f32, f32) {
+        self.context.mouse_delta()
+    }
+
+    /// Get the modifier keys that are currently pressed
+    ///
+    /// This is useful for events that don't carry the modifiers themselves, such as
+    /// [`CharInput`](crate::EventType::CharInput).
+    pub fn keyboard_modifiers(&self) -> KeyboardModifiers {
+        self.context.keyboard_modifiers()
+    }
+
+    /// Get the smoothed velocity of the mouse, in pixels per second
+    ///
+    /// This is in the same coordinate space as [`last_mouse_position`](Self::last_mouse_position)
+    /// and decays to zero once the mouse stops moving. This is useful for widgets that respond to
+    /// flick gestures, such as inertial scrolling.
+    pub fn mouse_velocity(&self) -> (f32, f32) {
+        self.context.mouse_velocity()
+    }
+
     /// Get the ID of the widget that was last clicked
     pub fn get_last_clicked_widget(&self) -> Binding<Index> {
         self.context.get_last_clicked_widget()
     }
 
+    /// Get the ID of the widget currently under the cursor, if any
+    ///
+    /// Binding to the returned value will cause the current widget to re-render whenever
+    /// the hovered widget changes.
+    pub fn get_hovered_widget(&self) -> Binding<Option<Index>> {
+        self.context.get_hovered_widget()
+    }
+
+    /// Returns the ID of the topmost widget at the given point (in pixels), if any
+    ///
+    /// This uses the layout from the last time layout was calculated. It respects the draw order,
+    /// clip regions, and `pointer_events` styles of widgets, making it useful for custom input
+    /// handling (such as detecting clicks outside of a widget).
+    pub fn widget_at(&self, x: f32, y: f32) -> Option<Index> {
+        self.context.widget_at(x, y)
+    }
+
+    /// Sets the callback fired when a click lands anywhere outside of the current widget's subtree
+    ///
+    /// This is useful for closing dropdowns, popovers, and menus. A click is considered outside if
+    /// the widget found by [`widget_at`](Self::widget_at) is neither the current widget nor one of its
+    /// descendants (including clicks that don't land on any widget at all).
+    ///
+    /// The callback remains set until it is replaced or removed by passing `None`, or until the
+    /// current widget is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler`: The callback to fire, or `None` to remove the current one
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn MyDropdown() {
+    ///   let (is_open, set_is_open, ..) = use_state!(false);
+    ///
+    ///   // Close the dropdown when clicking elsewhere, but only while it's open
+    ///   context.on_click_outside(is_open.then(|| Handler::new(move |_| set_is_open(false))));
+    /// }
+    /// ```
+    pub fn on_click_outside(&mut self, handler: Option<Handler>) {
+        self.context
+            .set_click_outside(self.current_id.unwrap_or_default(), handler);
+    }
+
+    /// Returns the time between the last two frames (in seconds)
+    ///
+    /// This can be used to animate widgets at the same speed regardless of the frame rate. Within
+    /// the Bevy integration, this is the delta time of Bevy's `Time` resource.
+    pub fn delta_time(&self) -> f32 {
+        self.context.delta_time()
+    }
+
+    /// Returns the total time of all frames rendered so far (in seconds)
+    pub fn elapsed(&self) -> f32 {
+        self.context.elapsed()
+    }
+
+    /// Sets the callback fired at the start of every frame while the current widget is mounted
+    ///
+    /// The callback is given the time since the previous frame (in seconds). This is meant for
+    /// animations, which can scale their progress by this time so that they run at the same speed
+    /// regardless of the frame rate. The callback is fired before any widgets are rendered in the
+    /// frame, so state changed from within it is rendered in the same frame.
+    ///
+    /// The callback remains set until it is removed by passing `None`, or until the current widget is
+    /// removed. Widgets should therefore only set it while they're animating, to avoid doing work
+    /// every frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler`: The callback to fire, or `None` to remove the current one
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn MyFade() {
+    ///   let (opacity, set_opacity, ..) = use_state!(0.0);
+    ///
+    ///   // Fade in over half a second
+    ///   let is_fading = opacity < 1.0;
+    ///   context.on_tick(is_fading.then(|| {
+    ///     Handler::new(move |delta_time: f32| set_opacity((opacity + delta_time * 2.0).min(1.0)))
+    ///   }));
+    /// }
+    /// ```
+    pub fn on_tick(&mut self, handler: Option<Handler<f32>>) {
+        self.context
+            .set_tick(self.current_id.unwrap_or_default(), handler);
+    }
+
+    /// Returns the clipboard, which can be used to copy and paste text
+    ///
+    /// Reading or writing the clipboard returns an error if the integration hasn't provided one
+    /// (or if the platform clipboard fails), so widgets should be prepared to handle that case.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let on_event = OnEvent::new(move |ctx, event| match event.event_type {
+    ///     EventType::Click(..) => {
+    ///         if let Err(err) = ctx.clipboard().set_text("Hello world!") {
+    ///             eprintln!("{}", err);
+    ///         }
+    ///     }
+    ///     _ => {}
+    /// });
+    /// ```
+    pub fn clipboard(&mut self) -> &mut Clipboard {
+        self.context.clipboard()
+    }
+
+    /// Returns the logical size of the window the UI is rendered in (in pixels)
+    ///
+    /// The current widget is re-rendered whenever the size changes, such as when the window is
+    /// resized. This is `None` if the integration hasn't provided a size.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn MyOverlay() {
+    ///   let (width, height) = context.window_size().unwrap_or((800.0, 600.0));
+    ///   let is_narrow = width < 600.0;
+    ///   // ...
+    /// }
+    /// ```
+    pub fn window_size(&mut self) -> Option<(f32, f32)> {
+        let binding = self.context.window_size_binding();
+        self.bind(&binding);
+        binding.get()
+    }
+
+    /// Returns the platform the app is running on
+    ///
+    /// This is known at compile time, so it never changes and the current widget isn't bound to it.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn MainMenu() {
+    ///   // Browsers can't be closed by the app, so there's no point in a quit button
+    ///   let can_quit = !context.platform().is_web();
+    ///   rsx! {
+    ///     <If condition={can_quit}>
+    ///       <Button label={Some("Quit".to_string())} />
+    ///     </If>
+    ///   }
+    /// }
+    /// ```
+    pub fn platform(&self) -> Platform {
+        self.context.platform()
+    }
+
+    /// Returns the accessibility preferences of the user
+    ///
+    /// This also binds the current widget to the preferences, so it's re-rendered whenever they
+    /// change. See [`KayakContext::set_system_preferences`].
+    pub fn preferences(&mut self) -> UserPreferences {
+        let binding = self.context.preferences_binding();
+        self.bind(&binding);
+        binding.get()
+    }
+
+    /// Returns true if the user prefers to minimize non-essential motion
+    ///
+    /// Widgets that animate should skip straight to the end of their animations when this is true.
+    /// Like [`preferences`](Self::preferences), this binds the current widget.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn MyPopup() {
+    ///   let duration = if context.prefers_reduced_motion() { 0.0 } else { 0.3 };
+    ///   // ...
+    /// }
+    /// ```
+    pub fn prefers_reduced_motion(&mut self) -> bool {
+        self.preferences().reduced_motion
+    }
+
+    /// Returns true if the user prefers colors with a higher contrast
+    ///
+    /// Like [`preferences`](Self::preferences), this binds the current widget.
+    pub fn prefers_high_contrast(&mut self) -> bool {
+        self.preferences().high_contrast
+    }
+
+    /// Sets the callback fired when the width or height of the current widget changes
+    ///
+    /// The callback is given the previously reported size along with the new one. Rapid changes,
+    /// such as while the window is being dragged, are debounced so that only the final size is
+    /// reported. See [`KayakContext::set_resize`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler`: The callback to fire, or `None` to remove the current one
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn MyCanvas() {
+    ///   let (size, set_size, ..) = use_state!((0.0, 0.0));
+    ///
+    ///   // Only re-draw the canvas content once its size settles
+    ///   context.on_resize(Some(Handler::new(move |event: ResizeEvent| set_size(event.size))));
+    /// }
+    /// ```
+    pub fn on_resize(&mut self, handler: Option<Handler<ResizeEvent>>) {
+        self.context
+            .set_resize(self.current_id.unwrap_or_default(), handler);
+    }
+
+    /// Schedules a callback to be fired once the given delay (in seconds) has passed
+    ///
+    /// Scheduling a timeout with the same key as a pending one (from the current widget) replaces
+    /// it, restarting the delay. The timeout is cancelled if the current widget is removed before it
+    /// fires. See [`KayakContext::set_timeout`] for when timeouts fire relative to rendering.
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: The key identifying the timeout within the current widget
+    /// * `delay`: The time to wait before firing the callback (in seconds)
+    /// * `handler`: The callback to fire
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn MySearch(props: MySearchProps) {
+    ///   let search = props.on_search.clone();
+    ///   props.on_event = Some(OnEvent::new(move |ctx, event| {
+    ///     if let EventType::CharInput { .. } = event.event_type {
+    ///       // Only search once the user stops typing for a moment
+    ///       let search = search.clone();
+    ///       ctx.set_timeout("search", 0.3, Handler::new(move |_| search.call(())));
+    ///     }
+    ///   }));
+    /// }
+    /// ```
+    pub fn set_timeout(&mut self, key: &str, delay: f32, handler: Handler) {
+        self.context
+            .set_timeout(self.current_id.unwrap_or_default(), key, delay, handler);
+    }
+
+    /// Cancels the pending timeout with the given key (from the current widget), if any
+    pub fn clear_timeout(&mut self, key: &str) {
+        self.context
+            .clear_timeout(self.current_id.unwrap_or_default(), key);
+    }
+
+    /// Returns true if the timeout with the given key (from the current widget) is still pending
+    pub fn has_timeout(&self, key: &str) -> bool {
+        self.context
+            .has_timeout(self.current_id.unwrap_or_default(), key)
+    }
+
+    /// Sets the callback fired when the Escape key is pressed while the current widget is open
+    ///
+    /// This is meant for closing overlays, such as modals, dropdowns, and drawers. When several
+    /// callbacks are set, only the most recently added one is fired, so that Escape closes only the
+    /// topmost overlay. The focused widget can prevent this by calling
+    /// [`event.prevent_default()`](crate::Event::prevent_default) on the `KeyDown` event.
+    ///
+    /// The callback remains set until it is removed by passing `None`, or until the current widget is
+    /// removed. Overlays should therefore only set it while they're open.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler`: The callback to fire, or `None` to remove the current one
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn MyModal(props: MyModalProps) {
+    ///   let (is_open, set_is_open, ..) = use_state!(true);
+    ///
+    ///   let close_on_escape = props.close_on_escape && is_open;
+    ///   context.on_escape(close_on_escape.then(|| Handler::new(move |_| set_is_open(false))));
+    /// }
+    /// ```
+    pub fn on_escape(&mut self, handler: Option<Handler>) {
+        self.context
+            .set_close_on_escape(self.current_id.unwrap_or_default(), handler);
+    }
+
+    /// Registers a keyboard shortcut for the current widget
+    ///
+    /// Shortcuts are fired before the focused widget receives the key press, unless that widget
+    /// captures key presses (see [`capture_keys`](Self::capture_keys)) and the shortcut doesn't use
+    /// Control, Alt, or Meta. This is meant for app-level actions, such as saving with Ctrl+S.
+    ///
+    /// Since widgets re-render often, registering the same shortcut again from the current widget
+    /// simply replaces its callback. The shortcut remains registered until it is removed with
+    /// [`unregister_shortcut`](Self::unregister_shortcut), or until the current widget is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `shortcut`: The key combination
+    /// * `handler`: The callback to fire
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ShortcutConflict`](crate::ShortcutConflict) if another widget already registered
+    /// the same shortcut.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn Editor() {
+    ///   let save = Handler::new(move |_| save_document());
+    ///   if let Err(conflict) = context.register_shortcut(Shortcut::ctrl('s'), save) {
+    ///     eprintln!("{}", conflict);
+    ///   }
+    /// }
+    /// ```
+    pub fn register_shortcut(
+        &mut self,
+        shortcut: Shortcut,
+        handler: Handler,
+    ) -> Result<(), ShortcutConflict> {
+        self.context
+            .register_shortcut(self.current_id.unwrap_or_default(), shortcut, handler)
+    }
+
+    /// Removes a keyboard shortcut registered by the current widget
+    pub fn unregister_shortcut(&mut self, shortcut: Shortcut) {
+        self.context
+            .unregister_shortcut(self.current_id.unwrap_or_default(), shortcut);
+    }
+
+    /// Sets whether the current widget captures key presses while focused, rather than letting them
+    /// trigger shortcuts
+    ///
+    /// This is meant for text inputs, so that typing doesn't trigger shortcuts bound to plain keys.
+    /// Shortcuts using Control, Alt, or Meta are still fired.
+    pub fn capture_keys(&mut self, captures_keys: bool) {
+        self.context
+            .set_captures_keys(self.current_id.unwrap_or_default(), captures_keys);
+    }
+
     /// Returns true if the cursor is currently over a valid widget
     ///
     /// For the purposes of this method, a valid widget is one which has the means to display a visual component on its own.
@@ -432,6 +888,18 @@ impl<'a> KayakContextRef<'a> {
         self.context.capture_cursor(index)
     }
 
+    /// Captures all cursor events for the current widget until the mouse is released
+    ///
+    /// This is meant to be called on `MouseDown` to start a drag. Every mouse event (including the
+    /// final `MouseUp`) is then sent to this widget, regardless of what's under the cursor. The
+    /// capture is released automatically once the mouse button is released.
+    ///
+    /// Returns the index of the previous captor.
+    pub fn capture_pointer(&mut self) -> Option<Index> {
+        self.context
+            .capture_pointer(self.current_id.unwrap_or_default())
+    }
+
     /// Releases the captured cursor
     ///
     /// Returns true if successful.
@@ -455,6 +923,19 @@ impl<'a> KayakContextRef<'a> {
         self.context.force_release_cursor()
     }
 
+    /// Checks if the widget with the given ID is still in the widget tree
+    ///
+    /// Widgets are removed from the tree once their parent no longer renders them. This is useful
+    /// for cleaning up state kept about other widgets, such as the owner of a tooltip.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The ID of the widget
+    ///
+    pub fn is_mounted(&self, id: Index) -> bool {
+        self.context.widget_manager.tree.contains(id)
+    }
+
     /// Attempts to get the parent of the widget with the given ID
     ///
     /// A "valid" parent is simply one that does not have a render command of
@@ -491,6 +972,228 @@ impl<'a> KayakContextRef<'a> {
         self.context.widget_manager.get_layout(widget_id)
     }
 
+    /// Sets the preferred size of the current widget (in pixels)
+    ///
+    /// This is meant for custom widgets with an intrinsic size, such as one drawn from an image or
+    /// text. The size is used for the widget's width and height whenever they aren't explicitly set
+    /// by its styles, and is reported to parents by [`measure`](Self::measure). Passing `None`
+    /// removes the preferred size.
+    ///
+    /// # Arguments
+    ///
+    /// * `size`: The preferred width and height
+    ///
+    pub fn set_preferred_size(&mut self, size: Option<(f32, f32)>) {
+        self.context
+            .widget_manager
+            .set_preferred_size(self.current_id.unwrap_or_default(), size);
+    }
+
+    /// Sets the baseline of the current widget, as an offset from the top of its layout (in pixels)
+    ///
+    /// This is meant for custom widgets that draw their own text (or text-like content), so they
+    /// can be aligned with neighboring text in rows with an
+    /// [`AlignItems::Baseline`](crate::styles::AlignItems::Baseline) alignment. Passing `None`
+    /// removes the baseline, so it's found from the widget's content instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `baseline`: The offset of the baseline from the top of the widget
+    ///
+    pub fn set_baseline(&mut self, baseline: Option<f32>) {
+        self.context
+            .widget_manager
+            .set_baseline(self.current_id.unwrap_or_default(), baseline);
+    }
+
+    /// Sets the accessibility information of the current widget
+    ///
+    /// This gives the widget a [`Role`](crate::Role) and an accessible name, making it part of
+    /// the tree returned by [`KayakContext::accessibility_tree`]. Since this is stored separately
+    /// from the widget's props, it should be set every time the widget renders. Passing `None`
+    /// removes the widget from the accessibility tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `accessibility`: The accessibility information of the current widget
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn Checkbox(props: CheckboxProps) {
+    ///   context.set_accessibility(Some(
+    ///     Accessibility::new(Role::Checkbox)
+    ///       .with_name(props.label.clone())
+    ///       .with_checked(props.checked),
+    ///   ));
+    ///   // ...
+    /// }
+    /// ```
+    pub fn set_accessibility(&mut self, accessibility: Option<Accessibility>) {
+        self.context
+            .widget_manager
+            .set_accessibility(self.current_id.unwrap_or_default(), accessibility);
+    }
+
+    /// Makes the current widget an error boundary, catching panics while rendering its descendants
+    ///
+    /// When a descendant panics while rendering, the panic is caught and passed to the handler of
+    /// the nearest boundary above it as a [`RenderError`], rather than crashing the app. The widget
+    /// that panicked stops rendering at that point, so its subtree is left as it was (or partly
+    /// built, if it was rendering for the first time). The handler should therefore replace the
+    /// children with a fallback, which also removes the widgets of the failed subtree along with
+    /// their state. Rendering the children again later creates them with fresh state.
+    ///
+    /// This must be set before the children are rendered, and should be set every time the widget
+    /// renders. Passing `None` removes the boundary, so panics are passed further up the tree.
+    /// Panics that no boundary catches are resumed as usual.
+    ///
+    /// This has a few limits:
+    /// * Only panics while rendering are caught. Panics in event handlers, layout callbacks,
+    ///   timeouts, and the like are not.
+    /// * The panic hook still runs, so the panic is printed as usual. Panics are also not caught
+    ///   at all when the app is compiled with `panic = "abort"`.
+    /// * State shared outside of the failed subtree (such as a provider's `Binding`) may have been
+    ///   left half-updated, and any lock held while panicking is poisoned. Such state should be
+    ///   reset by the handler.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler`: The callback fired with the error, or `None` to remove the boundary
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn MyBoundary(props: MyBoundaryProps) {
+    ///   let (error, set_error, ..) = use_state!(Option::<RenderError>::None);
+    ///   context.set_error_boundary(Some(Handler::new(move |error| set_error(Some(error)))));
+    ///   if let Some(error) = error {
+    ///     // Render a fallback
+    ///   } else {
+    ///     // Render the children
+    ///   }
+    /// }
+    /// ```
+    pub fn set_error_boundary(&mut self, handler: Option<Handler<RenderError>>) {
+        self.context
+            .widget_manager
+            .set_error_boundary(self.current_id.unwrap_or_default(), handler);
+    }
+
+    /// Sets whether the current widget is a portal
+    ///
+    /// A portal's node is placed at the root of the node tree, so it's laid out relative to the
+    /// root widget and drawn above everything else, escaping any ancestor clipping. It is still a
+    /// child of its logical parent, so styles and providers are inherited as usual.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_portal`: Whether the current widget should be a portal
+    ///
+    pub fn set_portal(&mut self, is_portal: bool) {
+        self.context
+            .widget_manager
+            .set_portal(self.current_id.unwrap_or_default(), is_portal);
+    }
+
+    /// Sets whether the subtree of the current widget is static, caching its layout
+    ///
+    /// The layout of a static subtree is reused until a widget within it renders with different
+    /// styles, content, or children, or until the size of the current widget changes. Moving the
+    /// current widget moves the cached layout along with it. This saves laying out large subtrees
+    /// that rarely change, such as the chrome around an app, on every layout.
+    ///
+    /// A static subtree always reports the baseline it had when its layout was cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_static`: Whether the current widget's subtree should be static
+    ///
+    pub fn set_static(&mut self, is_static: bool) {
+        self.context
+            .widget_manager
+            .set_static(self.current_id.unwrap_or_default(), is_static);
+    }
+
+    /// Measures the preferred size of the widget with the given ID (in pixels)
+    ///
+    /// Unlike [`get_layout`](Self::get_layout), this is independent of the space given to the widget
+    /// by its parent. This makes it useful for positioning auto-sized content, such as a popover,
+    /// based on how large it wants to be.
+    ///
+    /// Explicit pixel sizes take precedence, followed by the widget's preferred size (text reports
+    /// the size of its content automatically). Otherwise, the size is measured from the widget's
+    /// children, plus its padding and border.
+    ///
+    /// Returns `None` if the widget hasn't been rendered yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The ID of the widget
+    ///
+    pub fn measure(&self, widget_id: &Index) -> Option<(f32, f32)> {
+        self.context.widget_manager.measure(*widget_id)
+    }
+
+    /// Measures the given text content using the font with the given name
+    ///
+    /// This is useful for sizing or positioning a widget around text before it's rendered. The
+    /// current widget is bound to the font, so it will re-render once the font is loaded.
+    ///
+    /// Returns `None` if the font hasn't been loaded yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `content`: The text to measure
+    /// * `font`: The name of the font (such as [`DEFAULT_FONT`](crate::DEFAULT_FONT))
+    /// * `properties`: The font size, line height, letter spacing, and maximum size of the text
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[widget]
+    /// fn MyWidget() {
+    ///   let properties = TextProperties {
+    ///     font_size: 14.0,
+    ///     max_size: (200.0, f32::MAX),
+    ///     ..Default::default()
+    ///   };
+    ///   if let Some(measurement) = context.measure_text("Hello!", DEFAULT_FONT, properties) {
+    ///     // ...
+    ///   }
+    /// }
+    /// ```
+    pub fn measure_text(
+        &mut self,
+        content: &str,
+        font: &str,
+        properties: TextProperties,
+    ) -> Option<TextMeasurement> {
+        let asset = self.get_asset::<KayakFont>(font);
+        self.bind(&asset);
+        let font = asset.get()?;
+        Some(crate::measure_text_with_properties(
+            content, &font, properties,
+        ))
+    }
+
+    /// Get the computed space around the widget with the given ID
+    ///
+    /// This is the space morphorm placed on each side of the widget, which includes its margins.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The ID of the widget
+    ///
+    pub fn get_space(&self, widget_id: &Index) -> Option<crate::layout_cache::Space> {
+        self.context
+            .widget_manager
+            .layout_cache
+            .get_space(widget_id)
+    }
+
     /// Get the render node for the widget with the given ID
     ///
     /// This is useful if you need access to the resolved styles, z-index, etc. of a widget.
@@ -528,13 +1231,20 @@ impl<'a> KayakContextRef<'a> {
                 .create_widget(widget_index, widget, self.current_id);
         self.tree.as_ref().unwrap().add(child_id, self.current_id);
 
-        let mut child_widget = self.context.widget_manager.take(child_id);
-        {
-            let mut context = KayakContextRef::new(&mut self.context, Some(child_id));
-            // TODO: Use context ref here instead
-            child_widget.render(&mut context);
+        let child_widget = self.context.widget_manager.take(child_id);
+        // TODO: Use context ref here instead
+        self.context.render_widget(child_widget, self.current_id);
+    }
+
+    /// Returns the number of widgets added as children of the current widget so far in this render
+    ///
+    /// This lets a widget count the children it was given by wrapping them in a new
+    /// [`Children`](crate::Children) that checks this once they've been built.
+    pub fn child_count(&self) -> usize {
+        match (self.tree.as_ref(), self.current_id) {
+            (Some(tree), Some(id)) => tree.child_count(id),
+            _ => 0,
         }
-        self.context.widget_manager.repossess(child_widget);
     }
 
     /// Consumes the `KayakContextRef`. Internally this commits the newly built tree to the main widget tree.
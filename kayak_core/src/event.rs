@@ -74,11 +74,35 @@ pub enum EventType {
     /// An event that occurs when the user clicks a widget
     Click(CursorEvent),
     /// An event that occurs when the user hovers the cursor over a widget
+    ///
+    /// This is sent to the topmost widget under the cursor every time the cursor moves, and
+    /// propagates to its ancestors. It's equivalent to [`PointerOver`](Self::PointerOver).
     Hover(CursorEvent),
-    /// An event that occurs when the user moves the cursor into a widget
+    /// An event that occurs when the user moves the cursor into the layout of a widget
+    ///
+    /// This is sent to every widget whose layout the cursor crossed into, even if it's covered by
+    /// another widget. Use [`PointerEnter`](Self::PointerEnter) to only consider the widgets that
+    /// are actually under the cursor.
     MouseIn(CursorEvent),
-    /// An event that occurs when the user moves the cursor out of a widget
+    /// An event that occurs when the user moves the cursor out of the layout of a widget
+    ///
+    /// See [`MouseIn`](Self::MouseIn) for how this differs from [`PointerLeave`](Self::PointerLeave).
     MouseOut(CursorEvent),
+    /// An event that occurs once when the cursor moves onto a widget or any of its descendants
+    ///
+    /// A widget is considered under the cursor if it, or one of its descendants, is the topmost
+    /// widget at the cursor's position (respecting z-order and the `pointer_events` style). This is
+    /// sent exactly once per entry, no matter how fast the cursor moves, and is always followed by
+    /// a [`PointerLeave`](Self::PointerLeave) before it's sent again. It doesn't propagate.
+    PointerEnter(CursorEvent),
+    /// An event that occurs continuously, every time the cursor moves while over a widget
+    ///
+    /// This is sent to the topmost widget under the cursor and propagates to its ancestors.
+    PointerOver(CursorEvent),
+    /// An event that occurs once when the cursor moves off of a widget and all of its descendants
+    ///
+    /// This is the counterpart to [`PointerEnter`](Self::PointerEnter). It doesn't propagate.
+    PointerLeave(CursorEvent),
     /// An event that occurs when the user presses down on the cursor over a widget
     MouseDown(CursorEvent),
     /// An event that occurs when the user releases the cursor over a widget
@@ -89,6 +113,18 @@ pub enum EventType {
     Focus,
     /// An event that occurs when a widget loses focus
     Blur,
+    /// An event that occurs when a widget or one of its descendants receives focus
+    ///
+    /// This is sent right after [`Focus`](Self::Focus), to the same widget. Unlike `Focus`, it
+    /// propagates, so ancestors can react to focus moving within them.
+    FocusIn,
+    /// An event that occurs when a widget or one of its descendants loses focus
+    ///
+    /// This is sent right after [`Blur`](Self::Blur), to the same widget, and propagates. Since
+    /// focus may have only moved to another widget within an ancestor, ancestors can check the
+    /// new focus using
+    /// [`KayakContextRef::contains_focus`](crate::KayakContextRef::contains_focus).
+    FocusOut,
     /// An event that occurs when the user types in a character within a _focused_ widget
     CharInput { c: char },
     /// An event that occurs when the user releases a key within a _focused_ widget
@@ -133,6 +169,7 @@ impl EventType {
         match self {
             // Propagates
             Self::Hover(..) => true,
+            Self::PointerOver(..) => true,
             Self::Click(..) => true,
             Self::MouseDown(..) => true,
             Self::MouseUp(..) => true,
@@ -140,9 +177,13 @@ impl EventType {
             Self::CharInput { .. } => true,
             Self::KeyUp(..) => true,
             Self::KeyDown(..) => true,
+            Self::FocusIn => true,
+            Self::FocusOut => true,
             // Doesn't Propagate
             Self::MouseIn(..) => false,
             Self::MouseOut(..) => false,
+            Self::PointerEnter(..) => false,
+            Self::PointerLeave(..) => false,
             Self::Focus => false,
             Self::Blur => false,
         }
@@ -158,6 +199,9 @@ impl EventType {
             Self::MouseUp(..) => EventCategory::Mouse,
             Self::MouseIn(..) => EventCategory::Mouse,
             Self::MouseOut(..) => EventCategory::Mouse,
+            Self::PointerEnter(..) => EventCategory::Mouse,
+            Self::PointerOver(..) => EventCategory::Mouse,
+            Self::PointerLeave(..) => EventCategory::Mouse,
             Self::Scroll(..) => EventCategory::Mouse,
             // Keyboard
             Self::CharInput { .. } => EventCategory::Keyboard,
@@ -166,6 +210,8 @@ impl EventType {
             // Focus
             Self::Focus => EventCategory::Focus,
             Self::Blur => EventCategory::Focus,
+            Self::FocusIn => EventCategory::Focus,
+            Self::FocusOut => EventCategory::Focus,
         }
     }
 }
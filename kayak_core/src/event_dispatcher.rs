@@ -1,16 +1,23 @@
-use crate::flo_binding::{Binding, MutableBound};
+use crate::flo_binding::{Binding, Bound, MutableBound};
 
 use crate::cursor::{CursorEvent, ScrollEvent, ScrollUnit};
 use crate::layout_cache::Rect;
 use crate::render_command::RenderCommand;
+use crate::styles::{Display, TransformMatrix};
 use crate::widget_manager::WidgetManager;
 use crate::{
-    BoxedWidget, Event, EventType, Index, InputEvent, InputEventCategory, KayakContext,
-    KayakContextRef, KeyCode, KeyboardEvent, KeyboardModifiers, PointerEvents,
+    BoxedWidget, Event, EventCategory, EventType, Index, InputEvent, InputEventCategory,
+    KayakContext, KayakContextRef, KeyCode, KeyboardEvent, KeyboardModifiers, PointerEvents,
 };
 use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 
 type EventMap = HashMap<Index, HashSet<EventType>>;
+
+/// How long the mouse must be at rest before its velocity is reset
+const VELOCITY_TIMEOUT: Duration = Duration::from_millis(100);
+/// The weight given to each new velocity sample when smoothing (between 0.0 and 1.0)
+const VELOCITY_SMOOTHING: f32 = 0.5;
 type TreeNode = (
     // The node ID
     Index,
@@ -41,31 +48,50 @@ pub(crate) struct EventDispatcher {
     next_mouse_pressed: bool,
     current_mouse_position: (f32, f32),
     next_mouse_position: (f32, f32),
+    previous_mouse_position: (f32, f32),
+    /// The smoothed velocity of the mouse, in pixels per second
+    mouse_velocity: (f32, f32),
+    /// The time at which the mouse last moved
+    last_mouse_move: Option<Instant>,
     previous_events: EventMap,
     keyboard_modifiers: KeyboardModifiers,
+    /// The key presses since the last dispatch that happened while no widget was focused
+    unfocused_key_presses: Vec<KeyboardEvent>,
     pub last_clicked: Binding<Index>,
+    pub last_hovered: Binding<Option<Index>>,
     contains_cursor: Option<bool>,
     wants_cursor: Option<bool>,
     has_cursor: Option<Index>,
     pub cursor_capture: Option<Index>,
+    /// Whether the cursor capture ends once the mouse is released (see [`capture_pointer`](Self::capture_pointer))
+    release_capture_on_mouse_up: bool,
     pub hovered: Option<Index>,
+    /// The widget under the cursor followed by all of its ancestors, used for pointer enter and leave events
+    pointer_path: Vec<Index>,
 }
 
 impl EventDispatcher {
     pub fn new() -> Self {
         Self {
             last_clicked: Binding::new(Index::default()),
+            last_hovered: Binding::new(None),
             is_mouse_pressed: Default::default(),
             next_mouse_pressed: Default::default(),
             current_mouse_position: Default::default(),
             next_mouse_position: Default::default(),
+            previous_mouse_position: Default::default(),
+            mouse_velocity: Default::default(),
+            last_mouse_move: None,
             previous_events: Default::default(),
             keyboard_modifiers: Default::default(),
+            unfocused_key_presses: Vec::new(),
             contains_cursor: None,
             wants_cursor: None,
             has_cursor: None,
             cursor_capture: None,
+            release_capture_on_mouse_up: false,
             hovered: None,
+            pointer_path: Vec::new(),
         }
     }
 
@@ -81,13 +107,82 @@ impl EventDispatcher {
         self.current_mouse_position
     }
 
+    /// Gets the change in mouse position caused by the last mouse movement
+    pub fn mouse_delta(&self) -> (f32, f32) {
+        (
+            self.current_mouse_position.0 - self.previous_mouse_position.0,
+            self.current_mouse_position.1 - self.previous_mouse_position.1,
+        )
+    }
+
+    /// Gets the modifier keys that are currently pressed
+    pub fn keyboard_modifiers(&self) -> KeyboardModifiers {
+        self.keyboard_modifiers
+    }
+
+    /// Gets the smoothed velocity of the mouse, in pixels per second
+    ///
+    /// This decays to zero once the mouse stops moving.
+    pub fn mouse_velocity(&self) -> (f32, f32) {
+        match self.last_mouse_move {
+            Some(last_move) if last_move.elapsed() <= VELOCITY_TIMEOUT => self.mouse_velocity,
+            _ => (0.0, 0.0),
+        }
+    }
+
+    /// Updates the mouse delta and velocity for a mouse movement to the given position
+    fn track_mouse_motion(&mut self, position: (f32, f32)) {
+        let now = Instant::now();
+        let delta = (
+            position.0 - self.current_mouse_position.0,
+            position.1 - self.current_mouse_position.1,
+        );
+
+        self.mouse_velocity = match self.last_mouse_move {
+            Some(last_move) if now.duration_since(last_move) <= VELOCITY_TIMEOUT => {
+                let elapsed = now
+                    .duration_since(last_move)
+                    .as_secs_f32()
+                    .max(f32::EPSILON);
+                let velocity = (delta.0 / elapsed, delta.1 / elapsed);
+                (
+                    self.mouse_velocity.0
+                        + (velocity.0 - self.mouse_velocity.0) * VELOCITY_SMOOTHING,
+                    self.mouse_velocity.1
+                        + (velocity.1 - self.mouse_velocity.1) * VELOCITY_SMOOTHING,
+                )
+            }
+            // The mouse was at rest, so there's no meaningful interval to measure
+            _ => (0.0, 0.0),
+        };
+        self.previous_mouse_position = self.current_mouse_position;
+        self.last_mouse_move = Some(now);
+    }
+
     /// Captures all cursor events and instead makes the given index the target
     pub fn capture_cursor(&mut self, index: Index) -> Option<Index> {
         let old = self.cursor_capture;
         self.cursor_capture = Some(index);
+        self.release_capture_on_mouse_up = false;
+        old
+    }
+
+    /// Captures all cursor events for the given index until the mouse is released
+    pub fn capture_pointer(&mut self, index: Index) -> Option<Index> {
+        let old = self.capture_cursor(index);
+        self.release_capture_on_mouse_up = true;
         old
     }
 
+    /// Releases a capture made with [`capture_pointer`](Self::capture_pointer)
+    ///
+    /// This is called once the mouse is released and its events have been dispatched.
+    pub fn end_pointer_capture(&mut self) {
+        if self.release_capture_on_mouse_up {
+            self.force_release_cursor();
+        }
+    }
+
     /// Releases the captured cursor
     ///
     /// Returns true if successful.
@@ -115,6 +210,7 @@ impl EventDispatcher {
     pub fn force_release_cursor(&mut self) -> Option<Index> {
         let old = self.cursor_capture;
         self.cursor_capture = None;
+        self.release_capture_on_mouse_up = false;
         old
     }
 
@@ -160,9 +256,16 @@ impl EventDispatcher {
     }
 
     /// Process and dispatch a set of [InputEvents](crate::InputEvent)
-    pub fn process_events(&mut self, input_events: Vec<InputEvent>, context: &mut KayakContext) {
+    ///
+    /// Returns the generated events, each with the widget it targeted (before propagation)
+    pub fn process_events(
+        &mut self,
+        input_events: Vec<InputEvent>,
+        context: &mut KayakContext,
+    ) -> Vec<Event> {
         let events = self.build_event_stream(&input_events, &mut context.widget_manager);
-        self.dispatch_events(events, context);
+        self.dispatch_events(events.clone(), context);
+        events
     }
 
     /// Dispatch an [Event](crate::Event)
@@ -173,9 +276,27 @@ impl EventDispatcher {
 
     /// Dispatch a set of [Events](crate::Event)
     pub fn dispatch_events(&mut self, events: Vec<Event>, context: &mut KayakContext) {
+        // Focus changes are followed by their propagating counterparts
+        let events = events.into_iter().flat_map(|event| {
+            let counterpart = match event.event_type {
+                EventType::Focus => Some(EventType::FocusIn),
+                EventType::Blur => Some(EventType::FocusOut),
+                _ => None,
+            };
+            std::iter::once(event)
+                .chain(counterpart.map(|event_type| Event::new(event.target, event_type)))
+        });
+
         // === Dispatch Events === //
         let mut next_events = HashMap::default();
         for mut event in events {
+            if let EventType::KeyDown(evt) = event.event_type {
+                // Shortcuts take precedence over the focused widget's own key handling
+                if context.dispatch_shortcut(evt, Some(event.target)) {
+                    continue;
+                }
+            }
+
             let mut current_target: Option<Index> = Some(event.target);
             while let Some(index) = current_target {
                 // Create a copy of the event, specific for this node
@@ -190,10 +311,16 @@ impl EventDispatcher {
                 Self::insert_event(&mut next_events, &index, node_event.event_type);
 
                 // --- Call Event --- //
-                let mut target_widget = context.widget_manager.take(index);
-                let mut ctx = KayakContextRef::new(context, Some(index));
-                target_widget.on_event(&mut ctx, &mut node_event);
-                context.widget_manager.repossess(target_widget);
+                // Disabled widgets ignore input, but the event may still propagate past them
+                let is_ignored =
+                    !matches!(node_event.event_type.event_category(), EventCategory::Focus)
+                        && context.widget_manager.is_disabled(index);
+                if !is_ignored {
+                    let mut target_widget = context.widget_manager.take(index);
+                    let mut ctx = KayakContextRef::new(context, Some(index));
+                    target_widget.on_event(&mut ctx, &mut node_event);
+                    context.widget_manager.repossess(target_widget);
+                }
 
                 event.default_prevented |= node_event.default_prevented;
 
@@ -298,13 +425,19 @@ impl EventDispatcher {
                 // --- Process Event --- //
                 if matches!(input_event.category(), InputEventCategory::Mouse) {
                     // A widget's PointerEvents style will determine how it and its children are processed
-                    let pointer_events = Self::resolve_pointer_events(captor, widget_manager);
+                    // The captor may have been hidden by one of its ancestors after capturing
+                    let pointer_events = if widget_manager.display(captor) == Display::Visible {
+                        Self::resolve_pointer_events(captor, widget_manager)
+                    } else {
+                        PointerEvents::None
+                    };
 
                     match pointer_events {
                         PointerEvents::All | PointerEvents::SelfOnly => {
                             let events = self.process_pointer_events(
                                 input_event,
                                 (captor, 0),
+                                widget_manager.get_global_transform(captor),
                                 &mut states,
                                 widget_manager,
                                 true,
@@ -317,9 +450,11 @@ impl EventDispatcher {
             }
         } else {
             // No capturing widget -> process cursor events as normal
-            let mut stack: Vec<TreeNode> = vec![(root, 0)];
+            let mut stack: Vec<(TreeNode, TransformMatrix)> =
+                vec![((root, 0), TransformMatrix::IDENTITY)];
             while stack.len() > 0 {
-                let (current, depth) = stack.pop().unwrap();
+                let ((current, depth), parent_transform) = stack.pop().unwrap();
+                let transform = widget_manager.get_transform(current, parent_transform);
                 let mut enter_children = true;
 
                 for input_event in input_events {
@@ -333,6 +468,7 @@ impl EventDispatcher {
                                 let events = self.process_pointer_events(
                                     input_event,
                                     (current, depth),
+                                    transform,
                                     &mut states,
                                     widget_manager,
                                     false,
@@ -353,7 +489,7 @@ impl EventDispatcher {
                 if enter_children {
                     if let Some(children) = widget_manager.node_tree.children.get(&current) {
                         for child in children {
-                            stack.push((*child, depth + 1));
+                            stack.push(((*child, depth + 1), transform));
                         }
                     }
                 }
@@ -369,6 +505,7 @@ impl EventDispatcher {
 
         // === Additional Events === //
         let mut had_focus_event = false;
+        let mut pointer_over = None;
 
         // These events are ones that require a specific target and need the tree to be evaluated before selecting the best match
         for (event_type, state) in states {
@@ -384,15 +521,49 @@ impl EventDispatcher {
                             }
                         }
                         widget_manager.focus_tree.focus(node);
+                        widget_manager.focus_tree.set_focus_visible(false);
                     }
-                    EventType::Hover(..) => {
+                    EventType::Hover(cursor_event) => {
                         self.hovered = Some(node);
+                        pointer_over = Some((node, cursor_event));
                     }
                     _ => {}
                 }
             }
         }
 
+        // --- Pointer Events --- //
+        let has_moved = input_events
+            .iter()
+            .any(|input_event| matches!(input_event, InputEvent::MouseMoved(..)));
+        if has_moved && self.cursor_capture.is_none() {
+            // Enter and leave events are found by comparing the widgets under the cursor before and
+            // after the move, so each is sent exactly once per crossing (regardless of how far the
+            // cursor moved)
+            let cursor_event = self.get_cursor_event(self.next_mouse_position);
+            let mut path = Vec::new();
+            let mut current = pointer_over.map(|(node, _)| node);
+            while let Some(node) = current {
+                path.push(node);
+                current = widget_manager.node_tree.get_parent(node);
+            }
+
+            for node in &self.pointer_path {
+                if !path.contains(node) && widget_manager.node_tree.contains(*node) {
+                    event_stream.push(Event::new(*node, EventType::PointerLeave(cursor_event)));
+                }
+            }
+            for node in path.iter().rev() {
+                if !self.pointer_path.contains(node) {
+                    event_stream.push(Event::new(*node, EventType::PointerEnter(cursor_event)));
+                }
+            }
+            self.pointer_path = path;
+        }
+        if let Some((node, cursor_event)) = pointer_over {
+            event_stream.push(Event::new(node, EventType::PointerOver(cursor_event)));
+        }
+
         // --- Blur Event --- //
         if !had_focus_event && input_events.contains(&InputEvent::MouseLeftPress) {
             // A mouse press didn't contain a focus event -> blur
@@ -403,6 +574,9 @@ impl EventDispatcher {
         }
 
         // === Process Cursor States === //
+        if self.next_mouse_position != self.current_mouse_position {
+            self.track_mouse_motion(self.next_mouse_position);
+        }
         self.current_mouse_position = self.next_mouse_position;
         self.is_mouse_pressed = self.next_mouse_pressed;
 
@@ -410,6 +584,9 @@ impl EventDispatcher {
             // No change -> revert
             self.hovered = old_hovered;
         }
+        if self.last_hovered.get() != self.hovered {
+            self.last_hovered.set(self.hovered);
+        }
         if self.contains_cursor.is_none() {
             // No change -> revert
             self.contains_cursor = old_contains_cursor;
@@ -428,6 +605,7 @@ impl EventDispatcher {
     ///
     /// * `input_event`: The input event
     /// * `tree_node`: The current node to process
+    /// * `transform`: The transform of the current node (combined with those of its ancestors)
     /// * `states`: The map of events to their current state (for selecting best fit)
     /// * `widget_manager`: The widget manager
     /// * `ignore_layout`: Whether to ignore layout (useful for handling captured events)
@@ -437,19 +615,27 @@ impl EventDispatcher {
         &mut self,
         input_event: &InputEvent,
         tree_node: TreeNode,
+        transform: TransformMatrix,
         states: &mut HashMap<EventType, EventState>,
         widget_manager: &WidgetManager,
         ignore_layout: bool,
     ) -> Vec<Event> {
         let mut event_stream = Vec::<Event>::new();
         let (node, depth) = tree_node;
+        // Points are compared against the untransformed layout, so they're moved into that space
+        let inverse = transform.inverse();
+        let contains = |layout: &Rect, point: &(f32, f32)| {
+            inverse.map_or(false, |inverse| {
+                layout.contains(&inverse.transform_point(*point))
+            })
+        };
 
         match input_event {
             InputEvent::MouseMoved(point) => {
                 if let Some(layout) = widget_manager.get_layout(&node) {
                     let cursor_event = self.get_cursor_event(*point);
-                    let was_contained = layout.contains(&self.current_mouse_position);
-                    let is_contained = layout.contains(point);
+                    let was_contained = contains(layout, &self.current_mouse_position);
+                    let is_contained = contains(layout, point);
                     if !ignore_layout && was_contained != is_contained {
                         if was_contained {
                             event_stream.push(Event::new(node, EventType::MouseOut(cursor_event)));
@@ -487,7 +673,7 @@ impl EventDispatcher {
             }
             InputEvent::MouseLeftPress => {
                 if let Some(layout) = widget_manager.get_layout(&node) {
-                    if ignore_layout || layout.contains(&self.current_mouse_position) {
+                    if ignore_layout || contains(layout, &self.current_mouse_position) {
                         let cursor_event = self.get_cursor_event(self.current_mouse_position);
                         event_stream.push(Event::new(node, EventType::MouseDown(cursor_event)));
 
@@ -511,7 +697,7 @@ impl EventDispatcher {
             }
             InputEvent::MouseLeftRelease => {
                 if let Some(layout) = widget_manager.get_layout(&node) {
-                    if ignore_layout || layout.contains(&self.current_mouse_position) {
+                    if ignore_layout || contains(layout, &self.current_mouse_position) {
                         let cursor_event = self.get_cursor_event(self.current_mouse_position);
                         event_stream.push(Event::new(node, EventType::MouseUp(cursor_event)));
                         self.last_clicked.set(node);
@@ -534,7 +720,7 @@ impl EventDispatcher {
             InputEvent::Scroll { dx, dy, is_line } => {
                 if let Some(layout) = widget_manager.get_layout(&node) {
                     // Check for scroll eligibility
-                    if ignore_layout || layout.contains(&self.current_mouse_position) {
+                    if ignore_layout || contains(layout, &self.current_mouse_position) {
                         Self::update_state(
                             states,
                             (node, depth),
@@ -560,6 +746,10 @@ impl EventDispatcher {
         let mut pointer_events = PointerEvents::default();
         if let Some(widget) = widget_manager.current_widgets.get(index).unwrap() {
             if let Some(styles) = widget.get_props().get_styles() {
+                if styles.display.resolve_or_default() != Display::Visible {
+                    // Hidden widgets (along with their descendants) can't be interacted with
+                    return PointerEvents::None;
+                }
                 pointer_events = styles.pointer_events.resolve();
             }
         }
@@ -584,29 +774,33 @@ impl EventDispatcher {
         widget_manager: &WidgetManager,
     ) -> Vec<Event> {
         let mut event_stream = Vec::new();
+
+        // === Modifers === //
+        // These are tracked even without a focused widget so that shortcuts can use them
+        if let InputEvent::Keyboard { key, is_pressed } = input_event {
+            match key {
+                KeyCode::LControl | KeyCode::RControl => {
+                    self.keyboard_modifiers.is_ctrl_pressed = *is_pressed
+                }
+                KeyCode::LShift | KeyCode::RShift => {
+                    self.keyboard_modifiers.is_shift_pressed = *is_pressed
+                }
+                KeyCode::LAlt | KeyCode::RAlt => {
+                    self.keyboard_modifiers.is_alt_pressed = *is_pressed
+                }
+                KeyCode::LWin | KeyCode::RWin => {
+                    self.keyboard_modifiers.is_meta_pressed = *is_pressed
+                }
+                _ => {}
+            }
+        }
+
         if let Some(current_focus) = widget_manager.focus_tree.current() {
             match input_event {
                 InputEvent::CharEvent { c } => {
                     event_stream.push(Event::new(current_focus, EventType::CharInput { c: *c }))
                 }
                 InputEvent::Keyboard { key, is_pressed } => {
-                    // === Modifers === //
-                    match key {
-                        KeyCode::LControl | KeyCode::RControl => {
-                            self.keyboard_modifiers.is_ctrl_pressed = *is_pressed
-                        }
-                        KeyCode::LShift | KeyCode::RShift => {
-                            self.keyboard_modifiers.is_shift_pressed = *is_pressed
-                        }
-                        KeyCode::LAlt | KeyCode::RAlt => {
-                            self.keyboard_modifiers.is_alt_pressed = *is_pressed
-                        }
-                        KeyCode::LWin | KeyCode::RWin => {
-                            self.keyboard_modifiers.is_meta_pressed = *is_pressed
-                        }
-                        _ => {}
-                    }
-
                     // === Event === //
                     if *is_pressed {
                         event_stream.push(Event::new(
@@ -622,11 +816,25 @@ impl EventDispatcher {
                 }
                 _ => {}
             }
+        } else if let InputEvent::Keyboard {
+            key,
+            is_pressed: true,
+        } = input_event
+        {
+            // Without a focused widget, there's no one to send this to. However, the key press can
+            // still trigger shortcuts and other default actions.
+            self.unfocused_key_presses
+                .push(KeyboardEvent::new(*key, self.keyboard_modifiers));
         }
 
         event_stream
     }
 
+    /// Takes the key presses that happened while no widget was focused
+    pub fn take_unfocused_key_presses(&mut self) -> Vec<KeyboardEvent> {
+        std::mem::take(&mut self.unfocused_key_presses)
+    }
+
     /// Updates the state data for the given event
     fn update_state(
         states: &mut HashMap<EventType, EventState>,
@@ -701,9 +909,11 @@ impl EventDispatcher {
                             }
                         }
                         context.widget_manager.focus_tree.focus(index);
+                        context.widget_manager.focus_tree.set_focus_visible(true);
                         self.dispatch_events(events, context);
                     }
                 }
+                KeyCode::Escape => context.dispatch_escape(),
                 _ => {}
             },
             _ => {}
@@ -727,18 +937,25 @@ impl EventDispatcher {
         // Merge only what could be changed internally. External changes (i.e. from KayakContext)
         // should not be touched
         self.last_clicked = from.last_clicked;
+        self.last_hovered = from.last_hovered;
         self.is_mouse_pressed = from.is_mouse_pressed;
         self.next_mouse_pressed = from.next_mouse_pressed;
         self.current_mouse_position = from.current_mouse_position;
         self.next_mouse_position = from.next_mouse_position;
+        self.previous_mouse_position = from.previous_mouse_position;
+        self.mouse_velocity = from.mouse_velocity;
+        self.last_mouse_move = from.last_mouse_move;
         self.previous_events = from.previous_events;
         self.keyboard_modifiers = from.keyboard_modifiers;
+        self.unfocused_key_presses = from.unfocused_key_presses;
         self.contains_cursor = from.contains_cursor;
         self.wants_cursor = from.wants_cursor;
         self.has_cursor = from.has_cursor;
         self.hovered = from.hovered;
+        self.pointer_path = from.pointer_path;
 
         // Do not include:
         // self.cursor_capture = from.cursor_capture;
+        // self.release_capture_on_mouse_up = from.release_capture_on_mouse_up;
     }
 }
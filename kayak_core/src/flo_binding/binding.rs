@@ -153,15 +153,19 @@ impl<Value: 'static + Clone + PartialEq + Send + Sync> Bound<Value> for Binding<
 
 impl<Value: 'static + Clone + PartialEq + Send + Sync> MutableBound<Value> for Binding<Value> {
     fn set(&self, new_value: Value) {
+        self.set_if_changed(new_value);
+    }
+
+    fn set_if_changed(&self, new_value: Value) -> bool {
         // Update the value with the lock held
-        let notifications = {
+        let (changed, notifications) = {
             let mut cell = self.value.lock().unwrap();
             let changed = cell.set_without_notifying(new_value);
 
             if changed {
-                cell.get_notifiable_items()
+                (changed, cell.get_notifiable_items())
             } else {
-                vec![]
+                (changed, vec![])
             }
         };
 
@@ -176,6 +180,8 @@ impl<Value: 'static + Clone + PartialEq + Send + Sync> MutableBound<Value> for B
             let mut cell = self.value.lock().unwrap();
             cell.filter_unused_notifications();
         }
+
+        changed
     }
 }
 
@@ -237,3 +243,31 @@ impl<'a, Value: 'static + Clone + PartialEq + Send + Sync> From<&'a Value> for B
         Binding::new(val.clone())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn set_only_notifies_when_changed() {
+        let bind = bind(1);
+        let count = Arc::new(AtomicUsize::new(0));
+        let cloned_count = count.clone();
+        let mut lifetime = bind.when_changed(notify(move || {
+            cloned_count.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        assert!(!bind.set_if_changed(1));
+        assert_eq!(0, count.load(Ordering::SeqCst));
+
+        assert!(bind.set_if_changed(2));
+        assert_eq!(1, count.load(Ordering::SeqCst));
+
+        bind.set(2);
+        assert_eq!(1, count.load(Ordering::SeqCst));
+
+        lifetime.done();
+    }
+}
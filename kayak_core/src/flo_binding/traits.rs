@@ -85,5 +85,29 @@ pub trait MutableBound<Value>: Bound<Value> {
     ///
     /// Sets the value stored by this binding
     ///
+    /// Anything bound to this value is only notified if the new value differs
+    /// from the current one
+    ///
     fn set(&self, new_value: Value);
+
+    ///
+    /// Sets the value stored by this binding, returning true if it changed
+    ///
+    /// This works like `set`, but lets the caller skip any follow-up work when
+    /// the new value equals the current one (and so nothing was notified)
+    ///
+    /// The default implementation compares against `get` before calling `set`,
+    /// so implementors that can check for changes more cheaply should override it
+    ///
+    fn set_if_changed(&self, new_value: Value) -> bool
+    where
+        Value: PartialEq,
+    {
+        let changed = self.get() != new_value;
+        if changed {
+            self.set(new_value);
+        }
+
+        changed
+    }
 }
@@ -5,6 +5,8 @@ use std::collections::HashMap;
 pub struct FocusTree {
     tree: Tree,
     current_focus: Option<Index>,
+    /// Whether the current focus was moved using the keyboard
+    focus_visible: bool,
 }
 
 /// A struct used to track and calculate widget focusability, based on the following rule:
@@ -84,6 +86,20 @@ impl FocusTree {
         self.current_focus
     }
 
+    /// Set whether the current focus should be visibly indicated (i.e. with a focus ring)
+    ///
+    /// This should be true when focus was moved using the keyboard and false when it was moved
+    /// using a pointer. It isn't affected by [`focus`](Self::focus) or [`clear`](Self::clear), so
+    /// focus that's moved or restored programmatically keeps the state of the last interaction.
+    pub fn set_focus_visible(&mut self, visible: bool) {
+        self.focus_visible = visible;
+    }
+
+    /// Checks if the current focus should be visibly indicated (i.e. with a focus ring)
+    pub fn is_focus_visible(&self) -> bool {
+        self.focus_visible
+    }
+
     /// Change focus to the next focusable index
     pub fn next(&mut self) -> Option<Index> {
         self.current_focus = self.peek_next();
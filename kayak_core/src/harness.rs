@@ -0,0 +1,992 @@
+use crate::{Event, Index, InputEvent, KayakContext, KeyCode, LayoutSnapshot};
+
+/// A harness for simulating user input in tests, without a window or renderer
+///
+/// Each simulated action sends its input events through the context's event dispatcher, just like
+/// an integration would, and then re-renders any widgets whose state changed as a result. Every
+/// action returns the events it generated, each with the widget it targeted (before propagation).
+///
+/// # Examples
+///
+/// ```ignore
+/// let mut context = KayakContext::new();
+/// {
+///     let context = &mut context;
+///     render! {
+///         <TooltipProvider size={Some((400.0, 400.0))}>
+///             <TooltipConsumer text={"Hello".into()}>
+///                 <Element styles={Some(consumer_styles)} />
+///             </TooltipConsumer>
+///         </TooltipProvider>
+///     }
+/// }
+///
+/// let mut harness = TestHarness::new(context);
+/// let events = harness.hover(10.0, 10.0);
+/// assert!(events.iter().any(|event| matches!(event.event_type, EventType::MouseIn(..))));
+/// ```
+pub struct TestHarness {
+    /// The context being driven by this harness
+    pub context: KayakContext,
+}
+
+impl TestHarness {
+    /// Creates a new harness for the given context, rendering it for the first time
+    pub fn new(context: KayakContext) -> Self {
+        let mut harness = Self { context };
+        harness.render();
+        harness
+    }
+
+    /// Re-renders all dirty widgets and recalculates layout
+    pub fn render(&mut self) {
+        self.context.render();
+    }
+
+    /// Moves the cursor to the given point (in pixels)
+    pub fn hover(&mut self, x: f32, y: f32) -> Vec<Event> {
+        self.send(vec![InputEvent::MouseMoved((x, y))])
+    }
+
+    /// Moves the cursor to the given point (in pixels) and clicks the left mouse button
+    pub fn click(&mut self, x: f32, y: f32) -> Vec<Event> {
+        let mut events = self.hover(x, y);
+        events.extend(self.send(vec![InputEvent::MouseLeftPress]));
+        events.extend(self.send(vec![InputEvent::MouseLeftRelease]));
+        events
+    }
+
+    /// Scrolls the given amount of pixels at the current cursor position
+    pub fn scroll(&mut self, dx: f32, dy: f32) -> Vec<Event> {
+        self.send(vec![InputEvent::Scroll {
+            dx,
+            dy,
+            is_line: false,
+        }])
+    }
+
+    /// Types the given character into the focused widget
+    pub fn key(&mut self, c: char) -> Vec<Event> {
+        self.send(vec![InputEvent::CharEvent { c }])
+    }
+
+    /// Presses and releases the given key
+    pub fn press(&mut self, key: KeyCode) -> Vec<Event> {
+        let mut events = self.send(vec![InputEvent::Keyboard {
+            key,
+            is_pressed: true,
+        }]);
+        events.extend(self.send(vec![InputEvent::Keyboard {
+            key,
+            is_pressed: false,
+        }]));
+        events
+    }
+
+    /// Sends the given input events, then re-renders the context
+    pub fn send(&mut self, input_events: Vec<InputEvent>) -> Vec<Event> {
+        let events = self.context.process_events(input_events);
+        self.render();
+        events
+    }
+
+    /// Returns the ID of the widget currently under the cursor, if any
+    pub fn hovered(&self) -> Option<Index> {
+        let (x, y) = self.context.last_mouse_position();
+        self.context.widget_at(x, y)
+    }
+
+    /// Takes a snapshot of the current layout
+    ///
+    /// See [`KayakContext::snapshot`] for details.
+    pub fn snapshot(&self) -> Option<LayoutSnapshot> {
+        self.context.snapshot()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TestHarness;
+    use crate::render_command::RenderCommand;
+    use crate::styles::{Display, LayoutType, Style, StyleProp, Transform2D, Units};
+    use crate::{
+        Accessibility, Binding, Bound, Children, ClipboardError, EventCategory, EventType,
+        Fragment, FragmentProps, Handler, Index, InputEvent, KayakContext, KayakContextRef,
+        KeyCode, MemoryClipboard, MutableBound, OnEvent, OnLayout, RenderError, ResizeEvent, Role,
+        Shortcut, UserPreferences, Widget, WidgetProps,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    fn build() -> KayakContext {
+        build_with_child_styles(Style::default())
+    }
+
+    /// Builds a 100x50 root containing a 30x20 child, which is given the other styles
+    fn build_with_child_styles(styles: Style) -> KayakContext {
+        let mut context = KayakContext::new();
+        let child = Fragment::constructor(FragmentProps {
+            styles: Some(Style {
+                height: StyleProp::Value(Units::Pixels(20.0)),
+                width: StyleProp::Value(Units::Pixels(30.0)),
+                ..styles
+            }),
+            children: None,
+        });
+        let root = Fragment::constructor(FragmentProps {
+            styles: Some(Style {
+                height: StyleProp::Value(Units::Pixels(50.0)),
+                layout_type: StyleProp::Value(LayoutType::Row),
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                width: StyleProp::Value(Units::Pixels(100.0)),
+                ..Default::default()
+            }),
+            children: Some(Children::new(move |_, context| {
+                context.add_widget(child.clone(), 0);
+                context.commit();
+            })),
+        });
+
+        {
+            let mut context = KayakContextRef::new(&mut context, None);
+            context.add_widget(root, 0);
+            context.commit();
+        }
+        context
+    }
+
+    #[test]
+    fn should_target_hovered_and_clicked_widgets() {
+        let mut harness = TestHarness::new(build());
+        let snapshot = harness.snapshot().unwrap();
+        let root = snapshot.id;
+        let child = snapshot.children[0].id;
+
+        let events = harness.hover(10.0, 10.0);
+        assert!(events.iter().any(
+            |event| event.target == child && matches!(event.event_type, EventType::MouseIn(..))
+        ));
+        assert_eq!(Some(child), harness.hovered());
+
+        let events = harness.click(10.0, 10.0);
+        let clicked: Vec<_> = events
+            .iter()
+            .filter(|event| matches!(event.event_type, EventType::Click(..)))
+            .map(|event| event.target)
+            .collect();
+        assert_eq!(vec![child], clicked);
+
+        let events = harness.hover(60.0, 40.0);
+        assert!(events
+            .iter()
+            .any(|event| event.target == child
+                && matches!(event.event_type, EventType::MouseOut(..))));
+        assert_eq!(Some(root), harness.hovered());
+    }
+
+    #[test]
+    fn should_hit_test_transformed_widgets() {
+        let context = build_with_child_styles(Style {
+            transform: StyleProp::Value(Transform2D::from_scale(2.0, 2.0)),
+            transform_origin: StyleProp::Value((Units::Pixels(0.0), Units::Pixels(0.0))),
+            ..Default::default()
+        });
+        let mut harness = TestHarness::new(context);
+        let child = harness.snapshot().unwrap().children[0].id;
+
+        // Outside of the child's layout, but within its scaled bounds
+        let events = harness.hover(50.0, 30.0);
+        assert!(events.iter().any(
+            |event| event.target == child && matches!(event.event_type, EventType::MouseIn(..))
+        ));
+
+        let events = harness.click(50.0, 30.0);
+        let clicked: Vec<_> = events
+            .iter()
+            .filter(|event| matches!(event.event_type, EventType::Click(..)))
+            .map(|event| event.target)
+            .collect();
+        assert_eq!(vec![child], clicked);
+    }
+
+    #[test]
+    fn should_not_send_pointer_events_to_hidden_widgets() {
+        let mut harness = TestHarness::new(build_with_child_styles(Style {
+            display: StyleProp::Value(Display::Hidden),
+            ..Default::default()
+        }));
+        let snapshot = harness.snapshot().unwrap();
+        let root = snapshot.id;
+        let child = snapshot.children[0].id;
+
+        let events = harness.hover(10.0, 10.0);
+        assert!(!events.iter().any(|event| event.target == child));
+
+        let events = harness.click(10.0, 10.0);
+        let clicked: Vec<_> = events
+            .iter()
+            .filter(|event| matches!(event.event_type, EventType::Click(..)))
+            .map(|event| event.target)
+            .collect();
+        assert_eq!(vec![root], clicked);
+    }
+
+    #[test]
+    fn should_send_pointer_enter_and_leave_once_per_crossing() {
+        let mut harness = TestHarness::new(build());
+        let snapshot = harness.snapshot().unwrap();
+        let (root, child) = (snapshot.id, snapshot.children[0].id);
+        let count = |events: &[crate::Event], target, event_type: EventType| {
+            events
+                .iter()
+                .filter(|event| event.target == target && event.event_type == event_type)
+                .count()
+        };
+        let enter = EventType::PointerEnter(Default::default());
+        let leave = EventType::PointerLeave(Default::default());
+        let over = EventType::PointerOver(Default::default());
+
+        // Jumping straight into the child enters both it and its parent
+        let events = harness.hover(10.0, 10.0);
+        assert_eq!(1, count(&events, root, enter));
+        assert_eq!(1, count(&events, child, enter));
+        assert_eq!(1, count(&events, child, over));
+
+        let events = harness.hover(12.0, 12.0);
+        assert_eq!(
+            0,
+            count(&events, root, enter) + count(&events, child, enter)
+        );
+        assert_eq!(1, count(&events, child, over));
+
+        let events = harness.hover(60.0, 40.0);
+        assert_eq!(1, count(&events, child, leave));
+        assert_eq!(0, count(&events, root, leave) + count(&events, root, enter));
+
+        let events = harness.hover(500.0, 500.0);
+        assert_eq!(1, count(&events, root, leave));
+        assert_eq!(0, count(&events, child, leave));
+    }
+
+    #[test]
+    fn should_route_captured_pointer_events_until_release() {
+        let mut harness = TestHarness::new(build());
+        let snapshot = harness.snapshot().unwrap();
+        let (root, child) = (snapshot.id, snapshot.children[0].id);
+
+        harness.hover(10.0, 10.0);
+        harness.context.capture_pointer(child);
+
+        // The cursor is now over the root, but the child keeps receiving events
+        let events = harness.hover(90.0, 40.0);
+        assert!(
+            events
+                .iter()
+                .any(|event| event.target == child
+                    && matches!(event.event_type, EventType::Hover(..)))
+        );
+        assert!(events.iter().all(|event| event.target != root));
+
+        let events = harness.send(vec![InputEvent::MouseLeftRelease]);
+        assert!(events.iter().any(
+            |event| event.target == child && matches!(event.event_type, EventType::MouseUp(..))
+        ));
+
+        // The capture is released along with the mouse
+        let events = harness.hover(91.0, 40.0);
+        assert!(events
+            .iter()
+            .any(|event| event.target == root && matches!(event.event_type, EventType::Hover(..))));
+    }
+
+    #[test]
+    fn should_render_portals_outside_of_ancestor_clips() {
+        let mut context = KayakContext::new();
+        let child = Fragment::constructor(FragmentProps {
+            styles: Some(Style {
+                height: StyleProp::Value(Units::Pixels(20.0)),
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                width: StyleProp::Value(Units::Pixels(30.0)),
+                ..Default::default()
+            }),
+            children: None,
+        });
+        let clip = Fragment::constructor(FragmentProps {
+            styles: Some(Style {
+                height: StyleProp::Value(Units::Pixels(20.0)),
+                render_command: StyleProp::Value(RenderCommand::Clip),
+                width: StyleProp::Value(Units::Pixels(10.0)),
+                ..Default::default()
+            }),
+            children: Some(Children::new(move |_, context| {
+                context.add_widget(child.clone(), 0);
+                context.commit();
+            })),
+        });
+        let root = Fragment::constructor(FragmentProps {
+            styles: Some(Style {
+                height: StyleProp::Value(Units::Pixels(50.0)),
+                layout_type: StyleProp::Value(LayoutType::Row),
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                width: StyleProp::Value(Units::Pixels(100.0)),
+                ..Default::default()
+            }),
+            children: Some(Children::new(move |_, context| {
+                context.add_widget(clip.clone(), 0);
+                context.commit();
+            })),
+        });
+        {
+            let mut context = KayakContextRef::new(&mut context, None);
+            context.add_widget(root, 0);
+            context.commit();
+        }
+
+        let mut harness = TestHarness::new(context);
+        let snapshot = harness.snapshot().unwrap();
+        let (root, clip) = (snapshot.id, snapshot.children[0].id);
+        let child = snapshot.children[0].children[0].id;
+        // The child overflows its clip, so it can't be hit outside of it
+        assert_eq!(Some(root), harness.context.widget_at(20.0, 10.0));
+
+        harness.context.widget_manager.set_portal(child, true);
+        harness.render();
+
+        let node_tree = &harness.context.widget_manager.node_tree;
+        assert_eq!(Some(root), node_tree.get_parent(child));
+        assert_eq!(Some(&child), node_tree.children[&root].last());
+        assert!(!node_tree.children[&clip].contains(&child));
+        // The logical parent is unchanged
+        assert_eq!(
+            Some(clip),
+            harness.context.widget_manager.tree.get_parent(child)
+        );
+        assert_eq!(Some(child), harness.context.widget_at(20.0, 10.0));
+    }
+
+    #[test]
+    fn should_track_mouse_delta() {
+        let mut harness = TestHarness::new(build());
+        harness.hover(10.0, 10.0);
+        harness.hover(25.0, 5.0);
+
+        assert_eq!((15.0, -5.0), harness.context.mouse_delta());
+        let (velocity_x, velocity_y) = harness.context.mouse_velocity();
+        assert!(velocity_x > 0.0);
+        assert!(velocity_y < 0.0);
+    }
+
+    #[test]
+    fn should_dispatch_shortcuts_and_detect_conflicts() {
+        let mut harness = TestHarness::new(build());
+        let snapshot = harness.snapshot().unwrap();
+        let (root, child) = (snapshot.id, snapshot.children[0].id);
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let cloned_count = count.clone();
+        let save = Handler::new(move |_| {
+            cloned_count.fetch_add(1, Ordering::SeqCst);
+        });
+        let context = &mut harness.context;
+        assert!(context
+            .register_shortcut(root, Shortcut::ctrl('s'), save.clone())
+            .is_ok());
+        // Re-registering from the same widget replaces the callback
+        assert!(context
+            .register_shortcut(root, Shortcut::ctrl('s'), save.clone())
+            .is_ok());
+        let conflict = context
+            .register_shortcut(child, Shortcut::ctrl('s'), save)
+            .unwrap_err();
+        assert_eq!(root, conflict.owner);
+
+        harness.press(KeyCode::S);
+        assert_eq!(0, count.load(Ordering::SeqCst));
+
+        harness.send(vec![InputEvent::Keyboard {
+            key: KeyCode::LControl,
+            is_pressed: true,
+        }]);
+        harness.press(KeyCode::S);
+        assert_eq!(1, count.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn should_tick_mounted_widgets_every_frame() {
+        let mut harness = TestHarness::new(build());
+        let snapshot = harness.snapshot().unwrap();
+        let (root, child) = (snapshot.id, snapshot.children[0].id);
+
+        let deltas = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let cloned_deltas = deltas.clone();
+        let tick = Handler::new(move |delta_time: f32| {
+            cloned_deltas.lock().unwrap().push(delta_time);
+        });
+        harness.context.set_tick(child, Some(tick));
+
+        harness.context.set_delta_time(0.02);
+        harness.render();
+        harness.context.set_delta_time(0.01);
+        harness.render();
+        assert_eq!(vec![0.02, 0.01], *deltas.lock().unwrap());
+
+        harness.context.set_tick(child, None);
+        harness.context.set_delta_time(0.01);
+        harness.render();
+        assert_eq!(vec![0.02, 0.01], *deltas.lock().unwrap());
+
+        // Widgets that aren't in the tree (such as those that were removed) aren't ticked
+        let count = Arc::new(AtomicUsize::new(0));
+        let cloned_count = count.clone();
+        let tick = Handler::new(move |_: f32| {
+            cloned_count.fetch_add(1, Ordering::SeqCst);
+        });
+        harness
+            .context
+            .set_tick(crate::Index::from_raw_parts(1000, 0), Some(tick.clone()));
+        harness.context.set_tick(root, Some(tick));
+        harness.context.set_delta_time(0.01);
+        harness.render();
+        assert_eq!(1, count.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn should_use_given_delta_time() {
+        let mut harness = TestHarness::new(build());
+        assert_eq!(0.0, harness.context.delta_time());
+
+        let deltas = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let cloned_deltas = deltas.clone();
+        let root = harness.snapshot().unwrap().id;
+        let tick = Handler::new(move |delta_time: f32| {
+            cloned_deltas.lock().unwrap().push(delta_time);
+        });
+        harness.context.set_tick(root, Some(tick));
+
+        harness.context.set_delta_time(0.5);
+        harness.render();
+        harness.context.set_delta_time(0.25);
+        harness.render();
+        assert_eq!(vec![0.5, 0.25], *deltas.lock().unwrap());
+        assert_eq!(0.25, harness.context.delta_time());
+        assert!(harness.context.elapsed() >= 0.75);
+    }
+
+    #[test]
+    fn should_fire_timeouts_once_due() {
+        let mut harness = TestHarness::new(build());
+        let root = harness.snapshot().unwrap().id;
+
+        let fired = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let timeout = |name: &'static str| {
+            let fired = fired.clone();
+            Handler::new(move |_| fired.lock().unwrap().push(name))
+        };
+        let context = &mut harness.context;
+        context.set_timeout(root, "search", 1.0, timeout("first"));
+        context.set_timeout(root, "other", 0.5, timeout("other"));
+        context.set_timeout(root, "cancelled", 0.5, timeout("cancelled"));
+        context.clear_timeout(root, "cancelled");
+
+        harness.context.set_delta_time(0.75);
+        harness.render();
+        assert_eq!(vec!["other"], *fired.lock().unwrap());
+        assert!(harness.context.has_timeout(root, "search"));
+
+        // Re-scheduling restarts the delay, replacing the pending callback
+        harness
+            .context
+            .set_timeout(root, "search", 1.0, timeout("second"));
+        harness.context.set_delta_time(0.75);
+        harness.render();
+        assert_eq!(vec!["other"], *fired.lock().unwrap());
+        harness.context.set_delta_time(0.25);
+        harness.render();
+        assert_eq!(vec!["other", "second"], *fired.lock().unwrap());
+        assert!(!harness.context.has_timeout(root, "search"));
+
+        // Timeouts of widgets that aren't in the tree are cancelled
+        let removed = crate::Index::from_raw_parts(1000, 0);
+        harness
+            .context
+            .set_timeout(removed, "search", 0.0, timeout("removed"));
+        harness.render();
+        assert!(!harness.context.has_timeout(removed, "search"));
+        assert_eq!(2, fired.lock().unwrap().len());
+    }
+
+    #[test]
+    fn should_coalesce_throttled_updates() {
+        let mut harness = TestHarness::new(build());
+        let binding = Binding::new(0);
+
+        harness.context.set_throttled(&binding, 1, 0.05);
+        assert_eq!(1, binding.get());
+
+        harness.context.set_throttled(&binding, 2, 0.05);
+        harness.context.set_throttled(&binding, 3, 0.05);
+        harness.render();
+        assert_eq!(1, binding.get());
+
+        // The trailing value is applied once the interval has passed
+        std::thread::sleep(std::time::Duration::from_millis(60));
+        harness.render();
+        assert_eq!(3, binding.get());
+
+        harness.context.set_throttled(&binding, 4, 0.05);
+        harness.context.cancel_throttled(&binding);
+        std::thread::sleep(std::time::Duration::from_millis(60));
+        harness.render();
+        assert_eq!(3, binding.get());
+    }
+
+    #[test]
+    fn should_recompute_and_rerender_when_dependencies_change() {
+        let mut harness = TestHarness::new(build());
+        let root = harness.snapshot().unwrap().id;
+
+        let count = Binding::new(2);
+        let cloned_count = count.clone();
+        let doubled = harness
+            .context
+            .computed(root, move || cloned_count.get() * 2);
+        assert_eq!(4, doubled.get());
+        assert!(!harness
+            .context
+            .widget_manager
+            .dirty_nodes
+            .lock()
+            .unwrap()
+            .contains(&root));
+
+        count.set(3);
+        assert_eq!(6, doubled.get());
+        assert!(harness
+            .context
+            .widget_manager
+            .dirty_nodes
+            .lock()
+            .unwrap()
+            .contains(&root));
+    }
+
+    #[test]
+    fn should_report_debounced_size_changes() {
+        let mut harness = TestHarness::new(build());
+        let child = harness.snapshot().unwrap().children[0].id;
+
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let cloned_events = events.clone();
+        let on_resize = Handler::new(move |event: ResizeEvent| {
+            cloned_events.lock().unwrap().push(event);
+        });
+        harness.context.set_resize(child, Some(on_resize));
+
+        // The first size is reported immediately
+        harness.context.set_delta_time(0.0);
+        harness.render();
+        assert_eq!(
+            vec![ResizeEvent {
+                previous: None,
+                size: (30.0, 20.0),
+            }],
+            *events.lock().unwrap()
+        );
+
+        let mut resize = |width: f32, delta_time: f32| {
+            let node = harness.context.widget_manager.nodes[child]
+                .as_mut()
+                .unwrap();
+            node.resolved_styles.width = StyleProp::Value(Units::Pixels(width));
+            harness.context.set_delta_time(delta_time);
+            harness.render();
+        };
+
+        // Sizes are only reported once they stop changing
+        resize(40.0, 0.05);
+        resize(50.0, 0.05);
+        assert_eq!(1, events.lock().unwrap().len());
+        resize(50.0, 0.15);
+        assert_eq!(
+            ResizeEvent {
+                previous: Some((30.0, 20.0)),
+                size: (50.0, 20.0),
+            },
+            events.lock().unwrap()[1]
+        );
+
+        // Changing back before the delay has passed doesn't report anything
+        resize(60.0, 0.05);
+        resize(50.0, 0.2);
+        assert_eq!(2, events.lock().unwrap().len());
+    }
+
+    #[test]
+    fn should_rerender_widgets_reading_the_window_size() {
+        let mut harness = TestHarness::new(build());
+        let root = harness.snapshot().unwrap().id;
+        assert_eq!(None, harness.context.window_size());
+
+        let size = {
+            let mut context = KayakContextRef::new(&mut harness.context, Some(root));
+            context.window_size()
+        };
+        assert_eq!(None, size);
+
+        let is_dirty = |harness: &TestHarness| {
+            harness
+                .context
+                .widget_manager
+                .dirty_nodes
+                .lock()
+                .unwrap()
+                .contains(&root)
+        };
+        harness.context.set_window_size(800.0, 600.0);
+        assert_eq!(Some((800.0, 600.0)), harness.context.window_size());
+        assert!(is_dirty(&harness));
+
+        // Setting the same size again doesn't re-render anything
+        harness.render();
+        harness.context.set_window_size(800.0, 600.0);
+        assert!(!is_dirty(&harness));
+    }
+
+    #[test]
+    fn should_copy_and_paste_using_the_provided_clipboard() {
+        let mut harness = TestHarness::new(build());
+        let clipboard = harness.context.clipboard();
+        assert!(!clipboard.is_available());
+        assert_eq!(Err(ClipboardError::Unavailable), clipboard.get_text());
+        assert_eq!(
+            Err(ClipboardError::Unavailable),
+            clipboard.set_text("Hello")
+        );
+
+        harness
+            .context
+            .set_clipboard(Some(Box::new(MemoryClipboard::default())));
+        let clipboard = harness.context.clipboard();
+        assert!(clipboard.is_available());
+        assert_eq!(Ok(()), clipboard.set_text("Hello"));
+        assert_eq!(Ok("Hello".to_string()), clipboard.get_text());
+    }
+
+    #[test]
+    fn should_only_show_focus_moved_by_the_keyboard() {
+        let mut harness = TestHarness::new(build());
+        let child = harness.snapshot().unwrap().children[0].id;
+        harness.context.set_focusable(Some(true), child);
+        harness.render();
+
+        harness.press(KeyCode::Tab);
+        assert_eq!(Some(child), harness.context.current_focus());
+        assert!(harness.context.is_focus_visible(child));
+
+        // Clicking the focused widget keeps it focused, but no longer visibly
+        harness.click(10.0, 10.0);
+        assert_eq!(Some(child), harness.context.current_focus());
+        assert!(!harness.context.is_focus_visible(child));
+    }
+
+    /// Props for a [`Listener`], which records the events it receives
+    #[derive(Default, Debug, PartialEq, Clone)]
+    struct ListenerProps {
+        children: Option<Children>,
+        on_event: Option<OnEvent>,
+    }
+
+    impl WidgetProps for ListenerProps {
+        fn get_children(&self) -> Option<Children> {
+            self.children.clone()
+        }
+
+        fn set_children(&mut self, children: Option<Children>) {
+            self.children = children;
+        }
+
+        fn get_styles(&self) -> Option<Style> {
+            Some(Style {
+                height: StyleProp::Value(Units::Pixels(50.0)),
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                width: StyleProp::Value(Units::Pixels(100.0)),
+                ..Default::default()
+            })
+        }
+
+        fn get_on_event(&self) -> Option<OnEvent> {
+            self.on_event.clone()
+        }
+
+        fn get_on_layout(&self) -> Option<OnLayout> {
+            None
+        }
+
+        fn get_focusable(&self) -> Option<bool> {
+            None
+        }
+    }
+
+    #[derive(Default, Debug, PartialEq, Clone)]
+    struct Listener {
+        id: Index,
+        props: ListenerProps,
+    }
+
+    impl Widget for Listener {
+        type Props = ListenerProps;
+
+        fn constructor(props: Self::Props) -> Self {
+            Self {
+                id: Index::default(),
+                props,
+            }
+        }
+
+        fn get_id(&self) -> Index {
+            self.id
+        }
+
+        fn set_id(&mut self, id: Index) {
+            self.id = id;
+        }
+
+        fn get_props(&self) -> &Self::Props {
+            &self.props
+        }
+
+        fn get_props_mut(&mut self) -> &mut Self::Props {
+            &mut self.props
+        }
+
+        fn render(&mut self, context: &mut KayakContextRef) {
+            if let Some(children) = self.props.children.clone() {
+                let mut context = KayakContextRef::new(context.context, Some(self.id));
+                children.build(Some(self.id), &mut context);
+            }
+        }
+    }
+
+    #[test]
+    fn should_send_focus_in_and_out_to_ancestors() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut context = KayakContext::new();
+        let child = Fragment::constructor(FragmentProps {
+            styles: Some(Style {
+                height: StyleProp::Value(Units::Pixels(20.0)),
+                width: StyleProp::Value(Units::Pixels(30.0)),
+                ..Default::default()
+            }),
+            children: None,
+        });
+        let recorded = received.clone();
+        let root = Listener::constructor(ListenerProps {
+            children: Some(Children::new(move |_, context| {
+                context.add_widget(child.clone(), 0);
+                context.commit();
+            })),
+            on_event: Some(OnEvent::new(move |context, event| {
+                let contains_focus = context.contains_focus(event.current_target);
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push((event.event_type, contains_focus));
+            })),
+        });
+        {
+            let mut context = KayakContextRef::new(&mut context, None);
+            context.add_widget(root, 0);
+            context.commit();
+        }
+
+        let mut harness = TestHarness::new(context);
+        let child = harness.snapshot().unwrap().children[0].id;
+        harness.context.set_focusable(Some(true), child);
+        harness.render();
+
+        // Focusing the child only lets the parent know through the propagating event
+        harness.press(KeyCode::Tab);
+        assert_eq!(Some(child), harness.context.current_focus());
+        let focus_events: Vec<_> = received
+            .lock()
+            .unwrap()
+            .drain(..)
+            .filter(|(event_type, _)| event_type.event_category() == EventCategory::Focus)
+            .collect();
+        assert_eq!(vec![(EventType::FocusIn, true)], focus_events);
+
+        // Clicking beside the child blurs it
+        harness.click(80.0, 40.0);
+        assert_eq!(None, harness.context.current_focus());
+        let focus_events: Vec<_> = received
+            .lock()
+            .unwrap()
+            .drain(..)
+            .filter(|(event_type, _)| event_type.event_category() == EventCategory::Focus)
+            .collect();
+        assert_eq!(vec![(EventType::FocusOut, false)], focus_events);
+    }
+
+    #[test]
+    fn should_only_consider_widgets_in_the_tree_mounted() {
+        let mut harness = TestHarness::new(build());
+        let snapshot = harness.snapshot().unwrap();
+        let (root, child) = (snapshot.id, snapshot.children[0].id);
+
+        let context = KayakContextRef::new(&mut harness.context, Some(root));
+        assert!(context.is_mounted(root));
+        assert!(context.is_mounted(child));
+        assert!(!context.is_mounted(crate::Index::from_raw_parts(1000, 0)));
+    }
+
+    #[test]
+    fn should_build_the_accessibility_tree_from_annotated_widgets() {
+        let mut harness = TestHarness::new(build());
+        let snapshot = harness.snapshot().unwrap();
+        let root = snapshot.id;
+        let child = snapshot.children[0].id;
+        assert!(harness.context.accessibility_tree().is_empty());
+
+        let manager = &mut harness.context.widget_manager;
+        manager.set_accessibility(
+            child,
+            Some(Accessibility::new(Role::Button).with_name("Save")),
+        );
+        let tree = harness.context.accessibility_tree();
+        assert_eq!(1, tree.len());
+        assert_eq!(child, tree[0].id);
+        assert_eq!(Some("Save".to_string()), tree[0].accessibility.name);
+        assert_eq!(Some(30.0), tree[0].rect.map(|rect| rect.width));
+
+        // Annotated ancestors contain the nodes of their annotated descendants
+        let manager = &mut harness.context.widget_manager;
+        manager.set_accessibility(root, Some(Accessibility::new(Role::Group)));
+        let tree = harness.context.accessibility_tree();
+        assert_eq!(1, tree.len());
+        assert_eq!(root, tree[0].id);
+        assert_eq!(Some(child), tree[0].find(Role::Button).map(|node| node.id));
+
+        let manager = &mut harness.context.widget_manager;
+        manager.set_accessibility(root, None);
+        assert_eq!(child, harness.context.accessibility_tree()[0].id);
+
+        // Descriptions are linked from both sides
+        let manager = &mut harness.context.widget_manager;
+        manager.set_accessibility(
+            root,
+            Some(Accessibility::new(Role::Tooltip).with_describes(child)),
+        );
+        let tree = harness.context.accessibility_tree();
+        let button = tree[0].find(Role::Button).unwrap();
+        assert_eq!(vec![root], button.accessibility.described_by);
+    }
+
+    #[test]
+    fn should_prefer_overridden_preferences_over_the_system_ones() {
+        let mut harness = TestHarness::new(build());
+        let root = harness.snapshot().unwrap().id;
+        assert_eq!(UserPreferences::default(), harness.context.preferences());
+
+        let reduced_motion = {
+            let mut context = KayakContextRef::new(&mut harness.context, Some(root));
+            context.prefers_reduced_motion()
+        };
+        assert!(!reduced_motion);
+
+        let is_dirty = |harness: &TestHarness| {
+            harness
+                .context
+                .widget_manager
+                .dirty_nodes
+                .lock()
+                .unwrap()
+                .contains(&root)
+        };
+        let system = UserPreferences {
+            reduced_motion: true,
+            high_contrast: false,
+        };
+        harness.context.set_system_preferences(system);
+        assert_eq!(system, harness.context.preferences());
+        assert!(is_dirty(&harness));
+
+        let overridden = UserPreferences {
+            reduced_motion: false,
+            high_contrast: true,
+        };
+        harness.render();
+        harness.context.override_preferences(Some(overridden));
+        assert_eq!(overridden, harness.context.preferences());
+        assert!(is_dirty(&harness));
+
+        // The system preferences are ignored while overridden
+        harness.render();
+        harness
+            .context
+            .set_system_preferences(UserPreferences::default());
+        assert_eq!(overridden, harness.context.preferences());
+        assert!(!is_dirty(&harness));
+
+        harness.context.override_preferences(None);
+        assert_eq!(UserPreferences::default(), harness.context.preferences());
+        assert!(is_dirty(&harness));
+    }
+
+    fn build_failing(has_boundary: bool, errors: Arc<Mutex<Vec<RenderError>>>) -> KayakContext {
+        let mut context = KayakContext::new();
+        let failing = Fragment::constructor(FragmentProps {
+            styles: None,
+            children: Some(Children::new(|_, _| panic!("Oh no!"))),
+        });
+        let middle = Fragment::constructor(FragmentProps {
+            styles: None,
+            children: Some(Children::new(move |_, context| {
+                context.add_widget(failing.clone(), 0);
+                context.commit();
+            })),
+        });
+        let root = Fragment::constructor(FragmentProps {
+            styles: None,
+            children: Some(Children::new(move |_, context| {
+                if has_boundary {
+                    let errors = errors.clone();
+                    context.set_error_boundary(Some(Handler::new(move |error| {
+                        errors.lock().unwrap().push(error);
+                    })));
+                }
+                context.add_widget(middle.clone(), 0);
+                context.commit();
+            })),
+        });
+
+        {
+            let mut context = KayakContextRef::new(&mut context, None);
+            context.add_widget(root, 0);
+            context.commit();
+        }
+        context
+    }
+
+    #[test]
+    fn should_report_render_panics_to_the_nearest_error_boundary() {
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let mut harness = TestHarness::new(build_failing(true, errors.clone()));
+
+        let errors = errors.lock().unwrap().clone();
+        assert_eq!(1, errors.len());
+        // The error is passed up through the widget in between, keeping the one that panicked
+        assert!(errors[0].name.contains("Fragment"));
+        assert_eq!("Oh no!", errors[0].message);
+
+        // Every widget was returned to the widget manager, so rendering can carry on
+        let widgets = &harness.context.widget_manager.current_widgets;
+        assert!(widgets.iter().all(|(_, widget)| widget.is_some()));
+        harness.render();
+    }
+
+    #[test]
+    #[should_panic(expected = "Oh no!")]
+    fn should_resume_render_panics_without_an_error_boundary() {
+        build_failing(false, Arc::new(Mutex::new(Vec::new())));
+    }
+}
@@ -0,0 +1,105 @@
+use std::iter::{Filter, FilterMap, Map};
+
+use crate::{VecTracker, Widget};
+
+/// A trait for values that can be placed within the braces of an `rsx!` block
+///
+/// Besides regular widgets, this allows collections of widgets to be embedded directly, without
+/// needing to wrap them in a [`VecTracker`] manually:
+///
+/// * `Vec<W>` renders each widget in sequence
+/// * `Option<W>` renders the widget only if it's `Some`, which makes for a concise alternative to
+///   the `If` widget
+/// * Iterators created using `map`, `filter`, or `filter_map` render each widget they yield
+///
+/// The widgets within braces can be constructed using the `constructor!` macro. Those in a
+/// collection are matched by their position in it, so items that can be reordered should be
+/// given a [key](Widget::get_key).
+///
+/// # Examples
+///
+/// ```ignore
+/// let items = vec!["A".to_string(), "B".to_string()];
+/// let show_footer = false;
+///
+/// rsx! {
+///     <>
+///         {items.clone().into_iter().map(|item| constructor! {
+///             <Text key={item} content={item.clone()} />
+///         })}
+///         {show_footer.then(|| constructor! {
+///             <Text content={"Footer".to_string()} />
+///         })}
+///     </>
+/// }
+/// ```
+pub trait IntoWidget {
+    /// The widget this value becomes
+    type Widget: Widget;
+
+    /// Converts this value into a widget
+    fn into_widget(self) -> Self::Widget;
+}
+
+impl<W: Widget> IntoWidget for W {
+    type Widget = W;
+
+    fn into_widget(self) -> Self::Widget {
+        self
+    }
+}
+
+impl<W: Widget> IntoWidget for Vec<W> {
+    type Widget = VecTracker<W>;
+
+    fn into_widget(self) -> Self::Widget {
+        VecTracker::new(self)
+    }
+}
+
+impl<W: Widget> IntoWidget for Option<W> {
+    type Widget = VecTracker<W>;
+
+    fn into_widget(self) -> Self::Widget {
+        VecTracker::from(self.into_iter())
+    }
+}
+
+impl<I, F, W> IntoWidget for Map<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> W,
+    W: Widget,
+{
+    type Widget = VecTracker<W>;
+
+    fn into_widget(self) -> Self::Widget {
+        VecTracker::from(self)
+    }
+}
+
+impl<I, P, W> IntoWidget for Filter<I, P>
+where
+    I: Iterator<Item = W>,
+    P: FnMut(&W) -> bool,
+    W: Widget,
+{
+    type Widget = VecTracker<W>;
+
+    fn into_widget(self) -> Self::Widget {
+        VecTracker::from(self)
+    }
+}
+
+impl<I, F, W> IntoWidget for FilterMap<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Option<W>,
+    W: Widget,
+{
+    type Widget = VecTracker<W>;
+
+    fn into_widget(self) -> Self::Widget {
+        VecTracker::from(self)
+    }
+}
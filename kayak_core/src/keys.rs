@@ -201,3 +201,62 @@ pub enum KeyCode {
     Paste,
     Cut,
 }
+
+impl KeyCode {
+    /// Returns the key that types the given character on a US keyboard layout, if any
+    ///
+    /// Letters are matched regardless of case.
+    pub fn from_char(c: char) -> Option<Self> {
+        let key = match c.to_ascii_lowercase() {
+            'a' => Self::A,
+            'b' => Self::B,
+            'c' => Self::C,
+            'd' => Self::D,
+            'e' => Self::E,
+            'f' => Self::F,
+            'g' => Self::G,
+            'h' => Self::H,
+            'i' => Self::I,
+            'j' => Self::J,
+            'k' => Self::K,
+            'l' => Self::L,
+            'm' => Self::M,
+            'n' => Self::N,
+            'o' => Self::O,
+            'p' => Self::P,
+            'q' => Self::Q,
+            'r' => Self::R,
+            's' => Self::S,
+            't' => Self::T,
+            'u' => Self::U,
+            'v' => Self::V,
+            'w' => Self::W,
+            'x' => Self::X,
+            'y' => Self::Y,
+            'z' => Self::Z,
+            '0' => Self::Key0,
+            '1' => Self::Key1,
+            '2' => Self::Key2,
+            '3' => Self::Key3,
+            '4' => Self::Key4,
+            '5' => Self::Key5,
+            '6' => Self::Key6,
+            '7' => Self::Key7,
+            '8' => Self::Key8,
+            '9' => Self::Key9,
+            ' ' => Self::Space,
+            ',' => Self::Comma,
+            '.' => Self::Period,
+            '/' => Self::Slash,
+            ';' => Self::Semicolon,
+            '-' => Self::Minus,
+            '=' => Self::Equals,
+            '[' => Self::LBracket,
+            ']' => Self::RBracket,
+            '\\' => Self::Backslash,
+            '`' => Self::Grave,
+            _ => return None,
+        };
+        Some(key)
+    }
+}
@@ -66,8 +66,8 @@ pub struct LayoutEvent {
     pub layout: Layout,
     /// Flags denoting the layout change.
     ///
-    /// Note: The flags can potentially all be unset in cases where the [target's] layout did
-    /// not change, but one of its immediate children did.
+    /// Layout events are only sent when the [target's] rect differs from the one it was last
+    /// sent, so at least one of these flags will generally be set.
     ///
     /// [target's]: LayoutEvent::target
     pub flags: GeometryChanged,
@@ -84,3 +84,13 @@ impl LayoutEvent {
         }
     }
 }
+
+/// The data sent to the callbacks set with [`KayakContextRef::on_resize`](crate::KayakContextRef::on_resize)
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ResizeEvent {
+    /// The width and height (in pixels) that were last reported, or `None` if this is the first
+    /// time the size is being reported
+    pub previous: Option<(f32, f32)>,
+    /// The new width and height (in pixels)
+    pub size: (f32, f32),
+}
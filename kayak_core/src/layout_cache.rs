@@ -19,6 +19,24 @@ impl Rect {
         (point.0 >= self.posx && point.0 <= self.posx + self.width)
             && (point.1 >= self.posy && point.1 <= self.posy + self.height)
     }
+
+    /// Returns the region covered by both this rect and the given one
+    ///
+    /// If they don't overlap, the returned rect has a width and/or height of zero. The z-index of
+    /// this rect is kept.
+    pub fn intersection(&self, other: &Rect) -> Rect {
+        let posx = self.posx.max(other.posx);
+        let posy = self.posy.max(other.posy);
+        let right = (self.posx + self.width).min(other.posx + other.width);
+        let bottom = (self.posy + self.height).min(other.posy + other.height);
+        Rect {
+            posx,
+            posy,
+            width: (right - posx).max(0.0),
+            height: (bottom - posy).max(0.0),
+            z_index: self.z_index,
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -68,6 +86,9 @@ pub struct LayoutCache {
     geometry_changed: HashMap<Index, GeometryChanged>,
 
     visible: HashMap<Index, bool>,
+
+    /// A map of node IDs to the last rect sent to their `OnLayout` handler
+    dispatched: HashMap<Index, Rect>,
 }
 
 impl LayoutCache {
@@ -106,6 +127,49 @@ impl LayoutCache {
         self.rect.entry(node).or_default();
     }
 
+    /// Moves the given node by the given offset, marking its position as changed
+    pub(crate) fn translate(&mut self, node: Index, dx: f32, dy: f32) {
+        if let Some(rect) = self.rect.get_mut(&node) {
+            rect.posx += dx;
+            rect.posy += dy;
+        }
+
+        if dx != 0.0 {
+            self.set_geo_changed(node, GeometryChanged::POSX_CHANGED, true);
+        }
+        if dy != 0.0 {
+            self.set_geo_changed(node, GeometryChanged::POSY_CHANGED, true);
+        }
+    }
+
+    /// Sets the height of the given node, marking its height as changed if it differs
+    pub(crate) fn resize_height(&mut self, node: Index, height: f32) {
+        if let Some(rect) = self.rect.get_mut(&node) {
+            if rect.height != height {
+                rect.height = height;
+                self.set_geo_changed(node, GeometryChanged::HEIGHT_CHANGED, true);
+            }
+        }
+    }
+
+    /// Records the given rect as dispatched to the node's `OnLayout` handler
+    ///
+    /// Returns false if the rect is the same as the one last dispatched to this node, in which
+    /// case the handler should not be called again.
+    pub(crate) fn try_dispatch(&mut self, node: Index, rect: Rect) -> bool {
+        if self.dispatched.get(&node) == Some(&rect) {
+            return false;
+        }
+
+        self.dispatched.insert(node, rect);
+        true
+    }
+
+    /// Returns the computed space around the given node
+    pub fn get_space(&self, node: &Index) -> Option<Space> {
+        self.space.get(node).copied()
+    }
+
     /// Returns an iterator over nodes whose layout have been changed since the last update
     pub fn iter_changed(&self) -> Iter<'_, Index, GeometryChanged> {
         self.geometry_changed.iter()
@@ -384,3 +448,72 @@ impl Cache for LayoutCache {
         *self.stack_last_child.get_mut(&node).unwrap() = value;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{LayoutCache, Rect};
+    use crate::Index;
+
+    #[test]
+    fn should_only_dispatch_changed_rects() {
+        let mut cache = LayoutCache::default();
+        let node = Index::from_raw_parts(0, 0);
+        let rect = Rect {
+            posx: 10.0,
+            posy: 20.0,
+            width: 100.0,
+            height: 50.0,
+            z_index: 0.0,
+        };
+
+        assert!(cache.try_dispatch(node, rect));
+        assert!(!cache.try_dispatch(node, rect));
+
+        let moved = Rect { posx: 15.0, ..rect };
+        assert!(cache.try_dispatch(node, moved));
+        assert!(!cache.try_dispatch(node, moved));
+        assert!(cache.try_dispatch(node, rect));
+    }
+
+    #[test]
+    fn should_intersect_rects() {
+        let rect = Rect {
+            posx: 0.0,
+            posy: 0.0,
+            width: 100.0,
+            height: 100.0,
+            z_index: 1.0,
+        };
+        let offset = Rect {
+            posx: 50.0,
+            posy: -20.0,
+            width: 100.0,
+            height: 60.0,
+            z_index: 2.0,
+        };
+
+        let expected = Rect {
+            posx: 50.0,
+            posy: 0.0,
+            width: 50.0,
+            height: 40.0,
+            z_index: 1.0,
+        };
+        assert_eq!(expected, rect.intersection(&offset));
+        assert_eq!(
+            Rect {
+                z_index: 2.0,
+                ..expected
+            },
+            offset.intersection(&rect)
+        );
+
+        let disjoint = Rect {
+            posx: 200.0,
+            ..offset
+        };
+        let empty = rect.intersection(&disjoint);
+        assert_eq!(0.0, empty.width);
+        assert!(!empty.contains(&(50.0, 50.0)));
+    }
+}
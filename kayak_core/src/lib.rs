@@ -1,6 +1,8 @@
+mod accessibility;
 mod assets;
 mod binding;
 mod children;
+mod clipboard;
 pub mod color;
 pub mod context;
 mod context_ref;
@@ -12,7 +14,9 @@ mod flo_binding;
 mod focus_tree;
 pub mod fragment;
 pub(crate) mod generational_arena;
+mod harness;
 mod input_event;
+mod into_widget;
 mod keyboard;
 mod keys;
 mod layout;
@@ -21,11 +25,17 @@ mod layout_dispatcher;
 mod lifetime;
 mod multi_state;
 pub mod node;
+mod on_change;
 mod on_event;
 mod on_layout;
+mod platform;
 pub mod render_command;
+mod render_error;
 pub mod render_primitive;
+mod shortcut;
+mod snapshot;
 pub mod styles;
+mod text;
 pub mod tree;
 mod vec;
 pub mod widget;
@@ -33,8 +43,10 @@ pub mod widget_manager;
 
 use std::sync::{Arc, RwLock};
 
+pub use accessibility::{AccessNode, Accessibility, Role, UserPreferences};
 pub use binding::*;
 pub use children::Children;
+pub use clipboard::{Clipboard, ClipboardError, ClipboardProvider, MemoryClipboard};
 pub use color::Color;
 pub use context::*;
 pub use context_ref::KayakContextRef;
@@ -44,13 +56,25 @@ pub use event::*;
 pub use focus_tree::FocusTree;
 pub use fragment::{Fragment, FragmentProps};
 pub use generational_arena::{Arena, Index};
+pub use harness::TestHarness;
 pub use input_event::*;
+pub use into_widget::IntoWidget;
+pub use kayak_font::TextProperties;
 pub use keyboard::{KeyboardEvent, KeyboardModifiers};
 pub use keys::KeyCode;
 pub use layout::*;
+pub use on_change::OnChange;
 pub use on_event::OnEvent;
 pub use on_layout::OnLayout;
+pub use platform::Platform;
+pub use render_error::RenderError;
 pub use resources::Resources;
+pub use shortcut::{Shortcut, ShortcutConflict};
+pub use snapshot::LayoutSnapshot;
+pub use text::{
+    measure_text, measure_text_with_properties, next_word_boundary, prev_word_boundary,
+    text_range_rects, LineMeasurement, TextMeasurement,
+};
 pub use tree::{Tree, WidgetTree};
 pub use vec::{VecTracker, VecTrackerProps};
 pub use widget::{BaseWidget, Widget, WidgetProps};
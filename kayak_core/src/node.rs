@@ -1,6 +1,6 @@
 use crate::render_primitive::RenderPrimitive;
 use crate::{
-    styles::{Style, StyleProp},
+    styles::{Edge, PositionType, Style, StyleProp, Units},
     Arena, Index,
 };
 
@@ -19,6 +19,59 @@ pub struct Node {
     pub primitive: RenderPrimitive,
     /// The z-index of this node, used for controlling layering
     pub z: f32,
+    /// The height computed for this node after wrapping its children onto multiple lines
+    ///
+    /// This is only set for nodes with an auto height and a [`LayoutWrap::Wrap`](crate::styles::LayoutWrap::Wrap) layout.
+    pub wrap_height: Option<f32>,
+    /// The padding of this node, with any percentages resolved against the width of its parent
+    ///
+    /// This is set during layout and overrides the padding given by the node's styles.
+    pub resolved_padding: Option<Edge<Units>>,
+}
+
+impl Node {
+    /// Returns the padding of this node as given by its styles
+    ///
+    /// The side-specific properties (such as [`padding_left`](Style::padding_left)) take precedence
+    /// over [`padding`](Style::padding). Unset sides are [`Units::Auto`].
+    pub fn declared_padding(&self) -> Edge<Units> {
+        let styles = &self.resolved_styles;
+        let padding = styles.padding.resolve_or(Edge::all(Units::Auto));
+        Edge::new(
+            styles.padding_top.resolve_or(padding.top),
+            styles.padding_right.resolve_or(padding.right),
+            styles.padding_bottom.resolve_or(padding.bottom),
+            styles.padding_left.resolve_or(padding.left),
+        )
+    }
+
+    /// Returns the padding of this node used for layout
+    ///
+    /// This is the [declared padding](Self::declared_padding) with any percentages resolved
+    /// against the width of the parent, if layout has done so.
+    pub fn padding(&self) -> Edge<Units> {
+        self.resolved_padding
+            .unwrap_or_else(|| self.declared_padding())
+    }
+
+    /// Returns the margin of this node along the given side, falling back to its offset
+    ///
+    /// Margins only apply to parent-directed nodes, so self-directed ones only use their offset.
+    fn margin(&self, side: fn(&Edge<Units>) -> Units, specific: &StyleProp<Units>) -> Units {
+        let styles = &self.resolved_styles;
+        if styles.position_type.resolve_or_default() == PositionType::ParentDirected {
+            if let StyleProp::Value(value) = specific {
+                return *value;
+            }
+            if let StyleProp::Value(margin) = &styles.margin {
+                return side(margin);
+            }
+        }
+        if let StyleProp::Value(offset) = &styles.offset {
+            return side(offset);
+        }
+        Units::Auto
+    }
 }
 
 /// A struct used for building a [`Node`]
@@ -37,6 +90,8 @@ impl NodeBuilder {
                 raw_styles: None,
                 primitive: RenderPrimitive::Empty,
                 z: 0.0,
+                wrap_height: None,
+                resolved_padding: None,
             },
         }
     }
@@ -51,6 +106,8 @@ impl NodeBuilder {
                 raw_styles: None,
                 primitive: RenderPrimitive::Empty,
                 z: 0.0,
+                wrap_height: None,
+                resolved_padding: None,
             },
         }
     }
@@ -131,6 +188,10 @@ impl<'a> morphorm::Node<'a> for Index {
     fn height(&self, store: &'_ Self::Data) -> Option<morphorm::Units> {
         if let Some(node) = store.get(*self) {
             if let Some(node) = node {
+                if let Some(wrap_height) = node.wrap_height {
+                    return Some(morphorm::Units::Pixels(wrap_height));
+                }
+
                 return match node.resolved_styles.height {
                     StyleProp::Default => Some(morphorm::Units::Stretch(1.0)),
                     StyleProp::Value(prop) => Some(prop),
@@ -197,11 +258,9 @@ impl<'a> morphorm::Node<'a> for Index {
         if let Some(node) = store.get(*self) {
             if let Some(node) = node {
                 return match node.resolved_styles.left {
-                    StyleProp::Default => match node.resolved_styles.offset {
-                        StyleProp::Default => Some(morphorm::Units::Auto),
-                        StyleProp::Value(prop) => Some(prop.left),
-                        _ => Some(morphorm::Units::Auto),
-                    },
+                    StyleProp::Default => {
+                        Some(node.margin(|edge| edge.left, &node.resolved_styles.margin_left))
+                    }
                     StyleProp::Value(prop) => Some(prop),
                     _ => Some(morphorm::Units::Auto),
                 };
@@ -214,11 +273,9 @@ impl<'a> morphorm::Node<'a> for Index {
         if let Some(node) = store.get(*self) {
             if let Some(node) = node {
                 return match node.resolved_styles.right {
-                    StyleProp::Default => match node.resolved_styles.offset {
-                        StyleProp::Default => Some(morphorm::Units::Auto),
-                        StyleProp::Value(prop) => Some(prop.right),
-                        _ => Some(morphorm::Units::Auto),
-                    },
+                    StyleProp::Default => {
+                        Some(node.margin(|edge| edge.right, &node.resolved_styles.margin_right))
+                    }
                     StyleProp::Value(prop) => Some(prop),
                     _ => Some(morphorm::Units::Auto),
                 };
@@ -231,11 +288,9 @@ impl<'a> morphorm::Node<'a> for Index {
         if let Some(node) = store.get(*self) {
             if let Some(node) = node {
                 return match node.resolved_styles.top {
-                    StyleProp::Default => match node.resolved_styles.offset {
-                        StyleProp::Default => Some(morphorm::Units::Auto),
-                        StyleProp::Value(prop) => Some(prop.top),
-                        _ => Some(morphorm::Units::Auto),
-                    },
+                    StyleProp::Default => {
+                        Some(node.margin(|edge| edge.top, &node.resolved_styles.margin_top))
+                    }
                     StyleProp::Value(prop) => Some(prop),
                     _ => Some(morphorm::Units::Auto),
                 };
@@ -248,11 +303,9 @@ impl<'a> morphorm::Node<'a> for Index {
         if let Some(node) = store.get(*self) {
             if let Some(node) = node {
                 return match node.resolved_styles.bottom {
-                    StyleProp::Default => match node.resolved_styles.offset {
-                        StyleProp::Default => Some(morphorm::Units::Auto),
-                        StyleProp::Value(prop) => Some(prop.bottom),
-                        _ => Some(morphorm::Units::Auto),
-                    },
+                    StyleProp::Default => {
+                        Some(node.margin(|edge| edge.bottom, &node.resolved_styles.margin_bottom))
+                    }
                     StyleProp::Value(prop) => Some(prop),
                     _ => Some(morphorm::Units::Auto),
                 };
@@ -296,15 +349,7 @@ impl<'a> morphorm::Node<'a> for Index {
     fn child_left(&self, store: &'_ Self::Data) -> Option<morphorm::Units> {
         if let Some(node) = store.get(*self) {
             if let Some(node) = node {
-                return match node.resolved_styles.padding_left {
-                    StyleProp::Default => match node.resolved_styles.padding {
-                        StyleProp::Default => Some(morphorm::Units::Auto),
-                        StyleProp::Value(prop) => Some(prop.left),
-                        _ => Some(morphorm::Units::Auto),
-                    },
-                    StyleProp::Value(prop) => Some(prop),
-                    _ => Some(morphorm::Units::Auto),
-                };
+                return Some(node.padding().left);
             }
         }
         return Some(morphorm::Units::Auto);
@@ -313,15 +358,7 @@ impl<'a> morphorm::Node<'a> for Index {
     fn child_right(&self, store: &'_ Self::Data) -> Option<morphorm::Units> {
         if let Some(node) = store.get(*self) {
             if let Some(node) = node {
-                return match node.resolved_styles.padding_right {
-                    StyleProp::Default => match node.resolved_styles.padding {
-                        StyleProp::Default => Some(morphorm::Units::Auto),
-                        StyleProp::Value(prop) => Some(prop.right),
-                        _ => Some(morphorm::Units::Auto),
-                    },
-                    StyleProp::Value(prop) => Some(prop),
-                    _ => Some(morphorm::Units::Auto),
-                };
+                return Some(node.padding().right);
             }
         }
         return Some(morphorm::Units::Auto);
@@ -330,15 +367,7 @@ impl<'a> morphorm::Node<'a> for Index {
     fn child_top(&self, store: &'_ Self::Data) -> Option<morphorm::Units> {
         if let Some(node) = store.get(*self) {
             if let Some(node) = node {
-                return match node.resolved_styles.padding_top {
-                    StyleProp::Default => match node.resolved_styles.padding {
-                        StyleProp::Default => Some(morphorm::Units::Auto),
-                        StyleProp::Value(prop) => Some(prop.top),
-                        _ => Some(morphorm::Units::Auto),
-                    },
-                    StyleProp::Value(prop) => Some(prop),
-                    _ => Some(morphorm::Units::Auto),
-                };
+                return Some(node.padding().top);
             }
         }
         return Some(morphorm::Units::Auto);
@@ -347,15 +376,7 @@ impl<'a> morphorm::Node<'a> for Index {
     fn child_bottom(&self, store: &'_ Self::Data) -> Option<morphorm::Units> {
         if let Some(node) = store.get(*self) {
             if let Some(node) = node {
-                return match node.resolved_styles.padding_bottom {
-                    StyleProp::Default => match node.resolved_styles.padding {
-                        StyleProp::Default => Some(morphorm::Units::Auto),
-                        StyleProp::Value(prop) => Some(prop.bottom),
-                        _ => Some(morphorm::Units::Auto),
-                    },
-                    StyleProp::Value(prop) => Some(prop),
-                    _ => Some(morphorm::Units::Auto),
-                };
+                return Some(node.padding().bottom);
             }
         }
         return Some(morphorm::Units::Auto);
@@ -416,7 +437,7 @@ impl<'a> morphorm::Node<'a> for Index {
             if let Some(node) = node {
                 return match node.resolved_styles.border {
                     StyleProp::Default => Some(morphorm::Units::Auto),
-                    StyleProp::Value(prop) => Some(morphorm::Units::Pixels(prop.left)),
+                    StyleProp::Value(prop) => Some(prop.left),
                     _ => Some(morphorm::Units::Auto),
                 };
             }
@@ -429,7 +450,7 @@ impl<'a> morphorm::Node<'a> for Index {
             if let Some(node) = node {
                 return match node.resolved_styles.border {
                     StyleProp::Default => Some(morphorm::Units::Auto),
-                    StyleProp::Value(prop) => Some(morphorm::Units::Pixels(prop.right)),
+                    StyleProp::Value(prop) => Some(prop.right),
                     _ => Some(morphorm::Units::Auto),
                 };
             }
@@ -442,7 +463,7 @@ impl<'a> morphorm::Node<'a> for Index {
             if let Some(node) = node {
                 return match node.resolved_styles.border {
                     StyleProp::Default => Some(morphorm::Units::Auto),
-                    StyleProp::Value(prop) => Some(morphorm::Units::Pixels(prop.top)),
+                    StyleProp::Value(prop) => Some(prop.top),
                     _ => Some(morphorm::Units::Auto),
                 };
             }
@@ -455,7 +476,7 @@ impl<'a> morphorm::Node<'a> for Index {
             if let Some(node) = node {
                 return match node.resolved_styles.border {
                     StyleProp::Default => Some(morphorm::Units::Auto),
-                    StyleProp::Value(prop) => Some(morphorm::Units::Pixels(prop.bottom)),
+                    StyleProp::Value(prop) => Some(prop.bottom),
                     _ => Some(morphorm::Units::Auto),
                 };
             }
@@ -463,3 +484,106 @@ impl<'a> morphorm::Node<'a> for Index {
         Some(morphorm::Units::Auto)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::layout_cache::LayoutCache;
+    use crate::node::NodeBuilder;
+    use crate::styles::{Edge, PositionType, Style, StyleProp, Units};
+    use crate::{Arena, Tree};
+    use morphorm::Node as _;
+
+    #[test]
+    fn padding_should_prefer_side_specific_properties() {
+        let styles = Style {
+            padding: StyleProp::Value(Edge::all(Units::Pixels(4.0))),
+            padding_left: StyleProp::Value(Units::Percentage(10.0)),
+            ..Style::initial()
+        };
+        let mut node = NodeBuilder::new(Default::default(), styles).build();
+
+        let expected = Edge::new(
+            Units::Pixels(4.0),
+            Units::Pixels(4.0),
+            Units::Pixels(4.0),
+            Units::Percentage(10.0),
+        );
+        assert_eq!(expected, node.declared_padding());
+        assert_eq!(expected, node.padding());
+
+        let resolved = Edge::all(Units::Pixels(8.0));
+        node.resolved_padding = Some(resolved);
+        assert_eq!(resolved, node.padding());
+    }
+
+    #[test]
+    fn margin_should_only_apply_to_parent_directed_nodes() {
+        let mut nodes = Arena::new();
+        let parent_directed = nodes.insert(None);
+        let self_directed = nodes.insert(None);
+
+        let styles = Style {
+            margin: StyleProp::Value(Edge::all(Units::Pixels(10.0))),
+            margin_left: StyleProp::Value(Units::Pixels(20.0)),
+            offset: StyleProp::Value(Edge::all(Units::Pixels(5.0))),
+            ..Style::initial()
+        };
+        nodes[parent_directed] = Some(NodeBuilder::new(parent_directed, styles.clone()).build());
+        nodes[self_directed] = Some(
+            NodeBuilder::new(
+                self_directed,
+                Style {
+                    position_type: StyleProp::Value(PositionType::SelfDirected),
+                    ..styles
+                },
+            )
+            .build(),
+        );
+
+        assert_eq!(Some(Units::Pixels(20.0)), parent_directed.left(&nodes));
+        assert_eq!(Some(Units::Pixels(10.0)), parent_directed.top(&nodes));
+        assert_eq!(Some(Units::Pixels(5.0)), self_directed.left(&nodes));
+        assert_eq!(Some(Units::Pixels(5.0)), self_directed.top(&nodes));
+    }
+
+    #[test]
+    fn border_should_inset_content_box() {
+        let mut nodes = Arena::new();
+        let parent = nodes.insert(None);
+        let child = nodes.insert(None);
+
+        let parent_styles = Style {
+            border: StyleProp::Value(Edge::all(Units::Pixels(2.0))),
+            height: StyleProp::Value(Units::Pixels(100.0)),
+            width: StyleProp::Value(Units::Pixels(100.0)),
+            ..Style::initial()
+        };
+        let child_styles = Style {
+            height: StyleProp::Value(Units::Stretch(1.0)),
+            width: StyleProp::Value(Units::Stretch(1.0)),
+            ..Style::initial()
+        };
+        nodes[parent] = Some(
+            NodeBuilder::new(parent, parent_styles)
+                .with_children(vec![child])
+                .build(),
+        );
+        nodes[child] = Some(NodeBuilder::new(child, child_styles).build());
+
+        let mut tree = Tree::default();
+        tree.root_node = Some(parent);
+        tree.parents.insert(child, parent);
+        tree.children.insert(parent, vec![child]);
+
+        let mut layout_cache = LayoutCache::default();
+        layout_cache.add(parent);
+        layout_cache.add(child);
+        morphorm::layout(&mut layout_cache, &tree, &nodes);
+
+        let rect = layout_cache.rect.get(&child).unwrap();
+        assert_eq!(2.0, rect.posx);
+        assert_eq!(2.0, rect.posy);
+        assert_eq!(96.0, rect.width);
+        assert_eq!(96.0, rect.height);
+    }
+}
@@ -0,0 +1,62 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, RwLock};
+
+/// A container for a function that handles changes to a value
+///
+/// This is the standard callback used by value-producing widgets (such as text inputs and
+/// pickers) to report a new value. It differs from [`OnEvent`](crate::OnEvent) in that it's
+/// given the new value directly, rather than the raw event that caused the change.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_core::OnChange;
+///
+/// let on_change = OnChange::new(|value: f32| {
+///   println!("Changed to: {}", value);
+/// });
+///
+/// on_change.call(0.5);
+/// ```
+#[derive(Clone)]
+pub struct OnChange<T>(Arc<RwLock<dyn FnMut(T) + Send + Sync + 'static>>);
+
+impl<T> OnChange<T> {
+    /// Create a new change handler
+    ///
+    /// The handler should be a closure that takes the new value as its only argument.
+    pub fn new<F: FnMut(T) + Send + Sync + 'static>(f: F) -> OnChange<T> {
+        OnChange(Arc::new(RwLock::new(f)))
+    }
+
+    /// Call the change handler with the new value
+    ///
+    /// Returns true if the handler was successfully invoked.
+    pub fn call(&self, value: T) -> bool {
+        if let Ok(mut on_change) = self.0.write() {
+            on_change(value);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<T> Default for OnChange<T> {
+    fn default() -> Self {
+        Self::new(|_| {})
+    }
+}
+
+impl<T> Debug for OnChange<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnChange").finish()
+    }
+}
+
+impl<T> PartialEq for OnChange<T> {
+    fn eq(&self, _: &Self) -> bool {
+        // Never prevent "==" for being true because of this struct
+        true
+    }
+}
@@ -35,6 +35,14 @@ impl OnEvent {
             false
         }
     }
+
+    /// Returns true if both handlers hold the same function
+    ///
+    /// Handlers always compare as equal (so they never cause props to differ), which makes this
+    /// useful for telling whether a handler has actually been replaced.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::as_ptr(&self.0) as *const () == Arc::as_ptr(&other.0) as *const ()
+    }
 }
 
 impl Debug for OnEvent {
@@ -49,3 +57,19 @@ impl PartialEq for OnEvent {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::OnEvent;
+
+    #[test]
+    fn should_compare_handlers_by_identity() {
+        let handler = OnEvent::new(|_, _| {});
+        let other = OnEvent::new(|_, _| {});
+
+        assert!(handler.ptr_eq(&handler.clone()));
+        assert!(!handler.ptr_eq(&other));
+        // Equality alone can't tell them apart
+        assert_eq!(handler, other);
+    }
+}
@@ -5,6 +5,9 @@ use std::sync::{Arc, RwLock};
 
 /// A container for a function that handles layout
 ///
+/// The handler is called after layout whenever the computed rect of its widget changes,
+/// making it possible to position other elements relative to the widget's actual geometry.
+///
 /// This differs from a standard [`Handler`](crate::Handler) in that it's sent directly
 /// from the [`KayakContext`](crate::KayakContext) and gives the [`KayakContextRef`]
 /// as a parameter.
@@ -0,0 +1,99 @@
+/// The platform an app is running on
+///
+/// This is determined at compile time from the target, and is available to widgets through
+/// [`KayakContextRef::platform`](crate::KayakContextRef::platform). Combined with an `If` widget,
+/// it lets a single widget adapt to each platform without sprinkling `#[cfg]` attributes
+/// throughout the app, such as to hide a "Quit" button on the web.
+///
+/// # Examples
+///
+/// ```
+/// use kayak_core::Platform;
+///
+/// let can_quit = !Platform::current().is_web();
+/// # let _ = can_quit;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    /// Windows
+    Windows,
+    /// macOS
+    MacOs,
+    /// Linux and other Unix-like desktops, such as the BSDs
+    Linux,
+    /// A web browser, using WebAssembly
+    Web,
+    /// Android
+    Android,
+    /// iOS
+    Ios,
+    /// Any other platform
+    Other,
+}
+
+impl Platform {
+    /// Returns the platform the app was compiled for
+    pub const fn current() -> Self {
+        if cfg!(target_arch = "wasm32") {
+            Self::Web
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else if cfg!(target_os = "macos") {
+            Self::MacOs
+        } else if cfg!(target_os = "android") {
+            Self::Android
+        } else if cfg!(target_os = "ios") {
+            Self::Ios
+        } else if cfg!(any(
+            target_os = "linux",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        )) {
+            Self::Linux
+        } else {
+            Self::Other
+        }
+    }
+
+    /// Returns true for desktop platforms (Windows, macOS, and Linux)
+    pub const fn is_desktop(&self) -> bool {
+        matches!(self, Self::Windows | Self::MacOs | Self::Linux)
+    }
+
+    /// Returns true for mobile platforms (Android and iOS)
+    pub const fn is_mobile(&self) -> bool {
+        matches!(self, Self::Android | Self::Ios)
+    }
+
+    /// Returns true for the web
+    pub const fn is_web(&self) -> bool {
+        matches!(self, Self::Web)
+    }
+}
+
+impl Default for Platform {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Platform;
+
+    #[test]
+    fn should_detect_the_compiled_platform() {
+        let platform = Platform::current();
+        assert_eq!(cfg!(target_arch = "wasm32"), platform.is_web());
+        assert_eq!(cfg!(target_os = "linux"), platform == Platform::Linux);
+        // Every platform falls into at most one group
+        let groups = [
+            platform.is_desktop(),
+            platform.is_mobile(),
+            platform.is_web(),
+        ];
+        assert!(groups.iter().filter(|is_in_group| **is_in_group).count() <= 1);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::styles::Edge;
+use std::ops::Range;
+
+use crate::{color::Color, styles::Edge};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum RenderCommand {
@@ -9,9 +11,16 @@ pub enum RenderCommand {
     Quad,
     Text {
         content: String,
+        /// The ranges of chars (not bytes) to draw a background color behind
+        highlights: Vec<(Range<usize>, Color)>,
     },
     Image {
         handle: u16,
+        /// The region of the image to display, as normalized `(min_x, min_y, max_x, max_y)`
+        /// coordinates from its top-left corner
+        ///
+        /// The full image is displayed if this is `None`.
+        uv: Option<(f32, f32, f32, f32)>,
     },
     NinePatch {
         border: Edge<f32>,
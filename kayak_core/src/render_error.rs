@@ -0,0 +1,75 @@
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+
+use crate::Index;
+
+/// A panic caught while rendering a widget
+///
+/// This is passed to the nearest error boundary above the widget that panicked, which is set using
+/// [`KayakContextRef::set_error_boundary`](crate::KayakContextRef::set_error_boundary).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderError {
+    /// The ID of the widget that panicked
+    pub widget: Index,
+    /// The name of the widget that panicked
+    pub name: String,
+    /// The message the widget panicked with
+    ///
+    /// This is only available when the panic was given a string (such as with `panic!` or
+    /// `unwrap`), and is empty otherwise.
+    pub message: String,
+}
+
+impl Display for RenderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.message.is_empty() {
+            write!(f, "{} panicked while rendering", self.name)
+        } else {
+            write!(
+                f,
+                "{} panicked while rendering: {}",
+                self.name, self.message
+            )
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+/// A panic caught while rendering a widget, which is being passed up to an error boundary
+///
+/// This is the payload the panic is resumed with between the renders of nested widgets, so the
+/// error keeps the widget that actually panicked. The original payload is resumed if no error
+/// boundary is found.
+pub(crate) struct CaughtPanic {
+    pub error: RenderError,
+    pub payload: Box<dyn Any + Send>,
+}
+
+impl CaughtPanic {
+    /// Wraps the payload of a panic caught while rendering the given widget
+    ///
+    /// If the panic was already caught while rendering one of its descendants, that is kept.
+    pub fn new(widget: Index, name: &str, payload: Box<dyn Any + Send>) -> Self {
+        match payload.downcast::<CaughtPanic>() {
+            Ok(caught) => *caught,
+            Err(payload) => {
+                let message = if let Some(message) = payload.downcast_ref::<&str>() {
+                    message.to_string()
+                } else if let Some(message) = payload.downcast_ref::<String>() {
+                    message.clone()
+                } else {
+                    String::new()
+                };
+                Self {
+                    error: RenderError {
+                        widget,
+                        name: name.to_string(),
+                        message,
+                    },
+                    payload,
+                }
+            }
+        }
+    }
+}
@@ -2,9 +2,10 @@ use crate::{
     color::Color,
     layout_cache::Rect,
     render_command::RenderCommand,
-    styles::{Corner, Edge, Style},
+    styles::{Corner, Edge, SliceCenter, Style, StyleProp, TransformMatrix},
 };
 use kayak_font::{TextLayout, TextProperties};
+use std::ops::Range;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum RenderPrimitive {
@@ -23,6 +24,7 @@ pub enum RenderPrimitive {
         color: Color,
         content: String,
         font: String,
+        highlights: Vec<(Range<usize>, Color)>,
         text_layout: TextLayout,
         layout: Rect,
         properties: TextProperties,
@@ -31,11 +33,18 @@ pub enum RenderPrimitive {
         border_radius: Corner<f32>,
         layout: Rect,
         handle: u16,
+        uv: Option<(f32, f32, f32, f32)>,
     },
     NinePatch {
         border: Edge<f32>,
         layout: Rect,
         handle: u16,
+        center: SliceCenter,
+    },
+    /// Sets the transform applied to all following primitives (except clips), until the next
+    /// `Transform` primitive
+    Transform {
+        matrix: TransformMatrix,
     },
 }
 
@@ -68,6 +77,10 @@ impl From<&Style> for RenderPrimitive {
 
         let line_height = style.line_height.resolve_or(font_size * 1.2);
 
+        let letter_spacing = style.letter_spacing.resolve_or(0.0);
+
+        let shaping = style.font_shaping.resolve_or(true);
+
         match render_command {
             RenderCommand::Empty => Self::Empty,
             RenderCommand::Layout => Self::Empty,
@@ -78,30 +91,48 @@ impl From<&Style> for RenderPrimitive {
                 background_color,
                 border_color,
                 border_radius: style.border_radius.resolve(),
-                border: style.border.resolve(),
+                // Borders are resolved once the layout of this widget is known
+                border: Edge::default(),
                 layout: Rect::default(),
             },
-            RenderCommand::Text { content } => Self::Text {
+            RenderCommand::Text {
+                content,
+                highlights,
+            } => Self::Text {
                 color: style.color.resolve(),
                 content,
                 font,
+                highlights,
                 text_layout: TextLayout::default(),
                 layout: Rect::default(),
                 properties: TextProperties {
                     font_size,
                     line_height,
+                    letter_spacing,
+                    shaping,
                     ..Default::default()
                 },
             },
-            RenderCommand::Image { handle } => Self::Image {
-                border_radius: style.border_radius.resolve(),
-                layout: Rect::default(),
-                handle,
+            RenderCommand::Image { handle, uv } => match style.slice {
+                // Sliced images are rendered the same as a nine-patch
+                StyleProp::Value(border) => Self::NinePatch {
+                    border,
+                    layout: Rect::default(),
+                    handle,
+                    center: style.slice_center.resolve_or_default(),
+                },
+                _ => Self::Image {
+                    border_radius: style.border_radius.resolve(),
+                    layout: Rect::default(),
+                    handle,
+                    uv,
+                },
             },
             RenderCommand::NinePatch { handle, border } => Self::NinePatch {
                 border,
                 layout: Rect::default(),
                 handle,
+                center: style.slice_center.resolve_or_default(),
             },
         }
     }
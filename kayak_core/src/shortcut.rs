@@ -0,0 +1,176 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{Index, KeyCode, KeyboardEvent, KeyboardModifiers};
+
+/// A key combination that triggers an app-level action, such as Ctrl+S
+///
+/// Shortcuts are registered using [`KayakContextRef::register_shortcut`](crate::KayakContextRef::register_shortcut).
+/// A shortcut only matches a key press with exactly the same modifiers, so Ctrl+S does not match
+/// Ctrl+Shift+S.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_core::{KeyCode, Shortcut};
+///
+/// let save = Shortcut::ctrl('s');
+/// let save_as = Shortcut::ctrl('s').with_shift();
+/// let help = Shortcut::new(KeyCode::F1);
+///
+/// assert_eq!("Ctrl+Shift+S", save_as.to_string());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The key that triggers the shortcut
+    pub key: KeyCode,
+    /// The modifiers that must be held when the key is pressed
+    pub modifiers: KeyboardModifiers,
+}
+
+impl Shortcut {
+    /// Creates a shortcut for the given key, without any modifiers
+    pub fn new(key: KeyCode) -> Self {
+        Self {
+            key,
+            modifiers: KeyboardModifiers::default(),
+        }
+    }
+
+    /// Creates a shortcut for the given character while holding Control
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character has no matching key (see [`KeyCode::from_char`]).
+    pub fn ctrl(c: char) -> Self {
+        Self::from_char(c).with_ctrl()
+    }
+
+    /// Creates a shortcut for the given character while holding Alt
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character has no matching key (see [`KeyCode::from_char`]).
+    pub fn alt(c: char) -> Self {
+        Self::from_char(c).with_alt()
+    }
+
+    /// Creates a shortcut for the given character while holding Meta
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character has no matching key (see [`KeyCode::from_char`]).
+    pub fn meta(c: char) -> Self {
+        Self::from_char(c).with_meta()
+    }
+
+    /// Adds the Control modifier to this shortcut
+    pub fn with_ctrl(mut self) -> Self {
+        self.modifiers.is_ctrl_pressed = true;
+        self
+    }
+
+    /// Adds the Shift modifier to this shortcut
+    pub fn with_shift(mut self) -> Self {
+        self.modifiers.is_shift_pressed = true;
+        self
+    }
+
+    /// Adds the Alt modifier to this shortcut
+    pub fn with_alt(mut self) -> Self {
+        self.modifiers.is_alt_pressed = true;
+        self
+    }
+
+    /// Adds the Meta modifier to this shortcut
+    pub fn with_meta(mut self) -> Self {
+        self.modifiers.is_meta_pressed = true;
+        self
+    }
+
+    /// Returns true if the given key press triggers this shortcut
+    pub fn matches(&self, event: &KeyboardEvent) -> bool {
+        self.key == event.key() && self.modifiers == event.modifiers()
+    }
+
+    /// Returns true if this shortcut requires Control, Alt, or Meta to be held
+    ///
+    /// Widgets capturing key presses (such as text inputs) only receive shortcuts without these
+    /// modifiers.
+    pub fn is_command(&self) -> bool {
+        self.modifiers.is_ctrl_pressed
+            || self.modifiers.is_alt_pressed
+            || self.modifiers.is_meta_pressed
+    }
+
+    fn from_char(c: char) -> Self {
+        let key =
+            KeyCode::from_char(c).unwrap_or_else(|| panic!("no key matches the character {:?}", c));
+        Self::new(key)
+    }
+}
+
+impl Display for Shortcut {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.modifiers.is_ctrl_pressed {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.is_alt_pressed {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.is_shift_pressed {
+            write!(f, "Shift+")?;
+        }
+        if self.modifiers.is_meta_pressed {
+            write!(f, "Meta+")?;
+        }
+        write!(f, "{:?}", self.key)
+    }
+}
+
+/// The error returned when registering a [`Shortcut`] that another widget has already registered
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ShortcutConflict {
+    /// The conflicting shortcut
+    pub shortcut: Shortcut,
+    /// The ID of the widget that registered the shortcut first
+    pub owner: Index,
+}
+
+impl Display for ShortcutConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the shortcut {} is already registered by widget {:?}",
+            self.shortcut, self.owner
+        )
+    }
+}
+
+impl std::error::Error for ShortcutConflict {}
+
+#[cfg(test)]
+mod tests {
+    use super::Shortcut;
+    use crate::{KeyCode, KeyboardEvent, KeyboardModifiers};
+
+    #[test]
+    fn should_match_exact_modifiers() {
+        let save = Shortcut::ctrl('S');
+        let ctrl = KeyboardModifiers {
+            is_ctrl_pressed: true,
+            ..Default::default()
+        };
+        let ctrl_shift = KeyboardModifiers {
+            is_shift_pressed: true,
+            ..ctrl
+        };
+
+        assert!(save.matches(&KeyboardEvent::new(KeyCode::S, ctrl)));
+        assert!(!save.matches(&KeyboardEvent::new(KeyCode::S, ctrl_shift)));
+        assert!(!save.matches(&KeyboardEvent::new(KeyCode::S, Default::default())));
+        assert!(save
+            .with_shift()
+            .matches(&KeyboardEvent::new(KeyCode::S, ctrl_shift)));
+        assert_eq!("Ctrl+S", save.to_string());
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt::{Display, Formatter};
+
+use crate::layout_cache::Rect;
+use crate::styles::Style;
+use crate::widget_manager::WidgetManager;
+use crate::Index;
+
+/// A snapshot of the computed layout of a widget and all of its descendants
+///
+/// Snapshots are taken using [`KayakContext::snapshot`](crate::KayakContext::snapshot) and don't
+/// require a window or renderer, making them useful for testing layouts. The [`Display`] output
+/// lists the name and rect of every widget, indented by depth, which makes it suitable for
+/// golden-file tests. Use the `Debug` output for a snapshot that also includes resolved styles.
+///
+/// # Examples
+///
+/// ```ignore
+/// let mut context = KayakContext::new();
+/// {
+///     let context = &mut context;
+///     render! {
+///         <Element styles={Some(root_styles)}>
+///             <Text content={"Hello".into()} />
+///         </Element>
+///     }
+/// }
+/// context.render();
+///
+/// let snapshot = context.snapshot().unwrap();
+/// let text = snapshot.find("Text").unwrap();
+/// assert_eq!(Some(0.0), text.rect.map(|rect| rect.posx));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutSnapshot {
+    /// The ID of the widget
+    pub id: Index,
+    /// The name of the widget
+    pub name: String,
+    /// The computed rect of the widget, if it has been laid out
+    pub rect: Option<Rect>,
+    /// The resolved styles of the widget, if it has been rendered
+    pub styles: Option<Style>,
+    /// The snapshots of the widget's children, in order
+    pub children: Vec<LayoutSnapshot>,
+}
+
+impl LayoutSnapshot {
+    /// Creates a snapshot of the given widget and its descendants
+    pub(crate) fn new(widget_manager: &WidgetManager, id: Index) -> Self {
+        let children = widget_manager
+            .tree
+            .children
+            .get(&id)
+            .map(|children| {
+                children
+                    .iter()
+                    .map(|child| Self::new(widget_manager, *child))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            id,
+            name: widget_manager.get_name(&id).unwrap_or_default(),
+            rect: widget_manager.get_layout(&id).copied(),
+            styles: widget_manager
+                .get_node(&id)
+                .map(|node| node.resolved_styles),
+            children,
+        }
+    }
+
+    /// Finds the first widget with the given name, searching depth-first (including this one)
+    pub fn find(&self, name: &str) -> Option<&LayoutSnapshot> {
+        self.iter().find(|snapshot| snapshot.name == name)
+    }
+
+    /// Finds the widget with the given ID within this snapshot (including this one)
+    pub fn find_by_id(&self, id: Index) -> Option<&LayoutSnapshot> {
+        self.iter().find(|snapshot| snapshot.id == id)
+    }
+
+    /// Returns an iterator over this snapshot and all of its descendants, depth-first
+    pub fn iter(&self) -> impl Iterator<Item = &LayoutSnapshot> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let current = stack.pop()?;
+            stack.extend(current.children.iter().rev());
+            Some(current)
+        })
+    }
+
+    fn fmt_depth(&self, f: &mut Formatter<'_>, depth: usize) -> std::fmt::Result {
+        write!(f, "{:indent$}{}", "", self.name, indent = depth * 2)?;
+        match self.rect {
+            Some(rect) => writeln!(
+                f,
+                " ({}, {}, {}, {})",
+                rect.posx, rect.posy, rect.width, rect.height
+            )?,
+            None => writeln!(f, " (no layout)")?,
+        }
+
+        for child in &self.children {
+            child.fmt_depth(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for LayoutSnapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.fmt_depth(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::render_command::RenderCommand;
+    use crate::styles::{LayoutType, Style, StyleProp, Units};
+    use crate::{Children, Fragment, FragmentProps, KayakContext, KayakContextRef, Widget};
+
+    fn build(context: &mut KayakContext) {
+        let child_styles = Style {
+            height: StyleProp::Value(Units::Pixels(20.0)),
+            width: StyleProp::Value(Units::Pixels(30.0)),
+            ..Default::default()
+        };
+        let root = Fragment::constructor(FragmentProps {
+            styles: Some(Style {
+                height: StyleProp::Value(Units::Pixels(50.0)),
+                layout_type: StyleProp::Value(LayoutType::Row),
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                width: StyleProp::Value(Units::Pixels(100.0)),
+                ..Default::default()
+            }),
+            children: Some(Children::new(move |_, context| {
+                for index in 0..2 {
+                    let child = Fragment::constructor(FragmentProps {
+                        styles: Some(child_styles.clone()),
+                        children: None,
+                    });
+                    context.add_widget(child, index);
+                }
+                context.commit();
+            })),
+        });
+
+        let mut context = KayakContextRef::new(context, None);
+        context.add_widget(root, 0);
+        context.commit();
+    }
+
+    #[test]
+    fn should_snapshot_headless_layout() {
+        let mut context = KayakContext::new();
+        build(&mut context);
+        context.render();
+
+        let snapshot = context.snapshot().expect("should have a root widget");
+        assert_eq!(2, snapshot.children.len());
+
+        let first = snapshot.children[0].rect.unwrap();
+        let second = snapshot.children[1].rect.unwrap();
+        assert_eq!((0.0, 30.0, 20.0), (first.posx, first.width, first.height));
+        assert_eq!(
+            (30.0, 30.0, 20.0),
+            (second.posx, second.width, second.height)
+        );
+
+        let resolved = snapshot.children[0].styles.as_ref().unwrap();
+        assert_eq!(StyleProp::Value(Units::Pixels(30.0)), resolved.width);
+
+        let expected = format!(
+            "{name} (0, 0, 100, 50)\n  {name} (0, 0, 30, 20)\n  {name} (30, 0, 30, 20)\n",
+            name = snapshot.name
+        );
+        assert_eq!(expected, snapshot.to_string());
+    }
+}
@@ -0,0 +1,98 @@
+//! Shorthand builder methods for commonly used style properties
+//!
+//! Every property of [`Style`] also has a builder method of the same name, which accepts
+//! either the property's value or a [`StyleProp`](super::StyleProp).
+
+use super::{Corner, Edge, Style, Units};
+use crate::color::Color;
+
+impl Style {
+    /// Creates a new `Style` with all properties set to [`StyleProp::Unset`](super::StyleProp::Unset)
+    ///
+    /// This is the same as `Style::default`, but reads better at the start of a builder chain:
+    ///
+    /// ```
+    /// # use kayak_core::{Color, styles::Style};
+    /// let style = Style::new()
+    ///     .width_px(150.0)
+    ///     .bg(Color::BLACK)
+    ///     .padding_px(5.0, 10.0);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`background_color`](Self::background_color) property
+    pub fn bg(self, color: Color) -> Self {
+        self.background_color(color)
+    }
+
+    /// Sets the [`width`](Self::width) property in pixels
+    pub fn width_px(self, width: f32) -> Self {
+        self.width(Units::Pixels(width))
+    }
+
+    /// Sets the [`width`](Self::width) property as a percentage of the parent's width
+    pub fn width_pct(self, width: f32) -> Self {
+        self.width(Units::Percentage(width))
+    }
+
+    /// Sets the [`width`](Self::width) property to stretch by the given factor
+    pub fn width_stretch(self, factor: f32) -> Self {
+        self.width(Units::Stretch(factor))
+    }
+
+    /// Sets the [`height`](Self::height) property in pixels
+    pub fn height_px(self, height: f32) -> Self {
+        self.height(Units::Pixels(height))
+    }
+
+    /// Sets the [`height`](Self::height) property as a percentage of the parent's height
+    pub fn height_pct(self, height: f32) -> Self {
+        self.height(Units::Percentage(height))
+    }
+
+    /// Sets the [`height`](Self::height) property to stretch by the given factor
+    pub fn height_stretch(self, factor: f32) -> Self {
+        self.height(Units::Stretch(factor))
+    }
+
+    /// Sets the [`width`](Self::width) and [`height`](Self::height) properties in pixels
+    pub fn size_px(self, width: f32, height: f32) -> Self {
+        self.width_px(width).height_px(height)
+    }
+
+    /// Sets the [`padding`](Self::padding) property in pixels
+    ///
+    /// Like [`Edge::axis`], this takes the padding of the vertical edges followed by the
+    /// padding of the horizontal edges.
+    pub fn padding_px(self, vertical: f32, horizontal: f32) -> Self {
+        self.padding(Edge::axis(
+            Units::Pixels(vertical),
+            Units::Pixels(horizontal),
+        ))
+    }
+
+    /// Sets the [`margin`](Self::margin) property in pixels
+    ///
+    /// Like [`Edge::axis`], this takes the margin of the vertical edges followed by the
+    /// margin of the horizontal edges.
+    pub fn margin_px(self, vertical: f32, horizontal: f32) -> Self {
+        self.margin(Edge::axis(
+            Units::Pixels(vertical),
+            Units::Pixels(horizontal),
+        ))
+    }
+
+    /// Sets the [`border`](Self::border) property to the same width (in pixels) on all edges
+    /// along with the [`border_color`](Self::border_color)
+    pub fn border_px(self, width: f32, color: Color) -> Self {
+        self.border(Edge::all(Units::Pixels(width)))
+            .border_color(color)
+    }
+
+    /// Sets the [`border_radius`](Self::border_radius) property to the same radius on all corners
+    pub fn radius(self, radius: f32) -> Self {
+        self.border_radius(Corner::all(radius))
+    }
+}
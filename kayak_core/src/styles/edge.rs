@@ -1,3 +1,4 @@
+use morphorm::Units;
 use std::ops::{Mul, MulAssign};
 
 /// A struct for defining properties related to the edges of widgets
@@ -77,6 +78,29 @@ where
     }
 }
 
+impl Edge<Units> {
+    /// Resolves these edges into pixel values for a widget of the given size
+    ///
+    /// Percentages are relative to the `width` for the left and right edges, and relative to the
+    /// `height` for the top and bottom edges. Stretched and auto edges resolve to zero.
+    pub fn resolve_pixels(&self, width: f32, height: f32) -> Edge<f32> {
+        fn resolve(units: Units, size: f32) -> f32 {
+            match units {
+                Units::Pixels(pixels) => pixels,
+                Units::Percentage(percentage) => percentage / 100.0 * size,
+                Units::Stretch(..) | Units::Auto => 0.0,
+            }
+        }
+
+        Edge {
+            top: resolve(self.top, height),
+            right: resolve(self.right, width),
+            bottom: resolve(self.bottom, height),
+            left: resolve(self.left, width),
+        }
+    }
+}
+
 impl<T> From<Edge<T>> for (T, T, T, T)
 where
     T: Copy + Default + PartialEq,
@@ -172,6 +196,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::Edge;
+    use morphorm::Units;
 
     #[test]
     fn tuples_should_convert_to_edge() {
@@ -192,6 +217,18 @@ mod tests {
         assert_eq!(expected, edge.into_tuple());
     }
 
+    #[test]
+    fn units_should_resolve_to_pixels() {
+        let edge = Edge::new(
+            Units::Pixels(2.0),
+            Units::Percentage(10.0),
+            Units::Percentage(50.0),
+            Units::Auto,
+        );
+        let expected = (2.0, 20.0, 25.0, 0.0);
+        assert_eq!(expected, edge.resolve_pixels(200.0, 50.0).into_tuple());
+    }
+
     #[test]
     fn multiplication_should_work_on_edges() {
         let expected = (10.0, 20.0, 30.0, 40.0);
@@ -0,0 +1,134 @@
+/// Controls whether children of a widget are allowed to flow onto multiple lines
+///
+/// This currently only applies to widgets with a [`LayoutType::Row`](crate::styles::LayoutType::Row) layout.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LayoutWrap {
+    /// Children are placed on a single line, regardless of the available space
+    NoWrap,
+    /// Children that would overflow the widget's content box are moved onto a new line
+    ///
+    /// Lines are separated by the widget's [`row_between`](crate::styles::Style::row_between) spacing,
+    /// while children within a line are separated by its [`col_between`](crate::styles::Style::col_between)
+    /// spacing. If the widget's height is [`Units::Auto`](crate::styles::Units::Auto), it will grow to fit
+    /// all of the resulting lines.
+    Wrap,
+}
+
+impl Default for LayoutWrap {
+    fn default() -> Self {
+        Self::NoWrap
+    }
+}
+
+/// Controls how the children of a widget are aligned along its cross axis
+///
+/// The cross axis is vertical for widgets with a [`LayoutType::Row`](crate::styles::LayoutType::Row)
+/// layout and horizontal for those with a [`LayoutType::Column`](crate::styles::LayoutType::Column)
+/// layout. Other layouts are not affected.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AlignItems {
+    /// Children are positioned by their own styles along the cross axis (such as `top` and
+    /// `bottom` within a row)
+    Start,
+    /// Children are centered within the content box of the widget along the cross axis
+    ///
+    /// Within a wrapping row, children are centered within their line instead.
+    Center,
+    /// Children are moved to the far edge of the content box of the widget along the cross axis
+    ///
+    /// Within a wrapping row, children are moved to the bottom of their line instead.
+    End,
+    /// Children are moved down so that their baselines line up with the lowest baseline among them
+    ///
+    /// The baseline of text is that of its first line. Other widgets use the baseline they report
+    /// (see [`KayakContextRef::set_baseline`](crate::KayakContextRef::set_baseline)), falling back to
+    /// the baseline of their first child that has one. Children without a baseline are left in place.
+    ///
+    /// Since children are only ever moved down, they may extend past the bottom of a row that's
+    /// sized to fit them.
+    ///
+    /// This only applies to rows.
+    Baseline,
+}
+
+impl Default for AlignItems {
+    fn default() -> Self {
+        Self::Start
+    }
+}
+
+/// Controls how the children of a widget are distributed along its main axis
+///
+/// The main axis is horizontal for widgets with a [`LayoutType::Row`](crate::styles::LayoutType::Row)
+/// layout and vertical for those with a [`LayoutType::Column`](crate::styles::LayoutType::Column)
+/// layout. Other layouts are not affected.
+///
+/// Only the space left over once the children have been laid out is distributed, so children with
+/// stretched sizes or spacing (which take up all of the remaining space) are unaffected. Within a
+/// wrapping row, the space left on each line is distributed separately.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum JustifyContent {
+    /// Children are placed at the start of the main axis
+    Start,
+    /// Children are centered along the main axis
+    Center,
+    /// Children are placed at the end of the main axis
+    End,
+    /// The first and last children are placed at either end of the main axis, with the remaining
+    /// space divided evenly between each child
+    ///
+    /// A single child is placed at the start.
+    SpaceBetween,
+    /// The remaining space is divided evenly around each child, so the space at either end is half
+    /// the space between children
+    SpaceAround,
+}
+
+impl Default for JustifyContent {
+    fn default() -> Self {
+        Self::Start
+    }
+}
+
+/// The direction in which the content of a widget flows horizontally
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    /// Content flows from left to right
+    Ltr,
+    /// Content flows from right to left
+    ///
+    /// The layout of the widget's children is mirrored horizontally, so rows are laid out starting
+    /// from the right and the left and right edges (such as `left`, `right`, `padding_left`, and
+    /// `padding_right`) swap sides. Text is aligned to the right edge.
+    Rtl,
+}
+
+impl Default for Direction {
+    fn default() -> Self {
+        Self::Ltr
+    }
+}
+
+/// Controls whether a widget is displayed, while keeping it mounted either way
+///
+/// Unlike removing a widget (such as with an `If` widget), hiding it this way keeps it and its
+/// descendants alive, along with their state, so they appear unchanged once displayed again.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Display {
+    /// The widget is laid out and drawn as usual
+    Visible,
+    /// The widget still takes up space in the layout, but it and its descendants aren't drawn
+    ///
+    /// The hidden widgets can't be hovered, clicked, or focused.
+    Hidden,
+    /// The widget and its descendants are removed from the layout, as if they weren't there
+    ///
+    /// Like [`Hidden`](Self::Hidden), the widgets can't be hovered, clicked, or focused.
+    None,
+}
+
+impl Default for Display {
+    fn default() -> Self {
+        Self::Visible
+    }
+}
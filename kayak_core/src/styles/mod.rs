@@ -1,12 +1,23 @@
 //! Contains code related to the styling of widgets
 
+mod builder;
 mod corner;
 mod edge;
+mod layout;
 mod option_ref;
+mod overflow;
+mod responsive;
+mod slice;
+mod transform;
 
 pub use corner::Corner;
 pub use edge::Edge;
+pub use layout::{AlignItems, Direction, Display, JustifyContent, LayoutWrap};
 pub use morphorm::{LayoutType, PositionType, Units};
+pub use overflow::Overflow;
+pub use responsive::ResponsiveStyle;
+pub use slice::SliceCenter;
+pub use transform::{Transform2D, TransformMatrix};
 
 use crate::cursor::PointerEvents;
 use crate::{color::Color, render_command::RenderCommand, CursorIcon};
@@ -186,6 +197,16 @@ macro_rules! define_styles {
                 self.apply(other);
                 self
             }
+
+            $(
+                #[doc = concat!("Sets the [`", stringify!($field), "`](Self::", stringify!($field), ") property and returns the updated style")]
+                ///
+                /// This accepts either a [`StyleProp`] or the property's value directly.
+                pub fn $field<T: Into<$field_type>>(mut self, value: T) -> Self {
+                    self.$field = value.into();
+                    self
+                }
+            )*
         }
     };
 }
@@ -229,6 +250,11 @@ define_styles! {
     /// ```
     #[derive(Debug, Default, Clone, PartialEq)]
     pub struct Style {
+        /// The alignment of the children of this widget along its cross axis
+        ///
+        /// Only applies to widgets with a [`layout_type`](Self::layout_type) of [`LayoutType::Row`]
+        /// or [`LayoutType::Column`]
+        pub align_items: StyleProp<AlignItems>,
         /// The background color of this widget
         ///
         /// Only applies to widgets marked [`RenderCommand::Quad`]
@@ -245,14 +271,25 @@ define_styles! {
         ///
         /// Only applies to widgets marked [`RenderCommand::Quad`] and [`RenderCommand::Image`]
         pub border_radius: StyleProp<Corner<f32>>,
-        /// The widths of the borders (in pixels)
+        /// The widths of the borders
         ///
-        /// The order is (Top, Right, Bottom, Left).
+        /// The order is (Top, Right, Bottom, Left). Percentages are relative to the width (for the
+        /// left and right borders) or height (for the top and bottom borders) of this widget.
         ///
-        /// Only applies to widgets marked [`RenderCommand::Quad`]
-        pub border: StyleProp<Edge<f32>>,
+        /// Borders inset the content box of a widget, so children are laid out inside them. They are
+        /// only rendered for widgets marked [`RenderCommand::Quad`], where they follow the
+        /// [`border_radius`](Self::border_radius).
+        pub border: StyleProp<Edge<Units>>,
         /// The distance between the bottom edge of this widget and the bottom edge of its containing widget
         pub bottom: StyleProp<Units>,
+        /// The distances (in pixels) to move each edge of the clip region of this widget inwards
+        ///
+        /// The order is (Top, Right, Bottom, Left). Negative values expand the clip region beyond the
+        /// layout of the widget, allowing decorations such as focus rings to overflow it slightly.
+        ///
+        /// Only applies to widgets marked [`RenderCommand::Clip`] and those whose content is clipped by
+        /// their [`overflow`](Self::overflow)
+        pub clip_inset: StyleProp<Edge<f32>>,
         /// The text color for this widget
         ///
         /// This property defaults to [`StyleProp::Inherit`] meaning that setting this field to some value will
@@ -264,24 +301,76 @@ define_styles! {
         pub col_between: StyleProp<Units>,
         /// The cursor icon to display when hovering this widget
         pub cursor: StyleProp<CursorIcon>,
+        /// The horizontal direction in which the content of this widget flows
+        ///
+        /// This property defaults to [`StyleProp::Inherit`] meaning that setting this field to some value will
+        /// cause all descendents to receive that value, up to the next set value.
+        ///
+        /// See [`Direction::Rtl`] for how right-to-left content is laid out.
+        pub direction: StyleProp<Direction>,
+        /// Controls whether this widget and its descendants are displayed
+        ///
+        /// Hidden widgets stay mounted, keeping their state. See [`Display`] for how each value
+        /// affects layout.
+        pub display: StyleProp<Display>,
         /// The font name for this widget
         ///
         /// Only applies to [`RenderCommand::Text`]
         pub font: StyleProp<String>,
+        /// Whether kerning and common ligatures are applied to the text of this widget
+        ///
+        /// This is enabled by default, but can be disabled for monospace or code contexts where each
+        /// character should keep its own advance.
+        ///
+        /// Only applies to [`RenderCommand::Text`]
+        pub font_shaping: StyleProp<bool>,
         /// The font size for this widget, in pixels
         ///
         /// Only applies to [`RenderCommand::Text`]
         pub font_size: StyleProp<f32>,
         /// The height of this widget
         pub height: StyleProp<Units>,
+        /// The distribution of the children of this widget along its main axis
+        ///
+        /// Only applies to widgets with a [`layout_type`](Self::layout_type) of [`LayoutType::Row`]
+        /// or [`LayoutType::Column`]
+        pub justify_content: StyleProp<JustifyContent>,
         /// The layout method for children of this widget
         pub layout_type: StyleProp<LayoutType>,
+        /// Controls whether the children of this widget may wrap onto multiple lines
+        ///
+        /// Only applies to widgets with a [`layout_type`](Self::layout_type) of [`LayoutType::Row`]
+        pub layout_wrap: StyleProp<LayoutWrap>,
         /// The distance between the left edge of this widget and the left edge of its containing widget
         pub left: StyleProp<Units>,
+        /// Extra space added after each character of text in this widget, in pixels
+        ///
+        /// Only applies to [`RenderCommand::Text`]
+        pub letter_spacing: StyleProp<f32>,
         /// The line height for this widget, in pixels
         ///
         /// Only applies to [`RenderCommand::Text`]
         pub line_height: StyleProp<f32>,
+        /// The outer spacing between the edges of this widget and its siblings (or containing widget)
+        ///
+        /// This property has lower precedence than its more specific counterparts
+        /// ([`margin_top`](Self::margin_top), [`margin_right`](Self::margin_right),
+        /// [`margin_bottom`](Self::margin_bottom), and [`margin_left`](Self::margin_left)), allowing it
+        /// to be overridden. It takes precedence over [`offset`](Self::offset), but is itself overridden
+        /// by the positional properties ([`top`](Self::top), [`right`](Self::right), [`bottom`](Self::bottom),
+        /// and [`left`](Self::left)).
+        ///
+        /// Only applies to widgets with a [`position_type`](Self::position_type) of
+        /// [`PositionType::ParentDirected`].
+        pub margin: StyleProp<Edge<Units>>,
+        /// The outer spacing below this widget
+        pub margin_bottom: StyleProp<Units>,
+        /// The outer spacing to the left of this widget
+        pub margin_left: StyleProp<Units>,
+        /// The outer spacing to the right of this widget
+        pub margin_right: StyleProp<Units>,
+        /// The outer spacing above this widget
+        pub margin_top: StyleProp<Units>,
         /// The maximum height of this widget
         pub max_height: StyleProp<Units>,
         /// The maximum width of this widget
@@ -300,6 +389,25 @@ define_styles! {
         /// this acts like margin around the widget. For [`PositionType`](PositionType::SelfDirected) this
         /// acts as the actual position from the parent.
         pub offset: StyleProp<Edge<Units>>,
+        /// The opacity of this widget and all of its descendants, from `0.0` (fully transparent) to
+        /// `1.0` (fully opaque)
+        ///
+        /// Opacity is multiplied with the opacity of the widget's ancestors and applied to the alpha of
+        /// its background, border, and text colors (including the images of emoji within the text).
+        /// Other images are not affected.
+        ///
+        /// Defaults to `1.0`.
+        pub opacity: StyleProp<f32>,
+        /// Controls how content extending beyond the bounds of this widget is displayed
+        ///
+        /// This property has lower precedence than its more specific counterparts
+        /// ([`overflow_x`](Self::overflow_x) and [`overflow_y`](Self::overflow_y)), allowing it
+        /// to be overridden.
+        pub overflow: StyleProp<Overflow>,
+        /// Controls how content extending beyond the left and right edges of this widget is displayed
+        pub overflow_x: StyleProp<Overflow>,
+        /// Controls how content extending beyond the top and bottom edges of this widget is displayed
+        pub overflow_y: StyleProp<Overflow>,
         /// The inner padding between the edges of this widget and its children
         ///
         /// This property has lower precedence than its more specific counterparts
@@ -309,6 +417,9 @@ define_styles! {
         ///
         /// A child with their own padding properties set to anything other than [`Units::Auto`] will
         /// override the padding set by this widget.
+        ///
+        /// Following the box model, percentages on any side are relative to the width of the
+        /// containing widget.
         pub padding: StyleProp<Edge<Units>>,
         /// The inner padding between the bottom edge of this widget and its children
         ///
@@ -345,8 +456,36 @@ define_styles! {
         pub right: StyleProp<Units>,
         /// The spacing between child widgets along the vertical axis
         pub row_between: StyleProp<Units>,
+        /// The size of each border of a nine-slice image (in pixels of the source image)
+        ///
+        /// The order is (Top, Right, Bottom, Left). When set, the corners of the image keep their
+        /// size while its edges are stretched along their length, so the image can be scaled to any
+        /// size without distorting its corners.
+        ///
+        /// Only applies to widgets marked [`RenderCommand::Image`]
+        pub slice: StyleProp<Edge<f32>>,
+        /// Controls how the center of a nine-slice image fills the space between its borders
+        ///
+        /// Only applies to widgets with a [`slice`](Self::slice) and those marked
+        /// [`RenderCommand::NinePatch`]
+        pub slice_center: StyleProp<SliceCenter>,
         /// The distance between the top edge of this widget and the top edge of its containing widget
         pub top: StyleProp<Units>,
+        /// A translation, scale, and rotation applied when rendering this widget and its descendants
+        ///
+        /// Scaling and rotation happen around the [`transform_origin`](Self::transform_origin).
+        /// Transforms don't affect layout, so the space taken up by this widget is unchanged. They
+        /// are combined with the transforms of the widget's ancestors, and are also taken into
+        /// account when finding the widget under the cursor. Clip regions of transformed widgets
+        /// are expanded to the axis-aligned bounds of their transformed area.
+        pub transform: StyleProp<Transform2D>,
+        /// The point that this widget is scaled and rotated around by its [`transform`](Self::transform)
+        ///
+        /// The point is relative to the top-left corner of this widget, given as (x, y). Pixels
+        /// are an offset from the corner, while percentages are relative to the size of the
+        /// widget. Any other units (as well as leaving this unset) place the point at the center
+        /// along that axis.
+        pub transform_origin: StyleProp<(Units, Units)>,
         /// The width of this widget
         pub width: StyleProp<Units>,
     }
@@ -359,25 +498,42 @@ impl Style {
     /// resolving the style.
     pub fn initial() -> Self {
         Self {
+            align_items: StyleProp::Default,
             background_color: StyleProp::Default,
             border: StyleProp::Default,
             border_color: StyleProp::Default,
             border_radius: StyleProp::Default,
             bottom: StyleProp::Default,
+            clip_inset: StyleProp::Default,
             color: StyleProp::Inherit,
             cursor: StyleProp::Inherit,
             col_between: StyleProp::Default,
+            direction: StyleProp::Inherit,
+            display: StyleProp::Default,
             font: StyleProp::Inherit,
+            font_shaping: StyleProp::Inherit,
             font_size: StyleProp::Inherit,
             height: StyleProp::Default,
+            justify_content: StyleProp::Default,
             layout_type: StyleProp::Default,
+            layout_wrap: StyleProp::Default,
+            letter_spacing: StyleProp::Inherit,
             line_height: StyleProp::Inherit,
+            margin: StyleProp::Default,
+            margin_bottom: StyleProp::Default,
+            margin_left: StyleProp::Default,
+            margin_right: StyleProp::Default,
+            margin_top: StyleProp::Default,
             left: StyleProp::Default,
             max_height: StyleProp::Default,
             max_width: StyleProp::Default,
             min_height: StyleProp::Default,
             min_width: StyleProp::Default,
             offset: StyleProp::Default,
+            opacity: StyleProp::Default,
+            overflow: StyleProp::Default,
+            overflow_x: StyleProp::Default,
+            overflow_y: StyleProp::Default,
             padding: StyleProp::Default,
             padding_bottom: StyleProp::Default,
             padding_left: StyleProp::Default,
@@ -388,15 +544,32 @@ impl Style {
             render_command: StyleProp::Value(RenderCommand::Empty),
             right: StyleProp::Default,
             row_between: StyleProp::Default,
+            slice: StyleProp::Default,
+            slice_center: StyleProp::Default,
             top: StyleProp::Default,
+            transform: StyleProp::Default,
+            transform_origin: StyleProp::Default,
             width: StyleProp::Default,
         }
     }
+
+    /// Returns the resolved overflow of this widget along the x and y axes, respectively
+    ///
+    /// The axis-specific properties ([`overflow_x`](Self::overflow_x) and [`overflow_y`](Self::overflow_y))
+    /// take precedence over [`overflow`](Self::overflow).
+    pub fn resolved_overflow(&self) -> (Overflow, Overflow) {
+        let overflow = self.overflow.resolve_or_default();
+        (
+            self.overflow_x.resolve_or(overflow),
+            self.overflow_y.resolve_or(overflow),
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Edge, Style, StyleProp, Units};
+    use super::{Edge, Overflow, Style, StyleProp, Units};
+    use crate::color::Color;
 
     #[test]
     fn styles_should_equal() {
@@ -411,7 +584,12 @@ mod tests {
 
     #[test]
     fn style_should_inherit_property() {
-        let border = Edge::new(1.0, 2.0, 3.0, 4.0);
+        let border = Edge::new(
+            Units::Pixels(1.0),
+            Units::Pixels(2.0),
+            Units::Pixels(3.0),
+            Units::Pixels(4.0),
+        );
 
         let parent = Style {
             border: StyleProp::Value(border),
@@ -533,6 +711,48 @@ mod tests {
         assert_eq!(expected, style);
     }
 
+    #[test]
+    fn overflow_should_prefer_axis_specific_properties() {
+        let style = Style {
+            overflow: StyleProp::Value(Overflow::Hidden),
+            overflow_y: StyleProp::Value(Overflow::Scroll),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            (Overflow::Hidden, Overflow::Scroll),
+            style.resolved_overflow()
+        );
+        assert_eq!(
+            (Overflow::Visible, Overflow::Visible),
+            Style::default().resolved_overflow()
+        );
+    }
+
+    #[test]
+    fn styles_should_be_buildable_with_methods() {
+        let expected = Style {
+            background_color: StyleProp::Value(Color::WHITE),
+            height: StyleProp::Inherit,
+            padding: StyleProp::Value(Edge::axis(Units::Pixels(5.0), Units::Pixels(10.0))),
+            width: StyleProp::Value(Units::Pixels(150.0)),
+            ..Default::default()
+        };
+
+        let style = Style::new()
+            .width_px(150.0)
+            .height(StyleProp::Inherit)
+            .bg(Color::WHITE)
+            .padding_px(5.0, 10.0);
+
+        assert_eq!(expected, style);
+
+        // Builder methods should keep the existing merge semantics
+        let merged = Style::new().width_px(10.0).with_style(&style);
+        assert_eq!(StyleProp::Value(Units::Pixels(10.0)), merged.width);
+        assert_eq!(expected.background_color, merged.background_color);
+    }
+
     #[test]
     fn value_should_convert_to_property() {
         let expected_width = Units::Pixels(123.0);
@@ -0,0 +1,23 @@
+/// Controls how the content of a widget is displayed when it extends beyond the widget's bounds
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Overflow {
+    /// Content is not clipped and may be rendered outside the widget's bounds
+    Visible,
+    /// Content is clipped to the widget's bounds
+    ///
+    /// This behaves the same as [`RenderCommand::Clip`](crate::render_command::RenderCommand::Clip),
+    /// but can be applied to any widget.
+    Hidden,
+    /// Content is clipped to the widget's bounds and can be scrolled into view
+    ///
+    /// Scrolling is handled by container widgets (such as `Element` and `Background`), which place
+    /// their children in a `ScrollBox` when given this value. For all other widgets this behaves
+    /// like [`Overflow::Hidden`].
+    Scroll,
+}
+
+impl Default for Overflow {
+    fn default() -> Self {
+        Self::Visible
+    }
+}
@@ -0,0 +1,154 @@
+use super::Style;
+
+/// A set of styles that are selected based on an available width
+///
+/// A responsive style consists of a base style and any number of breakpoints. Each breakpoint has
+/// a minimum width (in pixels) and a style that's applied on top of the base style once that width
+/// is reached. When several breakpoints are reached, the ones with larger minimum widths take
+/// precedence.
+///
+/// The width is usually measured using the [`on_layout`](crate::OnLayout) handler of the
+/// container the styles should adapt to. Since layout events are sent whenever the container's
+/// rect changes, storing the measured width in state is enough to recompute the styles on resize.
+/// To adapt to the window instead, use the width from
+/// [`KayakContextRef::window_size`](crate::KayakContextRef::window_size).
+///
+/// Avoid measuring the same widget the resolved styles are applied to if those styles change
+/// its width, as this can cause it to alternate between breakpoints.
+///
+/// # Examples
+///
+/// ```
+/// use kayak_core::styles::{ResponsiveStyle, Style, StyleProp, Units};
+///
+/// let styles = ResponsiveStyle::new(Style {
+///     width: StyleProp::Value(Units::Stretch(1.0)),
+///     ..Default::default()
+/// })
+/// .with_breakpoint(
+///     600.0,
+///     Style {
+///         width: StyleProp::Value(Units::Percentage(50.0)),
+///         ..Default::default()
+///     },
+/// );
+///
+/// assert_eq!(StyleProp::Value(Units::Stretch(1.0)), styles.resolve(400.0).width);
+/// assert_eq!(StyleProp::Value(Units::Percentage(50.0)), styles.resolve(800.0).width);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ResponsiveStyle {
+    /// The style used regardless of the width
+    pub base: Style,
+    /// The minimum width (in pixels) and style of each breakpoint, sorted by width
+    breakpoints: Vec<(f32, Style)>,
+}
+
+impl ResponsiveStyle {
+    /// Creates a new `ResponsiveStyle` with the given base style and no breakpoints
+    pub fn new(base: Style) -> Self {
+        Self {
+            base,
+            breakpoints: Vec::new(),
+        }
+    }
+
+    /// Adds a breakpoint whose style is applied once the width reaches `min_width` (in pixels)
+    ///
+    /// Adding a breakpoint with the same minimum width as an existing one replaces it.
+    pub fn with_breakpoint(mut self, min_width: f32, style: Style) -> Self {
+        match self
+            .breakpoints
+            .binary_search_by(|(width, _)| width.total_cmp(&min_width))
+        {
+            Ok(index) => self.breakpoints[index].1 = style,
+            Err(index) => self.breakpoints.insert(index, (min_width, style)),
+        }
+        self
+    }
+
+    /// The minimum widths (in pixels) of the breakpoints, from smallest to largest
+    pub fn breakpoints(&self) -> impl Iterator<Item = f32> + '_ {
+        self.breakpoints.iter().map(|(width, _)| *width)
+    }
+
+    /// Returns the number of breakpoints that have been reached at the given width (in pixels)
+    ///
+    /// Two widths with the same breakpoint index resolve to the same style. This can be stored
+    /// instead of the width itself to avoid re-rendering on every resize.
+    pub fn breakpoint_index(&self, width: f32) -> usize {
+        self.breakpoints
+            .iter()
+            .take_while(|(min_width, _)| *min_width <= width)
+            .count()
+    }
+
+    /// Returns the style to use at the given width (in pixels)
+    pub fn resolve(&self, width: f32) -> Style {
+        self.resolve_index(self.breakpoint_index(width))
+    }
+
+    /// Returns the style to use once the given number of breakpoints have been reached
+    ///
+    /// See [`breakpoint_index`](Self::breakpoint_index).
+    pub fn resolve_index(&self, index: usize) -> Style {
+        let index = index.min(self.breakpoints.len());
+        // Styles applied first take precedence, so start with the largest breakpoint reached
+        self.breakpoints[..index]
+            .iter()
+            .rev()
+            .fold(Style::default(), |style, (_, breakpoint)| {
+                style.with_style(breakpoint)
+            })
+            .with_style(&self.base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponsiveStyle;
+    use crate::styles::{Style, StyleProp, Units};
+    use crate::Color;
+
+    fn width(value: f32) -> Style {
+        Style {
+            width: StyleProp::Value(Units::Pixels(value)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn should_select_the_largest_breakpoint_reached() {
+        let styles = ResponsiveStyle::new(Style {
+            background_color: StyleProp::Value(Color::WHITE),
+            ..width(100.0)
+        })
+        .with_breakpoint(800.0, width(300.0))
+        .with_breakpoint(400.0, width(200.0));
+
+        assert_eq!(vec![400.0, 800.0], styles.breakpoints().collect::<Vec<_>>());
+        assert_eq!(0, styles.breakpoint_index(399.0));
+        assert_eq!(1, styles.breakpoint_index(400.0));
+        assert_eq!(2, styles.breakpoint_index(1000.0));
+
+        assert_eq!(width(100.0).width, styles.resolve(0.0).width);
+        assert_eq!(width(200.0).width, styles.resolve(500.0).width);
+        assert_eq!(width(300.0).width, styles.resolve(800.0).width);
+        // Properties not set by a breakpoint come from the base style
+        assert_eq!(
+            StyleProp::Value(Color::WHITE),
+            styles.resolve(800.0).background_color
+        );
+    }
+
+    #[test]
+    fn should_replace_breakpoints_with_the_same_width() {
+        let styles = ResponsiveStyle::default()
+            .with_breakpoint(400.0, width(200.0))
+            .with_breakpoint(400.0, width(250.0));
+
+        assert_eq!(1, styles.breakpoints().count());
+        assert_eq!(width(250.0).width, styles.resolve(400.0).width);
+        assert_eq!(StyleProp::Unset, styles.resolve(0.0).width);
+    }
+}
@@ -0,0 +1,17 @@
+/// Controls how the center of a nine-slice image fills the space between its borders
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SliceCenter {
+    /// The center of the image is stretched to fill the space
+    Stretch,
+    /// The center of the image is repeated at its original size to fill the space
+    ///
+    /// Tiles along the right and bottom edges are cut off if the space isn't an exact multiple of
+    /// the size of the center.
+    Tile,
+}
+
+impl Default for SliceCenter {
+    fn default() -> Self {
+        Self::Stretch
+    }
+}
@@ -0,0 +1,205 @@
+use std::ops::Mul;
+
+use crate::layout_cache::Rect;
+
+/// A 2D transformation applied to the rendering of a widget and its descendants
+///
+/// The transformation is applied in the order: scale, rotate, then translate. Scaling and rotation
+/// happen around the [`transform_origin`](super::Style::transform_origin) of the widget, which is
+/// its center by default.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transform2D {
+    /// The offset along the x and y axes (in pixels)
+    pub translate: (f32, f32),
+    /// The scale factor along the x and y axes
+    pub scale: (f32, f32),
+    /// The clockwise rotation (in radians)
+    pub rotate: f32,
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self {
+            translate: (0.0, 0.0),
+            scale: (1.0, 1.0),
+            rotate: 0.0,
+        }
+    }
+}
+
+impl Transform2D {
+    /// Creates a new `Transform2D` that only translates by the given offset (in pixels)
+    pub fn from_translation(x: f32, y: f32) -> Self {
+        Self {
+            translate: (x, y),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new `Transform2D` that only scales by the given factors
+    pub fn from_scale(x: f32, y: f32) -> Self {
+        Self {
+            scale: (x, y),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new `Transform2D` that only rotates clockwise by the given angle (in radians)
+    pub fn from_rotation(angle: f32) -> Self {
+        Self {
+            rotate: angle,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the offset of this transform (in pixels)
+    pub fn with_translation(mut self, x: f32, y: f32) -> Self {
+        self.translate = (x, y);
+        self
+    }
+
+    /// Sets the scale factors of this transform
+    pub fn with_scale(mut self, x: f32, y: f32) -> Self {
+        self.scale = (x, y);
+        self
+    }
+
+    /// Sets the clockwise rotation of this transform (in radians)
+    pub fn with_rotation(mut self, angle: f32) -> Self {
+        self.rotate = angle;
+        self
+    }
+
+    /// Returns the matrix of this transform when scaling and rotating around the given point
+    pub fn to_matrix(&self, origin: (f32, f32)) -> TransformMatrix {
+        let (sin, cos) = self.rotate.sin_cos();
+        let (scale_x, scale_y) = self.scale;
+        let (a, b) = (cos * scale_x, sin * scale_x);
+        let (c, d) = (-sin * scale_y, cos * scale_y);
+        // Move the origin to (0, 0), scale and rotate, then move it back and translate
+        let (x, y) = origin;
+        TransformMatrix {
+            a,
+            b,
+            c,
+            d,
+            tx: x - (a * x + c * y) + self.translate.0,
+            ty: y - (b * x + d * y) + self.translate.1,
+        }
+    }
+}
+
+/// A 2D affine transformation matrix
+///
+/// A point `(x, y)` is mapped to `(a * x + c * y + tx, b * x + d * y + ty)`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TransformMatrix {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub tx: f32,
+    pub ty: f32,
+}
+
+impl Default for TransformMatrix {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl TransformMatrix {
+    /// The matrix that leaves every point unchanged
+    pub const IDENTITY: Self = Self {
+        a: 1.0,
+        b: 0.0,
+        c: 0.0,
+        d: 1.0,
+        tx: 0.0,
+        ty: 0.0,
+    };
+
+    /// Returns true if this matrix leaves every point unchanged
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Maps the given point using this matrix
+    pub fn transform_point(&self, point: (f32, f32)) -> (f32, f32) {
+        let (x, y) = point;
+        (
+            self.a * x + self.c * y + self.tx,
+            self.b * x + self.d * y + self.ty,
+        )
+    }
+
+    /// Returns the smallest axis-aligned rect containing the given rect once it's been transformed
+    pub fn transform_rect(&self, rect: Rect) -> Rect {
+        if self.is_identity() {
+            return rect;
+        }
+
+        let corners = [
+            (rect.posx, rect.posy),
+            (rect.posx + rect.width, rect.posy),
+            (rect.posx, rect.posy + rect.height),
+            (rect.posx + rect.width, rect.posy + rect.height),
+        ]
+        .map(|corner| self.transform_point(corner));
+        let (mut min_x, mut min_y) = corners[0];
+        let (mut max_x, mut max_y) = corners[0];
+        for (x, y) in corners {
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        }
+
+        Rect {
+            posx: min_x,
+            posy: min_y,
+            width: max_x - min_x,
+            height: max_y - min_y,
+            z_index: rect.z_index,
+        }
+    }
+
+    /// Returns the matrix that undoes this one, if any
+    ///
+    /// Returns `None` if this matrix collapses points onto a line (such as when scaling by zero).
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.a * self.d - self.b * self.c;
+        if determinant.abs() <= f32::EPSILON {
+            return None;
+        }
+
+        let a = self.d / determinant;
+        let b = -self.b / determinant;
+        let c = -self.c / determinant;
+        let d = self.a / determinant;
+        Some(Self {
+            a,
+            b,
+            c,
+            d,
+            tx: -(a * self.tx + c * self.ty),
+            ty: -(b * self.tx + d * self.ty),
+        })
+    }
+}
+
+impl Mul for TransformMatrix {
+    type Output = Self;
+
+    /// Combines two matrices, such that the right-hand matrix is applied first
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            a: self.a * rhs.a + self.c * rhs.b,
+            b: self.b * rhs.a + self.d * rhs.b,
+            c: self.a * rhs.c + self.c * rhs.d,
+            d: self.b * rhs.c + self.d * rhs.d,
+            tx: self.a * rhs.tx + self.c * rhs.ty + self.tx,
+            ty: self.b * rhs.tx + self.d * rhs.ty + self.ty,
+        }
+    }
+}
@@ -0,0 +1,440 @@
+use std::ops::Range;
+
+use kayak_font::{Alignment, KayakFont, TextLayout, TextProperties};
+
+use crate::layout_cache::Rect;
+
+/// The measured dimensions of a block of text
+///
+/// This is calculated using the same glyph metrics as [`RenderCommand::Text`](crate::render_command::RenderCommand::Text),
+/// so it can be used to size or position widgets around text before it is rendered.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TextMeasurement {
+    /// The width of the widest line (in pixels)
+    pub width: f32,
+    /// The total height of all lines (in pixels)
+    pub height: f32,
+    /// The measured lines, in order
+    pub lines: Vec<LineMeasurement>,
+}
+
+impl TextMeasurement {
+    /// The number of lines the text was wrapped onto
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// The width and height of the text (in pixels)
+    pub fn size(&self) -> (f32, f32) {
+        (self.width, self.height)
+    }
+
+    /// Returns the position of a caret placed before the char at the given index (in pixels)
+    ///
+    /// The position is the top-left corner of the caret, relative to the top-left of the text. An
+    /// index past the end of the text places the caret after the last char.
+    pub fn caret_position(&self, char_index: usize) -> (f32, f32) {
+        let line = self
+            .lines
+            .iter()
+            .rev()
+            .find(|line| line.chars.start <= char_index)
+            .or_else(|| self.lines.first());
+
+        match line {
+            Some(line) => {
+                let x = line
+                    .boundaries
+                    .iter()
+                    .rev()
+                    .find(|(index, _)| *index <= char_index)
+                    .map(|(_, x)| *x)
+                    .unwrap_or_default();
+                (x, line.y)
+            }
+            None => (0.0, 0.0),
+        }
+    }
+
+    /// Returns the char index of the grapheme boundary nearest to the given point (in pixels)
+    ///
+    /// The point is relative to the top-left of the text. Points above or below the text are
+    /// treated as being on the first or last line, respectively.
+    pub fn char_index_at(&self, x: f32, y: f32) -> usize {
+        let line_index = self
+            .lines
+            .iter()
+            .position(|line| y < line.y + line.height)
+            .unwrap_or_else(|| self.lines.len().saturating_sub(1));
+        let line = match self.lines.get(line_index) {
+            Some(line) => line,
+            None => return 0,
+        };
+
+        // The end of a wrapped line is the same index as the start of the next one, so it's only
+        // used for the last line
+        let boundaries = if line_index + 1 < self.lines.len() && line.boundaries.len() > 1 {
+            &line.boundaries[..line.boundaries.len() - 1]
+        } else {
+            &line.boundaries[..]
+        };
+
+        boundaries
+            .iter()
+            .min_by(|(_, a), (_, b)| (a - x).abs().total_cmp(&(b - x).abs()))
+            .map(|(index, _)| *index)
+            .unwrap_or(line.chars.start)
+    }
+}
+
+/// The measured dimensions of a single (possibly wrapped) line of text
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LineMeasurement {
+    /// The offset of the top of this line from the top of the text (in pixels)
+    pub y: f32,
+    /// The width of this line (in pixels)
+    pub width: f32,
+    /// The height of this line (in pixels)
+    pub height: f32,
+    /// The range of chars (not bytes) of the content within this line
+    pub chars: Range<usize>,
+    /// The char index and horizontal offset (in pixels) of each grapheme boundary within this line
+    ///
+    /// This contains the start of every grapheme on the line followed by the end of the line, so
+    /// it's never empty.
+    pub boundaries: Vec<(usize, f32)>,
+}
+
+/// Measures the given text content using a font size (in pixels) and an optional maximum width
+///
+/// The default line height of `1.2` times the font size is used, without any letter spacing. Use
+/// [`measure_text_with_properties`] to control these.
+///
+/// # Examples
+///
+/// ```ignore
+/// let measurement = measure_text("Hello world!", &font, 14.0, Some(100.0));
+/// println!("{} lines, {}x{}", measurement.line_count(), measurement.width, measurement.height);
+/// ```
+pub fn measure_text(
+    content: &str,
+    font: &KayakFont,
+    size: f32,
+    max_width: Option<f32>,
+) -> TextMeasurement {
+    let mut properties = TextProperties {
+        font_size: size,
+        line_height: size * 1.2,
+        ..Default::default()
+    };
+    if let Some(max_width) = max_width {
+        properties.max_size.0 = max_width;
+    }
+
+    measure_text_with_properties(content, font, properties)
+}
+
+/// Measures the given text content using the given properties
+///
+/// This respects the [`line_height`](TextProperties::line_height) and
+/// [`letter_spacing`](TextProperties::letter_spacing) of the properties. Lines are wrapped to the
+/// width of the [`max_size`](TextProperties::max_size). Kerning and ligatures are applied to the
+/// measured widths unless [`shaping`](TextProperties::shaping) is disabled.
+pub fn measure_text_with_properties(
+    content: &str,
+    font: &KayakFont,
+    properties: TextProperties,
+) -> TextMeasurement {
+    let layout = font.measure(content, properties);
+    let (width, height) = layout.size();
+
+    let lines = layout
+        .lines()
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let chars = line.char_index()..line.char_index() + line.total_chars();
+            let mut boundaries: Vec<_> = line
+                .graphemes()
+                .iter()
+                .map(|grapheme| (grapheme.char_index, grapheme.position.0))
+                .collect();
+            boundaries.push((chars.end, line.width()));
+
+            LineMeasurement {
+                y: properties.line_height * index as f32,
+                width: line.width(),
+                height: properties.line_height,
+                chars,
+                boundaries,
+            }
+        })
+        .collect();
+
+    TextMeasurement {
+        width,
+        height,
+        lines,
+    }
+}
+
+/// Returns the rects covering the given range of chars (not bytes) within a text layout
+///
+/// One rect is returned for each line the range overlaps, so a range that wraps onto multiple
+/// lines is covered by multiple rects. Each rect spans the full height of its line and is
+/// positioned relative to the top-left of the text. Ranges that fall between graphemes are
+/// extended to cover the graphemes they touch.
+pub fn text_range_rects(layout: &TextLayout, chars: Range<usize>) -> Vec<Rect> {
+    let properties = layout.properties();
+    layout
+        .lines()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let start = chars.start.max(line.char_index());
+            let end = chars.end.min(line.char_index() + line.total_chars());
+            if start >= end {
+                return None;
+            }
+
+            let graphemes = line.graphemes();
+            let start_x = graphemes
+                .iter()
+                .rev()
+                .find(|grapheme| grapheme.char_index <= start)
+                .map(|grapheme| grapheme.position.0)
+                .unwrap_or_default();
+            let end_x = graphemes
+                .iter()
+                .find(|grapheme| grapheme.char_index >= end)
+                .map(|grapheme| grapheme.position.0)
+                .unwrap_or_else(|| line.width());
+
+            // Grapheme positions don't include the offset used to align the line
+            let shift_x = match properties.alignment {
+                Alignment::Start => 0.0,
+                Alignment::Middle => (properties.max_size.0 - line.width()) / 2.0,
+                Alignment::End => properties.max_size.0 - line.width(),
+            };
+
+            Some(Rect {
+                posx: start_x + shift_x,
+                posy: properties.line_height * index as f32,
+                width: end_x - start_x,
+                height: properties.line_height,
+                z_index: 0.0,
+            })
+        })
+        .collect()
+}
+
+/// The kind of char used to find word boundaries
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CharClass {
+    Whitespace,
+    Punctuation,
+    Word,
+}
+
+impl CharClass {
+    fn of(c: char) -> Self {
+        if c.is_whitespace() {
+            Self::Whitespace
+        } else if c.is_alphanumeric() || c == '_' {
+            Self::Word
+        } else {
+            Self::Punctuation
+        }
+    }
+}
+
+/// Returns the char index of the end of the word at or after the given char index
+///
+/// Words are found by the transitions between kinds of chars:
+///
+/// * A run of letters, digits (from any script), or underscores forms a word
+/// * A run of any other non-whitespace chars (punctuation or symbols) also forms a word
+/// * Whitespace only separates words, so it's skipped before looking for the next word
+///
+/// This means `"foo.bar"` contains three words (`"foo"`, `"."`, and `"bar"`), while `"foo_bar"`
+/// is a single word.
+pub fn next_word_boundary(content: &str, char_index: usize) -> usize {
+    let classes: Vec<_> = content.chars().map(CharClass::of).collect();
+    let mut index = char_index.min(classes.len());
+    while index < classes.len() && classes[index] == CharClass::Whitespace {
+        index += 1;
+    }
+    if let Some(class) = classes.get(index).copied() {
+        while index < classes.len() && classes[index] == class {
+            index += 1;
+        }
+    }
+    index
+}
+
+/// Returns the char index of the start of the word before the given char index
+///
+/// This uses the same rules as [`next_word_boundary`], searching backwards.
+pub fn prev_word_boundary(content: &str, char_index: usize) -> usize {
+    let classes: Vec<_> = content.chars().map(CharClass::of).collect();
+    let mut index = char_index.min(classes.len());
+    while index > 0 && classes[index - 1] == CharClass::Whitespace {
+        index -= 1;
+    }
+    if index > 0 {
+        let class = classes[index - 1];
+        while index > 0 && classes[index - 1] == class {
+            index -= 1;
+        }
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        measure_text, measure_text_with_properties, next_word_boundary, prev_word_boundary,
+        text_range_rects,
+    };
+    use kayak_font::{KayakFont, Sdf, TextProperties};
+
+    fn make_font() -> KayakFont {
+        let bytes = std::fs::read("../assets/roboto.kayak_font")
+            .expect("a `roboto.kayak_font` file in the `assets/` directory of the workspace");
+
+        #[cfg(feature = "bevy_renderer")]
+        return KayakFont::new(Sdf::from_bytes(&bytes), bevy::asset::Handle::default());
+
+        #[cfg(not(feature = "bevy_renderer"))]
+        return KayakFont::new(Sdf::from_bytes(&bytes));
+    }
+
+    #[test]
+    fn should_measure_wrapped_lines() {
+        let font = make_font();
+        let content = "Hello world! How is everyone doing today?";
+        let single = measure_text(content, &font, 14.0, None);
+        assert_eq!(1, single.line_count());
+        assert_eq!(14.0 * 1.2, single.height);
+
+        let wrapped = measure_text(content, &font, 14.0, Some(single.width / 2.0));
+        assert!(wrapped.line_count() > 1);
+        assert_eq!(14.0 * 1.2 * wrapped.line_count() as f32, wrapped.height);
+        assert!(wrapped.width <= single.width / 2.0);
+
+        let mut next_char = 0;
+        for (index, line) in wrapped.lines.iter().enumerate() {
+            assert_eq!(14.0 * 1.2 * index as f32, line.y);
+            assert_eq!(next_char, line.chars.start);
+            next_char = line.chars.end;
+        }
+        assert_eq!(content.chars().count(), next_char);
+    }
+
+    #[test]
+    fn should_map_between_carets_and_positions() {
+        let font = make_font();
+        let content = "Hello world";
+        let measurement = measure_text(content, &font, 14.0, None);
+
+        assert_eq!((0.0, 0.0), measurement.caret_position(0));
+        let end = measurement.caret_position(content.chars().count());
+        assert_eq!((measurement.width, 0.0), end);
+        // Indices past the end are clamped
+        assert_eq!(end, measurement.caret_position(100));
+
+        for index in 0..=content.chars().count() {
+            let (x, y) = measurement.caret_position(index);
+            assert_eq!(index, measurement.char_index_at(x + 0.1, y + 1.0));
+        }
+        assert_eq!(0, measurement.char_index_at(-10.0, -10.0));
+        assert_eq!(11, measurement.char_index_at(1000.0, 1000.0));
+
+        let empty = measure_text("", &font, 14.0, None);
+        assert_eq!((0.0, 0.0), empty.caret_position(0));
+        assert_eq!(0, empty.char_index_at(50.0, 5.0));
+    }
+
+    #[test]
+    fn should_respect_line_height_and_letter_spacing() {
+        let font = make_font();
+        let content = "Hello";
+        let plain = measure_text(content, &font, 14.0, None);
+        let spaced = measure_text_with_properties(
+            content,
+            &font,
+            TextProperties {
+                font_size: 14.0,
+                line_height: 30.0,
+                letter_spacing: 1.0,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(30.0, spaced.height);
+        assert!((plain.width + 5.0 - spaced.width).abs() < 0.001);
+    }
+
+    #[test]
+    fn should_find_word_boundaries() {
+        let content = "let foo_bar = baz.qux();  ";
+        assert_eq!(3, next_word_boundary(content, 0));
+        assert_eq!(11, next_word_boundary(content, 3));
+        assert_eq!(13, next_word_boundary(content, 11));
+        assert_eq!(17, next_word_boundary(content, 13));
+        assert_eq!(18, next_word_boundary(content, 17));
+        assert_eq!(24, next_word_boundary(content, 21));
+        assert_eq!(26, next_word_boundary(content, 24));
+        assert_eq!(26, next_word_boundary(content, 100));
+
+        assert_eq!(21, prev_word_boundary(content, 26));
+        assert_eq!(18, prev_word_boundary(content, 21));
+        assert_eq!(4, prev_word_boundary(content, 11));
+        assert_eq!(4, prev_word_boundary(content, 8));
+        assert_eq!(0, prev_word_boundary(content, 3));
+        assert_eq!(0, prev_word_boundary(content, 0));
+
+        // Non-ASCII letters are part of words
+        assert_eq!(5, next_word_boundary("héllo wörld", 0));
+        assert_eq!(6, prev_word_boundary("héllo wörld", 11));
+    }
+
+    #[test]
+    fn should_cover_char_ranges_across_lines() {
+        let font = make_font();
+        let content = "Hello world! How is everyone doing today?";
+        let single = measure_text(content, &font, 14.0, None);
+        let properties = TextProperties {
+            font_size: 14.0,
+            line_height: 14.0 * 1.2,
+            max_size: (single.width / 2.0, f32::MAX),
+            ..Default::default()
+        };
+        let layout = font.measure(content, properties);
+        let measurement = measure_text_with_properties(content, &font, properties);
+        assert!(measurement.line_count() > 1);
+
+        // A range within the first line
+        let rects = text_range_rects(&layout, 1..4);
+        assert_eq!(1, rects.len());
+        assert_eq!(measurement.caret_position(1).0, rects[0].posx);
+        assert_eq!(0.0, rects[0].posy);
+        assert_eq!(
+            measurement.caret_position(4).0 - measurement.caret_position(1).0,
+            rects[0].width
+        );
+        assert_eq!(14.0 * 1.2, rects[0].height);
+
+        // A range spanning every line
+        let rects = text_range_rects(&layout, 0..content.chars().count());
+        assert_eq!(measurement.line_count(), rects.len());
+        for (rect, line) in rects.iter().zip(measurement.lines.iter()) {
+            assert_eq!(0.0, rect.posx);
+            assert_eq!(line.y, rect.posy);
+            assert_eq!(line.width, rect.width);
+        }
+
+        assert!(text_range_rects(&layout, 3..3).is_empty());
+        assert!(text_range_rects(&layout, 100..200).is_empty());
+    }
+}
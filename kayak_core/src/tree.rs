@@ -825,6 +825,14 @@ impl WidgetTree {
         }
     }
 
+    /// Returns the number of children added under the given parent so far
+    pub fn child_count(&self, parent: Index) -> usize {
+        self.tree
+            .read()
+            .map(|tree| tree.children.get(&parent).map_or(0, Vec::len))
+            .unwrap_or_default()
+    }
+
     pub fn take(self) -> Tree {
         Arc::try_unwrap(self.tree).unwrap().into_inner().unwrap()
     }
@@ -18,6 +18,10 @@ pub struct VecTrackerProps<T> {
 
 /// A widget that renders a `Vec` of widgets
 ///
+/// Items are matched to the previously rendered items by their position in the `Vec`. If the data
+/// can be reordered, or items can be inserted or removed, each item should be given a unique
+/// [key](Widget::get_key) so that its state stays with it.
+///
 /// # Props
 ///
 /// __Type:__ [`VecTrackerProps`]
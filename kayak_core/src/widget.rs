@@ -24,6 +24,7 @@ pub trait BaseWidget: SealedWidget + std::fmt::Debug + Send + Sync {
     fn get_props_mut(&mut self) -> &mut dyn WidgetProps;
     fn render(&mut self, context: &mut KayakContextRef);
     fn get_name(&self) -> &'static str;
+    fn get_key(&self) -> Option<&str>;
     fn on_event(&mut self, context: &mut KayakContextRef, event: &mut Event);
 }
 
@@ -63,6 +64,33 @@ pub trait Widget: std::fmt::Debug + Clone + Default + PartialEq + AsAny + Send +
         std::any::type_name::<Self>()
     }
 
+    /// Get this widget's key
+    ///
+    /// By default, a widget is matched to the widget it replaces by its position amongst its
+    /// siblings. This means that reordering or removing items in a list can cause the state of
+    /// one widget to be given to another. A widget with a key is instead matched to the previous
+    /// sibling with the same key (regardless of position), preserving its state and focus.
+    ///
+    /// Keys only need to be unique amongst siblings. Keyed widgets are never matched to unkeyed
+    /// widgets, and vice versa.
+    ///
+    /// Keys can be set in `rsx!` using the `key` attribute:
+    ///
+    /// ```ignore
+    /// rsx! {
+    ///     <Text key={item.id} content={item.name} />
+    /// }
+    /// ```
+    fn get_key(&self) -> Option<&str> {
+        None
+    }
+
+    /// Set this widget's key
+    ///
+    /// This method is used internally by the `key` attribute. Widgets that don't store a key
+    /// simply ignore it.
+    fn set_key(&mut self, _key: Option<String>) {}
+
     /// Send an event to this widget
     fn on_event(&mut self, context: &mut KayakContextRef, event: &mut Event) {
         if let Some(on_event) = self.get_props().get_on_event() {
@@ -141,6 +169,10 @@ where
         Widget::get_name(self)
     }
 
+    fn get_key(&self) -> Option<&str> {
+        Widget::get_key(self)
+    }
+
     fn on_event(&mut self, context: &mut KayakContextRef, event: &mut Event) {
         Widget::on_event(self, context, event);
     }
@@ -1,14 +1,19 @@
 use indexmap::IndexSet;
-use kayak_font::KayakFont;
+use kayak_font::{Alignment, KayakFont};
 use morphorm::Units;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use crate::assets::Assets;
 use crate::layout_cache::Rect;
 use crate::lifetime::WidgetLifetime;
-use crate::styles::StyleProp;
+use crate::styles::{
+    AlignItems, Direction, Display, Edge, JustifyContent, LayoutType, LayoutWrap, Overflow,
+    PositionType, StyleProp, TransformMatrix,
+};
 use crate::{
+    accessibility::Accessibility,
     focus_tree::FocusTracker,
     focus_tree::FocusTree,
     layout_cache::LayoutCache,
@@ -17,7 +22,8 @@ use crate::{
     render_primitive::RenderPrimitive,
     styles::Style,
     tree::Tree,
-    Arena, Binding, Bound, BoxedWidget, Index, Widget, WidgetProps,
+    Arena, Binding, Bound, BoxedWidget, Handler, Index, PointerEvents, RenderError, Widget,
+    WidgetProps,
 };
 // use as_any::Downcast;
 
@@ -38,6 +44,33 @@ pub struct WidgetManager {
     pub layout_cache: LayoutCache,
     focus_tracker: FocusTracker,
     current_z: f32,
+    /// The sizes reported by widgets as their preferred size, used when they aren't explicitly sized
+    preferred_sizes: HashMap<Index, (f32, f32)>,
+    /// The baselines reported by widgets, as an offset from the top of their layout (in pixels)
+    baselines: HashMap<Index, f32>,
+    /// The widgets placed at the root of the node tree, rather than within their parent's node
+    portals: IndexSet<Index>,
+    /// The widgets whose subtrees are disabled
+    disabled: IndexSet<Index>,
+    /// The accessibility information set by widgets
+    accessibility: HashMap<Index, Accessibility>,
+    /// The callbacks of the widgets that catch panics rendering their descendants
+    error_boundaries: HashMap<Index, Handler<RenderError>>,
+    /// The widgets whose subtrees are static, along with their cached layout (if calculated)
+    static_layouts: HashMap<Index, Option<StaticLayout>>,
+    /// The time taken to calculate the last layout
+    layout_time: Duration,
+}
+
+/// The cached layout of a static subtree
+#[derive(Debug, Clone)]
+struct StaticLayout {
+    /// The rect of the subtree's root when it was cached
+    rect: Rect,
+    /// The baseline of the subtree's root
+    baseline: Option<f32>,
+    /// The rects of the root's descendants when it was cached
+    descendants: Vec<(Index, Rect)>,
 }
 
 impl WidgetManager {
@@ -53,6 +86,14 @@ impl WidgetManager {
             focus_tree: FocusTree::default(),
             focus_tracker: FocusTracker::default(),
             current_z: 0.0,
+            preferred_sizes: HashMap::new(),
+            baselines: HashMap::new(),
+            portals: IndexSet::new(),
+            disabled: IndexSet::new(),
+            accessibility: HashMap::new(),
+            error_boundaries: HashMap::new(),
+            static_layouts: HashMap::new(),
+            layout_time: Duration::ZERO,
             widget_lifetimes: HashMap::new(),
         }
     }
@@ -80,15 +121,8 @@ impl WidgetManager {
         mut widget: T,
         parent: Option<Index>,
     ) -> (bool, Index) {
-        let widget_id = if let Some(parent) = parent.clone() {
-            if let Some(parent_children) = self.tree.children.get_mut(&parent) {
-                parent_children.get(index).cloned()
-            } else {
-                None
-            }
-        } else {
-            None
-        };
+        let widget_id = parent
+            .and_then(|parent| self.find_existing_child(parent, index, Widget::get_key(&widget)));
 
         // Pull child and update.
         if let Some(widget_id) = widget_id {
@@ -157,6 +191,35 @@ impl WidgetManager {
         (true, widget_id)
     }
 
+    /// Finds the existing child of the given parent that a newly rendered widget should replace
+    ///
+    /// Keyed widgets are matched to the child with the same key, regardless of their position.
+    /// Unkeyed widgets are matched by their sibling index, but only to an unkeyed child. This
+    /// ensures that inserting, removing, or reordering keyed widgets never causes their state to
+    /// be given to another widget.
+    ///
+    /// Returns `None` if no matching child exists, in which case a new widget should be created.
+    fn find_existing_child(&self, parent: Index, index: usize, key: Option<&str>) -> Option<Index> {
+        let parent_children = self.tree.children.get(&parent)?;
+        let child_key = |child: &Index| {
+            self.current_widgets
+                .get(*child)
+                .and_then(|widget| widget.as_ref())
+                .and_then(|widget| widget.get_key())
+        };
+
+        match key {
+            Some(key) => parent_children
+                .iter()
+                .find(|child| child_key(child) == Some(key))
+                .cloned(),
+            None => parent_children
+                .get(index)
+                .filter(|child| child_key(child).is_none())
+                .cloned(),
+        }
+    }
+
     pub fn take(&mut self, id: Index) -> BoxedWidget {
         self.current_widgets[id].take().unwrap()
     }
@@ -248,6 +311,16 @@ impl WidgetManager {
             // Fill in all `inherited` values for any `inherit` property
             styles.inherit(&parent_styles);
 
+            if let Some((width, height)) = self.preferred_sizes.get(&dirty_node_index) {
+                // Widgets that aren't explicitly sized use the size they reported
+                if matches!(styles.width, StyleProp::Default) {
+                    styles.width = StyleProp::Value(Units::Pixels(*width));
+                }
+                if matches!(styles.height, StyleProp::Default) {
+                    styles.height = StyleProp::Value(Units::Pixels(*height));
+                }
+            }
+
             let primitive = self.create_primitive(dirty_node_index, &mut styles, assets);
 
             let children = self
@@ -265,6 +338,16 @@ impl WidgetManager {
                 .build();
             node.z = current_z;
 
+            let is_unchanged = matches!(
+                &self.nodes[dirty_node_index],
+                Some(old) if old.resolved_styles == node.resolved_styles
+                    && old.primitive == node.primitive
+                    && old.children == node.children
+            );
+            if !is_unchanged {
+                self.invalidate_static_layout(dirty_node_index);
+            }
+
             self.nodes[dirty_node_index] = Some(node);
         }
 
@@ -278,8 +361,752 @@ impl WidgetManager {
         }
     }
 
+    /// Calculates the layout of every node in the node tree
+    ///
+    /// Static subtrees with a cached layout are laid out as a single node, whose descendants are
+    /// then moved along with it. See [`set_static`](Self::set_static) for details.
     pub fn calculate_layout(&mut self) {
+        let start = Instant::now();
+
+        let cached: Vec<(Index, StaticLayout)> = self
+            .static_layouts
+            .iter()
+            .filter(|(root, _)| self.node_tree.contains(**root))
+            .filter_map(|(root, layout)| Some((*root, layout.clone()?)))
+            .collect();
+        if cached.is_empty() {
+            self.layout_nodes();
+        } else if !self.layout_with_static_layouts(&cached) {
+            // The size of a static root changed, so its descendants need to be laid out again
+            self.layout_nodes();
+        }
+        self.cache_static_layouts();
+
+        self.layout_time = start.elapsed();
+    }
+
+    /// Returns the time taken to calculate the last layout
+    pub fn layout_time(&self) -> Duration {
+        self.layout_time
+    }
+
+    /// Lays out the node tree, with the descendants of the given static roots left out
+    ///
+    /// The descendants are then restored to their cached layouts, offset by how much their root
+    /// moved. Returns false (and clears the cache) if the size of any of the roots changed instead,
+    /// in which case the layouts of their descendants are left out of date.
+    fn layout_with_static_layouts(&mut self, cached: &[(Index, StaticLayout)]) -> bool {
+        let mut pruned = Tree {
+            children: self.node_tree.children.clone(),
+            parents: self.node_tree.parents.clone(),
+            root_node: self.node_tree.root_node,
+        };
+        let mut pinned = Vec::new();
+        for (root, layout) in cached {
+            for (descendant, _) in &layout.descendants {
+                pruned.children.remove(descendant);
+                pruned.parents.remove(descendant);
+            }
+            pruned.children.insert(*root, Vec::new());
+
+            // Roots sized by their content keep the size of their cached content
+            if let Some(Some(node)) = self.nodes.get_mut(*root) {
+                let styles = &mut node.resolved_styles;
+                pinned.push((
+                    *root,
+                    styles.width.clone(),
+                    styles.height.clone(),
+                    node.wrap_height.take(),
+                ));
+                if matches!(styles.width, StyleProp::Value(Units::Auto)) {
+                    styles.width = StyleProp::Value(Units::Pixels(layout.rect.width));
+                }
+                if matches!(styles.height, StyleProp::Value(Units::Auto)) {
+                    styles.height = StyleProp::Value(Units::Pixels(layout.rect.height));
+                }
+            }
+        }
+
+        let node_tree = std::mem::replace(&mut self.node_tree, pruned);
+        self.layout_nodes();
+        self.node_tree = node_tree;
+
+        for (root, width, height, wrap_height) in pinned {
+            if let Some(Some(node)) = self.nodes.get_mut(root) {
+                node.resolved_styles.width = width;
+                node.resolved_styles.height = height;
+                node.wrap_height = wrap_height;
+            }
+        }
+
+        let was_resized = cached.iter().any(|(root, layout)| {
+            self.layout_cache.rect.get(root).map_or(true, |rect| {
+                rect.width != layout.rect.width || rect.height != layout.rect.height
+            })
+        });
+        if was_resized {
+            for (root, _) in cached {
+                self.static_layouts.insert(*root, None);
+            }
+            return false;
+        }
+
+        for (root, layout) in cached {
+            let rect = match self.layout_cache.rect.get(root) {
+                Some(rect) => *rect,
+                None => continue,
+            };
+            let dx = rect.posx - layout.rect.posx;
+            let dy = rect.posy - layout.rect.posy;
+            for (descendant, cached_rect) in &layout.descendants {
+                let current = self.layout_cache.rect.get(descendant).copied();
+                let offset = match current {
+                    Some(current) => (
+                        cached_rect.posx + dx - current.posx,
+                        cached_rect.posy + dy - current.posy,
+                    ),
+                    None => continue,
+                };
+                self.layout_cache.translate(*descendant, offset.0, offset.1);
+            }
+        }
+        true
+    }
+
+    /// Caches the layouts of the static subtrees that don't have one yet
+    fn cache_static_layouts(&mut self) {
+        let uncached: Vec<Index> = self
+            .static_layouts
+            .iter()
+            .filter(|(root, layout)| layout.is_none() && self.node_tree.contains(**root))
+            .map(|(root, _)| *root)
+            .collect();
+        for root in uncached {
+            let rect = match self.layout_cache.rect.get(&root) {
+                Some(rect) => *rect,
+                None => continue,
+            };
+            let mut descendants = Vec::new();
+            let mut stack = self
+                .node_tree
+                .children
+                .get(&root)
+                .cloned()
+                .unwrap_or_default();
+            while let Some(descendant) = stack.pop() {
+                if let Some(descendant_rect) = self.layout_cache.rect.get(&descendant) {
+                    descendants.push((descendant, *descendant_rect));
+                }
+                if let Some(children) = self.node_tree.children.get(&descendant) {
+                    stack.extend(children.iter().copied());
+                }
+            }
+            let layout = StaticLayout {
+                rect,
+                baseline: self.baseline(root),
+                descendants,
+            };
+            self.static_layouts.insert(root, Some(layout));
+        }
+    }
+
+    /// Sets whether the subtree of the given widget is static, caching its layout
+    ///
+    /// The cached layout is reused until a widget within the subtree renders with different styles,
+    /// content, or children (rendering identically keeps it), or until the size of the root
+    /// changes. Roots sized by their content keep their cached size until then.
+    pub fn set_static(&mut self, id: Index, is_static: bool) {
+        if is_static {
+            self.static_layouts.entry(id).or_insert(None);
+        } else {
+            self.static_layouts.remove(&id);
+        }
+    }
+
+    /// Clears the cached layouts of the static subtrees containing the given widget
+    pub fn invalidate_static_layout(&mut self, id: Index) {
+        if self.static_layouts.is_empty() {
+            return;
+        }
+
+        let mut current = Some(id);
+        while let Some(index) = current {
+            if let Some(layout) = self.static_layouts.get_mut(&index) {
+                *layout = None;
+            }
+            current = self.tree.get_parent(index);
+        }
+    }
+
+    fn layout_nodes(&mut self) {
         morphorm::layout(&mut self.layout_cache, &self.node_tree, &self.nodes);
+
+        // Percentage padding depends on the width of the parent, and the height of a wrapping
+        // node on the widths of its children, which are only known once the tree has been laid out
+        let is_padding_changed = self.resolve_percentage_padding();
+        let is_wrap_changed = self.wrap_layouts();
+        if is_padding_changed || is_wrap_changed {
+            // Both are accounted for in a single extra layout, after which the children of
+            // wrapping nodes only need to be moved back onto their lines
+            morphorm::layout(&mut self.layout_cache, &self.node_tree, &self.nodes);
+            self.wrap_layouts();
+        }
+
+        self.arrange_children();
+        self.align_baselines();
+        self.mirror_layouts();
+    }
+
+    /// Mirrors the children of all right-to-left nodes horizontally within their parent
+    ///
+    /// Nodes are processed from the top down so that each node is in its final position
+    /// before its own children are mirrored within it.
+    fn mirror_layouts(&mut self) {
+        let root = match self.node_tree.root_node {
+            Some(root) => root,
+            None => return,
+        };
+
+        let mut stack = vec![root];
+        while let Some(current) = stack.pop() {
+            let children = self
+                .node_tree
+                .children
+                .get(&current)
+                .cloned()
+                .unwrap_or_default();
+
+            let is_rtl = matches!(
+                self.nodes.get(current),
+                Some(Some(node)) if node.resolved_styles.direction.resolve_or_default() == Direction::Rtl
+            );
+            if let (true, Some(rect)) = (is_rtl, self.layout_cache.rect.get(&current).copied()) {
+                for child in &children {
+                    let child_rect = match self.layout_cache.rect.get(child) {
+                        Some(rect) => *rect,
+                        None => continue,
+                    };
+
+                    let mirrored_x =
+                        2.0 * rect.posx + rect.width - child_rect.posx - child_rect.width;
+                    let dx = mirrored_x - child_rect.posx;
+                    if dx != 0.0 {
+                        let mut descendants = vec![*child];
+                        while let Some(descendant) = descendants.pop() {
+                            self.layout_cache.translate(descendant, dx, 0.0);
+                            if let Some(grandchildren) = self.node_tree.children.get(&descendant) {
+                                descendants.extend(grandchildren.iter().copied());
+                            }
+                        }
+                    }
+                }
+            }
+
+            stack.extend(children);
+        }
+    }
+
+    /// Resolves the percentage padding of all nodes against the width of their parent
+    ///
+    /// Returns true if the resolved padding of any node changed since the last layout.
+    fn resolve_percentage_padding(&mut self) -> bool {
+        let mut changed = false;
+        for index in self.node_tree.flatten() {
+            let node = match self.nodes.get_mut(index) {
+                Some(Some(node)) => node,
+                _ => continue,
+            };
+
+            let declared = node.declared_padding();
+            let is_percentage = |units: Units| matches!(units, Units::Percentage(..));
+            let resolved_padding = if is_percentage(declared.top)
+                || is_percentage(declared.right)
+                || is_percentage(declared.bottom)
+                || is_percentage(declared.left)
+            {
+                let parent = self.node_tree.get_parent(index).unwrap_or(index);
+                let parent_width = self
+                    .layout_cache
+                    .rect
+                    .get(&parent)
+                    .map(|rect| rect.width)
+                    .unwrap_or_default();
+                let resolve = |units: Units| match units {
+                    Units::Percentage(value) => Units::Pixels(value / 100.0 * parent_width),
+                    units => units,
+                };
+                Some(Edge::new(
+                    resolve(declared.top),
+                    resolve(declared.right),
+                    resolve(declared.bottom),
+                    resolve(declared.left),
+                ))
+            } else {
+                None
+            };
+
+            if node.resolved_padding != resolved_padding {
+                node.resolved_padding = resolved_padding;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Moves the children of all wrapping nodes onto multiple lines
+    ///
+    /// Nodes are processed from the bottom up so that nested wrapping nodes are sized
+    /// before their parents place them.
+    ///
+    /// Returns true if the height of any wrapping node changed since the last layout.
+    fn wrap_layouts(&mut self) -> bool {
+        let root = match self.node_tree.root_node {
+            Some(root) => root,
+            None => return false,
+        };
+
+        let mut order = Vec::new();
+        let mut stack = vec![root];
+        while let Some(current) = stack.pop() {
+            order.push(current);
+            if let Some(children) = self.node_tree.children.get(&current) {
+                stack.extend(children.iter().copied());
+            }
+        }
+
+        let mut changed = false;
+        for index in order.into_iter().rev() {
+            changed |= self.wrap_children(index);
+        }
+        changed
+    }
+
+    fn wrap_children(&mut self, index: Index) -> bool {
+        let (is_wrapping, is_auto_height) = match self.nodes.get(index) {
+            Some(Some(node)) => (
+                matches!(
+                    node.resolved_styles.layout_wrap,
+                    StyleProp::Value(LayoutWrap::Wrap)
+                ) && matches!(
+                    node.resolved_styles.layout_type,
+                    StyleProp::Value(LayoutType::Row)
+                ),
+                matches!(node.resolved_styles.height, StyleProp::Value(Units::Auto)),
+            ),
+            _ => return false,
+        };
+
+        if !is_wrapping {
+            return false;
+        }
+
+        let rect = match self.layout_cache.rect.get(&index) {
+            Some(rect) => *rect,
+            None => return false,
+        };
+        let children = self
+            .node_tree
+            .children
+            .get(&index)
+            .cloned()
+            .unwrap_or_default();
+
+        let to_pixels = |units: Option<Units>, size: f32| match units {
+            Some(Units::Pixels(value)) => value,
+            Some(Units::Percentage(value)) => value / 100.0 * size,
+            _ => 0.0,
+        };
+        let padding_left = to_pixels(morphorm::Node::child_left(&index, &self.nodes), rect.width);
+        let padding_right = to_pixels(morphorm::Node::child_right(&index, &self.nodes), rect.width);
+        let padding_top = to_pixels(morphorm::Node::child_top(&index, &self.nodes), rect.height);
+        let padding_bottom = to_pixels(
+            morphorm::Node::child_bottom(&index, &self.nodes),
+            rect.height,
+        );
+        let col_between = to_pixels(morphorm::Node::col_between(&index, &self.nodes), rect.width);
+        let row_between = to_pixels(
+            morphorm::Node::row_between(&index, &self.nodes),
+            rect.height,
+        );
+
+        let line_start = rect.posx + padding_left;
+        let line_end = rect.posx + rect.width - padding_right;
+        let mut x = line_start;
+        let mut y = rect.posy + padding_top;
+        let mut line_height = 0.0_f32;
+        let mut line_is_empty = true;
+
+        for child in children {
+            if morphorm::Node::position_type(&child, &self.nodes)
+                == Some(morphorm::PositionType::SelfDirected)
+            {
+                continue;
+            }
+
+            let child_rect = match self.layout_cache.rect.get(&child) {
+                Some(rect) => *rect,
+                None => continue,
+            };
+            // Lines are filled with the outer size of each child, including its margins
+            let margin_left = to_pixels(morphorm::Node::left(&child, &self.nodes), rect.width);
+            let margin_right = to_pixels(morphorm::Node::right(&child, &self.nodes), rect.width);
+            let margin_top = to_pixels(morphorm::Node::top(&child, &self.nodes), rect.height);
+            let margin_bottom = to_pixels(morphorm::Node::bottom(&child, &self.nodes), rect.height);
+            let outer_width = margin_left + child_rect.width + margin_right;
+            let outer_height = margin_top + child_rect.height + margin_bottom;
+
+            if !line_is_empty && x + outer_width > line_end {
+                x = line_start;
+                y += line_height + row_between;
+                line_height = 0.0;
+            }
+
+            let dx = x + margin_left - child_rect.posx;
+            let dy = y + margin_top - child_rect.posy;
+            if dx != 0.0 || dy != 0.0 {
+                let mut stack = vec![child];
+                while let Some(descendant) = stack.pop() {
+                    self.layout_cache.translate(descendant, dx, dy);
+                    if let Some(grandchildren) = self.node_tree.children.get(&descendant) {
+                        stack.extend(grandchildren.iter().copied());
+                    }
+                }
+            }
+
+            x += outer_width + col_between;
+            line_height = line_height.max(outer_height);
+            line_is_empty = false;
+        }
+
+        if !is_auto_height {
+            return false;
+        }
+
+        let height = y + line_height + padding_bottom - rect.posy;
+        self.layout_cache.resize_height(index, height);
+
+        if let Some(Some(node)) = self.nodes.get_mut(index) {
+            let changed = node.wrap_height != Some(height);
+            node.wrap_height = Some(height);
+            changed
+        } else {
+            false
+        }
+    }
+
+    /// Distributes the children of all rows and columns along their main axis and aligns them
+    /// along their cross axis, according to their `justify_content` and `align_items` styles
+    fn arrange_children(&mut self) {
+        for index in self.node_tree.flatten() {
+            let (layout_type, justify, align, is_wrapping) = match self.nodes.get(index) {
+                Some(Some(node)) => {
+                    let styles = &node.resolved_styles;
+                    (
+                        styles.layout_type.resolve_or_default(),
+                        styles.justify_content.resolve_or_default(),
+                        styles.align_items.resolve_or_default(),
+                        styles.layout_wrap.resolve_or_default() == LayoutWrap::Wrap,
+                    )
+                }
+                _ => continue,
+            };
+            let is_row = match layout_type {
+                LayoutType::Row => true,
+                LayoutType::Column => false,
+                _ => continue,
+            };
+            let aligns = matches!(align, AlignItems::Center | AlignItems::End);
+            if justify == JustifyContent::Start && !aligns {
+                continue;
+            }
+
+            let rect = match self.layout_cache.rect.get(&index) {
+                Some(rect) => *rect,
+                None => continue,
+            };
+            let to_pixels = |units: Option<Units>, size: f32| match units {
+                Some(Units::Pixels(value)) => value,
+                Some(Units::Percentage(value)) => value / 100.0 * size,
+                _ => 0.0,
+            };
+            let padding_left =
+                to_pixels(morphorm::Node::child_left(&index, &self.nodes), rect.width);
+            let padding_right =
+                to_pixels(morphorm::Node::child_right(&index, &self.nodes), rect.width);
+            let padding_top =
+                to_pixels(morphorm::Node::child_top(&index, &self.nodes), rect.height);
+            let padding_bottom = to_pixels(
+                morphorm::Node::child_bottom(&index, &self.nodes),
+                rect.height,
+            );
+
+            // The start and end of the content box along the main and cross axes
+            let (main_start, main_end, cross_start, cross_end) = if is_row {
+                (
+                    rect.posx + padding_left,
+                    rect.posx + rect.width - padding_right,
+                    rect.posy + padding_top,
+                    rect.posy + rect.height - padding_bottom,
+                )
+            } else {
+                (
+                    rect.posy + padding_top,
+                    rect.posy + rect.height - padding_bottom,
+                    rect.posx + padding_left,
+                    rect.posx + rect.width - padding_right,
+                )
+            };
+            // The position and size of a rect along the main and cross axes
+            let axes = |rect: &Rect| {
+                if is_row {
+                    (rect.posx, rect.width, rect.posy, rect.height)
+                } else {
+                    (rect.posy, rect.height, rect.posx, rect.width)
+                }
+            };
+
+            let children: Vec<_> = self
+                .node_tree
+                .children
+                .get(&index)
+                .cloned()
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|child| {
+                    morphorm::Node::position_type(child, &self.nodes)
+                        != Some(morphorm::PositionType::SelfDirected)
+                })
+                .filter_map(|child| Some((child, *self.layout_cache.rect.get(&child)?)))
+                .collect();
+
+            // Each line of a wrapping row shares the same top
+            let mut lines: Vec<Vec<(Index, Rect)>> = Vec::new();
+            for (child, child_rect) in children {
+                match lines.last_mut() {
+                    Some(line) if !is_wrapping || line[0].1.posy == child_rect.posy => {
+                        line.push((child, child_rect))
+                    }
+                    _ => lines.push(vec![(child, child_rect)]),
+                }
+            }
+
+            for line in lines {
+                let count = line.len() as f32;
+                let line_end = line
+                    .iter()
+                    .map(|(_, child_rect)| {
+                        let (pos, size, ..) = axes(child_rect);
+                        pos + size
+                    })
+                    .fold(main_start, f32::max);
+                let free = (main_end - line_end).max(0.0);
+
+                let (line_start, line_size) = if is_wrapping {
+                    let start = line[0].1.posy;
+                    let size = line
+                        .iter()
+                        .map(|(_, child_rect)| child_rect.height)
+                        .fold(0.0, f32::max);
+                    (start, size)
+                } else {
+                    (cross_start, cross_end - cross_start)
+                };
+
+                for (position, (child, child_rect)) in line.iter().enumerate() {
+                    let position = position as f32;
+                    let main_offset = match justify {
+                        JustifyContent::Start => 0.0,
+                        JustifyContent::Center => free / 2.0,
+                        JustifyContent::End => free,
+                        JustifyContent::SpaceBetween if count > 1.0 => {
+                            free * position / (count - 1.0)
+                        }
+                        JustifyContent::SpaceBetween => 0.0,
+                        JustifyContent::SpaceAround => free * (position + 0.5) / count,
+                    };
+
+                    let (.., cross_pos, cross_size) = axes(child_rect);
+                    let cross_offset = match align {
+                        AlignItems::Center => {
+                            line_start + (line_size - cross_size) / 2.0 - cross_pos
+                        }
+                        AlignItems::End => line_start + line_size - cross_size - cross_pos,
+                        _ => 0.0,
+                    };
+
+                    let (dx, dy) = if is_row {
+                        (main_offset, cross_offset)
+                    } else {
+                        (cross_offset, main_offset)
+                    };
+                    if dx != 0.0 || dy != 0.0 {
+                        let mut descendants = vec![*child];
+                        while let Some(descendant) = descendants.pop() {
+                            self.layout_cache.translate(descendant, dx, dy);
+                            if let Some(grandchildren) = self.node_tree.children.get(&descendant) {
+                                descendants.extend(grandchildren.iter().copied());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Aligns the children of all baseline-aligned rows along a common baseline
+    ///
+    /// Nodes are processed from the bottom up so that nested rows are aligned before their own
+    /// baseline is used by their parents.
+    fn align_baselines(&mut self) {
+        let root = match self.node_tree.root_node {
+            Some(root) => root,
+            None => return,
+        };
+
+        let mut order = Vec::new();
+        let mut stack = vec![root];
+        while let Some(current) = stack.pop() {
+            order.push(current);
+            if let Some(children) = self.node_tree.children.get(&current) {
+                stack.extend(children.iter().copied());
+            }
+        }
+
+        for index in order.into_iter().rev() {
+            let is_baseline_row = match self.nodes.get(index) {
+                Some(Some(node)) => {
+                    let styles = &node.resolved_styles;
+                    styles.align_items.resolve_or_default() == AlignItems::Baseline
+                        && styles.layout_type.resolve_or_default() == LayoutType::Row
+                }
+                _ => false,
+            };
+            if !is_baseline_row {
+                continue;
+            }
+
+            // The absolute position of each child's baseline
+            let baselines: Vec<_> = self
+                .node_tree
+                .children
+                .get(&index)
+                .cloned()
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|child| {
+                    morphorm::Node::position_type(child, &self.nodes)
+                        != Some(morphorm::PositionType::SelfDirected)
+                })
+                .filter_map(|child| {
+                    let rect = self.layout_cache.rect.get(&child)?;
+                    let baseline = self.baseline(child)?;
+                    Some((child, rect.posy + baseline))
+                })
+                .collect();
+
+            let target = baselines
+                .iter()
+                .map(|(_, baseline)| *baseline)
+                .fold(f32::MIN, f32::max);
+
+            for (child, baseline) in baselines {
+                let dy = target - baseline;
+                if dy != 0.0 {
+                    let mut descendants = vec![child];
+                    while let Some(descendant) = descendants.pop() {
+                        self.layout_cache.translate(descendant, 0.0, dy);
+                        if let Some(grandchildren) = self.node_tree.children.get(&descendant) {
+                            descendants.extend(grandchildren.iter().copied());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the baseline of the given node, as an offset from the top of its layout (in pixels)
+    ///
+    /// Baselines reported by the widget take precedence, followed by the baseline of its text.
+    /// Otherwise, the baseline of the node's first child with one is used.
+    pub fn baseline(&self, id: Index) -> Option<f32> {
+        if let Some(baseline) = self.baselines.get(&id) {
+            return Some(*baseline);
+        }
+
+        if let Some(Some(layout)) = self.static_layouts.get(&id) {
+            // The descendants of a cached static subtree may not be in place yet
+            return layout.baseline;
+        }
+
+        if let Some(Some(node)) = self.nodes.get(id) {
+            if let RenderPrimitive::Text { text_layout, .. } = &node.primitive {
+                return Some(text_layout.baseline());
+            }
+        }
+
+        let posy = self.layout_cache.rect.get(&id)?.posy;
+        self.node_tree
+            .children
+            .get(&id)?
+            .iter()
+            .filter(|child| {
+                morphorm::Node::position_type(*child, &self.nodes)
+                    != Some(morphorm::PositionType::SelfDirected)
+            })
+            .find_map(|child| {
+                let child_posy = self.layout_cache.rect.get(child)?.posy;
+                Some(child_posy - posy + self.baseline(*child)?)
+            })
+    }
+
+    /// Sets the baseline of the given widget, as an offset from the top of its layout (in pixels)
+    ///
+    /// This is used to align the widget within rows with an [`AlignItems::Baseline`] alignment.
+    /// Passing `None` removes the baseline, so it's found from the widget's content instead.
+    pub fn set_baseline(&mut self, id: Index, baseline: Option<f32>) {
+        let tree = &self.tree;
+        // Widgets that have since been removed no longer need their baseline
+        self.baselines.retain(|id, _| tree.contains(*id));
+
+        let previous = match baseline {
+            Some(baseline) => self.baselines.insert(id, baseline),
+            None => self.baselines.remove(&id),
+        };
+        if previous != baseline {
+            self.dirty_render_nodes.insert(id);
+        }
+    }
+
+    /// Sets the accessibility information of the given widget
+    ///
+    /// Passing `None` removes the widget from the accessibility tree.
+    pub fn set_accessibility(&mut self, id: Index, accessibility: Option<Accessibility>) {
+        match accessibility {
+            Some(accessibility) => self.accessibility.insert(id, accessibility),
+            None => self.accessibility.remove(&id),
+        };
+    }
+
+    /// Returns the accessibility information of the given widget, if it has any
+    pub fn get_accessibility(&self, id: Index) -> Option<&Accessibility> {
+        self.accessibility
+            .get(&id)
+            .filter(|_| self.tree.contains(id))
+    }
+
+    /// Sets the callback fired when a descendant of the given widget panics while rendering
+    ///
+    /// Passing `None` removes the widget's error boundary, so panics are passed to its ancestors.
+    pub fn set_error_boundary(&mut self, id: Index, handler: Option<Handler<RenderError>>) {
+        match handler {
+            Some(handler) => self.error_boundaries.insert(id, handler),
+            None => self.error_boundaries.remove(&id),
+        };
+    }
+
+    /// Returns the error boundary callback of the given widget, if it has one
+    pub fn get_error_boundary(&self, id: Index) -> Option<&Handler<RenderError>> {
+        self.error_boundaries.get(&id)
     }
 
     fn create_primitive(
@@ -312,6 +1139,16 @@ impl WidgetManager {
                             *text_layout = font.measure(&content, *properties);
                             let measurement = text_layout.size();
 
+                            if styles.direction.resolve_or_default() == Direction::Rtl {
+                                // Align each line to the right edge of the text, or the edge
+                                // of the parent if the text is explicitly sized
+                                if matches!(styles.width, StyleProp::Default) {
+                                    properties.max_size.0 = measurement.0;
+                                }
+                                properties.alignment = Alignment::End;
+                                *text_layout = font.measure(&content, *properties);
+                            }
+
                             // --- Apply Layout --- //
                             if matches!(styles.width, StyleProp::Default) {
                                 styles.width = StyleProp::Value(Units::Pixels(measurement.0));
@@ -342,12 +1179,20 @@ impl WidgetManager {
         current_node: Index,
         mut main_z_index: f32,
         mut prev_clip: RenderPrimitive,
+        parent_opacity: f32,
+        parent_transform: TransformMatrix,
     ) -> Vec<RenderPrimitive> {
         let mut render_primitives = Vec::new();
 
         if let Some(node) = nodes.get(current_node).unwrap() {
+            if node.resolved_styles.display.resolve_or_default() != Display::Visible {
+                // Hidden nodes keep their layout, but nothing within them is drawn
+                return render_primitives;
+            }
             if let Some(layout) = layout_cache.rect.get(&current_node) {
                 let mut render_primitive = node.primitive.clone();
+                let opacity = parent_opacity * node.resolved_styles.opacity.resolve_or(1.0);
+                Self::apply_opacity(&mut render_primitive, opacity);
                 let mut layout = *layout;
                 let new_z_index = if matches!(render_primitive, RenderPrimitive::Clip { .. }) {
                     main_z_index - 0.1
@@ -355,11 +1200,35 @@ impl WidgetManager {
                     main_z_index
                 };
                 layout.z_index = new_z_index;
-                render_primitive.set_layout(layout);
+                let transform =
+                    Self::node_transform(&node.resolved_styles, layout, parent_transform);
+                if transform != parent_transform {
+                    render_primitives.push(RenderPrimitive::Transform { matrix: transform });
+                }
+                if let RenderPrimitive::Clip { .. } = render_primitive {
+                    // Clips are confined to the clips of their ancestors
+                    let clip =
+                        transform.transform_rect(Self::clip_bounds(&node.resolved_styles, layout));
+                    render_primitive.set_layout(Self::intersect_clip(clip, &prev_clip));
+                } else {
+                    render_primitive.set_layout(layout);
+                }
+                if let RenderPrimitive::Quad { border, .. } = &mut render_primitive {
+                    *border = node
+                        .resolved_styles
+                        .border
+                        .resolve_or_default()
+                        .resolve_pixels(layout.width, layout.height);
+                }
                 render_primitives.push(render_primitive.clone());
 
                 let new_prev_clip = if matches!(render_primitive, RenderPrimitive::Clip { .. }) {
                     render_primitive.clone()
+                } else if let Some(overflow_clip) =
+                    Self::create_overflow_clip(&node.resolved_styles, layout, transform, &prev_clip)
+                {
+                    render_primitives.push(overflow_clip.clone());
+                    overflow_clip
                 } else {
                     prev_clip
                 };
@@ -376,6 +1245,8 @@ impl WidgetManager {
                             *child,
                             main_z_index,
                             new_prev_clip.clone(),
+                            opacity,
+                            transform,
                         ));
 
                         main_z_index = layout.z_index;
@@ -392,48 +1263,344 @@ impl WidgetManager {
                         }
                     }
                 }
+
+                if transform != parent_transform {
+                    // Restore the transform of the parent for the siblings that follow
+                    render_primitives.push(RenderPrimitive::Transform {
+                        matrix: parent_transform,
+                    });
+                }
             }
         }
 
         render_primitives
     }
 
-    /// Forces layout to be recalculated before rendering.
+    /// Returns the transform of a node, combined with the transforms of its ancestors
     ///
-    /// This should be used _sparingly_, if at all.
-    pub fn needs_layout(&mut self, id: Index) {
-        self.dirty_render_nodes.insert(id);
+    /// The node's own [`transform`](Style::transform) scales and rotates around its
+    /// [`transform_origin`](Style::transform_origin).
+    fn node_transform(
+        styles: &Style,
+        layout: Rect,
+        parent_transform: TransformMatrix,
+    ) -> TransformMatrix {
+        match styles.transform {
+            StyleProp::Value(transform) => {
+                let resolve = |units: Units, size: f32| match units {
+                    Units::Pixels(offset) => offset,
+                    Units::Percentage(percent) => size * percent / 100.0,
+                    _ => size / 2.0,
+                };
+                let (origin_x, origin_y) = styles
+                    .transform_origin
+                    .resolve_or((Units::Auto, Units::Auto));
+                let origin = (
+                    layout.posx + resolve(origin_x, layout.width),
+                    layout.posy + resolve(origin_y, layout.height),
+                );
+                parent_transform * transform.to_matrix(origin)
+            }
+            _ => parent_transform,
+        }
     }
 
-    pub fn build_render_primitives(&self) -> Vec<RenderPrimitive> {
-        if self.node_tree.root_node.is_none() {
-            return vec![];
+    /// Returns the transform of the given node, combined with the given transform of its parent
+    pub(crate) fn get_transform(
+        &self,
+        id: Index,
+        parent_transform: TransformMatrix,
+    ) -> TransformMatrix {
+        match (self.nodes.get(id), self.layout_cache.rect.get(&id)) {
+            (Some(Some(node)), Some(layout)) => {
+                Self::node_transform(&node.resolved_styles, *layout, parent_transform)
+            }
+            _ => parent_transform,
         }
+    }
 
-        Self::recurse_node_tree_to_build_primitives(
-            &self.node_tree,
-            &self.layout_cache,
-            &self.nodes,
-            self.node_tree.root_node.unwrap(),
-            0.0,
-            RenderPrimitive::Empty,
-        )
+    /// Returns the transform of the given node, combined with the transforms of all its ancestors
+    pub(crate) fn get_global_transform(&self, id: Index) -> TransformMatrix {
+        let parent_transform = self
+            .node_tree
+            .get_parent(id)
+            .map_or(TransformMatrix::IDENTITY, |parent| {
+                self.get_global_transform(parent)
+            });
+        self.get_transform(id, parent_transform)
     }
 
-    fn build_nodes_tree(&mut self) -> Tree {
-        let mut tree = Tree::default();
-        let (root_node_id, _) = self.current_widgets.iter().next().unwrap();
+    /// Multiplies the alpha of the colors of the given primitive by the given opacity
+    fn apply_opacity(render_primitive: &mut RenderPrimitive, opacity: f32) {
+        if opacity >= 1.0 {
+            return;
+        }
+
+        let opacity = opacity.max(0.0);
+        match render_primitive {
+            RenderPrimitive::Quad {
+                background_color,
+                border_color,
+                ..
+            } => {
+                background_color.a *= opacity;
+                border_color.a *= opacity;
+            }
+            RenderPrimitive::Text {
+                color, highlights, ..
+            } => {
+                color.a *= opacity;
+                for (_, highlight_color) in highlights {
+                    highlight_color.a *= opacity;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the topmost widget at the given point (in pixels), if any
+    ///
+    /// Widgets are tested in the order they're drawn, so later siblings (including those that are
+    /// [`SelfDirected`](crate::styles::PositionType::SelfDirected) and overlap earlier ones) and
+    /// descendants take precedence. Points outside of an ancestor's clip region do not hit any of
+    /// the clipped widgets, and widgets are skipped according to their `pointer_events` style.
+    pub fn widget_at(&self, point: (f32, f32)) -> Option<Index> {
+        let root = self.node_tree.root_node?;
+        let mut hit = None;
+        self.recurse_node_tree_to_hit_test(
+            root,
+            point,
+            RenderPrimitive::Empty,
+            TransformMatrix::IDENTITY,
+            &mut hit,
+        );
+        hit
+    }
+
+    fn recurse_node_tree_to_hit_test(
+        &self,
+        current_node: Index,
+        point: (f32, f32),
+        prev_clip: RenderPrimitive,
+        parent_transform: TransformMatrix,
+        hit: &mut Option<Index>,
+    ) {
+        let node = match self.nodes.get(current_node) {
+            Some(Some(node)) => node,
+            _ => return,
+        };
+        let layout = match self.layout_cache.rect.get(&current_node) {
+            Some(layout) => *layout,
+            None => return,
+        };
+        if node.resolved_styles.display.resolve_or_default() != Display::Visible {
+            // Nothing within a hidden subtree can be hit
+            return;
+        }
+
+        if let RenderPrimitive::Clip { layout: clip } = &prev_clip {
+            if !clip.contains(&point) {
+                // Nothing within this subtree is visible at this point
+                return;
+            }
+        }
+
+        let pointer_events = self
+            .current_widgets
+            .get(current_node)
+            .and_then(|widget| widget.as_ref())
+            .and_then(|widget| widget.get_props().get_styles())
+            .map(|styles| styles.pointer_events.resolve())
+            .unwrap_or_default();
+        // The point is compared against the untransformed layout, so it's moved into that space
+        let transform = Self::node_transform(&node.resolved_styles, layout, parent_transform);
+        let local_point = transform
+            .inverse()
+            .map(|inverse| inverse.transform_point(point));
+        if matches!(pointer_events, PointerEvents::All | PointerEvents::SelfOnly)
+            && local_point.map_or(false, |local_point| layout.contains(&local_point))
+        {
+            *hit = Some(current_node);
+        }
+        if matches!(
+            pointer_events,
+            PointerEvents::None | PointerEvents::SelfOnly
+        ) {
+            return;
+        }
+
+        // Children are clipped in the same way they're clipped when rendered
+        let next_clip = if let RenderPrimitive::Clip { .. } = node.primitive {
+            RenderPrimitive::Clip {
+                layout: Self::intersect_clip(
+                    transform.transform_rect(Self::clip_bounds(&node.resolved_styles, layout)),
+                    &prev_clip,
+                ),
+            }
+        } else if let Some(overflow_clip) =
+            Self::create_overflow_clip(&node.resolved_styles, layout, transform, &prev_clip)
+        {
+            overflow_clip
+        } else {
+            prev_clip
+        };
+
+        if let Some(children) = self.node_tree.children.get(&current_node) {
+            for child in children {
+                self.recurse_node_tree_to_hit_test(
+                    *child,
+                    point,
+                    next_clip.clone(),
+                    transform,
+                    hit,
+                );
+            }
+        }
+    }
+
+    /// Creates the clip primitive for a node whose content should not overflow its bounds
+    ///
+    /// Along an axis where the content _is_ allowed to overflow, the clip extends to the bounds
+    /// of the enclosing clip (or is left unbounded if there is none). Returns `None` if the
+    /// content may overflow along both axes.
+    fn create_overflow_clip(
+        styles: &Style,
+        layout: Rect,
+        transform: TransformMatrix,
+        prev_clip: &RenderPrimitive,
+    ) -> Option<RenderPrimitive> {
+        let (overflow_x, overflow_y) = styles.resolved_overflow();
+        let clip_x = !matches!(overflow_x, Overflow::Visible);
+        let clip_y = !matches!(overflow_y, Overflow::Visible);
+        if !clip_x && !clip_y {
+            return None;
+        }
+
+        let bounds = match prev_clip {
+            RenderPrimitive::Clip { layout } => *layout,
+            _ => Rect {
+                posx: 0.0,
+                posy: 0.0,
+                width: f32::MAX,
+                height: f32::MAX,
+                z_index: 0.0,
+            },
+        };
+
+        let mut clip = transform.transform_rect(Self::clip_bounds(styles, layout));
+        if !clip_x {
+            clip.posx = bounds.posx;
+            clip.width = bounds.width;
+        }
+        if !clip_y {
+            clip.posy = bounds.posy;
+            clip.height = bounds.height;
+        }
+        // Place the clip just above this node so it only applies to its children
+        clip.z_index = layout.z_index + 0.1;
+
+        Some(RenderPrimitive::Clip {
+            layout: Self::intersect_clip(clip, prev_clip),
+        })
+    }
+
+    /// Intersects a clip region with the enclosing clip (if any)
+    ///
+    /// Content is only drawn within the intersection of all ancestor clips, so a nested clip can
+    /// never extend beyond the clips around it.
+    fn intersect_clip(clip: Rect, prev_clip: &RenderPrimitive) -> Rect {
+        match prev_clip {
+            RenderPrimitive::Clip { layout } => clip.intersection(layout),
+            _ => clip,
+        }
+    }
+
+    /// Returns the clip region of a node: its layout, with each edge moved inwards by its
+    /// [`clip_inset`](Style::clip_inset)
+    fn clip_bounds(styles: &Style, layout: Rect) -> Rect {
+        let inset = styles.clip_inset.resolve_or_default();
+        Rect {
+            posx: layout.posx + inset.left,
+            posy: layout.posy + inset.top,
+            width: (layout.width - inset.left - inset.right).max(0.0),
+            height: (layout.height - inset.top - inset.bottom).max(0.0),
+            z_index: layout.z_index,
+        }
+    }
+
+    /// Forces layout to be recalculated before rendering.
+    ///
+    /// This should be used _sparingly_, if at all.
+    pub fn needs_layout(&mut self, id: Index) {
+        self.dirty_render_nodes.insert(id);
+    }
+
+    pub fn build_render_primitives(&self) -> Vec<RenderPrimitive> {
+        if self.node_tree.root_node.is_none() {
+            return vec![];
+        }
+
+        Self::recurse_node_tree_to_build_primitives(
+            &self.node_tree,
+            &self.layout_cache,
+            &self.nodes,
+            self.node_tree.root_node.unwrap(),
+            0.0,
+            RenderPrimitive::Empty,
+            1.0,
+            TransformMatrix::IDENTITY,
+        )
+    }
+
+    fn build_nodes_tree(&mut self) -> Tree {
+        let mut tree = Tree::default();
+        let (root_node_id, _) = self.current_widgets.iter().next().unwrap();
         tree.root_node = Some(root_node_id);
         tree.children.insert(
             tree.root_node.unwrap(),
             self.get_valid_node_children(tree.root_node.unwrap()),
         );
 
+        let tree_ref = &self.tree;
+        self.disabled
+            .retain(|disabled| tree_ref.contains(*disabled));
+        // Boundaries and accessibility information are only pruned here, once rendering is done,
+        // since widgets being rendered for the first time aren't in the tree yet
+        self.error_boundaries
+            .retain(|boundary, _| tree_ref.contains(*boundary));
+        self.accessibility.retain(|id, _| tree_ref.contains(*id));
+        self.static_layouts
+            .retain(|root, _| tree_ref.contains(*root));
+
         let old_focus = self.focus_tree.current();
         self.focus_tree.clear();
         self.focus_tree.add(root_node_id, &self.tree);
 
+        // Displays are resolved from the top down, so each widget only combines its own with that
+        // of its parent
+        let mut displays = HashMap::<Index, Display>::default();
+        for id in self.tree.flatten() {
+            let parent_display = self
+                .tree
+                .get_parent(id)
+                .and_then(|parent| displays.get(&parent).copied())
+                .unwrap_or_default();
+            displays.insert(
+                id,
+                Self::combine_display(parent_display, self.own_display(id)),
+            );
+        }
+
         for (widget_id, widget) in self.current_widgets.iter().skip(1) {
+            let display = displays
+                .get(&widget_id)
+                .copied()
+                .unwrap_or_else(|| self.display(widget_id));
+            if display == Display::None {
+                // Removed from the layout, along with the rest of its subtree
+                continue;
+            }
+
             let widget_styles = widget.as_ref().unwrap().get_props().get_styles();
             if let Some(widget_styles) = widget_styles {
                 // Only add widgets who have renderable nodes.
@@ -448,7 +1615,7 @@ impl WidgetManager {
             }
 
             let focusable = self.get_focusable(widget_id).unwrap_or_default();
-            if focusable {
+            if focusable && display == Display::Visible {
                 self.focus_tree.add(widget_id, &self.tree);
             }
         }
@@ -459,15 +1626,127 @@ impl WidgetManager {
             }
         }
 
+        // --- Portals --- //
+        // Portals are moved to the end of the root's children, so they're laid out relative to
+        // the root and drawn above (and unclipped by) everything declared around them
+        let tree_ref = &self.tree;
+        self.portals.retain(|portal| tree_ref.contains(*portal));
+        for portal in self.portals.iter() {
+            let parent = match tree.parents.get(portal) {
+                Some(parent) => *parent,
+                None => continue,
+            };
+            if let Some(siblings) = tree.children.get_mut(&parent) {
+                siblings.retain(|sibling| sibling != portal);
+            }
+            tree.parents.insert(*portal, root_node_id);
+            if let Some(root_children) = tree.children.get_mut(&root_node_id) {
+                root_children.push(*portal);
+            }
+        }
+
         tree
     }
 
+    /// Sets whether the given widget (and all of its descendants) is disabled
+    ///
+    /// Disabled widgets are not focusable and don't receive mouse or keyboard events.
+    pub fn set_disabled(&mut self, id: Index, is_disabled: bool) {
+        let changed = if is_disabled {
+            self.disabled.insert(id)
+        } else {
+            self.disabled.shift_remove(&id)
+        };
+
+        if changed {
+            // Rebuilds the focus tree
+            self.dirty_render_nodes.insert(id);
+        }
+    }
+
+    /// Returns how the given widget is displayed, taking the `display` styles of its ancestors into
+    /// account
+    ///
+    /// A widget is hidden if any of its ancestors is hidden, and removed from the layout if any of
+    /// them is, which takes precedence.
+    pub fn display(&self, id: Index) -> Display {
+        let mut display = Display::Visible;
+        let mut current = Some(id);
+        while let Some(index) = current {
+            display = Self::combine_display(display, self.own_display(index));
+            if display == Display::None {
+                break;
+            }
+            current = self.tree.get_parent(index);
+        }
+        display
+    }
+
+    /// Returns the `display` style of the given widget, ignoring its ancestors
+    fn own_display(&self, id: Index) -> Display {
+        self.current_widgets
+            .get(id)
+            .and_then(|widget| widget.as_ref())
+            .and_then(|widget| widget.get_props().get_styles())
+            .map(|styles| styles.display.resolve_or_default())
+            .unwrap_or_default()
+    }
+
+    /// Combines the display of an ancestor with that of its descendant
+    ///
+    /// Removing a widget from the layout takes precedence over hiding it.
+    fn combine_display(ancestor: Display, descendant: Display) -> Display {
+        match (ancestor, descendant) {
+            (Display::None, _) | (_, Display::None) => Display::None,
+            (Display::Hidden, _) | (_, Display::Hidden) => Display::Hidden,
+            _ => Display::Visible,
+        }
+    }
+
+    /// Returns true if the given widget, or any of its ancestors, is disabled
+    pub fn is_disabled(&self, id: Index) -> bool {
+        if self.disabled.is_empty() {
+            return false;
+        }
+
+        let mut current = Some(id);
+        while let Some(index) = current {
+            if self.disabled.contains(&index) {
+                return true;
+            }
+            current = self.tree.get_parent(index);
+        }
+        false
+    }
+
+    /// Sets whether the given widget is a portal
+    ///
+    /// A portal is placed at the root of the node tree rather than within its parent. It's laid out
+    /// relative to the root widget and drawn above everything else, escaping any clipping done by
+    /// its ancestors. Its place in the widget tree is unchanged, so it still inherits styles and
+    /// consumes providers from its ancestors.
+    pub fn set_portal(&mut self, id: Index, is_portal: bool) {
+        let changed = if is_portal {
+            self.portals.insert(id)
+        } else {
+            self.portals.shift_remove(&id)
+        };
+
+        if changed {
+            self.dirty_render_nodes.insert(id);
+        }
+    }
+
     pub fn get_valid_node_children(&self, node_id: Index) -> Vec<Index> {
         let mut children = Vec::new();
         if let Some(node_children) = self.tree.children.get(&node_id) {
             for child_id in node_children {
                 if let Some(child_widget) = &self.current_widgets[*child_id] {
                     if let Some(child_styles) = child_widget.get_props().get_styles() {
+                        if child_styles.display.resolve_or_default() == Display::None {
+                            // Removed from the layout, along with the rest of its subtree
+                            continue;
+                        }
                         if child_styles.render_command.resolve() != RenderCommand::Empty {
                             children.push(*child_id);
                         } else {
@@ -500,6 +1779,116 @@ impl WidgetManager {
         self.nodes[*id].clone()
     }
 
+    /// Sets the preferred size of the given widget (in pixels)
+    ///
+    /// This size is used for the width and height of the widget whenever they aren't explicitly set
+    /// by its styles. Passing `None` removes the preferred size.
+    pub fn set_preferred_size(&mut self, id: Index, size: Option<(f32, f32)>) {
+        let tree = &self.tree;
+        // Widgets that have since been removed no longer need their size
+        self.preferred_sizes.retain(|id, _| tree.contains(*id));
+
+        let previous = match size {
+            Some(size) => self.preferred_sizes.insert(id, size),
+            None => self.preferred_sizes.remove(&id),
+        };
+        if previous != size {
+            self.dirty_render_nodes.insert(id);
+        }
+    }
+
+    /// Measures the preferred size of the given widget (in pixels), independent of its parent
+    ///
+    /// Sizes explicitly given in pixels take precedence, followed by the preferred size reported by
+    /// the widget (text reports the size of its content automatically). Otherwise, the size is
+    /// measured from the widget's children according to its layout type, plus its padding and
+    /// border. Children positioned with [`PositionType::SelfDirected`] are not included, and any
+    /// sizes not given in pixels count as zero.
+    ///
+    /// Returns `None` if the widget hasn't been rendered yet.
+    pub fn measure(&self, id: Index) -> Option<(f32, f32)> {
+        let node = self.nodes.get(id)?.as_ref()?;
+        let styles = &node.resolved_styles;
+        let width = Self::pixels(&styles.width);
+        let height = Self::pixels(&styles.height);
+        if let (Some(width), Some(height)) = (width, height) {
+            return Some((width, height));
+        }
+
+        let content = match self.preferred_sizes.get(&id) {
+            Some(size) => *size,
+            None => self.measure_children(node),
+        };
+        Some((width.unwrap_or(content.0), height.unwrap_or(content.1)))
+    }
+
+    /// Measures the size of the content box needed to fit the children of the given node, plus its
+    /// padding and border
+    fn measure_children(&self, node: &Node) -> (f32, f32) {
+        let styles = &node.resolved_styles;
+        let is_row = matches!(styles.layout_type.resolve_or_default(), LayoutType::Row);
+        let between = if is_row {
+            &styles.col_between
+        } else {
+            &styles.row_between
+        };
+        let between = Self::pixels(between).unwrap_or_default();
+
+        let (mut main, mut cross, mut count) = (0.0_f32, 0.0_f32, 0);
+        for child in self.node_tree.children.get(&node.id).into_iter().flatten() {
+            let is_self_directed = self
+                .nodes
+                .get(*child)
+                .and_then(|child| child.as_ref())
+                .map_or(false, |child| {
+                    matches!(
+                        child.resolved_styles.position_type.resolve_or_default(),
+                        PositionType::SelfDirected
+                    )
+                });
+            if is_self_directed {
+                continue;
+            }
+
+            if let Some((width, height)) = self.measure(*child) {
+                let (child_main, child_cross) = if is_row {
+                    (width, height)
+                } else {
+                    (height, width)
+                };
+                main += child_main;
+                cross = cross.max(child_cross);
+                count += 1;
+            }
+        }
+        if count > 1 {
+            main += between * (count - 1) as f32;
+        }
+
+        let (width, height) = if is_row { (main, cross) } else { (cross, main) };
+        let padding = node.padding();
+        let border = styles
+            .border
+            .resolve_or_default()
+            .resolve_pixels(width, height);
+        let pixels = |units: Units| match units {
+            Units::Pixels(value) => value,
+            _ => 0.0,
+        };
+        (
+            width + pixels(padding.left) + pixels(padding.right) + border.left + border.right,
+            height + pixels(padding.top) + pixels(padding.bottom) + border.top + border.bottom,
+        )
+    }
+
+    /// Returns the given size if it's set in pixels
+    fn pixels(units: &StyleProp<Units>) -> Option<f32> {
+        match units {
+            StyleProp::Value(Units::Pixels(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
     /// Bind a widget so that it re-renders when the binding changes
     ///
     /// # Arguments
@@ -540,6 +1929,9 @@ impl WidgetManager {
     }
 
     pub fn get_focusable(&self, index: Index) -> Option<bool> {
+        if self.is_disabled(index) {
+            return Some(false);
+        }
         self.focus_tracker.get_focusability(index)
     }
 
@@ -548,3 +1940,940 @@ impl WidgetManager {
             .set_focusability(index, focusable, is_parent);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::WidgetManager;
+    use crate::layout_cache::Rect;
+    use crate::node::NodeBuilder;
+    use crate::render_primitive::RenderPrimitive;
+    use crate::styles::{
+        AlignItems, Corner, Direction, Display, Edge, JustifyContent, LayoutType, LayoutWrap,
+        Style, StyleProp, Transform2D, Units,
+    };
+    use crate::{Color, Index, KayakContextRef, Tree, Widget};
+
+    #[derive(Debug, Default, Clone, PartialEq)]
+    struct TestWidget {
+        id: Index,
+        key: Option<String>,
+        props: (),
+    }
+
+    impl TestWidget {
+        fn keyed(key: &str) -> Self {
+            Self {
+                key: Some(key.to_string()),
+                ..Default::default()
+            }
+        }
+    }
+
+    impl Widget for TestWidget {
+        type Props = ();
+
+        fn constructor(props: Self::Props) -> Self {
+            Self {
+                props,
+                ..Default::default()
+            }
+        }
+
+        fn get_id(&self) -> Index {
+            self.id
+        }
+
+        fn set_id(&mut self, id: Index) {
+            self.id = id;
+        }
+
+        fn get_props(&self) -> &Self::Props {
+            &self.props
+        }
+
+        fn get_props_mut(&mut self) -> &mut Self::Props {
+            &mut self.props
+        }
+
+        fn render(&mut self, _context: &mut KayakContextRef) {}
+
+        fn get_key(&self) -> Option<&str> {
+            self.key.as_deref()
+        }
+
+        fn set_key(&mut self, key: Option<String>) {
+            self.key = key;
+        }
+    }
+
+    #[test]
+    fn should_match_keyed_widgets_by_key() {
+        let mut manager = WidgetManager::new();
+        let (_, root) = manager.create_widget(0, TestWidget::default(), None);
+        let (_, a) = manager.create_widget(0, TestWidget::keyed("a"), Some(root));
+        let (_, b) = manager.create_widget(1, TestWidget::keyed("b"), Some(root));
+        let (_, unkeyed) = manager.create_widget(2, TestWidget::default(), Some(root));
+
+        // Reordered: "b" now comes first
+        let (_, reused_b) = manager.create_widget(0, TestWidget::keyed("b"), Some(root));
+        assert_eq!(b, reused_b);
+        let (_, reused_a) = manager.create_widget(1, TestWidget::keyed("a"), Some(root));
+        assert_eq!(a, reused_a);
+
+        // Unkeyed widgets are still matched by index, but never to a keyed widget
+        let (_, reused_unkeyed) = manager.create_widget(2, TestWidget::default(), Some(root));
+        assert_eq!(unkeyed, reused_unkeyed);
+        let (_, created) = manager.create_widget(0, TestWidget::default(), Some(root));
+        assert_ne!(a, created);
+        assert_ne!(b, created);
+
+        // A new key creates a new widget
+        let (_, c) = manager.create_widget(1, TestWidget::keyed("c"), Some(root));
+        assert!(![a, b, unkeyed].contains(&c));
+    }
+
+    #[test]
+    fn should_disable_descendants() {
+        let mut manager = WidgetManager::new();
+        let (_, root) = manager.create_widget(0, TestWidget::default(), None);
+        let (_, panel) = manager.create_widget(0, TestWidget::default(), Some(root));
+        let (_, sibling) = manager.create_widget(1, TestWidget::default(), Some(root));
+        let (_, input) = manager.create_widget(0, TestWidget::default(), Some(panel));
+        manager.set_focusable(Some(true), input, false);
+
+        manager.set_disabled(panel, true);
+        assert!(manager.is_disabled(panel));
+        assert!(manager.is_disabled(input));
+        assert!(!manager.is_disabled(root));
+        assert!(!manager.is_disabled(sibling));
+        assert_eq!(Some(false), manager.get_focusable(input));
+
+        manager.set_disabled(panel, false);
+        assert!(!manager.is_disabled(input));
+        assert_eq!(Some(true), manager.get_focusable(input));
+    }
+
+    /// Adds a node to the manager's node tree with the given layout
+    fn add_node(
+        manager: &mut WidgetManager,
+        node_tree: &mut Tree,
+        parent: Option<Index>,
+        rect: (f32, f32, f32, f32),
+        primitive: RenderPrimitive,
+    ) -> Index {
+        let index = parent
+            .and_then(|parent| manager.tree.children.get(&parent))
+            .map(|children| children.len())
+            .unwrap_or_default();
+        let (_, id) = manager.create_widget(index, TestWidget::default(), parent);
+        manager.nodes[id] = Some(
+            NodeBuilder::new(id, Style::initial())
+                .with_primitive(primitive)
+                .build(),
+        );
+        manager.layout_cache.rect.insert(
+            id,
+            Rect {
+                posx: rect.0,
+                posy: rect.1,
+                width: rect.2,
+                height: rect.3,
+                z_index: 0.0,
+            },
+        );
+        node_tree.add(id, parent);
+        id
+    }
+
+    #[test]
+    fn should_hit_topmost_widget_within_clip() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let empty = RenderPrimitive::Empty;
+        let root = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 100.0, 100.0),
+            empty.clone(),
+        );
+        let clip_layout = Rect {
+            posx: 0.0,
+            posy: 0.0,
+            width: 50.0,
+            height: 50.0,
+            z_index: 0.0,
+        };
+        let clip = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (0.0, 0.0, 50.0, 50.0),
+            RenderPrimitive::Clip {
+                layout: clip_layout,
+            },
+        );
+        // A child overflowing its clip
+        let clipped = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(clip),
+            (0.0, 0.0, 80.0, 80.0),
+            empty.clone(),
+        );
+        // A later sibling overlapping the clip
+        let overlay = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (40.0, 40.0, 20.0, 20.0),
+            empty,
+        );
+        manager.node_tree = node_tree;
+
+        assert_eq!(Some(clipped), manager.widget_at((10.0, 10.0)));
+        assert_eq!(Some(overlay), manager.widget_at((45.0, 45.0)));
+        // Outside the clip, only the root is hit
+        assert_eq!(Some(root), manager.widget_at((70.0, 70.0)));
+        assert_eq!(None, manager.widget_at((150.0, 150.0)));
+    }
+
+    #[test]
+    fn should_intersect_nested_clips() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let empty = RenderPrimitive::Empty;
+        let clip = RenderPrimitive::Clip {
+            layout: Rect::default(),
+        };
+        let root = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 200.0, 200.0),
+            empty.clone(),
+        );
+        let outer = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (0.0, 0.0, 100.0, 100.0),
+            clip.clone(),
+        );
+        // Partly outside of the outer clip
+        let inner = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(outer),
+            (50.0, 50.0, 100.0, 100.0),
+            clip,
+        );
+        let content = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(inner),
+            (50.0, 50.0, 100.0, 100.0),
+            empty,
+        );
+        manager.node_tree = node_tree;
+
+        let clips: Vec<_> = manager
+            .build_render_primitives()
+            .into_iter()
+            .filter_map(|primitive| match primitive {
+                RenderPrimitive::Clip { layout } => {
+                    Some((layout.posx, layout.posy, layout.width, layout.height))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!((0.0, 0.0, 100.0, 100.0), clips[0]);
+        // Only the intersection of both clips is drawn to
+        assert_eq!((50.0, 50.0, 50.0, 50.0), clips[1]);
+        assert!(clips
+            .iter()
+            .all(|(x, y, width, height)| x + width <= 100.0 && y + height <= 100.0));
+
+        assert_eq!(Some(content), manager.widget_at((75.0, 75.0)));
+        // Within the inner clip, but outside of the outer one
+        assert_eq!(Some(root), manager.widget_at((120.0, 75.0)));
+        assert_eq!(Some(root), manager.widget_at((75.0, 120.0)));
+    }
+
+    #[test]
+    fn should_adjust_clips_by_their_inset() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let empty = RenderPrimitive::Empty;
+        let root = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 100.0, 100.0),
+            empty.clone(),
+        );
+        let clip = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (10.0, 10.0, 50.0, 50.0),
+            RenderPrimitive::Clip {
+                layout: Rect::default(),
+            },
+        );
+        let clipped = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(clip),
+            (0.0, 0.0, 80.0, 80.0),
+            empty,
+        );
+        manager.node_tree = node_tree;
+        manager.nodes[clip]
+            .as_mut()
+            .unwrap()
+            .resolved_styles
+            .clip_inset = StyleProp::Value(Edge::new(-5.0, -5.0, 10.0, 0.0));
+
+        // The top and right edges are expanded
+        assert_eq!(Some(clipped), manager.widget_at((30.0, 7.0)));
+        assert_eq!(Some(clipped), manager.widget_at((63.0, 30.0)));
+        // The bottom edge is shrunk
+        assert_eq!(Some(clipped), manager.widget_at((30.0, 49.0)));
+        assert_eq!(Some(root), manager.widget_at((30.0, 55.0)));
+        // The left edge is unchanged
+        assert_eq!(Some(root), manager.widget_at((8.0, 30.0)));
+
+        let clip_layout = manager
+            .build_render_primitives()
+            .into_iter()
+            .find_map(|primitive| match primitive {
+                RenderPrimitive::Clip { layout } => Some(layout),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(
+            (10.0, 5.0, 55.0, 45.0),
+            (
+                clip_layout.posx,
+                clip_layout.posy,
+                clip_layout.width,
+                clip_layout.height
+            )
+        );
+    }
+
+    #[test]
+    fn should_apply_transforms_to_descendants() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let empty = RenderPrimitive::Empty;
+        let root = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 200.0, 200.0),
+            empty.clone(),
+        );
+        let moved = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (0.0, 0.0, 40.0, 40.0),
+            empty.clone(),
+        );
+        let moved_child = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(moved),
+            (0.0, 0.0, 10.0, 10.0),
+            empty.clone(),
+        );
+        let scaled = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (50.0, 50.0, 20.0, 20.0),
+            empty,
+        );
+        manager.node_tree = node_tree;
+        for (id, transform) in [
+            (moved, Transform2D::from_translation(100.0, 0.0)),
+            (scaled, Transform2D::from_scale(2.0, 2.0)),
+        ] {
+            manager.nodes[id]
+                .as_mut()
+                .unwrap()
+                .resolved_styles
+                .transform = StyleProp::Value(transform);
+        }
+
+        // Descendants are moved along with their ancestors
+        assert_eq!(Some(moved_child), manager.widget_at((105.0, 5.0)));
+        assert_eq!(Some(moved), manager.widget_at((130.0, 30.0)));
+        assert_eq!(Some(root), manager.widget_at((5.0, 5.0)));
+        // Scaling happens around the center of the widget
+        assert_eq!(Some(scaled), manager.widget_at((45.0, 45.0)));
+        assert_eq!(Some(scaled), manager.widget_at((78.0, 78.0)));
+        assert_eq!(Some(root), manager.widget_at((82.0, 82.0)));
+
+        let transforms: Vec<_> = manager
+            .build_render_primitives()
+            .into_iter()
+            .filter_map(|primitive| match primitive {
+                RenderPrimitive::Transform { matrix } => Some(matrix.transform_point((0.0, 0.0))),
+                _ => None,
+            })
+            .collect();
+        // Each transform is reset once its widget (and its descendants) have been drawn
+        assert_eq!(
+            vec![(100.0, 0.0), (0.0, 0.0), (-60.0, -60.0), (0.0, 0.0)],
+            transforms
+        );
+    }
+
+    #[test]
+    fn should_transform_around_origin() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let empty = RenderPrimitive::Empty;
+        let root = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 200.0, 200.0),
+            empty.clone(),
+        );
+        let scaled = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (50.0, 50.0, 20.0, 20.0),
+            empty.clone(),
+        );
+        let rotated = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (100.0, 100.0, 40.0, 10.0),
+            empty,
+        );
+        manager.node_tree = node_tree;
+        let styles = &mut manager.nodes[scaled].as_mut().unwrap().resolved_styles;
+        styles.transform = StyleProp::Value(Transform2D::from_scale(2.0, 2.0));
+        styles.transform_origin = StyleProp::Value((Units::Pixels(0.0), Units::Pixels(0.0)));
+        let styles = &mut manager.nodes[rotated].as_mut().unwrap().resolved_styles;
+        styles.transform =
+            StyleProp::Value(Transform2D::from_rotation(std::f32::consts::FRAC_PI_2));
+        styles.transform_origin =
+            StyleProp::Value((Units::Percentage(0.0), Units::Percentage(100.0)));
+
+        // Scaled from the top-left corner
+        assert_eq!(Some(root), manager.widget_at((45.0, 45.0)));
+        assert_eq!(Some(scaled), manager.widget_at((52.0, 52.0)));
+        assert_eq!(Some(scaled), manager.widget_at((85.0, 85.0)));
+        // Rotated (clockwise) around the bottom-left corner, so it points downwards
+        assert_eq!(Some(rotated), manager.widget_at((105.0, 130.0)));
+        assert_eq!(Some(root), manager.widget_at((130.0, 105.0)));
+    }
+
+    #[test]
+    fn should_mirror_children_of_rtl_nodes() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let empty = RenderPrimitive::Empty;
+        let root = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 100.0, 10.0),
+            empty.clone(),
+        );
+        let first = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (0.0, 0.0, 30.0, 10.0),
+            empty.clone(),
+        );
+        let second = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (30.0, 0.0, 20.0, 10.0),
+            empty.clone(),
+        );
+        let grandchild = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(first),
+            (5.0, 0.0, 10.0, 10.0),
+            empty,
+        );
+        manager.node_tree = node_tree;
+        manager.nodes[root]
+            .as_mut()
+            .unwrap()
+            .resolved_styles
+            .direction = StyleProp::Value(Direction::Rtl);
+
+        manager.mirror_layouts();
+
+        let posx = |id| manager.layout_cache.rect.get(&id).unwrap().posx;
+        assert_eq!(0.0, posx(root));
+        assert_eq!(70.0, posx(first));
+        assert_eq!(50.0, posx(second));
+        // Descendants move with their mirrored ancestor, but are not mirrored themselves
+        assert_eq!(75.0, posx(grandchild));
+    }
+
+    #[test]
+    fn should_multiply_opacity_of_ancestors() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let quad = RenderPrimitive::Quad {
+            layout: Rect::default(),
+            background_color: Color::WHITE,
+            border_color: Color::WHITE,
+            border: Edge::default(),
+            border_radius: Corner::default(),
+        };
+        let root = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 100.0, 100.0),
+            RenderPrimitive::Empty,
+        );
+        let child = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (0.0, 0.0, 50.0, 50.0),
+            quad,
+        );
+        manager.node_tree = node_tree;
+        for (id, opacity) in [(root, 0.5), (child, 0.5)] {
+            manager.nodes[id].as_mut().unwrap().resolved_styles.opacity = StyleProp::Value(opacity);
+        }
+
+        let primitives = manager.build_render_primitives();
+        let alpha = primitives.iter().find_map(|primitive| match primitive {
+            RenderPrimitive::Quad {
+                background_color, ..
+            } => Some(background_color.a),
+            _ => None,
+        });
+        assert_eq!(Some(0.25), alpha);
+    }
+
+    #[test]
+    fn should_not_draw_hidden_nodes() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let quad = RenderPrimitive::Quad {
+            layout: Rect::default(),
+            background_color: Color::WHITE,
+            border_color: Color::WHITE,
+            border: Edge::default(),
+            border_radius: Corner::default(),
+        };
+        let root = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 100.0, 100.0),
+            RenderPrimitive::Empty,
+        );
+        let parent = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (0.0, 0.0, 50.0, 50.0),
+            RenderPrimitive::Empty,
+        );
+        add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(parent),
+            (0.0, 0.0, 50.0, 50.0),
+            quad,
+        );
+        manager.node_tree = node_tree;
+        manager.nodes[parent]
+            .as_mut()
+            .unwrap()
+            .resolved_styles
+            .display = StyleProp::Value(Display::Hidden);
+
+        let primitives = manager.build_render_primitives();
+        let has_quad = primitives
+            .iter()
+            .any(|primitive| matches!(primitive, RenderPrimitive::Quad { .. }));
+        assert!(!has_quad);
+    }
+
+    #[test]
+    fn should_reuse_static_layouts() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let empty = RenderPrimitive::Empty;
+        let root = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 200.0, 200.0),
+            empty.clone(),
+        );
+        let container = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (0.0, 0.0, 0.0, 0.0),
+            empty.clone(),
+        );
+        let child = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(container),
+            (0.0, 0.0, 0.0, 0.0),
+            empty,
+        );
+        manager.node_tree = node_tree;
+
+        for (id, left, size) in [
+            (root, 0.0, 200.0),
+            (container, 10.0, 100.0),
+            (child, 5.0, 20.0),
+        ] {
+            let styles = &mut manager.nodes[id].as_mut().unwrap().resolved_styles;
+            styles.left = StyleProp::Value(Units::Pixels(left));
+            styles.width = StyleProp::Value(Units::Pixels(size));
+            styles.height = StyleProp::Value(Units::Pixels(size));
+        }
+        manager.set_static(container, true);
+        manager.calculate_layout();
+
+        let posx = |manager: &WidgetManager, id| manager.layout_cache.rect.get(&id).unwrap().posx;
+        assert_eq!(15.0, posx(&manager, child));
+
+        // Changing the child without rendering it keeps the cached layout, which moves with its root
+        for (id, left) in [(container, 30.0), (child, 25.0)] {
+            let styles = &mut manager.nodes[id].as_mut().unwrap().resolved_styles;
+            styles.left = StyleProp::Value(Units::Pixels(left));
+        }
+        manager.calculate_layout();
+        assert_eq!(30.0, posx(&manager, container));
+        assert_eq!(35.0, posx(&manager, child));
+
+        manager.invalidate_static_layout(child);
+        manager.calculate_layout();
+        assert_eq!(55.0, posx(&manager, child));
+    }
+
+    #[test]
+    fn should_measure_content_size() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let empty = RenderPrimitive::Empty;
+        let root = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 100.0, 100.0),
+            empty.clone(),
+        );
+        let sized = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (0.0, 0.0, 0.0, 0.0),
+            empty.clone(),
+        );
+        let preferred = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (0.0, 0.0, 0.0, 0.0),
+            empty,
+        );
+        manager.node_tree = node_tree;
+
+        let root_styles = &mut manager.nodes[root].as_mut().unwrap().resolved_styles;
+        root_styles.layout_type = StyleProp::Value(LayoutType::Row);
+        root_styles.col_between = StyleProp::Value(Units::Pixels(5.0));
+        root_styles.padding = StyleProp::Value(Edge::all(Units::Pixels(2.0)));
+        let sized_styles = &mut manager.nodes[sized].as_mut().unwrap().resolved_styles;
+        sized_styles.width = StyleProp::Value(Units::Pixels(30.0));
+        sized_styles.height = StyleProp::Value(Units::Pixels(20.0));
+        manager.set_preferred_size(preferred, Some((20.0, 10.0)));
+
+        assert_eq!(Some((30.0, 20.0)), manager.measure(sized));
+        assert_eq!(Some((20.0, 10.0)), manager.measure(preferred));
+        assert_eq!(Some((59.0, 24.0)), manager.measure(root));
+    }
+
+    #[test]
+    fn should_align_children_of_baseline_rows() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let empty = RenderPrimitive::Empty;
+        let root = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 100.0, 40.0),
+            empty.clone(),
+        );
+        let small = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (0.0, 0.0, 20.0, 12.0),
+            empty.clone(),
+        );
+        let large = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (20.0, 0.0, 40.0, 36.0),
+            empty.clone(),
+        );
+        // A container whose baseline comes from its (offset) child
+        let container = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (60.0, 0.0, 20.0, 20.0),
+            empty.clone(),
+        );
+        let nested = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(container),
+            (60.0, 4.0, 20.0, 12.0),
+            empty.clone(),
+        );
+        let no_baseline = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(root),
+            (80.0, 0.0, 20.0, 20.0),
+            empty,
+        );
+        manager.node_tree = node_tree;
+
+        let root_styles = &mut manager.nodes[root].as_mut().unwrap().resolved_styles;
+        root_styles.layout_type = StyleProp::Value(LayoutType::Row);
+        root_styles.align_items = StyleProp::Value(AlignItems::Baseline);
+        manager.set_baseline(small, Some(10.0));
+        manager.set_baseline(large, Some(30.0));
+        manager.set_baseline(nested, Some(10.0));
+        assert_eq!(Some(14.0), manager.baseline(container));
+        assert_eq!(None, manager.baseline(no_baseline));
+
+        manager.align_baselines();
+
+        let posy = |id| manager.layout_cache.rect.get(&id).unwrap().posy;
+        assert_eq!(20.0, posy(small));
+        assert_eq!(0.0, posy(large));
+        assert_eq!(16.0, posy(container));
+        // Descendants move with their aligned ancestor
+        assert_eq!(20.0, posy(nested));
+        assert_eq!(0.0, posy(no_baseline));
+        assert_eq!(Some(30.0), manager.baseline(root));
+    }
+
+    #[test]
+    fn should_justify_and_align_children() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let empty = RenderPrimitive::Empty;
+        let row = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 100.0, 40.0),
+            empty.clone(),
+        );
+        let first = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(row),
+            (0.0, 0.0, 20.0, 10.0),
+            empty.clone(),
+        );
+        let second = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(row),
+            (20.0, 0.0, 20.0, 20.0),
+            empty.clone(),
+        );
+        let nested = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(second),
+            (20.0, 0.0, 10.0, 10.0),
+            empty.clone(),
+        );
+        let column = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(row),
+            (40.0, 0.0, 30.0, 40.0),
+            empty.clone(),
+        );
+        let item = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(column),
+            (40.0, 0.0, 10.0, 10.0),
+            empty,
+        );
+        manager.node_tree = node_tree;
+
+        let row_styles = &mut manager.nodes[row].as_mut().unwrap().resolved_styles;
+        row_styles.layout_type = StyleProp::Value(LayoutType::Row);
+        row_styles.justify_content = StyleProp::Value(JustifyContent::SpaceBetween);
+        row_styles.align_items = StyleProp::Value(AlignItems::Center);
+        let column_styles = &mut manager.nodes[column].as_mut().unwrap().resolved_styles;
+        column_styles.layout_type = StyleProp::Value(LayoutType::Column);
+        column_styles.justify_content = StyleProp::Value(JustifyContent::End);
+        column_styles.align_items = StyleProp::Value(AlignItems::End);
+
+        manager.arrange_children();
+
+        let pos = |id| {
+            let rect = manager.layout_cache.rect.get(&id).unwrap();
+            (rect.posx, rect.posy)
+        };
+        // The 30px of free space is split between the three children of the row
+        assert_eq!((0.0, 15.0), pos(first));
+        assert_eq!((35.0, 10.0), pos(second));
+        assert_eq!((70.0, 0.0), pos(column));
+        // Descendants move with their arranged ancestor
+        assert_eq!((35.0, 10.0), pos(nested));
+        assert_eq!((90.0, 30.0), pos(item));
+    }
+
+    #[test]
+    fn should_wrap_children_onto_new_lines() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let empty = RenderPrimitive::Empty;
+        let row = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 100.0, 20.0),
+            empty.clone(),
+        );
+        let first = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(row),
+            (0.0, 0.0, 40.0, 10.0),
+            empty.clone(),
+        );
+        let second = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(row),
+            (50.0, 0.0, 40.0, 20.0),
+            empty.clone(),
+        );
+        // Overflows the row, so it's moved onto a new line
+        let third = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(row),
+            (100.0, 0.0, 30.0, 10.0),
+            empty.clone(),
+        );
+        let nested = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(third),
+            (110.0, 0.0, 10.0, 10.0),
+            empty,
+        );
+        manager.node_tree = node_tree;
+
+        let row_styles = &mut manager.nodes[row].as_mut().unwrap().resolved_styles;
+        row_styles.layout_type = StyleProp::Value(LayoutType::Row);
+        row_styles.layout_wrap = StyleProp::Value(LayoutWrap::Wrap);
+        row_styles.height = StyleProp::Value(Units::Auto);
+        row_styles.col_between = StyleProp::Value(Units::Pixels(10.0));
+        row_styles.row_between = StyleProp::Value(Units::Pixels(5.0));
+
+        assert!(manager.wrap_layouts());
+
+        let pos = |id| {
+            let rect = manager.layout_cache.rect.get(&id).unwrap();
+            (rect.posx, rect.posy)
+        };
+        assert_eq!((0.0, 0.0), pos(first));
+        assert_eq!((50.0, 0.0), pos(second));
+        // The new line starts below the tallest child of the first one
+        assert_eq!((0.0, 25.0), pos(third));
+        assert_eq!((10.0, 25.0), pos(nested));
+        // An auto height row grows to fit every line
+        assert_eq!(35.0, manager.layout_cache.rect.get(&row).unwrap().height);
+        assert_eq!(Some(35.0), manager.nodes[row].as_ref().unwrap().wrap_height);
+
+        // Wrapping again leaves the settled layout as it is
+        assert!(!manager.wrap_layouts());
+        assert_eq!(25.0, manager.layout_cache.rect.get(&third).unwrap().posy);
+    }
+
+    #[test]
+    fn should_include_margins_when_wrapping() {
+        let mut manager = WidgetManager::new();
+        let mut node_tree = Tree::default();
+        let empty = RenderPrimitive::Empty;
+        let row = add_node(
+            &mut manager,
+            &mut node_tree,
+            None,
+            (0.0, 0.0, 100.0, 20.0),
+            empty.clone(),
+        );
+        let first = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(row),
+            (0.0, 0.0, 40.0, 10.0),
+            empty.clone(),
+        );
+        // Only fits next to the first child when their margins are left out
+        let second = add_node(
+            &mut manager,
+            &mut node_tree,
+            Some(row),
+            (65.0, 2.0, 40.0, 20.0),
+            empty,
+        );
+        manager.node_tree = node_tree;
+
+        let row_styles = &mut manager.nodes[row].as_mut().unwrap().resolved_styles;
+        row_styles.layout_type = StyleProp::Value(LayoutType::Row);
+        row_styles.layout_wrap = StyleProp::Value(LayoutWrap::Wrap);
+        row_styles.height = StyleProp::Value(Units::Auto);
+        row_styles.col_between = StyleProp::Value(Units::Pixels(10.0));
+        row_styles.row_between = StyleProp::Value(Units::Pixels(5.0));
+        let first_styles = &mut manager.nodes[first].as_mut().unwrap().resolved_styles;
+        first_styles.margin_right = StyleProp::Value(Units::Pixels(20.0));
+        let second_styles = &mut manager.nodes[second].as_mut().unwrap().resolved_styles;
+        second_styles.margin_left = StyleProp::Value(Units::Pixels(5.0));
+        second_styles.margin_top = StyleProp::Value(Units::Pixels(2.0));
+
+        assert!(manager.wrap_layouts());
+
+        let second_rect = manager.layout_cache.rect.get(&second).unwrap();
+        // The new line starts after the first one, and the child is offset by its own margins
+        assert_eq!((5.0, 17.0), (second_rect.posx, second_rect.posy));
+        // The line is as tall as the child along with its top margin
+        assert_eq!(37.0, manager.layout_cache.rect.get(&row).unwrap().height);
+    }
+}
@@ -6,7 +6,8 @@ use unicode_segmentation::UnicodeSegmentation;
 
 use crate::utility::{BreakableWord, MISSING, SPACE};
 use crate::{
-    utility, Alignment, Glyph, GlyphRect, Grapheme, Line, Sdf, TextLayout, TextProperties,
+    utility, Alignment, EmojiRect, Glyph, GlyphRect, Grapheme, Line, Sdf, TextLayout,
+    TextProperties,
 };
 
 #[cfg(feature = "bevy_renderer")]
@@ -17,6 +18,7 @@ pub struct KayakFont {
     pub atlas_image: Handle<Image>,
     pub missing_glyph: Option<char>,
     char_ids: HashMap<char, u32>,
+    kerning: HashMap<(char, char), f32>,
     max_glyph_size: (f32, f32),
 }
 
@@ -26,6 +28,7 @@ pub struct KayakFont {
     pub sdf: Sdf,
     pub missing_glyph: Option<char>,
     char_ids: HashMap<char, u32>,
+    kerning: HashMap<(char, char), f32>,
     max_glyph_size: (f32, f32),
 }
 
@@ -44,6 +47,16 @@ impl KayakFont {
             .map(|(idx, glyph)| (glyph.unicode, idx as u32))
             .collect();
 
+        let kerning: HashMap<(char, char), f32> = sdf
+            .kerning
+            .iter()
+            .filter_map(|data| {
+                let left = char::from_u32(data.unicode1)?;
+                let right = char::from_u32(data.unicode2)?;
+                Some(((left, right), data.advance))
+            })
+            .collect();
+
         let missing_glyph = if char_ids.contains_key(&MISSING) {
             Some(MISSING)
         } else if char_ids.contains_key(&SPACE) {
@@ -58,6 +71,7 @@ impl KayakFont {
             atlas_image,
             missing_glyph,
             char_ids,
+            kerning,
             max_glyph_size,
         }
     }
@@ -79,13 +93,22 @@ impl KayakFont {
         let tab_width = self.get_tab_width(properties);
 
         let mut width = 0.0;
-        for c in word.chars() {
-            if utility::is_space(c) {
-                width += space_width;
-            } else if utility::is_tab(c) {
-                width += tab_width;
-            } else if let Some(glyph) = self.get_glyph(c) {
-                width += glyph.advance * properties.font_size;
+        for cluster in self.shape(word, properties) {
+            width += cluster.kerning;
+
+            if self.is_missing_emoji(cluster.content) {
+                width += self.get_emoji_width(properties);
+                continue;
+            }
+
+            for c in cluster.glyphs() {
+                if utility::is_space(c) {
+                    width += space_width + properties.letter_spacing;
+                } else if utility::is_tab(c) {
+                    width += tab_width + properties.letter_spacing;
+                } else if let Some(glyph) = self.get_glyph(c) {
+                    width += glyph.advance * properties.font_size + properties.letter_spacing;
+                }
             }
         }
 
@@ -106,6 +129,9 @@ impl KayakFont {
 
         let mut size: (f32, f32) = (0.0, 0.0);
         let mut glyph_rects = Vec::new();
+        let mut emoji_rects = Vec::new();
+        // The line index of each emoji rect (used when shifting lines)
+        let mut emoji_lines = Vec::new();
         let mut lines = Vec::new();
 
         // This is the normalized glyph bounds for all glyphs in the atlas.
@@ -128,9 +154,11 @@ impl KayakFont {
         //   2. Split each word by its UAX #29 grapheme clusters.
         //      This step is important since "a̐" is technically two characters (codepoints),
         //      but rendered as a single glyph.
-        //   3. Process each character within the grapheme cluster.
+        //   3. Shape the grapheme clusters of each word, combining any that form a ligature and
+        //      finding the kerning between them (unless shaping is disabled).
+        //   4. Process each character within the grapheme cluster.
         //
-        // FIXME: I think #3 is wrong— we probably need to process the full grapheme cluster
+        // FIXME: I think #4 is wrong— we probably need to process the full grapheme cluster
         //        rather than each character individually,— however, this might take some
         //        careful thought and consideration, so it should probably be addressed later.
         //        Once resolved, this comment should be updated accordingly.
@@ -167,7 +195,8 @@ impl KayakFont {
             }
 
             // === Iterate Grapheme Clusters === //
-            for grapheme_content in word.content.graphemes(true) {
+            for cluster in self.shape(word.content, properties) {
+                let grapheme_content = cluster.content;
                 let mut grapheme = Grapheme {
                     position: (line.width(), properties.line_height * lines.len() as f32),
                     glyph_index,
@@ -175,10 +204,53 @@ impl KayakFont {
                     ..Default::default()
                 };
 
-                for c in grapheme_content.chars() {
-                    char_index += 1;
-                    grapheme.char_total += 1;
+                let char_total = grapheme_content.chars().count();
+                char_index += char_total;
+                grapheme.char_total += char_total;
+                // Kerning adjusts the space between this cluster and the previous one
+                grapheme.size.0 += cluster.kerning;
+
+                if self.is_missing_emoji(grapheme_content) {
+                    // The font can't render this emoji, so reserve a square for it (based on the
+                    // font size) that can be filled in by the renderer instead
+                    let emoji_size = properties.font_size;
+
+                    let fallback = self.missing_glyph.and_then(|missing| {
+                        self.get_glyph(missing).map(|glyph| {
+                            let (left, top) = glyph
+                                .plane_bounds
+                                .as_ref()
+                                .map(|rect| (rect.left, rect.top))
+                                .unwrap_or_default();
+                            GlyphRect {
+                                position: (
+                                    grapheme.position.0 + left * properties.font_size,
+                                    grapheme.position.1 - top * properties.font_size,
+                                ),
+                                size: norm_glyph_bounds,
+                                content: glyph.unicode,
+                            }
+                        })
+                    });
+
+                    emoji_rects.push(EmojiRect {
+                        position: (
+                            grapheme.position.0,
+                            grapheme.position.1 + (properties.line_height - emoji_size) / 2.0,
+                        ),
+                        size: (emoji_size, emoji_size),
+                        content: grapheme_content.to_string(),
+                        fallback,
+                    });
+                    emoji_lines.push(lines.len());
+
+                    grapheme.size.0 += self.get_emoji_width(properties);
+                    line.add_grapheme(grapheme);
+                    size.0 = size.0.max(line.width());
+                    continue;
+                }
 
+                for c in cluster.glyphs() {
                     if utility::is_newline(c) {
                         // Newlines (hard breaks) are already accounted for by the line break algorithm
                         continue;
@@ -187,10 +259,10 @@ impl KayakFont {
                     if utility::is_space(c) {
                         if !will_break {
                             // Don't add the space if we're about to break the line
-                            grapheme.size.0 += space_width;
+                            grapheme.size.0 += space_width + properties.letter_spacing;
                         }
                     } else if utility::is_tab(c) {
-                        grapheme.size.0 += tab_width;
+                        grapheme.size.0 += tab_width + properties.letter_spacing;
                     } else {
                         let glyph = self.get_glyph(c).or_else(|| {
                             if let Some(missing) = self.missing_glyph {
@@ -227,7 +299,8 @@ impl KayakFont {
 
                             glyph_index += 1;
                             grapheme.glyph_total += 1;
-                            grapheme.size.0 += glyph.advance * properties.font_size;
+                            grapheme.size.0 +=
+                                glyph.advance * properties.font_size + properties.letter_spacing;
                         }
                     }
                 }
@@ -242,7 +315,7 @@ impl KayakFont {
         size.1 = properties.line_height * lines.len() as f32;
 
         // === Shift Lines & Glyphs === //
-        for line in lines.iter() {
+        for (line_index, line) in lines.iter().enumerate() {
             let shift_x = match properties.alignment {
                 Alignment::Start => 0.0,
                 Alignment::Middle => (properties.max_size.0 - line.width()) / 2.0,
@@ -256,9 +329,20 @@ impl KayakFont {
                 let rect = &mut glyph_rects[index];
                 rect.position.0 += shift_x;
             }
+
+            for (emoji, _) in emoji_rects
+                .iter_mut()
+                .zip(emoji_lines.iter())
+                .filter(|(_, emoji_line)| **emoji_line == line_index)
+            {
+                emoji.position.0 += shift_x;
+                if let Some(fallback) = &mut emoji.fallback {
+                    fallback.position.0 += shift_x;
+                }
+            }
         }
 
-        TextLayout::new(glyph_rects, lines, size, properties)
+        TextLayout::new(glyph_rects, emoji_rects, lines, size, properties)
     }
 
     /// Attempts to find the next line break for a given set of [breakable words](BreakableWord).
@@ -415,6 +499,107 @@ impl KayakFont {
         }
     }
 
+    /// Splits a word into the clusters it should be laid out with.
+    ///
+    /// Each cluster is a single grapheme unless it's been combined with the following graphemes to
+    /// form a ligature. If [shaping](TextProperties::shaping) is disabled, no ligatures are formed
+    /// and no kerning is applied.
+    ///
+    /// Kerning is only applied between the clusters of a word. This keeps the width of each word
+    /// independent of its neighbors, which the line break algorithm relies on.
+    fn shape<'a>(&self, word: &'a str, properties: TextProperties) -> Vec<ShapedCluster<'a>> {
+        let graphemes = word.grapheme_indices(true).collect::<Vec<_>>();
+        let mut clusters = Vec::with_capacity(graphemes.len());
+        let mut prev: Option<char> = None;
+        let mut index = 0;
+
+        while index < graphemes.len() {
+            let (start, content) = graphemes[index];
+            let ligature = if properties.shaping {
+                self.find_ligature(&graphemes[index..])
+            } else {
+                None
+            };
+
+            let (content, ligature, count) = match ligature {
+                Some((ligature, count)) => {
+                    let (last_start, last) = graphemes[index + count - 1];
+                    (&word[start..last_start + last.len()], Some(ligature), count)
+                }
+                None => (content, None, 1),
+            };
+
+            let first = ligature.or_else(|| content.chars().next());
+            let kerning = match (prev, first) {
+                // Whitespace is skipped so that trailing spaces can be trimmed from a line
+                // without changing its width
+                (Some(left), Some(right))
+                    if properties.shaping && !left.is_whitespace() && !right.is_whitespace() =>
+                {
+                    self.get_kerning(left, right) * properties.font_size
+                }
+                _ => 0.0,
+            };
+
+            clusters.push(ShapedCluster {
+                content,
+                ligature,
+                kerning,
+            });
+            prev = ligature.or_else(|| content.chars().last());
+            index += count;
+        }
+
+        clusters
+    }
+
+    /// Finds the ligature (if any) formed by the first few of the given graphemes.
+    ///
+    /// Returns the ligature glyph and the number of graphemes it replaces.
+    fn find_ligature(&self, graphemes: &[(usize, &str)]) -> Option<(char, usize)> {
+        utility::LIGATURES.iter().find_map(|(sequence, ligature)| {
+            let count = sequence.chars().count();
+            let is_match = graphemes.len() >= count
+                && graphemes
+                    .iter()
+                    .zip(sequence.chars())
+                    .all(|((_, grapheme), c)| {
+                        // Only graphemes made of a single char can be combined (i.e. "fí" can't be)
+                        let mut chars = grapheme.chars();
+                        chars.next() == Some(c) && chars.next().is_none()
+                    });
+
+            (is_match && self.get_glyph(*ligature).is_some()).then(|| (*ligature, count))
+        })
+    }
+
+    /// Returns the kerning between two characters (in ems).
+    ///
+    /// This is negative when the characters should be moved closer together.
+    pub fn get_kerning(&self, left: char, right: char) -> f32 {
+        self.kerning
+            .get(&(left, right))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the pixel width of an emoji that isn't contained in this font.
+    fn get_emoji_width(&self, properties: TextProperties) -> f32 {
+        properties.font_size + properties.letter_spacing
+    }
+
+    /// Returns true if the given grapheme cluster is an emoji that this font has no glyph for.
+    ///
+    /// These are measured as a single square glyph (rather than char by char) so that sequences
+    /// joined by ZWJ or followed by a variation selector take up the space of only one emoji.
+    fn is_missing_emoji(&self, grapheme: &str) -> bool {
+        grapheme
+            .chars()
+            .next()
+            .map(|c| utility::is_emoji(c) && self.get_glyph(c).is_none())
+            .unwrap_or_default()
+    }
+
     /// Returns the pixel width of a tab.
     fn get_tab_width(&self, properties: TextProperties) -> f32 {
         self.get_space_width(properties) * properties.tab_size as f32
@@ -441,3 +626,26 @@ impl KayakFont {
         )
     }
 }
+
+/// A cluster of text that is laid out as a single unit.
+struct ShapedCluster<'a> {
+    /// The content of this cluster (one or more grapheme clusters).
+    content: &'a str,
+    /// The glyph that replaces the content of this cluster, if it forms a ligature.
+    ligature: Option<char>,
+    /// The kerning (in pixels) applied between the previous cluster and this one.
+    kerning: f32,
+}
+
+impl<'a> ShapedCluster<'a> {
+    /// Returns the characters to render glyphs for.
+    fn glyphs(&self) -> impl Iterator<Item = char> + 'a {
+        let content = if self.ligature.is_some() {
+            ""
+        } else {
+            self.content
+        };
+
+        self.ligature.into_iter().chain(content.chars())
+    }
+}
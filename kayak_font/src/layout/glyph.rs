@@ -5,3 +5,18 @@ pub struct GlyphRect {
     pub size: (f32, f32),
     pub content: char,
 }
+
+/// Layout information for an emoji (or other color glyph) that isn't contained in the font.
+///
+/// Since the font can't render these itself, they're expected to be rendered separately (i.e. with
+/// an image). The [`fallback`](Self::fallback) glyph can be used when that isn't possible.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct EmojiRect {
+    /// The position of the top-left corner of the emoji, relative to the top-left of the text.
+    pub position: (f32, f32),
+    pub size: (f32, f32),
+    /// The full grapheme cluster of the emoji (including any joiners or variation selectors).
+    pub content: String,
+    /// The font's missing glyph, positioned in place of the emoji.
+    pub fallback: Option<GlyphRect>,
+}
@@ -1,4 +1,4 @@
-use crate::{GlyphRect, Line, RowCol};
+use crate::{EmojiRect, GlyphRect, Line, RowCol};
 use std::cmp::Ordering;
 
 /// The text alignment.
@@ -22,6 +22,13 @@ pub struct TextProperties {
     pub alignment: Alignment,
     /// The size of a tab (`'\t'`) character in equivalent spaces.
     pub tab_size: u8,
+    /// Extra space added after each character (in pixels).
+    pub letter_spacing: f32,
+    /// Whether to apply the font's kerning and form common ligatures (such as "fi").
+    ///
+    /// This can be disabled for monospace or code contexts, where each character should keep its
+    /// own advance.
+    pub shaping: bool,
 }
 
 impl Default for TextProperties {
@@ -32,6 +39,8 @@ impl Default for TextProperties {
             max_size: (f32::MAX, f32::MAX),
             tab_size: 4,
             alignment: Alignment::Start,
+            letter_spacing: 0.0,
+            shaping: true,
         }
     }
 }
@@ -42,6 +51,7 @@ impl Default for TextProperties {
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct TextLayout {
     glyphs: Vec<GlyphRect>,
+    emojis: Vec<EmojiRect>,
     lines: Vec<Line>,
     size: (f32, f32),
     properties: TextProperties,
@@ -50,19 +60,22 @@ pub struct TextLayout {
 impl TextLayout {
     /// Create a new [`TextLayout`].
     ///
-    /// The given lists of [lines] and [glyphs] should be in their appropriate order
+    /// The given lists of [lines], [glyphs], and [emojis] should be in their appropriate order
     /// (i.e. Line 1 should come before Line 2, etc.).
     ///
     /// [lines]: Line
     /// [glyphs]: GlyphRect
+    /// [emojis]: EmojiRect
     pub fn new(
         glyphs: Vec<GlyphRect>,
+        emojis: Vec<EmojiRect>,
         lines: Vec<Line>,
         size: (f32, f32),
         properties: TextProperties,
     ) -> Self {
         Self {
             glyphs,
+            emojis,
             lines,
             size,
             properties,
@@ -79,11 +92,24 @@ impl TextLayout {
         &self.glyphs
     }
 
+    /// Returns the calculated emoji rects for the text content.
+    ///
+    /// These are the emojis that the font doesn't contain a glyph for.
+    pub fn emojis(&self) -> &[EmojiRect] {
+        &self.emojis
+    }
+
     /// Returns the total width and height of the text content (in pixels).
     pub fn size(&self) -> (f32, f32) {
         self.size
     }
 
+    /// Returns the offset of the first line's baseline from the top of the text (in pixels).
+    pub fn baseline(&self) -> f32 {
+        // Glyphs are positioned relative to a baseline one font size below the top of each line
+        self.properties.font_size
+    }
+
     /// Returns the properties used to calculate this layout.
     pub fn properties(&self) -> TextProperties {
         self.properties
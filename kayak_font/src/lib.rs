@@ -18,7 +18,7 @@ pub mod bevy;
 
 #[cfg(test)]
 mod tests {
-    use crate::{Alignment, KayakFont, Sdf, TextProperties};
+    use crate::{utility, Alignment, KayakFont, KerningData, Sdf, TextProperties};
 
     fn make_font() -> KayakFont {
         let bytes = std::fs::read("assets/roboto.kayak_font")
@@ -31,6 +31,33 @@ mod tests {
         return KayakFont::new(Sdf::from_bytes(&bytes));
     }
 
+    /// Creates a font with some kerning and an "fi" ligature (which the default font lacks)
+    fn make_shaping_font() -> KayakFont {
+        let bytes = std::fs::read("assets/roboto.kayak_font")
+            .expect("a `roboto.kayak_font` file in the `assets/` directory of this crate");
+        let mut sdf = Sdf::from_bytes(&bytes);
+
+        let mut ligature = *sdf
+            .glyphs
+            .iter()
+            .find(|glyph| glyph.unicode == 'f')
+            .unwrap();
+        ligature.unicode = '\u{FB01}';
+        ligature.advance *= 1.5;
+        sdf.glyphs.push(ligature);
+        sdf.kerning.push(KerningData {
+            unicode1: 'A' as u32,
+            unicode2: 'V' as u32,
+            advance: -0.1,
+        });
+
+        #[cfg(feature = "bevy_renderer")]
+        return KayakFont::new(sdf, bevy::asset::Handle::default());
+
+        #[cfg(not(feature = "bevy_renderer"))]
+        return KayakFont::new(sdf);
+    }
+
     fn make_properties() -> TextProperties {
         TextProperties {
             line_height: 14.0 * 1.2,
@@ -38,6 +65,8 @@ mod tests {
             alignment: Alignment::Start,
             max_size: (200.0, 300.0),
             tab_size: 4,
+            letter_spacing: 0.0,
+            shaping: true,
         }
     }
 
@@ -133,4 +162,106 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn should_add_letter_spacing_to_each_char() {
+        let content = "Hello world!";
+        let font = make_font();
+        let properties = make_properties();
+        let layout = font.measure(content, properties);
+        let spaced = font.measure(
+            content,
+            TextProperties {
+                letter_spacing: 2.0,
+                ..properties
+            },
+        );
+
+        let expected = layout.size().0 + 2.0 * content.len() as f32;
+        assert!((expected - spaced.size().0).abs() < 0.001);
+    }
+
+    #[test]
+    fn should_measure_missing_emoji_as_single_square() {
+        let font = make_font();
+        let properties = make_properties();
+        let plain = font.measure("Hi !", properties);
+        // A waving hand with a skin tone modifier, followed by a ZWJ family sequence
+        let content = "Hi \u{1F44B}\u{1F3FD}\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}!";
+        let layout = font.measure(content, properties);
+
+        assert_eq!(content.chars().count(), layout.total_chars());
+        assert_eq!(plain.total_glyphs(), layout.total_glyphs());
+        assert_eq!(2, layout.emojis().len());
+
+        let emoji = &layout.emojis()[0];
+        assert_eq!("\u{1F44B}\u{1F3FD}", emoji.content);
+        assert_eq!((properties.font_size, properties.font_size), emoji.size);
+        assert_eq!(
+            properties.font_size,
+            layout.emojis()[1].position.0 - emoji.position.0
+        );
+
+        let expected = plain.size().0 + 2.0 * properties.font_size;
+        assert!((expected - layout.size().0).abs() < 0.001);
+    }
+
+    #[test]
+    fn should_apply_kerning_and_ligatures() {
+        let font = make_shaping_font();
+        let properties = make_properties();
+        let unshaped = TextProperties {
+            shaping: false,
+            ..properties
+        };
+
+        let kerned = font.measure("AV", properties);
+        let plain = font.measure("AV", unshaped);
+        let expected = plain.size().0 - 0.1 * properties.font_size;
+        assert!((expected - kerned.size().0).abs() < 0.001);
+
+        let ligated = font.measure("fit", properties);
+        assert_eq!(2, ligated.total_glyphs());
+        assert_eq!(2, ligated.total_graphemes());
+        assert_eq!(3, ligated.total_chars());
+        assert_eq!('\u{FB01}', ligated.glyphs()[0].content);
+
+        let plain = font.measure("fit", unshaped);
+        assert_eq!(3, plain.total_glyphs());
+        assert_eq!(3, plain.total_graphemes());
+    }
+
+    #[test]
+    fn wrapped_line_widths_should_match_shaped_advances() {
+        let font = make_shaping_font();
+        let content = "AVAVA fit fifty AVA-VAV final AV";
+        let properties = make_properties();
+        let single = font.measure(content, properties);
+
+        let words = utility::split_breakable_words(content).collect::<Vec<_>>();
+        let expected: f32 = words
+            .iter()
+            .map(|word| font.get_word_width(word.content, properties))
+            .sum();
+        assert!((expected - single.size().0).abs() < 0.001);
+
+        let wrapped = font.measure(
+            content,
+            TextProperties {
+                max_size: (single.size().0 / 2.0, 300.0),
+                ..properties
+            },
+        );
+        assert!(wrapped.total_lines() > 1);
+        for line in wrapped.lines() {
+            let content = content
+                .chars()
+                .skip(line.char_index())
+                .take(line.total_chars())
+                .collect::<String>();
+            let expected = font.get_word_width(content.trim_end(), properties);
+            assert!(line.width() <= single.size().0 / 2.0);
+            assert!((expected - line.width()).abs() < 0.001);
+        }
+    }
 }
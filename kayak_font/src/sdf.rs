@@ -6,7 +6,7 @@ pub struct Sdf {
     pub atlas: Atlas,
     metrics: Metrics,
     pub glyphs: Vec<Glyph>,
-    kerning: Vec<KerningData>,
+    pub kerning: Vec<KerningData>,
 }
 
 #[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
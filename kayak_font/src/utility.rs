@@ -7,6 +7,17 @@ pub const NBSP: char = '\u{a0}';
 pub const TAB: char = '\t';
 pub const MISSING: char = '�';
 
+/// The common Latin ligatures, along with the sequences they replace.
+///
+/// Longer sequences come first so that they take precedence over the shorter ones they contain.
+pub const LIGATURES: &[(&str, char)] = &[
+    ("ffi", '\u{FB03}'),
+    ("ffl", '\u{FB04}'),
+    ("ff", '\u{FB00}'),
+    ("fi", '\u{FB01}'),
+    ("fl", '\u{FB02}'),
+];
+
 /// Returns true if the given character is a newline.
 pub fn is_newline(c: char) -> bool {
     c == NEWLINE || c == CARRIAGE
@@ -24,6 +35,24 @@ pub fn is_tab(c: char) -> bool {
     c == TAB
 }
 
+/// Returns true if the given character is (most likely) an emoji.
+///
+/// This only checks the blocks that the common emoji are found in, rather than the full set of
+/// emoji properties from [UTS #51](https://www.unicode.org/reports/tr51/).
+pub fn is_emoji(c: char) -> bool {
+    matches!(
+        c as u32,
+        // Mahjong, Domino, and Playing Card symbols, and Enclosed Alphanumerics (including the
+        // regional indicators used for flags)
+        0x1F000..=0x1F2FF
+        // Misc Symbols and Pictographs, Emoticons, Transport and Map, Supplemental Symbols and
+        // Pictographs, etc.
+        | 0x1F300..=0x1FAFF
+        // Misc Symbols and Dingbats
+        | 0x2600..=0x27BF
+    )
+}
+
 /// Split a string into a collection of "words" that may be followed by a line break,
 /// according to [UAX #14](https://www.unicode.org/reports/tr14/).
 ///
@@ -40,6 +40,12 @@ impl Attribute {
         self.ident().iter().collect::<Vec<_>>()
     }
 
+    /// Returns true if this is the special `key` attribute
+    pub fn is_key(&self) -> bool {
+        let idents = self.idents();
+        idents.len() == 1 && idents[0] == "key"
+    }
+
     pub(crate) fn validate(self) -> Result<Self> {
         if self.idents().len() < 2 {
             Ok(self)
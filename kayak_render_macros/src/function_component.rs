@@ -39,6 +39,7 @@ pub fn create_function_widget(f: syn::ItemFn, _widget_arguments: WidgetArguments
         #[derive(Default, Debug, PartialEq, Clone)]
         #vis struct #struct_name #impl_generics {
             pub id: #kayak_core::Index,
+            pub key: Option<String>,
             pub #props: #prop_type
         }
 
@@ -49,6 +50,7 @@ pub fn create_function_widget(f: syn::ItemFn, _widget_arguments: WidgetArguments
             fn constructor(props: Self::Props) -> Self where Self: Sized {
                 Self {
                     id: #kayak_core::Index::default(),
+                    key: None,
                     #props: props,
                 }
             }
@@ -61,6 +63,14 @@ pub fn create_function_widget(f: syn::ItemFn, _widget_arguments: WidgetArguments
                 self.id = id;
             }
 
+            fn get_key(&self) -> Option<&str> {
+                self.key.as_deref()
+            }
+
+            fn set_key(&mut self, key: Option<String>) {
+                self.key = key;
+            }
+
             fn get_props(&self) -> &Self::Props {
                 &self.#props
             }
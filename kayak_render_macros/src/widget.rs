@@ -111,8 +111,19 @@ impl Widget {
             #attrs
         };
 
-        let constructor = quote! {
-            <#name as #kayak_core::Widget>::constructor(#prop_ident)
+        let constructor = if let Some(key) = attrs.key_tokens() {
+            quote! {{
+                let mut keyed_widget = <#name as #kayak_core::Widget>::constructor(#prop_ident);
+                #kayak_core::Widget::set_key(
+                    &mut keyed_widget,
+                    Some(::std::string::ToString::to_string(&(#key))),
+                );
+                keyed_widget
+            }}
+        } else {
+            quote! {
+                <#name as #kayak_core::Widget>::constructor(#prop_ident)
+            }
         };
 
         (props, constructor)
@@ -84,6 +84,7 @@ impl<'a, 'c> CustomWidgetAttributes<'a, 'c> {
         let mut attrs = self
             .attributes
             .iter()
+            .filter(|attribute| !attribute.is_key())
             .map(|attribute| {
                 let key = attribute.ident();
                 let value = attribute.value_tokens();
@@ -111,6 +112,17 @@ impl<'a, 'c> CustomWidgetAttributes<'a, 'c> {
         result
     }
 
+    /// Returns the value of this widget's `key` attribute, if any
+    ///
+    /// The key is not a prop, so it's excluded from [`assign_attributes`](Self::assign_attributes)
+    /// and set directly on the constructed widget instead.
+    pub fn key_tokens(&self) -> Option<TokenStream> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.is_key())
+            .map(|attribute| attribute.value_tokens())
+    }
+
     /// Determines whether `children` should be added to this widget or not
     fn should_add_children(&self) -> bool {
         if self.children.nodes.len() == 0 {
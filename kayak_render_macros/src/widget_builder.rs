@@ -1,8 +1,13 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::get_core_crate;
+
 /// Creates a token stream for building a widget
 ///
+/// The constructed value is converted using `IntoWidget`, allowing collections of widgets (such
+/// as a `Vec` or an iterator) to be added as a single child.
+///
 /// # Arguments
 ///
 /// * `widget_name`: The name of the widget to build
@@ -17,15 +22,17 @@ use quote::quote;
 /// build_widget(quote! { my_widget }, quote!{ <MyWidget as Widget>::constructor(props) }, 0);
 /// // Outputs token stream:
 /// //   let my_widget = <MyWidget as Widget>::constructor(props);
-/// //   context.add_widget(my_widget, 0);
+/// //   context.add_widget(IntoWidget::into_widget(my_widget), 0);
 /// ```
 pub fn build_widget_stream(
     widget_name: TokenStream,
     widget_constructor: TokenStream,
     index: usize,
 ) -> TokenStream {
+    let kayak_core = get_core_crate();
+
     quote! {
         let #widget_name = #widget_constructor;
-        context.add_widget(#widget_name, #index);
+        context.add_widget(#kayak_core::IntoWidget::into_widget(#widget_name), #index);
     }
 }
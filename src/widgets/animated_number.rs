@@ -0,0 +1,195 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp, Units},
+    use_state, widget, Handler, OnEvent, OnLayout, WidgetProps,
+};
+
+use crate::widgets::{transition::ease_out, Text};
+
+/// The default time it takes to animate to a new value (in seconds)
+const DEFAULT_DURATION: f32 = 0.5;
+
+/// A function used to convert the displayed value of an [`AnimatedNumber`] to text
+#[derive(Clone)]
+pub struct NumberFormat(Arc<dyn Fn(f64) -> String + Send + Sync>);
+
+impl NumberFormat {
+    /// Create a new number format from the given function
+    pub fn new<F: Fn(f64) -> String + Send + Sync + 'static>(f: F) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Formats the given value
+    pub fn format(&self, value: f64) -> String {
+        (self.0)(value)
+    }
+}
+
+impl Default for NumberFormat {
+    /// Rounds the value to the nearest integer
+    fn default() -> Self {
+        Self::new(|value| format!("{:.0}", value))
+    }
+}
+
+impl Debug for NumberFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NumberFormat").finish()
+    }
+}
+
+impl PartialEq for NumberFormat {
+    fn eq(&self, _: &Self) -> bool {
+        // Never prevent "==" from being true because of this struct
+        true
+    }
+}
+
+/// The animation of an [`AnimatedNumber`] from one value to another
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Animation {
+    from: f64,
+    to: f64,
+    /// The time since the animation started (in seconds)
+    elapsed: f32,
+}
+
+impl Animation {
+    /// Returns the value displayed at the current point of the animation
+    fn value(&self, duration: f32) -> f64 {
+        if duration <= 0.0 || self.elapsed >= duration {
+            return self.to;
+        }
+        let amount = ease_out(self.elapsed / duration) as f64;
+        self.from + (self.to - self.from) * amount
+    }
+}
+
+/// Props used by the [`AnimatedNumber`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct AnimatedNumberProps {
+    /// The value to display
+    ///
+    /// When this changes, the displayed value counts up or down to it.
+    pub value: f64,
+    /// The time it takes to animate to a new value (in seconds)
+    ///
+    /// Defaults to 0.5 seconds. A value of `0.0` displays new values immediately.
+    pub duration: Option<f32>,
+    /// The function used to convert the displayed value to text
+    ///
+    /// Defaults to rounding the value to the nearest integer.
+    pub format: Option<NumberFormat>,
+    /// The font size (in pixels)
+    ///
+    /// If `None`, the font size is taken from the styles of this widget.
+    pub size: Option<f32>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A widget that displays a number, counting up or down to it whenever it changes
+///
+/// The displayed value eases towards the new [`value`](AnimatedNumberProps::value) over the
+/// given [`duration`](AnimatedNumberProps::duration), advancing with the frame time. If the value
+/// changes again mid-animation, the animation restarts from the value currently displayed, so the
+/// number never jumps. The first value is displayed without animating, as is every value while the
+/// user [prefers reduced motion](crate::core::KayakContextRef::prefers_reduced_motion).
+///
+/// The text inherits its color and font from this widget's styles.
+///
+/// # Props
+///
+/// __Type:__ [`AnimatedNumberProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{AnimatedNumber, NumberFormat};
+///
+/// let format = NumberFormat::new(|value| format!("${:.2}", value));
+///
+/// rsx! {
+///     <AnimatedNumber value={1234.5} format={Some(format)} size={Some(24.0)} />
+/// }
+/// ```
+pub fn AnimatedNumber(props: AnimatedNumberProps) {
+    let AnimatedNumberProps {
+        value,
+        duration,
+        format,
+        size,
+        ..
+    } = props.clone();
+    let duration = if context.prefers_reduced_motion() {
+        0.0
+    } else {
+        duration.unwrap_or(DEFAULT_DURATION)
+    };
+
+    // === Animation === //
+    let (animation, set_animation, ..) = use_state!(Animation {
+        from: value,
+        to: value,
+        elapsed: duration,
+    });
+    let mut animation = animation;
+    if animation.to != value {
+        // Re-target the animation from whatever is currently displayed
+        animation = Animation {
+            from: animation.value(duration),
+            to: value,
+            elapsed: 0.0,
+        };
+        set_animation(animation);
+    }
+
+    let is_animating = duration > 0.0 && animation.elapsed < duration;
+    context.on_tick(is_animating.then(|| {
+        Handler::new(move |delta_time: f32| {
+            set_animation(Animation {
+                elapsed: animation.elapsed + delta_time,
+                ..animation
+            });
+        })
+    }));
+
+    // === Styles === //
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                height: StyleProp::Value(Units::Auto),
+                width: StyleProp::Value(Units::Auto),
+                ..Default::default()
+            }),
+    );
+
+    let content = format.unwrap_or_default().format(animation.value(duration));
+
+    rsx! {
+        <Text content={content} size={size.unwrap_or(-1.0)} />
+    }
+}
@@ -3,8 +3,8 @@ use kayak_core::OnLayout;
 use crate::core::{
     render_command::RenderCommand,
     rsx,
-    styles::{Style, StyleProp},
-    widget, Children, OnEvent, WidgetProps,
+    styles::{Style, StyleProp, Units},
+    widget, Children, Color, OnEvent, WidgetProps,
 };
 
 use crate::widgets::Clip;
@@ -12,6 +12,15 @@ use crate::widgets::Clip;
 /// Props used by the [`App`] widget
 #[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
 pub struct AppProps {
+    /// The color drawn behind the entire UI
+    ///
+    /// If `None`, nothing is drawn and whatever was rendered before the UI (such as the rest of the
+    /// Bevy scene) shows through.
+    pub clear_color: Option<Color>,
+    /// The name of the font used by all text in the app, unless a widget sets its own
+    ///
+    /// If `None`, the [`DEFAULT_FONT`](crate::core::DEFAULT_FONT) is used.
+    pub default_font: Option<String>,
     #[prop_field(Styles)]
     pub styles: Option<Style>,
     #[prop_field(Children)]
@@ -39,40 +48,54 @@ pub struct AppProps {
 /// | `on_layout` | ✅        |
 /// | `focusable` | ✅        |
 ///
-/// # Using the `bevy_renderer` feature
+/// # Window size
 ///
-/// When the `bevy_renderer` feature is enabled, this widget will automatically bind to the window size
-/// of the Bevy app. This allows it to update on window resize in order to match the width and height of the window.
+/// This widget matches the size of the window its UI is rendered in, as reported by the integration
+/// (such as the Bevy integration from the `bevy_renderer` feature), and updates whenever the window
+/// is resized. With multiple windows, each UI matches its own window.
+///
+/// The UI camera doesn't clear the screen before drawing the UI, so the `clear_color` is drawn as a
+/// background covering the window instead. This means it will cover anything else drawn by Bevy.
+///
+/// # Styles
+///
+/// This widget accepts all styles. The `clear_color` and `default_font` props take precedence over
+/// the `background_color` and `font` styles, respectively.
 pub fn App(props: AppProps) {
-    #[cfg(feature = "bevy_renderer")]
-    {
-        use crate::bevy::WindowSize;
-        use crate::core::styles::Units;
-        use crate::core::{Binding, Bound};
-        let window_size = if let Ok(world) = context.get_global::<bevy::prelude::World>() {
-            if let Some(window_size) = world.get_resource::<Binding<WindowSize>>() {
-                window_size.clone()
-            } else {
-                return;
-            }
-        } else {
-            return;
-        };
+    let AppProps {
+        clear_color,
+        default_font,
+        ..
+    } = props.clone();
 
-        context.bind(&window_size);
-        let window_size = window_size.get();
+    // The window size is set by the integration, for the window this UI is rendered in
+    if let Some((width, height)) = context.window_size() {
         props.styles = Some(
             Style::default()
                 .with_style(Style {
                     render_command: StyleProp::Value(RenderCommand::Layout),
-                    width: StyleProp::Value(Units::Pixels(window_size.0)),
-                    height: StyleProp::Value(Units::Pixels(window_size.1)),
+                    width: StyleProp::Value(Units::Pixels(width)),
+                    height: StyleProp::Value(Units::Pixels(height)),
                     ..Default::default()
                 })
                 .with_style(&props.styles),
         );
     }
 
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: clear_color
+                    .map(|_| StyleProp::Value(RenderCommand::Quad))
+                    .unwrap_or_default(),
+                background_color: clear_color.map(StyleProp::Value).unwrap_or_default(),
+                // Fonts are inherited, so every descendant falls back to this one
+                font: default_font.map(StyleProp::Value).unwrap_or_default(),
+                ..Default::default()
+            })
+            .with_style(&props.styles),
+    );
+
     rsx! {
         <Clip>
             {children}
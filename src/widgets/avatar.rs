@@ -0,0 +1,134 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Corner, Style, StyleProp, Units},
+    widget, Children, Color, OnEvent, OnLayout, WidgetProps,
+};
+
+use crate::widgets::{Background, Image, Text};
+
+/// The size of an [`Avatar`] (in pixels) when no valid size is given
+const DEFAULT_SIZE: f32 = 40.0;
+
+/// Props used by the [`Avatar`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct AvatarProps {
+    /// The background color displayed behind the initials
+    ///
+    /// If `None`, a color will be derived from the initials themselves, meaning the
+    /// same initials will always be given the same color.
+    pub color: Option<Color>,
+    /// The handle of the image to display, as given by the integration's image manager
+    ///
+    /// The image is clipped to the avatar's circle. If `None`, the
+    /// [`initials`](Self::initials) are displayed instead.
+    pub image: Option<u16>,
+    /// The initials displayed when no image is given
+    pub initials: String,
+    /// The width and height of the avatar (in pixels)
+    ///
+    /// Non-positive values are replaced by a default size of 40 pixels.
+    pub size: f32,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A circular widget that displays a user's image or, if not available, their initials
+///
+/// # Props
+///
+/// __Type:__ [`AvatarProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::Avatar;
+///
+/// rsx! {
+///     <Avatar initials={"KU".to_string()} size={48.0} />
+/// }
+/// ```
+pub fn Avatar(props: AvatarProps) {
+    let AvatarProps {
+        color,
+        image,
+        initials,
+        size,
+        ..
+    } = props.clone();
+
+    let size = if size > 0.0 { size } else { DEFAULT_SIZE };
+
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                width: StyleProp::Value(Units::Pixels(size)),
+                height: StyleProp::Value(Units::Pixels(size)),
+                ..Default::default()
+            }),
+    );
+
+    let circle_styles = Style {
+        border_radius: StyleProp::Value(Corner::all(size / 2.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+
+    if let Some(handle) = image {
+        rsx! {
+            <Image handle={handle} styles={Some(circle_styles)} />
+        }
+    } else {
+        let background_styles = Style {
+            background_color: StyleProp::Value(color.unwrap_or_else(|| initials_color(&initials))),
+            ..circle_styles
+        };
+        let text_styles = Style {
+            color: StyleProp::Value(Color::WHITE),
+            top: StyleProp::Value(Units::Stretch(1.0)),
+            right: StyleProp::Value(Units::Stretch(1.0)),
+            bottom: StyleProp::Value(Units::Stretch(1.0)),
+            left: StyleProp::Value(Units::Stretch(1.0)),
+            ..Default::default()
+        };
+        let font_size = size * 0.4;
+
+        rsx! {
+            <Background styles={Some(background_styles)}>
+                <Text content={initials} size={font_size} styles={Some(text_styles)} />
+            </Background>
+        }
+    }
+}
+
+/// Deterministically picks a background color for the given initials
+fn initials_color(initials: &str) -> Color {
+    let hash = initials
+        .chars()
+        .fold(0u32, |hash, c| hash.wrapping_mul(31).wrapping_add(c as u32));
+    Color::hsla((hash % 360) as f32, 0.5, 0.45, 1.0)
+}
@@ -1,11 +1,13 @@
 use crate::core::{
     render_command::RenderCommand,
     rsx,
-    styles::{Style, StyleProp},
+    styles::{Overflow, Style, StyleProp},
     widget, Children, Fragment, OnEvent, WidgetProps,
 };
 use kayak_core::OnLayout;
 
+use crate::widgets::ScrollBox;
+
 /// Props used by the [`Background`] widget
 #[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
 pub struct BackgroundProps {
@@ -24,6 +26,9 @@ pub struct BackgroundProps {
 #[widget]
 /// A widget that provides a simple, rectangular background
 ///
+/// If either axis has an [`overflow`](Style::overflow) of [`Overflow::Scroll`], the children
+/// are placed within a [`ScrollBox`], allowing them to be scrolled along that axis.
+///
 /// # Props
 ///
 /// __Type:__ [`BackgroundProps`]
@@ -41,9 +46,26 @@ pub fn Background(props: BackgroundProps) {
         props.styles = Some(Style::default())
     }
     props.styles.as_mut().unwrap().render_command = StyleProp::Value(RenderCommand::Quad);
-    rsx! {
-        <Fragment>
-            {children}
-        </Fragment>
+    let (overflow_x, overflow_y) = props.styles.as_ref().unwrap().resolved_overflow();
+    let scroll_x = matches!(overflow_x, Overflow::Scroll);
+    let scroll_y = matches!(overflow_y, Overflow::Scroll);
+
+    if scroll_x || scroll_y {
+        rsx! {
+            <ScrollBox
+                disable_horizontal={!scroll_x}
+                disable_vertical={!scroll_y}
+                hide_horizontal={!scroll_x}
+                hide_vertical={!scroll_y}
+            >
+                {children}
+            </ScrollBox>
+        }
+    } else {
+        rsx! {
+            <Fragment>
+                {children}
+            </Fragment>
+        }
     }
 }
@@ -0,0 +1,193 @@
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    widget, Children, CursorIcon, EventType, OnEvent, OnLayout, VecTracker, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Element, If, Text};
+
+/// A single level displayed within a [`Breadcrumb`]
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct BreadcrumbItem {
+    /// The string displayed for this level
+    pub label: String,
+    /// A callback for when the user clicks this level
+    ///
+    /// This is never fired for the last item since it represents the current page.
+    pub on_click: Option<OnEvent>,
+}
+
+impl BreadcrumbItem {
+    /// Creates a new `BreadcrumbItem` with the given label and click callback
+    pub fn new<T: Into<String>>(label: T, on_click: Option<OnEvent>) -> Self {
+        Self {
+            label: label.into(),
+            on_click,
+        }
+    }
+}
+
+/// Props used by the [`Breadcrumb`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct BreadcrumbProps {
+    /// The levels to display, ordered from the root to the current page
+    pub items: Vec<BreadcrumbItem>,
+    /// The maximum number of items to display
+    ///
+    /// When there are more items than this, the first item is kept and the middle items are
+    /// collapsed into a single ellipsis, which counts towards the limit. Values less than 3 are
+    /// treated as 3, so the first item, the ellipsis, and the current item are always shown.
+    pub max_items: Option<usize>,
+    /// The string displayed between each item
+    ///
+    /// Defaults to `"/"`.
+    pub separator: Option<String>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A widget that displays the path to the current page within a hierarchy
+///
+/// All items except for the last are clickable. The last item is displayed as the current page.
+///
+/// # Props
+///
+/// __Type:__ [`BreadcrumbProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, EventType, OnEvent};
+/// # use kayak_ui::widgets::{Breadcrumb, BreadcrumbItem};
+///
+/// let go_home = OnEvent::new(|_, event| match event.event_type {
+///     EventType::Click(..) => println!("Home!"),
+///     _ => {}
+/// });
+///
+/// let items = vec![
+///     BreadcrumbItem::new("Home", Some(go_home)),
+///     BreadcrumbItem::new("Settings", None),
+/// ];
+///
+/// rsx! {
+///     <Breadcrumb items={items} />
+/// }
+/// ```
+pub fn Breadcrumb(props: BreadcrumbProps) {
+    let BreadcrumbProps {
+        items,
+        max_items,
+        separator,
+        ..
+    } = props.clone();
+
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                col_between: StyleProp::Value(Units::Pixels(6.0)),
+                height: StyleProp::Value(Units::Auto),
+                layout_type: StyleProp::Value(LayoutType::Row),
+                width: StyleProp::Value(Units::Auto),
+                ..Default::default()
+            }),
+    );
+
+    let separator = separator.unwrap_or_else(|| "/".to_string());
+    let last_index = items.len().saturating_sub(1);
+
+    // `None` represents the collapsed ellipsis item
+    let mut visible: Vec<(usize, Option<BreadcrumbItem>)> =
+        items.into_iter().map(Some).enumerate().collect();
+    if let Some(max_items) = max_items {
+        let max_items = max_items.max(3);
+        if visible.len() > max_items {
+            let tail = visible.split_off(visible.len() - (max_items - 2));
+            visible.truncate(1);
+            visible.push((0, None));
+            visible.extend(tail);
+        }
+    }
+
+    let theme = use_theme(context).unwrap_or_default();
+    let muted_color = theme.text_muted;
+
+    rsx! {
+        <>
+            {VecTracker::from(visible.clone().into_iter().enumerate().map(|(position, (index, item))| {
+                let is_current = item.is_some() && index == last_index;
+                let label = item
+                    .as_ref()
+                    .map(|item| item.label.clone())
+                    .unwrap_or_else(|| "...".to_string());
+
+                let on_click = item.and_then(|item| item.on_click).filter(|_| !is_current);
+                let is_clickable = on_click.is_some();
+                let on_event = on_click.map(|on_click| {
+                    OnEvent::new(move |ctx, event| {
+                        if matches!(event.event_type, EventType::Click(..)) {
+                            on_click.try_call(ctx, event);
+                        }
+                    })
+                });
+
+                let item_styles = Style {
+                    layout_type: StyleProp::Value(LayoutType::Row),
+                    col_between: StyleProp::Value(Units::Pixels(6.0)),
+                    width: StyleProp::Value(Units::Auto),
+                    height: StyleProp::Value(Units::Auto),
+                    ..Default::default()
+                };
+                let separator_styles = Style {
+                    color: StyleProp::Value(muted_color),
+                    ..Default::default()
+                };
+                let label_styles = Style {
+                    color: StyleProp::Value(if is_current { theme.text } else { muted_color }),
+                    cursor: if is_clickable {
+                        CursorIcon::Hand.into()
+                    } else {
+                        StyleProp::Inherit
+                    },
+                    ..Default::default()
+                };
+                let has_separator = position > 0;
+                let separator = separator.clone();
+                let label_size = if is_current { 16.0 } else { 14.0 };
+
+                constructor! {
+                    <Element styles={Some(item_styles)}>
+                        <If condition={has_separator}>
+                            <Text content={separator} size={14.0} styles={Some(separator_styles)} />
+                        </If>
+                        <Text content={label} size={label_size} styles={Some(label_styles)} on_event={on_event} />
+                    </Element>
+                }
+            }))}
+        </>
+    }
+}
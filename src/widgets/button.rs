@@ -1,19 +1,68 @@
 use crate::core::{
     render_command::RenderCommand,
     rsx,
-    styles::{Corner, Style, StyleProp, Units},
-    widget, Children, Color, Fragment, OnEvent, OnLayout, WidgetProps,
+    styles::{Corner, Edge, LayoutType, PositionType, Style, StyleProp, Units},
+    use_state, widget, Accessibility, Children, Color, CursorEvent, Event, EventType, Fragment,
+    Handler, KeyCode, OnEvent, OnLayout, PointerEvents, Role, WidgetProps,
 };
 use kayak_core::CursorIcon;
 
+use crate::widgets::{
+    transition::ease_out, use_theme, Background, Clip, Element, If, Image, Spinner, Text,
+};
+
+/// The width of the focus ring (in pixels)
+const FOCUS_RING_WIDTH: f32 = 2.0;
+/// The gap between the button and its focus ring (in pixels)
+const FOCUS_RING_OFFSET: f32 = 1.0;
+/// The default height of a button (in pixels)
+const BUTTON_HEIGHT: f32 = 45.0;
+/// The duration of the ripple shown when a button is pressed (in seconds)
+const RIPPLE_DURATION: f32 = 0.4;
+/// The opacity of the ripple when it starts, before fading out
+const RIPPLE_OPACITY: f32 = 0.3;
+/// The distance a button is raised while hovered (in pixels)
+const HOVER_LIFT: f32 = 2.0;
+/// The time it takes to raise or lower a hovered button (in seconds)
+const HOVER_LIFT_DURATION: f32 = 0.15;
+
+/// An icon displayed alongside the label of a [`Button`]
+#[derive(Debug, PartialEq, Clone)]
+pub enum ButtonIcon {
+    /// An image, given by its handle
+    Image(u16),
+    /// A glyph (or short string) rendered with the button's font, such as from an icon font
+    Glyph(String),
+}
+
 /// Props used by the [`Button`] widget
 #[derive(Default, Debug, PartialEq, Clone)]
 pub struct ButtonProps {
-    /// If true, disables this widget not allowing it to be focusable
+    /// If true, disables this widget, so it can't be focused or clicked
     ///
-    // TODO: Update this documentation when the disabled issue is fixed
-    /// Currently, this does not actually disable the button from being clicked.
+    /// Buttons within a [`Disabled`](crate::widgets::Disabled) widget are also disabled.
     pub disabled: bool,
+    /// If true, replaces the children with a [`Spinner`] and disables this widget until it's false
+    ///
+    /// The children are only hidden, so the size of the button doesn't change while it's loading.
+    /// This can be used to prevent an action from being triggered again before it completes.
+    pub loading: bool,
+    /// If true, a ripple expands from the cursor whenever the button is pressed
+    ///
+    /// The ripple is purely visual, so the button is still clicked as usual when released.
+    pub ripple: bool,
+    /// If true, the button is raised slightly while hovered
+    ///
+    /// The button moves back down when the cursor leaves it. Both movements are animated.
+    pub hover_lift: bool,
+    /// The text displayed in the button
+    ///
+    /// This is displayed before the children, if any.
+    pub label: Option<String>,
+    /// An icon displayed before the label
+    pub leading_icon: Option<ButtonIcon>,
+    /// An icon displayed after the label
+    pub trailing_icon: Option<ButtonIcon>,
     pub styles: Option<Style>,
     pub children: Option<Children>,
     pub on_event: Option<OnEvent>,
@@ -43,13 +92,41 @@ impl WidgetProps for ButtonProps {
     }
 
     fn get_focusable(&self) -> Option<bool> {
-        Some(!self.disabled)
+        Some(!self.disabled && !self.loading)
     }
 }
 
 #[widget]
 /// A widget that is styled like a button
 ///
+/// A focused button can be activated with `Enter` or `Space`, which sends a
+/// [`Click`](EventType::Click) event to its `on_event` handler, just like clicking it with the
+/// mouse. The key press itself is consumed by the button. While focused using the keyboard, a
+/// ring is drawn around the button using the theme's [`primary`](crate::widgets::Theme::primary)
+/// color. Clicking a button focuses it without showing the ring (see
+/// [`KayakContextRef::is_focus_visible`](crate::core::KayakContextRef::is_focus_visible)).
+///
+/// A [`label`](ButtonProps::label) and [`leading`](ButtonProps::leading_icon) or
+/// [`trailing`](ButtonProps::trailing_icon) icons can be given, which are laid out in a row, with
+/// the theme's [`spacing_small`](crate::widgets::Theme::spacing_small) between them and centered
+/// vertically. Icons are sized to the theme's [`font_size`](crate::widgets::Theme::font_size). A
+/// button with icons but no label or children is made square, so the icon has equal padding on
+/// every side.
+///
+/// While [`loading`](ButtonProps::loading), a [`Spinner`] is shown in place of the children and
+/// the button is disabled.
+///
+/// When [`ripple`](ButtonProps::ripple) is enabled, pressing the button shows a circle that
+/// expands from the cursor and fades out, clipped to the bounds of the button. The ripple is
+/// advanced on every [tick](crate::core::KayakContextRef::on_tick) while it plays.
+///
+/// When [`hover_lift`](ButtonProps::hover_lift) is enabled, the button moves up slightly while
+/// hovered. This offsets its `top` and `bottom` styles (when given in pixels) in opposite
+/// directions, so the space it takes up in its parent doesn't change.
+///
+/// Neither the ripple nor the lift is shown while the user
+/// [prefers reduced motion](crate::core::KayakContextRef::prefers_reduced_motion).
+///
 /// # Props
 ///
 /// __Type:__ [`ButtonProps`]
@@ -62,33 +139,400 @@ impl WidgetProps for ButtonProps {
 /// | `on_layout` | ✅        |
 /// | `focusable` | ✅        |
 ///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, EventType, OnEvent};
+/// # use kayak_ui::widgets::{Button, Text};
+///
+/// // Called for both mouse clicks and keyboard activation
+/// let on_event = OnEvent::new(|_, event| {
+///     if let EventType::Click(..) = event.event_type {
+///         println!("Saved!");
+///     }
+/// });
+///
+/// rsx! {
+///     <Button on_event={Some(on_event)}>
+///         <Text content={"Save".to_string()} size={16.0} />
+///     </Button>
+/// }
+/// ```
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{Button, ButtonIcon};
+///
+/// rsx! {
+///     <>
+///         <Button
+///             label={Some("Next".to_string())}
+///             trailing_icon={Some(ButtonIcon::Glyph(">".to_string()))}
+///         />
+///         // An icon-only button is square
+///         <Button leading_icon={Some(ButtonIcon::Glyph("+".to_string()))} />
+///     </>
+/// }
+/// ```
 pub fn Button(props: ButtonProps) {
     // TODO: This should probably do more than just provide basic styling.
     //       Ideally, we could add a `Handler` prop for `on_click` and other common cursor
     //       events. Giving it the additional purpose of being a compact way to define a button.
-    //       This also allows us to make `disable` trule disable the button.
     //       Also, styles need to reflect disabled status.
+    let theme = use_theme(context).unwrap_or_default();
+    let (is_focus_visible, set_is_focus_visible, ..) = use_state!(false);
+    // The position of the last press (relative to the button) and the time since it happened
+    let (ripple, set_ripple, ..) = use_state!(Option::<(f32, f32, f32)>::None);
+    // Whether the button is hovered, the time since that last changed, and how far it was lifted at
+    // the time
+    let (hover, set_hover, ..) = use_state!((false, 0.0_f32, 0.0_f32));
+
+    let ButtonProps {
+        label,
+        leading_icon,
+        trailing_icon,
+        ..
+    } = props.clone();
+    let has_icons = leading_icon.is_some() || trailing_icon.is_some();
+    let has_label = label.is_some();
+    let is_icon_only = has_icons && !has_label && children.is_none();
+    let has_content = has_icons || has_label;
+    let is_disabled = props.disabled || props.loading;
+
+    // === Hover Lift === //
+    let has_motion = !context.prefers_reduced_motion();
+    let (is_hovered, ..) = hover;
+    let lift_amount = if props.hover_lift && has_motion {
+        hover_lift_amount(hover)
+    } else {
+        0.0
+    };
+    let is_lifting =
+        props.hover_lift && has_motion && lift_amount != if is_hovered { 1.0 } else { 0.0 };
+    let lift = HOVER_LIFT * ease_out(lift_amount);
+
+    // The lift applied below is written back into the props, so the styles given by the parent are
+    // kept separately to avoid compounding the lift every time this widget re-renders itself
+    let (styles, set_styles, ..) = use_state!((Option::<Style>::None, Option::<Style>::None));
+    let (given_styles, applied_styles) = styles;
+    let base_styles = if props.hover_lift && props.styles == applied_styles {
+        given_styles.clone()
+    } else {
+        props.styles.clone()
+    };
+    let lift_styles = if lift > 0.0 {
+        let offset = |base: &StyleProp<Units>, offset: f32| match base {
+            StyleProp::Value(Units::Pixels(value)) => {
+                StyleProp::Value(Units::Pixels(value + offset))
+            }
+            StyleProp::Value(_) => base.clone(),
+            _ => StyleProp::Value(Units::Pixels(offset)),
+        };
+        let base = base_styles.clone().unwrap_or_default();
+        Style {
+            bottom: offset(&base.bottom, lift),
+            top: offset(&base.top, -lift),
+            ..Default::default()
+        }
+    } else {
+        Style::default()
+    };
+
     props.styles = Some(
         Style::default()
             .with_style(Style {
                 render_command: StyleProp::Value(RenderCommand::Quad),
                 ..Default::default()
             })
-            .with_style(&props.styles)
+            .with_style(lift_styles)
+            .with_style(&base_styles)
             .with_style(Style {
-                background_color: StyleProp::Value(Color::new(0.0781, 0.0898, 0.101, 1.0)),
-                border_radius: StyleProp::Value(Corner::all(5.0)),
-                height: StyleProp::Value(Units::Pixels(45.0)),
+                background_color: StyleProp::Value(theme.surface),
+                border_radius: StyleProp::Value(Corner::all(theme.radius)),
+                height: StyleProp::Value(Units::Pixels(BUTTON_HEIGHT)),
                 padding_left: StyleProp::Value(Units::Stretch(1.0)),
                 padding_right: StyleProp::Value(Units::Stretch(1.0)),
                 cursor: CursorIcon::Hand.into(),
                 ..Default::default()
+            })
+            .with_style(if is_icon_only {
+                Style {
+                    width: StyleProp::Value(Units::Pixels(BUTTON_HEIGHT)),
+                    ..Default::default()
+                }
+            } else {
+                Style::default()
             }),
     );
 
+    if props.hover_lift && (&base_styles, &props.styles) != (&given_styles, &applied_styles) {
+        set_styles((base_styles, props.styles.clone()));
+    }
+
+    context.set_disabled(is_disabled);
+    context.set_accessibility(Some(match label {
+        Some(ref label) => Accessibility::new(Role::Button).with_name(label.clone()),
+        None => Accessibility::new(Role::Button),
+    }));
+
+    // === Keyboard Activation === //
+    // The handler below is written back into the props, so the parent's handler is kept separately
+    // to avoid wrapping it again every time this widget re-renders itself
+    let (handlers, set_handlers, ..) =
+        use_state!((Option::<OnEvent>::None, Option::<OnEvent>::None));
+    let (given_on_event, applied_on_event) = handlers;
+    let is_applied = match (&props.on_event, &applied_on_event) {
+        (Some(current), Some(applied)) => current.ptr_eq(applied),
+        _ => false,
+    };
+    let on_event = if is_applied {
+        given_on_event
+    } else {
+        props.on_event.clone()
+    };
+    let given_on_event = on_event.clone();
+    let has_ripple = props.ripple && has_motion;
+    let set_ripple_origin = set_ripple.clone();
+    let tick_set_hover = set_hover.clone();
+    let set_hovered = move |is_hovered: bool| {
+        if is_hovered != hover.0 {
+            set_hover((is_hovered, 0.0, hover_lift_amount(hover)));
+        }
+    };
+    props.on_event = Some(OnEvent::new(move |ctx, event| {
+        match event.event_type {
+            // The ring is hidden when clicking the button, even if it was already focused
+            EventType::Focus => set_is_focus_visible(ctx.is_focus_visible(event.target)),
+            EventType::Blur => set_is_focus_visible(false),
+            EventType::MouseIn(..) => set_hovered(true),
+            EventType::MouseOut(..) => set_hovered(false),
+            EventType::MouseDown(cursor) if has_ripple => {
+                if let Some(layout) = ctx.get_layout(&event.current_target) {
+                    let x = cursor.position.0 - layout.posx;
+                    let y = cursor.position.1 - layout.posy;
+                    set_ripple_origin(Some((x, y, 0.0)));
+                }
+            }
+            EventType::KeyDown(evt)
+                if event.target == event.current_target
+                    && matches!(evt.key(), KeyCode::Return | KeyCode::Space) =>
+            {
+                // Only the click is passed on, rather than the key press itself
+                event.stop_propagation();
+                event.prevent_default();
+                if let Some(ref on_event) = on_event {
+                    let mut click =
+                        Event::new(event.target, EventType::Click(CursorEvent::default()));
+                    on_event.try_call(ctx, &mut click);
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        if let Some(ref on_event) = on_event {
+            on_event.try_call(ctx, event);
+        }
+    }));
+    set_handlers((given_on_event, props.on_event.clone()));
+
+    // === Focus Ring === //
+    let ring_inset = -(FOCUS_RING_WIDTH + FOCUS_RING_OFFSET);
+    let focus_ring_styles = Style {
+        background_color: StyleProp::Value(Color::TRANSPARENT),
+        border: StyleProp::Value(Edge::all(Units::Pixels(FOCUS_RING_WIDTH))),
+        border_color: StyleProp::Value(theme.primary),
+        border_radius: StyleProp::Value(Corner::all(theme.radius - ring_inset)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        pointer_events: StyleProp::Value(PointerEvents::None),
+        left: StyleProp::Value(Units::Pixels(ring_inset)),
+        right: StyleProp::Value(Units::Pixels(ring_inset)),
+        top: StyleProp::Value(Units::Pixels(ring_inset)),
+        bottom: StyleProp::Value(Units::Pixels(ring_inset)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let show_focus_ring = is_focus_visible && !is_disabled;
+
+    // === Loading === //
+    // The children are wrapped (even when not loading) so they aren't remounted when the state
+    // changes, and they're hidden rather than removed so the button keeps its size
+    let button_styles = props.styles.clone().unwrap_or_default();
+    let content_styles = if has_content {
+        // Icons and labels are laid out in a centered row
+        let center = StyleProp::Value(Units::Stretch(1.0));
+        Style {
+            bottom: center.clone(),
+            col_between: StyleProp::Value(Units::Pixels(theme.spacing_small)),
+            layout_type: StyleProp::Value(LayoutType::Row),
+            top: center,
+            ..Default::default()
+        }
+    } else {
+        Style {
+            col_between: button_styles.col_between.clone(),
+            layout_type: button_styles.layout_type.clone(),
+            row_between: button_styles.row_between.clone(),
+            ..Default::default()
+        }
+    }
+    .with_style(Style {
+        height: StyleProp::Value(Units::Auto),
+        opacity: StyleProp::Value(if props.loading { 0.0 } else { 1.0 }),
+        width: StyleProp::Value(Units::Auto),
+        ..Default::default()
+    });
+    let label_styles = Style {
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let label = label.unwrap_or_default();
+    let icon_size = theme.font_size;
+    let spinner_styles = Style {
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let is_loading = props.loading;
+
+    // === Ripple === //
+    let ripple_progress = match ripple {
+        Some((.., elapsed)) if props.ripple && has_motion => {
+            let progress = (elapsed / RIPPLE_DURATION).min(1.0);
+            if progress < 1.0 {
+                Some(progress)
+            } else {
+                set_ripple(None);
+                None
+            }
+        }
+        _ => None,
+    };
+
+    // Both animations share the button's tick, so each one is only advanced while it's running
+    let is_rippling = ripple_progress.is_some();
+    let tick_set_ripple = set_ripple.clone();
+    context.on_tick((is_lifting || is_rippling).then(|| {
+        Handler::new(move |delta_time: f32| {
+            if is_lifting {
+                let (is_hovered, elapsed, from) = hover;
+                tick_set_hover((is_hovered, elapsed + delta_time, from));
+            }
+            if let Some((x, y, elapsed)) = ripple.filter(|_| is_rippling) {
+                tick_set_ripple(Some((x, y, elapsed + delta_time)));
+            }
+        })
+    }));
+    let show_ripple = ripple_progress.is_some();
+    let ripple_layer_styles = Style {
+        left: StyleProp::Value(Units::Pixels(0.0)),
+        pointer_events: StyleProp::Value(PointerEvents::None),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        top: StyleProp::Value(Units::Pixels(0.0)),
+        ..Default::default()
+    };
+    let ripple_styles = match (ripple, ripple_progress) {
+        (Some((x, y, _)), Some(progress)) => {
+            // The ripple grows until it covers the corner of the button farthest from the cursor
+            let (width, height) = context
+                .get_layout(&self.get_id())
+                .map(|layout| (layout.width, layout.height))
+                .unwrap_or_default();
+            let max_radius = x.max(width - x).hypot(y.max(height - y));
+            let radius = max_radius * ease_out(progress);
+            Style {
+                background_color: StyleProp::Value(theme.text),
+                border_radius: StyleProp::Value(Corner::all(radius)),
+                height: StyleProp::Value(Units::Pixels(radius * 2.0)),
+                left: StyleProp::Value(Units::Pixels(x - radius)),
+                opacity: StyleProp::Value(RIPPLE_OPACITY * (1.0 - progress)),
+                position_type: StyleProp::Value(PositionType::SelfDirected),
+                top: StyleProp::Value(Units::Pixels(y - radius)),
+                width: StyleProp::Value(Units::Pixels(radius * 2.0)),
+                ..Default::default()
+            }
+        }
+        _ => Style::default(),
+    };
+
     rsx! {
         <Fragment>
-            {children}
+            <Element styles={Some(content_styles)}>
+                <ButtonIconContent icon={leading_icon} size={icon_size} />
+                <If condition={has_label}>
+                    <Text content={label} size={theme.font_size} styles={Some(label_styles)} />
+                </If>
+                {children}
+                <ButtonIconContent icon={trailing_icon} size={icon_size} />
+            </Element>
+            <If condition={show_ripple}>
+                <Clip styles={Some(ripple_layer_styles)}>
+                    <Background styles={Some(ripple_styles)} />
+                </Clip>
+            </If>
+            <If condition={is_loading}>
+                <Spinner styles={Some(spinner_styles)} />
+            </If>
+            <If condition={show_focus_ring}>
+                <Background styles={Some(focus_ring_styles)} />
+            </If>
         </Fragment>
     }
 }
+
+/// Props used by the [`ButtonIconContent`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+struct ButtonIconContentProps {
+    /// The icon to display, if any
+    icon: Option<ButtonIcon>,
+    /// The width and height of the icon (in pixels)
+    size: f32,
+}
+
+#[widget]
+/// Displays a [`ButtonIcon`], sized and centered within the row of a [`Button`]
+fn ButtonIconContent(props: ButtonIconContentProps) {
+    let ButtonIconContentProps { icon, size } = props.clone();
+    let icon_styles = Style {
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+
+    match icon {
+        Some(ButtonIcon::Image(handle)) => {
+            let image_styles = Style {
+                height: StyleProp::Value(Units::Pixels(size)),
+                width: StyleProp::Value(Units::Pixels(size)),
+                ..icon_styles
+            };
+            rsx! {
+                <Image handle={handle} styles={Some(image_styles)} />
+            }
+        }
+        Some(ButtonIcon::Glyph(glyph)) => {
+            rsx! {
+                <Text content={glyph} size={size} styles={Some(icon_styles)} />
+            }
+        }
+        None => {}
+    }
+}
+
+/// Returns how far a button is lifted (from `0.0` to `1.0`) given its hover state
+///
+/// The state contains whether the button is hovered, the time since that last changed, and how far
+/// it was lifted at the time.
+fn hover_lift_amount((is_hovered, elapsed, from): (bool, f32, f32)) -> f32 {
+    let progress = elapsed / HOVER_LIFT_DURATION;
+    if is_hovered {
+        (from + progress).min(1.0)
+    } else {
+        (from - progress).max(0.0)
+    }
+}
@@ -0,0 +1,335 @@
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{Corner, LayoutType, PositionType, Style, StyleProp, Units},
+    use_state, widget, Children, CursorIcon, EventType, Handler, OnChange, OnEvent, OnLayout,
+    VecTracker, WidgetProps,
+};
+
+use crate::widgets::{transition::ease_out, use_theme, Background, Button, Clip, Element, Text};
+
+/// The size of each navigation button (in pixels)
+const BUTTON_SIZE: f32 = 32.0;
+/// The size of each dot indicator (in pixels)
+const DOT_SIZE: f32 = 8.0;
+/// The key of the timeout used to advance to the next slide
+const AUTO_ADVANCE_TIMEOUT: &str = "auto_advance";
+/// The time it takes to slide to another slide (in seconds)
+const SLIDE_DURATION: f32 = 0.3;
+
+/// The animation of a [`Carousel`] sliding from one slide to another
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct SlideAnimation {
+    /// The offset of the track when the animation started (in slides)
+    from: f32,
+    /// The slide being slid to
+    to: usize,
+    /// The time since the animation started (in seconds)
+    elapsed: f32,
+}
+
+impl SlideAnimation {
+    /// Returns the offset of the track at the current point of the animation (in slides)
+    fn offset(&self, duration: f32) -> f32 {
+        if duration <= 0.0 || self.elapsed >= duration {
+            return self.to as f32;
+        }
+        let amount = ease_out(self.elapsed / duration);
+        self.from + (self.to as f32 - self.from) * amount
+    }
+}
+
+/// Props used by the [`Carousel`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct CarouselProps {
+    /// The initially displayed slide
+    pub active: usize,
+    /// The number of seconds between automatically advancing to the next slide
+    ///
+    /// If `None`, the carousel will only change slides when the user navigates.
+    /// Auto-advancing is paused while the carousel is hovered.
+    pub auto_advance: Option<f32>,
+    /// If true, navigating past the last slide returns to the first (and vice versa)
+    pub r#loop: bool,
+    /// A callback for when the displayed slide changes
+    pub on_change: Option<OnChange<usize>>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A widget that displays its children as slides, one at a time
+///
+/// Each child is a single slide and should stretch to fill the width of the carousel
+/// (i.e. have a `width` of `Units::Stretch(1.0)`, the default for most widgets). The slides
+/// can be navigated using the previous and next buttons or the dot indicators. Changing slides
+/// slides the track over to the new slide, advancing with the frame time, unless the user
+/// [prefers reduced motion](crate::core::KayakContextRef::prefers_reduced_motion).
+///
+/// # Props
+///
+/// __Type:__ [`CarouselProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ✅        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ❌        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{Carousel, Text};
+///
+/// rsx! {
+///     <Carousel r#loop={true}>
+///         <Text content={"First".to_string()} size={16.0} />
+///         <Text content={"Second".to_string()} size={16.0} />
+///     </Carousel>
+/// }
+/// ```
+pub fn Carousel(props: CarouselProps) {
+    let CarouselProps {
+        active,
+        auto_advance,
+        r#loop,
+        on_change,
+        ..
+    } = props.clone();
+
+    // === State === //
+    // The slides are only counted once they've been built, so the carousel renders again whenever
+    // their number changes
+    let (slide_count, set_slide_count, ..) = use_state!(0usize);
+    let children = children.map(|children| {
+        Children::new(move |parent, ctx| {
+            children.build(parent, ctx);
+            set_slide_count(ctx.child_count());
+        })
+    });
+    let (current, set_current, ..) = use_state!(active);
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+    // The slide that the pending auto-advance timeout was scheduled from, if any
+    let (scheduled_from, set_scheduled_from, ..) = use_state!(Option::<usize>::None);
+    let current = if slide_count > 0 {
+        current.min(slide_count - 1)
+    } else {
+        current
+    };
+
+    // === Animation === //
+    let duration = if context.prefers_reduced_motion() {
+        0.0
+    } else {
+        SLIDE_DURATION
+    };
+    let (animation, set_animation, ..) = use_state!(SlideAnimation {
+        from: current as f32,
+        to: current,
+        elapsed: duration,
+    });
+    let mut animation = animation;
+    if animation.to != current {
+        // Re-target the animation from wherever the track currently is
+        animation = SlideAnimation {
+            from: animation.offset(duration),
+            to: current,
+            elapsed: 0.0,
+        };
+        set_animation(animation);
+    }
+
+    let is_animating = duration > 0.0 && animation.elapsed < duration;
+    context.on_tick(is_animating.then(|| {
+        Handler::new(move |delta_time: f32| {
+            set_animation(SlideAnimation {
+                elapsed: animation.elapsed + delta_time,
+                ..animation
+            });
+        })
+    }));
+
+    let go_to = Handler::new(move |slide: usize| {
+        set_current(slide);
+        if let Some(ref on_change) = on_change {
+            on_change.call(slide);
+        }
+    });
+
+    let previous = if current > 0 {
+        Some(current - 1)
+    } else if r#loop && slide_count > 1 {
+        Some(slide_count - 1)
+    } else {
+        None
+    };
+    let next = if current + 1 < slide_count {
+        Some(current + 1)
+    } else if r#loop && slide_count > 1 {
+        Some(0)
+    } else {
+        None
+    };
+
+    // === Auto-Advance === //
+    match (auto_advance, next) {
+        (Some(interval), Some(next)) if !is_hovered => {
+            // The timeout is restarted whenever the slide changes, including when navigating manually
+            if scheduled_from != Some(current) || !context.has_timeout(AUTO_ADVANCE_TIMEOUT) {
+                let go_to = go_to.clone();
+                context.set_timeout(
+                    AUTO_ADVANCE_TIMEOUT,
+                    interval,
+                    Handler::new(move |_| go_to.call(next)),
+                );
+                if scheduled_from != Some(current) {
+                    set_scheduled_from(Some(current));
+                }
+            }
+        }
+        _ => {
+            context.clear_timeout(AUTO_ADVANCE_TIMEOUT);
+            if scheduled_from.is_some() {
+                set_scheduled_from(None);
+            }
+        }
+    }
+
+    // === Events === //
+    props.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn(..) => set_is_hovered(true),
+        EventType::MouseOut(..) => set_is_hovered(false),
+        _ => {}
+    }));
+
+    let navigate = |target: Option<usize>| {
+        let go_to = go_to.clone();
+        target.map(|target| {
+            OnEvent::new(move |_, event| match event.event_type {
+                EventType::Click(..) => go_to.call(target),
+                _ => {}
+            })
+        })
+    };
+    let on_previous = navigate(previous);
+    let on_next = navigate(next);
+
+    // === Styles === //
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles),
+    );
+
+    let track_styles = Style {
+        layout_type: StyleProp::Value(LayoutType::Row),
+        left: StyleProp::Value(Units::Percentage(-100.0 * animation.offset(duration))),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        top: StyleProp::Value(Units::Pixels(0.0)),
+        width: StyleProp::Value(Units::Percentage(100.0 * slide_count.max(1) as f32)),
+        ..Default::default()
+    };
+    let theme = use_theme(context).unwrap_or_default();
+    let button_styles = |enabled: bool, is_left: bool| Style {
+        background_color: StyleProp::Value(theme.overlay),
+        border_radius: StyleProp::Value(Corner::all(BUTTON_SIZE / 2.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        cursor: if enabled {
+            CursorIcon::Hand.into()
+        } else {
+            CursorIcon::Default.into()
+        },
+        height: StyleProp::Value(Units::Pixels(BUTTON_SIZE)),
+        left: StyleProp::Value(if is_left {
+            Units::Pixels(8.0)
+        } else {
+            Units::Stretch(1.0)
+        }),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        right: StyleProp::Value(if is_left {
+            Units::Stretch(1.0)
+        } else {
+            Units::Pixels(8.0)
+        }),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        width: StyleProp::Value(Units::Pixels(BUTTON_SIZE)),
+        ..Default::default()
+    };
+    let previous_styles = button_styles(previous.is_some(), true);
+    let next_styles = button_styles(next.is_some(), false);
+    let arrow_styles = Style {
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        color: StyleProp::Value(theme.text),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let next_arrow_styles = arrow_styles.clone();
+    let dots_styles = Style {
+        bottom: StyleProp::Value(Units::Pixels(8.0)),
+        col_between: StyleProp::Value(Units::Pixels(6.0)),
+        height: StyleProp::Value(Units::Pixels(DOT_SIZE)),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        width: StyleProp::Value(Units::Auto),
+        ..Default::default()
+    };
+
+    // === Render === //
+    rsx! {
+        <>
+            <Clip>
+                <Element styles={Some(track_styles)}>
+                    {children}
+                </Element>
+            </Clip>
+            <Button styles={Some(previous_styles)} on_event={on_previous}>
+                <Text content={"<".to_string()} size={16.0} styles={Some(arrow_styles)} />
+            </Button>
+            <Button styles={Some(next_styles)} on_event={on_next}>
+                <Text content={">".to_string()} size={16.0} styles={Some(next_arrow_styles)} />
+            </Button>
+            <Element styles={Some(dots_styles)}>
+                {VecTracker::from((0..slide_count).map(|slide| {
+                    let go_to = go_to.clone();
+                    let on_event = OnEvent::new(move |_, event| match event.event_type {
+                        EventType::Click(..) => go_to.call(slide),
+                        _ => {}
+                    });
+                    let dot_styles = Style {
+                        background_color: StyleProp::Value(if slide == current {
+                            theme.primary
+                        } else {
+                            theme.text_muted
+                        }),
+                        border_radius: StyleProp::Value(Corner::all(DOT_SIZE / 2.0)),
+                        cursor: CursorIcon::Hand.into(),
+                        height: StyleProp::Value(Units::Pixels(DOT_SIZE)),
+                        width: StyleProp::Value(Units::Pixels(DOT_SIZE)),
+                        ..Default::default()
+                    };
+                    constructor! {
+                        <Background styles={Some(dot_styles)} on_event={Some(on_event)} />
+                    }
+                }))}
+            </Element>
+        </>
+    }
+}
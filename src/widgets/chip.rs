@@ -0,0 +1,151 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Corner, LayoutType, Style, StyleProp, Units},
+    widget, Children, CursorIcon, EventType, OnEvent, OnLayout, WidgetProps,
+};
+
+use crate::widgets::{use_theme, If, Text};
+
+/// Props used by the [`Chip`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct ChipProps {
+    /// The string displayed within the chip
+    pub label: String,
+    /// A callback for when the user clicks the body of the chip
+    ///
+    /// This is not fired when clicking the remove button of a [`removable`](Self::removable) chip.
+    pub on_click: Option<OnEvent>,
+    /// A callback for when the user clicks the remove button of the chip
+    ///
+    /// Only applies to chips that are [`removable`](Self::removable).
+    pub on_remove: Option<OnEvent>,
+    /// If true, displays a small "x" button used to remove the chip
+    pub removable: bool,
+    /// If true, the chip is displayed with the selected fill color
+    pub selected: bool,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A compact, rounded widget used to display a label, such as a tag or filter
+///
+/// Chips size themselves to fit their label, making them well-suited for a row container
+/// with a [`layout_wrap`](crate::core::styles::Style::layout_wrap) of
+/// [`LayoutWrap::Wrap`](crate::core::styles::LayoutWrap::Wrap).
+///
+/// # Props
+///
+/// __Type:__ [`ChipProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, use_state, EventType, OnEvent};
+/// # use kayak_ui::widgets::Chip;
+///
+/// let (selected, set_selected, ..) = use_state!(false);
+/// let on_click = OnEvent::new(move |_, event| match event.event_type {
+///     EventType::Click(..) => set_selected(!selected),
+///     _ => {}
+/// });
+///
+/// rsx! {
+///     <Chip label={"Rust".to_string()} selected={selected} on_click={Some(on_click)} removable={true} />
+/// }
+/// ```
+pub fn Chip(props: ChipProps) {
+    let ChipProps {
+        label,
+        on_click,
+        on_remove,
+        removable,
+        selected,
+        ..
+    } = props.clone();
+
+    let theme = use_theme(context).unwrap_or_default();
+    let (background_color, color) = if selected {
+        (theme.primary, theme.on_primary)
+    } else {
+        (theme.surface, theme.text)
+    };
+
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Quad),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                background_color: StyleProp::Value(background_color),
+                border_radius: StyleProp::Value(Corner::all(12.0)),
+                color: StyleProp::Value(color),
+                col_between: StyleProp::Value(Units::Pixels(6.0)),
+                cursor: CursorIcon::Hand.into(),
+                height: StyleProp::Value(Units::Pixels(24.0)),
+                layout_type: StyleProp::Value(LayoutType::Row),
+                padding_left: StyleProp::Value(Units::Pixels(10.0)),
+                padding_right: StyleProp::Value(Units::Pixels(10.0)),
+                width: StyleProp::Value(Units::Auto),
+                ..Default::default()
+            }),
+    );
+
+    if let Some(on_click) = on_click {
+        let on_event = props.on_event.clone();
+        props.on_event = Some(OnEvent::new(move |ctx, event| {
+            if matches!(event.event_type, EventType::Click(..)) {
+                on_click.try_call(ctx, event);
+            }
+            if let Some(ref on_event) = on_event {
+                on_event.try_call(ctx, event);
+            }
+        }));
+    }
+
+    let remove_handler = OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::Click(..) => {
+            // Don't let the click be treated as a click on the chip itself
+            event.stop_propagation();
+            if let Some(ref on_remove) = on_remove {
+                on_remove.try_call(ctx, event);
+            }
+        }
+        _ => {}
+    });
+
+    let text_styles = Style {
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let remove_styles = text_styles.clone();
+
+    rsx! {
+        <>
+            <Text content={label} size={14.0} styles={Some(text_styles)} />
+            <If condition={removable}>
+                <Text content={"x".to_string()} size={12.0} styles={Some(remove_styles)} on_event={Some(remove_handler)} />
+            </If>
+        </>
+    }
+}
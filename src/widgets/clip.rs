@@ -24,6 +24,9 @@ pub struct ClipProps {
 /// A widget that clips its contents to fit the parent container or its designated
 /// [`width`](Style::width) and [`height`](Style::height) styling
 ///
+/// The clip region can be adjusted relative to the widget's layout using the
+/// [`clip_inset`](Style::clip_inset) style (negative values expand it).
+///
 /// # Props
 ///
 /// __Type:__ [`ClipProps`]
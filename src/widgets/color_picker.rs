@@ -0,0 +1,286 @@
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{Corner, Edge, LayoutType, LayoutWrap, PositionType, Style, StyleProp, Units},
+    use_state, widget, Children, Color, CursorIcon, EventType, Index, KayakContextRef, OnChange,
+    OnEvent, OnLayout, VecTracker, WidgetProps,
+};
+
+use crate::widgets::{Background, Element, Text};
+
+/// The width and height of the saturation/value square (in pixels)
+const SQUARE_SIZE: f32 = 160.0;
+/// The number of cells along each axis of the saturation/value square
+const SQUARE_CELLS: usize = 8;
+/// The height of the hue and alpha sliders (in pixels)
+const SLIDER_HEIGHT: f32 = 12.0;
+/// The number of segments making up the hue and alpha sliders
+const SLIDER_SEGMENTS: usize = 12;
+/// The size of the markers displayed over the square and sliders (in pixels)
+const MARKER_SIZE: f32 = 8.0;
+
+/// The area of a [`ColorPicker`] currently being dragged
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum DragTarget {
+    SaturationValue,
+    Hue,
+    Alpha,
+}
+
+/// Props used by the [`ColorPicker`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct ColorPickerProps {
+    /// The initial color of the picker
+    pub default_color: Color,
+    /// A callback for when the user changes the selected color
+    pub on_change: Option<OnChange<Color>>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A widget used to select a color
+///
+/// The picker is made up of a saturation/value square, a hue slider, and an alpha slider, each
+/// of which can be clicked or dragged. The selected color is previewed alongside its hex code.
+///
+/// # Props
+///
+/// __Type:__ [`ColorPickerProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Color, OnChange, rsx};
+/// # use kayak_ui::widgets::ColorPicker;
+///
+/// let on_change = OnChange::new(|color: Color| {
+///     println!("Selected: {}", color.to_hex());
+/// });
+///
+/// rsx! {
+///     <ColorPicker default_color={Color::new(1.0, 0.0, 0.0, 1.0)} on_change={Some(on_change)} />
+/// }
+/// ```
+pub fn ColorPicker(props: ColorPickerProps) {
+    let ColorPickerProps {
+        default_color,
+        on_change,
+        ..
+    } = props.clone();
+
+    // === State === //
+    // The color is stored as HSVA so that the hue is preserved, even when fully desaturated
+    let (hsva, set_hsva, ..) = use_state!(default_color.to_hsva());
+    let (dragging, set_dragging, ..) = use_state!(Option::<DragTarget>::None);
+    let (hue, saturation, value, alpha) = hsva;
+    let color = Color::hsva(hue, saturation, value, alpha);
+
+    // === Events === //
+    let make_handler = |target: DragTarget| {
+        let set_hsva = set_hsva.clone();
+        let set_dragging = set_dragging.clone();
+        let on_change = on_change.clone();
+        OnEvent::new(move |ctx, event| {
+            let position = match event.event_type {
+                EventType::MouseDown(data) => {
+                    ctx.capture_pointer();
+                    set_dragging(Some(target));
+                    data.position
+                }
+                EventType::Hover(data) if dragging == Some(target) => data.position,
+                EventType::MouseUp(..) => {
+                    set_dragging(None);
+                    return;
+                }
+                _ => return,
+            };
+
+            let (x, y) = normalized_position(ctx, event.current_target, position);
+            let hsva = match target {
+                DragTarget::SaturationValue => (hue, x, 1.0 - y, alpha),
+                DragTarget::Hue => (x * 360.0, saturation, value, alpha),
+                DragTarget::Alpha => (hue, saturation, value, x),
+            };
+            set_hsva(hsva);
+            if let Some(ref on_change) = on_change {
+                on_change.call(Color::hsva(hsva.0, hsva.1, hsva.2, hsva.3));
+            }
+        })
+    };
+    let square_handler = make_handler(DragTarget::SaturationValue);
+    let hue_handler = make_handler(DragTarget::Hue);
+    let alpha_handler = make_handler(DragTarget::Alpha);
+
+    // === Styles === //
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                height: StyleProp::Value(Units::Auto),
+                row_between: StyleProp::Value(Units::Pixels(8.0)),
+                width: StyleProp::Value(Units::Pixels(SQUARE_SIZE)),
+                ..Default::default()
+            }),
+    );
+
+    let square_styles = Style {
+        cursor: CursorIcon::Crosshair.into(),
+        height: StyleProp::Value(Units::Pixels(SQUARE_SIZE)),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        layout_wrap: StyleProp::Value(LayoutWrap::Wrap),
+        width: StyleProp::Value(Units::Pixels(SQUARE_SIZE)),
+        ..Default::default()
+    };
+    let slider_styles = Style {
+        cursor: CursorIcon::Hand.into(),
+        height: StyleProp::Value(Units::Pixels(SLIDER_HEIGHT)),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        width: StyleProp::Value(Units::Pixels(SQUARE_SIZE)),
+        ..Default::default()
+    };
+    let alpha_slider_styles = slider_styles.clone();
+    let square_marker_styles =
+        marker_styles(saturation * SQUARE_SIZE, (1.0 - value) * SQUARE_SIZE, color);
+    let hue_marker_styles = marker_styles(
+        hue / 360.0 * SQUARE_SIZE,
+        SLIDER_HEIGHT / 2.0,
+        Color::hsva(hue, 1.0, 1.0, 1.0),
+    );
+    let alpha_marker_styles = marker_styles(alpha * SQUARE_SIZE, SLIDER_HEIGHT / 2.0, color);
+
+    let readout_styles = Style {
+        col_between: StyleProp::Value(Units::Pixels(8.0)),
+        height: StyleProp::Value(Units::Pixels(24.0)),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        ..Default::default()
+    };
+    let swatch_styles = Style {
+        background_color: StyleProp::Value(color),
+        border: StyleProp::Value(Edge::all(Units::Pixels(1.0))),
+        border_color: StyleProp::Value(Color::new(0.4, 0.4, 0.4, 1.0)),
+        border_radius: StyleProp::Value(Corner::all(4.0)),
+        height: StyleProp::Value(Units::Pixels(24.0)),
+        width: StyleProp::Value(Units::Pixels(24.0)),
+        ..Default::default()
+    };
+    let hex_styles = Style {
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let hex = color.to_hex();
+
+    // === Render === //
+    let cell_size = SQUARE_SIZE / SQUARE_CELLS as f32;
+    let segment_width = SQUARE_SIZE / SLIDER_SEGMENTS as f32;
+    rsx! {
+        <>
+            <Element styles={Some(square_styles)} on_event={Some(square_handler)}>
+                {VecTracker::from((0..SQUARE_CELLS * SQUARE_CELLS).map(|index| {
+                    let column = index % SQUARE_CELLS;
+                    let row = index / SQUARE_CELLS;
+                    let cell_saturation = (column as f32 + 0.5) / SQUARE_CELLS as f32;
+                    let cell_value = 1.0 - (row as f32 + 0.5) / SQUARE_CELLS as f32;
+                    let cell_styles = segment_styles(
+                        Color::hsva(hue, cell_saturation, cell_value, 1.0),
+                        cell_size,
+                        cell_size,
+                    );
+                    constructor! {
+                        <Background styles={Some(cell_styles)} />
+                    }
+                }))}
+                <Background styles={Some(square_marker_styles)} />
+            </Element>
+            <Element styles={Some(slider_styles)} on_event={Some(hue_handler)}>
+                {VecTracker::from((0..SLIDER_SEGMENTS).map(|index| {
+                    let segment_hue = (index as f32 + 0.5) / SLIDER_SEGMENTS as f32 * 360.0;
+                    let cell_styles = segment_styles(
+                        Color::hsva(segment_hue, 1.0, 1.0, 1.0),
+                        segment_width,
+                        SLIDER_HEIGHT,
+                    );
+                    constructor! {
+                        <Background styles={Some(cell_styles)} />
+                    }
+                }))}
+                <Background styles={Some(hue_marker_styles)} />
+            </Element>
+            <Element styles={Some(alpha_slider_styles)} on_event={Some(alpha_handler)}>
+                {VecTracker::from((0..SLIDER_SEGMENTS).map(|index| {
+                    let mut segment_color = color;
+                    segment_color.a = (index as f32 + 0.5) / SLIDER_SEGMENTS as f32;
+                    let cell_styles = segment_styles(segment_color, segment_width, SLIDER_HEIGHT);
+                    constructor! {
+                        <Background styles={Some(cell_styles)} />
+                    }
+                }))}
+                <Background styles={Some(alpha_marker_styles)} />
+            </Element>
+            <Element styles={Some(readout_styles)}>
+                <Background styles={Some(swatch_styles)} />
+                <Text content={hex} size={14.0} styles={Some(hex_styles)} />
+            </Element>
+        </>
+    }
+}
+
+/// Returns the position relative to the given widget, normalized to the range `[0, 1]`
+fn normalized_position(context: &KayakContextRef, id: Index, position: (f32, f32)) -> (f32, f32) {
+    if let Some(layout) = context.get_layout(&id) {
+        (
+            ((position.0 - layout.posx) / layout.width.max(1.0)).clamp(0.0, 1.0),
+            ((position.1 - layout.posy) / layout.height.max(1.0)).clamp(0.0, 1.0),
+        )
+    } else {
+        (0.0, 0.0)
+    }
+}
+
+/// Returns the styles for a single, solid-colored segment of the square or a slider
+fn segment_styles(color: Color, width: f32, height: f32) -> Style {
+    Style {
+        background_color: StyleProp::Value(color),
+        height: StyleProp::Value(Units::Pixels(height)),
+        width: StyleProp::Value(Units::Pixels(width)),
+        ..Default::default()
+    }
+}
+
+/// Returns the styles for a marker centered at the given position
+fn marker_styles(x: f32, y: f32, color: Color) -> Style {
+    Style {
+        background_color: StyleProp::Value(color),
+        border: StyleProp::Value(Edge::all(Units::Pixels(2.0))),
+        border_color: StyleProp::Value(Color::WHITE),
+        border_radius: StyleProp::Value(Corner::all(MARKER_SIZE / 2.0)),
+        height: StyleProp::Value(Units::Pixels(MARKER_SIZE)),
+        left: StyleProp::Value(Units::Pixels(x - MARKER_SIZE / 2.0)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        top: StyleProp::Value(Units::Pixels(y - MARKER_SIZE / 2.0)),
+        width: StyleProp::Value(Units::Pixels(MARKER_SIZE)),
+        ..Default::default()
+    }
+}
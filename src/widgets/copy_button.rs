@@ -0,0 +1,174 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Corner, Edge, PositionType, Style, StyleProp, Units},
+    use_state, widget, EventType, Handler, OnEvent, OnLayout, PointerEvents, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Background, Button, Text, Transition};
+
+/// The key of the timeout used to dismiss the confirmation
+const DISMISS_TIMEOUT: &str = "dismiss";
+/// The default time the confirmation is displayed for (in seconds)
+const DEFAULT_DURATION: f32 = 1.5;
+/// The height of the confirmation (in pixels)
+const CONFIRMATION_HEIGHT: f32 = 24.0;
+
+/// Props used by the [`CopyButton`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct CopyButtonProps {
+    /// The text copied to the clipboard when the button is clicked
+    pub value: String,
+    /// The text displayed in the button
+    ///
+    /// Defaults to "Copy".
+    pub label: Option<String>,
+    /// The time the confirmation is displayed for after copying (in seconds)
+    ///
+    /// Defaults to 1.5 seconds.
+    pub duration: Option<f32>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A [`Button`] that copies a string to the clipboard when clicked
+///
+/// After copying, a "Copied!" message fades in above the button and is dismissed automatically
+/// once the [`duration`](CopyButtonProps::duration) has passed. Copying again while the message
+/// is displayed restarts that duration. If the clipboard can't be written to (such as when the
+/// integration hasn't provided one), the message reads "Copy failed" instead.
+///
+/// The text is copied using [`KayakContextRef::clipboard`](crate::core::KayakContextRef::clipboard).
+/// Like any button, this can also be activated with `Enter` or `Space` while focused.
+///
+/// # Props
+///
+/// __Type:__ [`CopyButtonProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::CopyButton;
+///
+/// rsx! {
+///     <CopyButton value={"cargo add kayak_ui".to_string()} label={Some("Copy command".to_string())} />
+/// }
+/// ```
+pub fn CopyButton(props: CopyButtonProps) {
+    let CopyButtonProps {
+        value,
+        label,
+        duration,
+        ..
+    } = props.clone();
+    let theme = use_theme(context).unwrap_or_default();
+
+    // === State === //
+    // The number of times the button was clicked and whether the last copy succeeded
+    let (copied, set_copied, ..) = use_state!((0_usize, true));
+    // The number of clicks when the confirmation was last dismissed
+    let (dismissed, set_dismissed, ..) = use_state!(0_usize);
+    // The number of clicks when the pending dismiss timeout was scheduled
+    let (scheduled_for, set_scheduled_for, ..) = use_state!(0_usize);
+    let (copies, succeeded) = copied;
+    let is_confirming = copies > dismissed;
+
+    // === Auto-Dismiss === //
+    if is_confirming && scheduled_for != copies {
+        // Replaces the timeout of any earlier copy, so the duration restarts
+        context.set_timeout(
+            DISMISS_TIMEOUT,
+            duration.unwrap_or(DEFAULT_DURATION),
+            Handler::new(move |_| set_dismissed(copies)),
+        );
+        set_scheduled_for(copies);
+    }
+
+    // === Events === //
+    let on_click = OnEvent::new(move |ctx, event| {
+        if let EventType::Click(..) = event.event_type {
+            let succeeded = ctx.clipboard().set_text(&value).is_ok();
+            set_copied((copies + 1, succeeded));
+        }
+    });
+
+    // === Styles === //
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                height: StyleProp::Value(Units::Auto),
+                width: StyleProp::Value(Units::Auto),
+                ..Default::default()
+            }),
+    );
+
+    let button_styles = Style {
+        padding_left: StyleProp::Value(Units::Pixels(theme.spacing_large)),
+        padding_right: StyleProp::Value(Units::Pixels(theme.spacing_large)),
+        width: StyleProp::Value(Units::Auto),
+        ..Default::default()
+    };
+    // The confirmation is centered above the button, without affecting its layout
+    let confirmation_styles = Style {
+        bottom: StyleProp::Value(Units::Auto),
+        height: StyleProp::Value(Units::Pixels(CONFIRMATION_HEIGHT)),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        pointer_events: StyleProp::Value(PointerEvents::None),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Pixels(-(CONFIRMATION_HEIGHT + theme.spacing_small))),
+        width: StyleProp::Value(Units::Auto),
+        ..Default::default()
+    };
+    let background_styles = Style {
+        background_color: StyleProp::Value(theme.overlay),
+        border_radius: StyleProp::Value(Corner::all(theme.radius)),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        padding: StyleProp::Value(Edge::new(
+            Units::Stretch(1.0),
+            Units::Pixels(theme.spacing_small),
+            Units::Stretch(1.0),
+            Units::Pixels(theme.spacing_small),
+        )),
+        width: StyleProp::Value(Units::Auto),
+        ..Default::default()
+    };
+    let message_styles = Style {
+        color: StyleProp::Value(theme.text),
+        ..Default::default()
+    };
+
+    let label = label.unwrap_or_else(|| "Copy".to_string());
+    let message = if succeeded { "Copied!" } else { "Copy failed" }.to_string();
+    let font_size = theme.font_size_small;
+
+    rsx! {
+        <>
+            <Button label={Some(label)} styles={Some(button_styles)} on_event={Some(on_click)} />
+            <Transition visible={is_confirming} styles={Some(confirmation_styles)}>
+                <Background styles={Some(background_styles)}>
+                    <Text content={message} size={font_size} styles={Some(message_styles)} />
+                </Background>
+            </Transition>
+        </>
+    }
+}
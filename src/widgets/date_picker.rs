@@ -0,0 +1,462 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{Corner, Edge, LayoutType, LayoutWrap, Style, StyleProp, Units},
+    use_state, widget, Children, Color, CursorIcon, EventType, OnChange, OnEvent, OnLayout,
+    VecTracker, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Background, Button, Element, Text};
+
+/// The width of each day cell (in pixels)
+const CELL_WIDTH: f32 = 32.0;
+/// The height of each day cell (in pixels)
+const CELL_HEIGHT: f32 = 28.0;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
+
+/// A calendar date, used by the [`DatePicker`] widget
+///
+/// Dates are ordered chronologically.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// The year (e.g. `2022`)
+    pub year: i32,
+    /// The month, starting at 1 for January
+    pub month: u32,
+    /// The day of the month, starting at 1
+    pub day: u32,
+}
+
+impl Date {
+    /// Creates a new date
+    ///
+    /// Returns `None` if the given month or day is out of range.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    /// Returns the current date (in UTC)
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    /// Returns the day of the week for this date, where `0` is Sunday
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 was a Thursday
+        (self.to_days() + 4).rem_euclid(7) as u32
+    }
+
+    /// Returns the number of days since 1970-01-01
+    fn to_days(&self) -> i64 {
+        // Based on Howard Hinnant's `days_from_civil` algorithm
+        let year = if self.month <= 2 {
+            self.year as i64 - 1
+        } else {
+            self.year as i64
+        };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Creates a date from the number of days since 1970-01-01
+    fn from_days(days: i64) -> Self {
+        // Based on Howard Hinnant's `civil_from_days` algorithm
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        } as u32;
+        let year = (year_of_era + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+
+        Self { year, month, day }
+    }
+}
+
+/// Props used by the [`DatePicker`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct DatePickerProps {
+    /// The latest selectable date
+    pub max: Option<Date>,
+    /// The earliest selectable date
+    pub min: Option<Date>,
+    /// A callback for when the user clicks a day
+    pub on_change: Option<OnChange<Date>>,
+    /// The currently selected date
+    ///
+    /// The calendar initially displays the month of this date or, if `None`, the current month.
+    pub selected: Option<Date>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A widget that displays a month calendar, used to select a date
+///
+/// Days outside of the [`min`](DatePickerProps::min) and [`max`](DatePickerProps::max) range
+/// are disabled. The current day and the selected day are highlighted.
+///
+/// # Props
+///
+/// __Type:__ [`DatePickerProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{OnChange, rsx, use_state};
+/// # use kayak_ui::widgets::{Date, DatePicker};
+///
+/// let (selected, set_selected, ..) = use_state!(Date::new(2022, 1, 1));
+/// let on_change = OnChange::new(move |date| {
+///     set_selected(Some(date));
+/// });
+///
+/// rsx! {
+///     <DatePicker selected={selected} on_change={Some(on_change)} />
+/// }
+/// ```
+pub fn DatePicker(props: DatePickerProps) {
+    let DatePickerProps {
+        max,
+        min,
+        on_change,
+        selected,
+        ..
+    } = props.clone();
+
+    let today = Date::today();
+    let initial = selected.unwrap_or(today);
+    // The year and month currently being displayed
+    let (visible, set_visible, ..) = use_state!((initial.year, initial.month));
+    let (year, month) = visible;
+
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                height: StyleProp::Value(Units::Auto),
+                row_between: StyleProp::Value(Units::Pixels(4.0)),
+                width: StyleProp::Value(Units::Pixels(CELL_WIDTH * 7.0)),
+                ..Default::default()
+            }),
+    );
+
+    // === Navigation === //
+    let cloned_set_visible = set_visible.clone();
+    let on_previous = OnEvent::new(move |_, event| match event.event_type {
+        EventType::Click(..) => {
+            if month == 1 {
+                cloned_set_visible((year - 1, 12));
+            } else {
+                cloned_set_visible((year, month - 1));
+            }
+        }
+        _ => {}
+    });
+    let on_next = OnEvent::new(move |_, event| match event.event_type {
+        EventType::Click(..) => {
+            if month == 12 {
+                set_visible((year + 1, 1));
+            } else {
+                set_visible((year, month + 1));
+            }
+        }
+        _ => {}
+    });
+
+    // === Styles === //
+    let theme = use_theme(context).unwrap_or_default();
+    let header_styles = Style {
+        height: StyleProp::Value(Units::Pixels(CELL_HEIGHT)),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        ..Default::default()
+    };
+    let navigation_styles = Style {
+        height: StyleProp::Value(Units::Pixels(CELL_HEIGHT)),
+        width: StyleProp::Value(Units::Pixels(CELL_WIDTH)),
+        ..Default::default()
+    };
+    let next_styles = navigation_styles.clone();
+    let title_styles = Style {
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let weekdays_styles = Style {
+        height: StyleProp::Value(Units::Pixels(CELL_HEIGHT)),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        ..Default::default()
+    };
+    let days_styles = Style {
+        height: StyleProp::Value(Units::Auto),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        layout_wrap: StyleProp::Value(LayoutWrap::Wrap),
+        ..Default::default()
+    };
+
+    let title = format!("{} {}", MONTH_NAMES[(month - 1) as usize], year);
+
+    // Leading blank cells followed by each day of the month
+    let first_weekday = Date {
+        year,
+        month,
+        day: 1,
+    }
+    .weekday();
+    let cells: Vec<Option<Date>> = (0..first_weekday)
+        .map(|_| None)
+        .chain((1..=days_in_month(year, month)).map(|day| Some(Date { year, month, day })))
+        .collect();
+
+    rsx! {
+        <>
+            <Element styles={Some(header_styles)}>
+                <Button styles={Some(navigation_styles)} on_event={Some(on_previous)}>
+                    <Text content={"<".to_string()} size={14.0} styles={Some(cell_text_styles(theme.text))} />
+                </Button>
+                <Text content={title} size={16.0} styles={Some(title_styles)} />
+                <Button styles={Some(next_styles)} on_event={Some(on_next)}>
+                    <Text content={">".to_string()} size={14.0} styles={Some(cell_text_styles(theme.text))} />
+                </Button>
+            </Element>
+            <Element styles={Some(weekdays_styles)}>
+                {VecTracker::from(WEEKDAY_NAMES.iter().map(|name| {
+                    let weekday_styles = cell_styles(Color::TRANSPARENT);
+                    constructor! {
+                        <Element styles={Some(weekday_styles)}>
+                            <Text content={name.to_string()} size={12.0} styles={Some(cell_text_styles(theme.text_muted))} />
+                        </Element>
+                    }
+                }))}
+            </Element>
+            <Element styles={Some(days_styles)}>
+                {VecTracker::from(cells.clone().into_iter().map(|cell| {
+                    let date = match cell {
+                        Some(date) => date,
+                        None => {
+                            let blank_styles = cell_styles(Color::TRANSPARENT);
+                            return constructor! {
+                                <Background styles={Some(blank_styles)} />
+                            };
+                        }
+                    };
+
+                    let is_enabled = min.map_or(true, |min| date >= min) && max.map_or(true, |max| date <= max);
+                    let is_selected = selected == Some(date);
+                    let is_today = date == today;
+
+                    let background_color = if is_selected {
+                        theme.primary
+                    } else {
+                        Color::TRANSPARENT
+                    };
+                    let text_color = if !is_enabled {
+                        theme.text_muted
+                    } else if is_selected {
+                        theme.on_primary
+                    } else {
+                        theme.text
+                    };
+
+                    let mut day_styles = cell_styles(background_color);
+                    day_styles.cursor = if is_enabled {
+                        CursorIcon::Hand.into()
+                    } else {
+                        CursorIcon::Default.into()
+                    };
+                    if is_today && !is_selected {
+                        day_styles.border = StyleProp::Value(Edge::all(Units::Pixels(1.0)));
+                        day_styles.border_color = StyleProp::Value(theme.primary);
+                    }
+
+                    let on_change = on_change.clone();
+                    let on_event = if is_enabled {
+                        Some(OnEvent::new(move |_, event| match event.event_type {
+                            EventType::Click(..) => {
+                                if let Some(ref on_change) = on_change {
+                                    on_change.call(date);
+                                }
+                            }
+                            _ => {}
+                        }))
+                    } else {
+                        None
+                    };
+
+                    constructor! {
+                        <Background styles={Some(day_styles)} on_event={on_event}>
+                            <Text content={date.day.to_string()} size={14.0} styles={Some(cell_text_styles(text_color))} />
+                        </Background>
+                    }
+                }))}
+            </Element>
+        </>
+    }
+}
+
+/// Returns the number of days in the given month
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Returns true if the given year is a leap year
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the styles for a single calendar cell
+fn cell_styles(background_color: Color) -> Style {
+    Style {
+        background_color: StyleProp::Value(background_color),
+        border_radius: StyleProp::Value(Corner::all(4.0)),
+        height: StyleProp::Value(Units::Pixels(CELL_HEIGHT)),
+        width: StyleProp::Value(Units::Pixels(CELL_WIDTH)),
+        ..Default::default()
+    }
+}
+
+/// Returns the styles for text centered within a calendar cell
+fn cell_text_styles(color: Color) -> Style {
+    Style {
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        color: StyleProp::Value(color),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{days_in_month, is_leap_year, Date};
+
+    #[test]
+    fn should_detect_leap_years() {
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(1600));
+        assert!(!is_leap_year(2023));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2100));
+    }
+
+    #[test]
+    fn should_validate_month_lengths() {
+        assert_eq!(29, days_in_month(2024, 2));
+        assert_eq!(28, days_in_month(1900, 2));
+        assert_eq!(31, days_in_month(2022, 12));
+        assert_eq!(30, days_in_month(2022, 4));
+
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(2022, 4, 31).is_none());
+        assert!(Date::new(2022, 0, 1).is_none());
+        assert!(Date::new(2022, 13, 1).is_none());
+        assert!(Date::new(2022, 1, 0).is_none());
+    }
+
+    #[test]
+    fn should_compute_weekdays() {
+        let weekday = |year, month, day| Date::new(year, month, day).unwrap().weekday();
+        // Thursday
+        assert_eq!(4, weekday(1970, 1, 1));
+        // Wednesday
+        assert_eq!(3, weekday(1969, 12, 31));
+        // Tuesday
+        assert_eq!(2, weekday(2000, 2, 29));
+        // Saturday
+        assert_eq!(6, weekday(2022, 1, 1));
+        // Thursday
+        assert_eq!(4, weekday(2024, 2, 29));
+        // Monday
+        assert_eq!(1, weekday(1900, 1, 1));
+    }
+
+    #[test]
+    fn should_convert_days_both_ways() {
+        assert_eq!(0, Date::new(1970, 1, 1).unwrap().to_days());
+        assert_eq!(11_017, Date::new(2000, 3, 1).unwrap().to_days());
+        assert_eq!(-1, Date::new(1969, 12, 31).unwrap().to_days());
+
+        // Every day across several leap cycles follows the previous one
+        let mut date = Date::new(1600, 1, 1).unwrap();
+        let mut days = date.to_days();
+        while date.year < 2401 {
+            assert_eq!(days, date.to_days());
+            assert_eq!(date, Date::from_days(days));
+
+            date = Date::new(date.year, date.month, date.day + 1)
+                .or_else(|| Date::new(date.year, date.month + 1, 1))
+                .or_else(|| Date::new(date.year + 1, 1, 1))
+                .unwrap();
+            days += 1;
+        }
+    }
+}
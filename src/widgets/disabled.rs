@@ -0,0 +1,108 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp},
+    widget, Children, OnEvent, OnLayout, WidgetProps,
+};
+
+/// The opacity multiplier applied to disabled content
+const DISABLED_OPACITY: f32 = 0.5;
+
+/// Props used by the [`Disabled`] widget
+#[derive(WidgetProps, Debug, PartialEq, Clone)]
+pub struct DisabledProps {
+    /// If true, disables all of this widget's descendants
+    ///
+    /// Defaults to `true`.
+    pub disabled: bool,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+impl Default for DisabledProps {
+    fn default() -> Self {
+        Self {
+            disabled: true,
+            styles: None,
+            children: None,
+            on_event: None,
+            on_layout: None,
+        }
+    }
+}
+
+#[widget]
+/// A widget that disables all of its descendants at once
+///
+/// While disabled, none of the descendants can be focused (any existing focus is lost), and they
+/// ignore all mouse and keyboard events. The content is also dimmed by multiplying its opacity.
+/// Re-enabling restores both, without any of the descendants needing a `disabled` prop of their
+/// own. This composes with widgets that can be disabled individually, such as [`Button`](crate::widgets::Button):
+/// a widget is disabled if it or any of its ancestors is.
+///
+/// # Props
+///
+/// __Type:__ [`DisabledProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ✅        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Styles
+///
+/// While disabled, the [`opacity`](Style::opacity) style is multiplied by `0.5`.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, use_state};
+/// # use kayak_ui::widgets::{Button, Disabled, TextBox};
+///
+/// let (is_submitting, ..) = use_state!(false);
+///
+/// rsx! {
+///     <Disabled disabled={is_submitting}>
+///         <TextBox value={"Name".to_string()} />
+///         <Button />
+///     </Disabled>
+/// }
+/// ```
+pub fn Disabled(props: DisabledProps) {
+    let disabled = props.disabled;
+    let opacity = props
+        .styles
+        .as_ref()
+        .map(|styles| styles.opacity.resolve_or(1.0))
+        .unwrap_or(1.0);
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                opacity: if disabled {
+                    StyleProp::Value(opacity * DISABLED_OPACITY)
+                } else {
+                    StyleProp::Unset
+                },
+                ..Default::default()
+            })
+            .with_style(&props.styles),
+    );
+
+    context.set_disabled(disabled);
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
@@ -3,10 +3,12 @@ use kayak_core::OnLayout;
 use crate::core::{
     render_command::RenderCommand,
     rsx,
-    styles::{Style, StyleProp},
+    styles::{Overflow, Style, StyleProp},
     widget, Children, OnEvent, WidgetProps,
 };
 
+use crate::widgets::ScrollBox;
+
 /// Props used by the [`Element`] widget
 #[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
 pub struct ElementProps {
@@ -27,6 +29,9 @@ pub struct ElementProps {
 ///
 /// It essentially just sets the [`RenderCommand`] of this widget to [`RenderCommand::Layout`].
 ///
+/// If either axis has an [`overflow`](Style::overflow) of [`Overflow::Scroll`], the children
+/// are placed within a [`ScrollBox`], allowing them to be scrolled along that axis.
+///
 /// # Props
 ///
 /// __Type:__ [`ElementProps`]
@@ -45,9 +50,26 @@ pub fn Element(props: ElementProps) {
         ..props.styles.clone().unwrap_or_default()
     });
 
-    rsx! {
-        <>
-            {children}
-        </>
+    let (overflow_x, overflow_y) = props.styles.as_ref().unwrap().resolved_overflow();
+    let scroll_x = matches!(overflow_x, Overflow::Scroll);
+    let scroll_y = matches!(overflow_y, Overflow::Scroll);
+
+    if scroll_x || scroll_y {
+        rsx! {
+            <ScrollBox
+                disable_horizontal={!scroll_x}
+                disable_vertical={!scroll_y}
+                hide_horizontal={!scroll_x}
+                hide_vertical={!scroll_y}
+            >
+                {children}
+            </ScrollBox>
+        }
+    } else {
+        rsx! {
+            <>
+                {children}
+            </>
+        }
     }
 }
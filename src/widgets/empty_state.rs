@@ -0,0 +1,180 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{AlignItems, Edge, JustifyContent, LayoutType, Style, StyleProp, Units},
+    widget, OnEvent, OnLayout, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Button, ButtonIcon, If, Image, Text};
+
+/// The size of the icon relative to the theme's large font size
+const ICON_SCALE: f32 = 2.5;
+
+/// Props used by the [`EmptyState`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct EmptyStateProps {
+    /// An icon displayed above the title
+    pub icon: Option<ButtonIcon>,
+    /// A short heading, such as "No results"
+    pub title: Option<String>,
+    /// A longer explanation displayed below the title, such as how to add items
+    pub message: Option<String>,
+    /// The label of a button displayed below the message and its handler
+    ///
+    /// The handler receives the events of the button, including a
+    /// [`Click`](crate::core::EventType::Click) when it's activated.
+    pub action: Option<(String, OnEvent)>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A placeholder displayed in place of a list or panel that has no content
+///
+/// The icon, title, message, and action button are stacked in a column and centered, both
+/// horizontally and vertically, within this widget. By default, the widget fills its parent, so it
+/// can be displayed instead of the missing content without any additional layout. Any of the
+/// parts can be left out.
+///
+/// The title uses the theme's [`text`](crate::widgets::Theme::text) color, while the icon and
+/// message use its [`text_muted`](crate::widgets::Theme::text_muted) color.
+///
+/// # Props
+///
+/// __Type:__ [`EmptyStateProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, EventType, OnEvent};
+/// # use kayak_ui::widgets::{ButtonIcon, EmptyState};
+///
+/// let on_clear = OnEvent::new(|_, event| {
+///     if let EventType::Click(..) = event.event_type {
+///         println!("Cleared the filters!");
+///     }
+/// });
+///
+/// rsx! {
+///     <EmptyState
+///         icon={Some(ButtonIcon::Glyph("?".to_string()))}
+///         title={Some("No results".to_string())}
+///         message={Some("Try a different search, or clear the filters.".to_string())}
+///         action={Some(("Clear filters".to_string(), on_clear))}
+///     />
+/// }
+/// ```
+pub fn EmptyState(props: EmptyStateProps) {
+    let EmptyStateProps {
+        icon,
+        title,
+        message,
+        action,
+        ..
+    } = props.clone();
+    let theme = use_theme(context).unwrap_or_default();
+
+    // === Styles === //
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                align_items: StyleProp::Value(AlignItems::Center),
+                justify_content: StyleProp::Value(JustifyContent::Center),
+                layout_type: StyleProp::Value(LayoutType::Column),
+                padding: StyleProp::Value(Edge::all(Units::Pixels(theme.spacing_large))),
+                row_between: StyleProp::Value(Units::Pixels(theme.spacing_medium)),
+                ..Default::default()
+            }),
+    );
+
+    let icon_size = theme.font_size_large * ICON_SCALE;
+    let has_title = title.is_some();
+    let title = title.unwrap_or_default();
+    let title_styles = Style {
+        color: StyleProp::Value(theme.text),
+        ..Default::default()
+    };
+    let has_message = message.is_some();
+    let message = message.unwrap_or_default();
+    let message_styles = Style {
+        color: StyleProp::Value(theme.text_muted),
+        ..Default::default()
+    };
+    let has_action = action.is_some();
+    let (action_label, action_handler) = match action {
+        Some((label, handler)) => (Some(label), Some(handler)),
+        None => (None, None),
+    };
+
+    // === Render === //
+    rsx! {
+        <>
+            <EmptyStateIcon icon={icon} size={icon_size} />
+            <If condition={has_title}>
+                <Text content={title} size={theme.font_size_large} styles={Some(title_styles)} />
+            </If>
+            <If condition={has_message}>
+                <Text content={message} size={theme.font_size} styles={Some(message_styles)} />
+            </If>
+            <If condition={has_action}>
+                <Button label={action_label} on_event={action_handler} />
+            </If>
+        </>
+    }
+}
+
+/// Props used by the [`EmptyStateIcon`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+struct EmptyStateIconProps {
+    /// The icon to display, if any
+    icon: Option<ButtonIcon>,
+    /// The width and height of the icon (in pixels)
+    size: f32,
+}
+
+#[widget]
+/// Displays the icon of an [`EmptyState`]
+fn EmptyStateIcon(props: EmptyStateIconProps) {
+    let EmptyStateIconProps { icon, size } = props.clone();
+    let theme = use_theme(context).unwrap_or_default();
+
+    match icon {
+        Some(ButtonIcon::Image(handle)) => {
+            let image_styles = Style {
+                height: StyleProp::Value(Units::Pixels(size)),
+                width: StyleProp::Value(Units::Pixels(size)),
+                ..Default::default()
+            };
+            rsx! {
+                <Image handle={handle} styles={Some(image_styles)} />
+            }
+        }
+        Some(ButtonIcon::Glyph(glyph)) => {
+            let glyph_styles = Style {
+                color: StyleProp::Value(theme.text_muted),
+                ..Default::default()
+            };
+            rsx! {
+                <Text content={glyph} size={size} styles={Some(glyph_styles)} />
+            }
+        }
+        None => {}
+    }
+}
@@ -0,0 +1,146 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp},
+    use_state, widget, Children, EventType, Handler, OnChange, OnEvent, OnLayout, RenderError,
+    WidgetProps,
+};
+
+use crate::widgets::{ButtonIcon, EmptyState, If};
+
+/// Props used by the [`ErrorBoundary`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct ErrorBoundaryProps {
+    /// The content displayed in place of the children once one of them panics
+    ///
+    /// If `None`, an [`EmptyState`] is displayed with the error and a button to try again.
+    pub fallback: Option<Children>,
+    /// A callback for when a descendant panics while rendering, such as to log the error
+    pub on_error: Option<OnChange<RenderError>>,
+    /// A key that clears the error whenever it changes
+    ///
+    /// This renders the children again, with fresh state, which is useful with a custom
+    /// [`fallback`](Self::fallback) that can't clear the error itself.
+    pub reset_key: usize,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A widget that displays a fallback in place of its children if any of them panics
+///
+/// When a descendant panics while rendering, the panic is caught (using `catch_unwind`) instead of
+/// crashing the app, and [`on_error`](ErrorBoundaryProps::on_error) is called with the error. The
+/// children are then replaced by the [`fallback`](ErrorBoundaryProps::fallback), which removes the
+/// widgets that failed along with their state. Trying again (or changing the
+/// [`reset_key`](ErrorBoundaryProps::reset_key)) renders the children from scratch, so they don't
+/// pick up any state left behind by the panic.
+///
+/// This is useful around widgets that might misbehave, such as those from third parties. Only
+/// panics while rendering are caught, and state shared with the rest of the app may still need to
+/// be reset. The fallback itself isn't guarded by this boundary, so it panicking is caught by the
+/// next boundary up the tree. See
+/// [`KayakContextRef::set_error_boundary`](crate::core::KayakContextRef::set_error_boundary) for
+/// the full details.
+///
+/// # Props
+///
+/// __Type:__ [`ErrorBoundaryProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ✅        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, OnChange, RenderError};
+/// # use kayak_ui::widgets::{ErrorBoundary, Text};
+///
+/// let on_error = OnChange::new(|error: RenderError| eprintln!("{}", error));
+///
+/// rsx! {
+///     <ErrorBoundary on_error={Some(on_error)}>
+///         <Text content={"Third-party widgets go here".to_string()} size={16.0} />
+///     </ErrorBoundary>
+/// }
+/// ```
+pub fn ErrorBoundary(props: ErrorBoundaryProps) {
+    let ErrorBoundaryProps {
+        fallback,
+        on_error,
+        reset_key,
+        ..
+    } = props.clone();
+
+    // === Error === //
+    let (error, set_error, ..) = use_state!(Option::<RenderError>::None);
+    let (last_reset_key, set_last_reset_key, ..) = use_state!(reset_key);
+    let error = if last_reset_key != reset_key {
+        set_last_reset_key(reset_key);
+        set_error(None);
+        None
+    } else {
+        error
+    };
+    let has_error = error.is_some();
+
+    if has_error {
+        // The fallback shouldn't catch its own panics, since that would re-render it endlessly
+        context.set_error_boundary(None);
+    } else {
+        let set_caught = set_error.clone();
+        context.set_error_boundary(Some(Handler::new(move |error: RenderError| {
+            if let Some(ref on_error) = on_error {
+                on_error.call(error.clone());
+            }
+            set_caught(Some(error));
+        })));
+    }
+
+    // === Styles === //
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles),
+    );
+
+    // === Fallback === //
+    let has_fallback = fallback.is_some();
+    let show_default = has_error && !has_fallback;
+    let message = error.map(|error| error.to_string());
+    let on_retry = OnEvent::new(move |_, event| {
+        if let EventType::Click(..) = event.event_type {
+            set_error(None);
+        }
+    });
+    let icon = Some(ButtonIcon::Glyph("!".to_string()));
+    let title = Some("Something went wrong".to_string());
+    let action = Some(("Try again".to_string(), on_retry));
+
+    // === Render === //
+    rsx! {
+        <>
+            <If condition={!has_error}>
+                {children}
+            </If>
+            <If condition={has_error && has_fallback} children={fallback.clone()} />
+            <If condition={show_default}>
+                <EmptyState icon={icon} title={title} message={message} action={action} />
+            </If>
+        </>
+    }
+}
@@ -3,11 +3,12 @@ use kayak_core::OnLayout;
 use crate::core::{
     render_command::RenderCommand,
     rsx,
-    styles::{Style, StyleProp, Units},
-    use_state, widget, Children, EventType, Handler, OnEvent, WidgetProps,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, Accessibility, Children, CursorIcon, EventType, KeyCode, OnChange, OnEvent,
+    Role, WidgetProps,
 };
 
-use crate::widgets::{Background, Clip, If, Text};
+use crate::widgets::{Background, Clip, Element, If, Text};
 
 // TODO: Add `disabled` prop
 
@@ -18,12 +19,12 @@ pub struct FoldProps {
     pub default_open: bool,
     /// The string displayed as the label of this fold element
     pub label: String,
-    /// A callback for when the user presses the fold's label
+    /// A callback for when the user toggles the fold
     ///
     /// The handler is given the boolean value of the desired open state. For example,
     /// if the fold is closed and the user presses on the label, this callback will be
     /// fired with the boolean value `true`.
-    pub on_change: Option<Handler<bool>>,
+    pub on_toggle: Option<OnChange<bool>>,
     /// Sets the controlled open state of the fold
     ///
     /// If `None`, the open state will be automatically handled internally.
@@ -44,6 +45,10 @@ pub struct FoldProps {
 #[widget]
 /// A widget container that toggles its content between visible and hidden when clicked
 ///
+/// The header is made up of a disclosure indicator followed by the label. It can be toggled
+/// by clicking it or, when focused, by pressing `Enter` or `Space`. Beyond that, the fold is left
+/// unstyled so that it can be composed into more opinionated widgets.
+///
 /// # Props
 ///
 /// __Type:__ [`FoldProps`]
@@ -59,16 +64,16 @@ pub struct FoldProps {
 /// # Examples
 ///
 /// ```
-/// # use kayak_ui::core::{Handler, rsx, use_state};
+/// # use kayak_ui::core::{OnChange, rsx, use_state};
 /// # use kayak_ui::widgets::{Text};
 ///
 /// let (open, set_open) = use_state!(false);
-/// let on_change = Handler::new(move |value| {
+/// let on_toggle = OnChange::new(move |value| {
 ///     set_open(value);
 /// });
 ///
 /// rsx! {
-///     <Fold label={"Toggle Open/Close".to_string()} open={open} on_change={Some(on_change)}>
+///     <Fold label={"Toggle Open/Close".to_string()} open={Some(open)} on_toggle={Some(on_toggle)}>
 ///         <Text content={"Fold Content".to_string()} size={16.0} />
 ///     </Fold>
 /// }
@@ -77,7 +82,7 @@ pub fn Fold(props: FoldProps) {
     let FoldProps {
         default_open,
         label,
-        on_change,
+        on_toggle,
         open,
         ..
     } = props.clone();
@@ -89,16 +94,26 @@ pub fn Fold(props: FoldProps) {
         // This is a controlled state
         set_is_open(open);
     }
+    context.set_accessibility(Some(
+        Accessibility::new(Role::Group)
+            .with_name(label.clone())
+            .with_expanded(is_open),
+    ));
 
+    let toggle = move || {
+        if open.is_none() {
+            // This is an internally-managed state
+            set_is_open(!is_open);
+        }
+        if let Some(ref callback) = on_toggle {
+            callback.call(!is_open);
+        }
+    };
     let handler = OnEvent::new(move |_, event| match event.event_type {
-        EventType::Click(..) => {
-            if open.is_none() {
-                // This is an internally-managed state
-                set_is_open(!is_open);
-            }
-            if let Some(ref callback) = on_change {
-                callback.call(!is_open);
-            }
+        EventType::Click(..) => toggle(),
+        EventType::KeyDown(evt) if matches!(evt.key(), KeyCode::Return | KeyCode::Space) => {
+            event.stop_propagation();
+            toggle();
         }
         _ => {}
     });
@@ -125,11 +140,24 @@ pub fn Fold(props: FoldProps) {
 
     let inner_container_styles = container_style.clone();
 
+    let header_styles = Style {
+        col_between: StyleProp::Value(Units::Pixels(6.0)),
+        cursor: CursorIcon::Hand.into(),
+        height: StyleProp::Value(Units::Auto),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        width: StyleProp::Value(Units::Auto),
+        ..Default::default()
+    };
+    let indicator = if is_open { "v" } else { ">" }.to_string();
+
     // === Render === //
     rsx! {
         <Background styles={Some(background_styles)}>
             <Clip styles={Some(container_style)}>
-                <Text content={label} on_event={Some(handler)} size={14.0} />
+                <Element styles={Some(header_styles)} on_event={Some(handler)} focusable={Some(true)}>
+                    <Text content={indicator} size={14.0} />
+                    <Text content={label} size={14.0} />
+                </Element>
                 <If condition={is_open}>
                     <Clip styles={Some(inner_container_styles)}>
                         {children}
@@ -1,4 +1,4 @@
-use crate::core::{rsx, styles::Style, widget, Children, OnEvent, WidgetProps};
+use crate::core::{rsx, styles::Style, widget, Children, OnEvent, OnLayout, WidgetProps};
 
 /// Props used by the [`If`] widget
 #[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
@@ -11,6 +11,8 @@ pub struct IfProps {
     pub children: Option<Children>,
     #[prop_field(OnEvent)]
     pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
     #[prop_field(Focusable)]
     pub focusable: Option<bool>,
 }
@@ -18,6 +20,13 @@ pub struct IfProps {
 #[widget]
 /// A widget that _conditionally_ renders its children
 ///
+/// See [`IfElse`] for rendering a fallback when the condition is false, and [`Match`] for choosing
+/// between several branches.
+///
+/// Children are unmounted (losing their state) when the condition becomes false. To hide them while
+/// keeping them mounted, set the [`display`](crate::core::styles::Style::display) style of a
+/// wrapping widget instead.
+///
 /// # Props
 ///
 /// __Type:__ [`IfProps`]
@@ -27,7 +36,7 @@ pub struct IfProps {
 /// | `children`  | ✅        |
 /// | `styles`    | ✅        |
 /// | `on_event`  | ✅        |
-/// | `on_layout` | ❌        |
+/// | `on_layout` | ✅        |
 /// | `focusable` | ✅        |
 ///
 pub fn If(props: IfProps) {
@@ -39,3 +48,147 @@ pub fn If(props: IfProps) {
         }
     }
 }
+
+/// Props used by the [`IfElse`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct IfElseProps {
+    /// If true, the children will be rendered, otherwise the [`otherwise`](Self::otherwise)
+    /// branch will be rendered
+    pub condition: bool,
+    /// The fallback branch, rendered when the condition is false
+    pub otherwise: Option<Children>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A widget that renders either its children or a fallback branch
+///
+/// Only the selected branch is built, so the widgets of the other branch are never constructed.
+///
+/// # Props
+///
+/// __Type:__ [`IfElseProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ✅        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, use_state, Children};
+/// # use kayak_ui::widgets::{IfElse, Text};
+///
+/// let (is_logged_in, ..) = use_state!(false);
+/// let login = Children::new(move |parent_id, context| {
+///     rsx! {
+///         <Text content={"Please log in".to_string()} size={16.0} />
+///     }
+///     context.commit();
+/// });
+///
+/// rsx! {
+///     <IfElse condition={is_logged_in} otherwise={Some(login)}>
+///         <Text content={"Welcome back!".to_string()} size={16.0} />
+///     </IfElse>
+/// }
+/// ```
+pub fn IfElse(props: IfElseProps) {
+    let children = if props.condition {
+        children
+    } else {
+        props.otherwise.clone()
+    };
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
+
+/// Props used by the [`Match`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct MatchProps {
+    /// The index of the arm to render
+    ///
+    /// Enums without data can be converted with `as usize`.
+    pub value: usize,
+    /// The branches to choose from
+    pub arms: Vec<Children>,
+    /// The branch rendered when [`value`](Self::value) doesn't match any arm
+    pub fallback: Option<Children>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A widget that renders one of several branches, selected by index
+///
+/// This is useful for tab-like content, where nesting an [`If`] for each branch would get
+/// unwieldy. Only the selected arm is built, so the widgets of the other arms are never constructed.
+///
+/// # Props
+///
+/// __Type:__ [`MatchProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, use_state, Children};
+/// # use kayak_ui::widgets::{Match, Text};
+///
+/// let page = |name: &'static str| {
+///     Children::new(move |parent_id, context| {
+///         rsx! {
+///             <Text content={name.to_string()} size={16.0} />
+///         }
+///         context.commit();
+///     })
+/// };
+/// let (tab, ..) = use_state!(0);
+///
+/// rsx! {
+///     <Match value={tab} arms={vec![page("General"), page("Advanced")]} />
+/// }
+/// ```
+pub fn Match(props: MatchProps) {
+    let children = props
+        .arms
+        .get(props.value)
+        .cloned()
+        .or_else(|| props.fallback.clone());
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
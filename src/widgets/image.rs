@@ -4,13 +4,47 @@ use crate::core::{
     render_command::RenderCommand,
     rsx,
     styles::{Style, StyleProp},
-    widget, Children, OnEvent, WidgetProps,
+    widget, Accessibility, Children, OnEvent, Role, WidgetProps,
 };
 
+/// A grid of equally sized regions (such as icons) packed into a single image
+#[derive(Default, Debug, PartialEq, Copy, Clone)]
+pub struct ImageAtlas {
+    /// The number of regions in each row of the image
+    pub columns: u32,
+    /// The number of regions in each column of the image
+    pub rows: u32,
+    /// The index of the region to display, counting left to right and then top to bottom
+    pub index: u32,
+}
+
+impl ImageAtlas {
+    /// Returns the region at the current index, as normalized `(min_x, min_y, max_x, max_y)`
+    /// coordinates from the top-left corner of the image
+    ///
+    /// Indices past the last region wrap around to the first.
+    pub fn uv(&self) -> (f32, f32, f32, f32) {
+        let columns = self.columns.max(1);
+        let rows = self.rows.max(1);
+        let index = self.index % (columns * rows);
+        let (width, height) = (1.0 / columns as f32, 1.0 / rows as f32);
+        let min_x = (index % columns) as f32 * width;
+        let min_y = (index / columns) as f32 * height;
+        (min_x, min_y, min_x + width, min_y + height)
+    }
+}
+
 /// Props used by the [`Image`] widget
 #[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
 pub struct ImageProps {
     pub handle: u16,
+    /// The region of the image to display, as normalized `(min_x, min_y, max_x, max_y)`
+    /// coordinates from its top-left corner
+    ///
+    /// This takes precedence over the [`atlas`](Self::atlas) region.
+    pub uv: Option<(f32, f32, f32, f32)>,
+    /// The region of the image to display, if it's an atlas of equally sized regions
+    pub atlas: Option<ImageAtlas>,
     #[prop_field(Styles)]
     pub styles: Option<Style>,
     #[prop_field(Children)]
@@ -26,6 +60,14 @@ pub struct ImageProps {
 #[widget]
 /// A widget that renders an image background
 ///
+/// A single region of the image can be displayed by setting either the `uv` or `atlas` prop,
+/// allowing many icons to be packed into one image. The region is stretched to fill the layout of
+/// the widget, so the widget should be sized to the aspect ratio of the region to avoid distortion.
+///
+/// Setting the [`slice`](Style::slice) style renders the image as a nine-slice instead, so its
+/// corners keep their size as it scales (see [`slice_center`](Style::slice_center) for how its
+/// center is filled). Sliced images always use the full image, ignoring any region.
+///
 /// # Props
 ///
 /// __Type:__ [`ImageProps`]
@@ -38,13 +80,32 @@ pub struct ImageProps {
 /// | `on_layout` | ✅        |
 /// | `focusable` | ✅        |
 ///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{Image, ImageAtlas};
+/// # let icons = 0;
+///
+/// // A 4x4 grid of icons, showing the second icon on the third row
+/// let atlas = ImageAtlas {
+///     columns: 4,
+///     rows: 4,
+///     index: 9,
+/// };
+/// rsx! {
+///     <Image handle={icons} atlas={Some(atlas)} />
+/// }
+/// ```
 pub fn Image(props: ImageProps) {
     props.styles = Some(Style {
         render_command: StyleProp::Value(RenderCommand::Image {
             handle: props.handle,
+            uv: props.uv.or_else(|| props.atlas.map(|atlas| atlas.uv())),
         }),
         ..props.styles.clone().unwrap_or_default()
     });
+    context.set_accessibility(Some(Accessibility::new(Role::Image)));
 
     rsx! {
         <>
@@ -52,3 +113,32 @@ pub fn Image(props: ImageProps) {
         </>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ImageAtlas;
+
+    #[test]
+    fn should_select_regions_left_to_right_then_top_to_bottom() {
+        let atlas = |index| ImageAtlas {
+            columns: 4,
+            rows: 2,
+            index,
+        };
+        assert_eq!((0.0, 0.0, 0.25, 0.5), atlas(0).uv());
+        assert_eq!((0.5, 0.0, 0.75, 0.5), atlas(2).uv());
+        assert_eq!((0.25, 0.5, 0.5, 1.0), atlas(5).uv());
+        // Past the last region, the index wraps around
+        assert_eq!(atlas(1).uv(), atlas(9).uv());
+    }
+
+    #[test]
+    fn should_treat_empty_atlases_as_a_single_region() {
+        let atlas = ImageAtlas {
+            columns: 0,
+            rows: 0,
+            index: 3,
+        };
+        assert_eq!((0.0, 0.0, 1.0, 1.0), atlas.uv());
+    }
+}
@@ -1,10 +1,37 @@
-use kayak_core::styles::{Corner, PositionType, Style, StyleProp, Units};
-use kayak_core::{Bound, Color, EventType, OnEvent, VecTracker};
+use kayak_core::layout_cache::{Rect, Space};
+use kayak_core::styles::{Corner, Edge, PositionType, Style, StyleProp, Units};
+use kayak_core::{
+    Bound, Children, Color, EventType, Index, KeyCode, OnEvent, OnLayout, PointerEvents, VecTracker,
+};
 use kayak_render_macros::{constructor, use_state};
 
-use crate::core::{rsx, widget, MutableBound, WidgetProps};
+use crate::core::{render_command::RenderCommand, rsx, widget, MutableBound, WidgetProps};
 
-use crate::widgets::{Background, Button, Text};
+use crate::widgets::{Background, Button, Element, If, ScrollBox, Text};
+
+/// The key used to toggle the [`Inspector`] if none is given
+const DEFAULT_HOTKEY: KeyCode = KeyCode::F12;
+/// The color of the outline around a widget's margin box
+const MARGIN_COLOR: Color = Color {
+    r: 0.96,
+    g: 0.62,
+    b: 0.22,
+    a: 1.0,
+};
+/// The color of the outline around a widget's border box
+const RECT_COLOR: Color = Color {
+    r: 0.24,
+    g: 0.56,
+    b: 0.96,
+    a: 1.0,
+};
+/// The color of the outline around a widget's content box
+const PADDING_COLOR: Color = Color {
+    r: 0.36,
+    g: 0.8,
+    b: 0.42,
+    a: 1.0,
+};
 
 // TODO: Remove if unneeded
 #[derive(Clone, PartialEq)]
@@ -16,6 +43,25 @@ pub enum InspectData {
 /// Props used by the [`Inspector`] widget
 #[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
 pub struct InspectorProps {
+    /// The key that toggles the inspector overlay
+    ///
+    /// Defaults to F12.
+    pub hotkey: Option<KeyCode>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+/// Props used by the [`InspectorOverlay`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+struct InspectorOverlayProps {
     #[prop_field(Styles)]
     pub styles: Option<Style>,
 }
@@ -23,7 +69,16 @@ pub struct InspectorProps {
 #[widget]
 /// A widget that displays debug data for inspected widgets
 ///
-/// "Inspected widgets" refers to the last clicked widget.
+/// "Inspected widgets" refers to the widget currently under the cursor. The inspected widget is
+/// highlighted with an outline around its margin box (orange), border box (blue), and content box
+/// (green). A side panel lists its computed size, box model, and fully resolved styles.
+///
+/// While the cursor is over the side panel, the last inspected widget is kept, allowing its
+/// ancestors to be inspected using the "Go Up" button.
+///
+/// The overlay can be toggled using the [`hotkey`](InspectorProps::hotkey). Since keyboard events
+/// are only sent to the focused widget and its ancestors, the inspector should wrap the content
+/// to inspect in order for the hotkey to work.
 ///
 /// # Props
 ///
@@ -31,53 +86,151 @@ pub struct InspectorProps {
 ///
 /// | Common Prop | Accepted |
 /// | :---------: | :------: |
-/// | `children`  | ❌        |
+/// | `children`  | ✅        |
 /// | `styles`    | ✅        |
-/// | `on_event`  | ❌        |
-/// | `on_layout` | ❌        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
 /// | `focusable` | ❌        |
 ///
+/// # Styles
+///
+/// The `styles` prop applies to the side panel.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{Inspector, Window};
+///
+/// rsx! {
+///     <Inspector>
+///         <Window />
+///     </Inspector>
+/// }
+/// ```
 pub fn Inspector(props: InspectorProps) {
-    let (inspect_data, set_inspect_data, _) = use_state!(Vec::<String>::new());
+    let InspectorProps {
+        hotkey,
+        styles,
+        on_event,
+        ..
+    } = props.clone();
+    let hotkey = hotkey.unwrap_or(DEFAULT_HOTKEY);
 
-    let background_styles = Some(Style {
-        background_color: StyleProp::Value(Color::new(0.125, 0.125, 0.125, 1.0)),
-        border_radius: StyleProp::Value(Corner::all(0.0)),
+    let (is_open, set_is_open, ..) = use_state!(true);
+
+    props.on_event = Some(OnEvent::new(move |ctx, event| {
+        if let Some(ref on_event) = on_event {
+            on_event.try_call(ctx, event);
+        }
+
+        match event.event_type {
+            EventType::KeyDown(evt) if evt.key() == hotkey => set_is_open(!is_open),
+            _ => {}
+        }
+    }));
+    props.focusable = Some(true);
+    props.styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
         position_type: StyleProp::Value(PositionType::SelfDirected),
-        left: StyleProp::Value(Units::Stretch(1.0)),
-        top: StyleProp::Value(Units::Stretch(0.0)),
-        bottom: StyleProp::Value(Units::Stretch(0.0)),
-        width: StyleProp::Value(Units::Pixels(200.0)),
-        ..props.styles.clone().unwrap_or_default()
+        ..Default::default()
     });
 
-    let last_clicked = context.get_last_clicked_widget();
-    context.bind(&last_clicked);
+    rsx! {
+        <>
+            <Element>
+                {children}
+            </Element>
+            <If condition={is_open}>
+                <InspectorOverlay styles={styles} />
+            </If>
+        </>
+    }
+}
+
+#[widget]
+/// The overlay of an [`Inspector`], containing the outlines and side panel
+fn InspectorOverlay(props: InspectorOverlayProps) {
+    let (inspected, set_inspected, ..) = use_state!(Option::<Index>::None);
+    let (is_panel_hovered, set_is_panel_hovered, ..) = use_state!(false);
 
-    let last_clicked_value = last_clicked.get();
-    let (id, _) = last_clicked_value.into_raw_parts();
+    let hovered = context.get_hovered_widget();
+    context.bind(&hovered);
+    if !is_panel_hovered && hovered.get() != inspected {
+        if let Some(hovered) = hovered.get() {
+            set_inspected(Some(hovered));
+        }
+    }
 
+    props.styles = Some(Style {
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        ..Default::default()
+    });
+
+    // Outlines are positioned relative to this overlay
+    let origin = parent_id
+        .and_then(|id| context.get_layout(&id))
+        .map(|layout| (layout.posx, layout.posy))
+        .unwrap_or_default();
+
+    let mut data = Vec::new();
+    let mut outlines = Vec::new();
     let mut parent_id_move = None;
-    if let Some(layout) = context.get_layout(&last_clicked_value) {
-        if let Some(node) = context.get_node(&last_clicked_value) {
-            let mut data = Vec::new();
+    if let Some(inspected) = inspected {
+        if let (Some(layout), Some(node)) = (
+            context.get_layout(&inspected).copied(),
+            context.get_node(&inspected),
+        ) {
+            let space = context.get_space(&inspected).unwrap_or_default();
+            let border = node
+                .resolved_styles
+                .border
+                .resolve_or_default()
+                .resolve_pixels(layout.width, layout.height);
+            let padding = resolve_padding(node.padding());
+
+            let margin_box = Rect {
+                posx: layout.posx - space.left,
+                posy: layout.posy - space.top,
+                width: layout.width + space.left + space.right,
+                height: layout.height + space.top + space.bottom,
+                ..layout
+            };
+            let content_box = Rect {
+                posx: layout.posx + border.left + padding.left,
+                posy: layout.posy + border.top + padding.top,
+                width: layout.width - border.left - border.right - padding.left - padding.right,
+                height: layout.height - border.top - border.bottom - padding.top - padding.bottom,
+                ..layout
+            };
+            outlines.push(outline_styles(&margin_box, origin, MARGIN_COLOR));
+            outlines.push(outline_styles(&layout, origin, RECT_COLOR));
+            outlines.push(outline_styles(&content_box, origin, PADDING_COLOR));
 
-            if let Some(name) = context.get_name(&last_clicked_value) {
+            let (id, _) = inspected.into_raw_parts();
+            if let Some(name) = context.get_name(&inspected) {
                 data.push(format!("Name: {}", name));
             }
-
             data.push(format!("ID: {}", id));
             data.push(format!("X: {}", layout.posx));
             data.push(format!("Y: {}", layout.posy));
             data.push(format!("Width: {}", layout.width));
             data.push(format!("Height: {}", layout.height));
-            data.push(format!(
-                "RenderCommand: \n{:#?}",
-                node.resolved_styles.render_command
+            data.push(format!("_________Box Model_________"));
+            data.push(format_sides("Margin", &space));
+            data.push(format_sides(
+                "Border",
+                &Space {
+                    left: border.left,
+                    right: border.right,
+                    top: border.top,
+                    bottom: border.bottom,
+                },
             ));
-            data.push(format!("Height: \n{:#?}", node.resolved_styles.height));
+            data.push(format_sides("Padding", &padding));
 
-            if let Some(parent_id) = context.get_valid_parent(last_clicked_value) {
+            if let Some(parent_id) = context.get_valid_parent(inspected) {
                 parent_id_move = Some(parent_id);
                 if let Some(layout) = context.get_layout(&parent_id) {
                     data.push(format!("_________Parent_________"));
@@ -90,25 +243,114 @@ pub fn Inspector(props: InspectorProps) {
                     data.push(format!("Height: {}", layout.height));
                 }
             }
-            set_inspect_data(data);
+
+            data.push(format!("_________Styles_________"));
+            data.extend(
+                format!("{:#?}", node.resolved_styles)
+                    .lines()
+                    .map(|line| line.to_string()),
+            );
         }
     }
 
+    let panel_styles = Some(
+        Style::default()
+            .with_style(Style {
+                background_color: StyleProp::Value(Color::new(0.125, 0.125, 0.125, 1.0)),
+                border_radius: StyleProp::Value(Corner::all(0.0)),
+                position_type: StyleProp::Value(PositionType::SelfDirected),
+                left: StyleProp::Value(Units::Stretch(1.0)),
+                top: StyleProp::Value(Units::Stretch(0.0)),
+                bottom: StyleProp::Value(Units::Stretch(0.0)),
+                width: StyleProp::Value(Units::Pixels(300.0)),
+                ..Default::default()
+            })
+            .with_style(&props.styles),
+    );
+
+    let handle_panel_events = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseIn(..) => set_is_panel_hovered(true),
+        EventType::MouseOut(..) => set_is_panel_hovered(false),
+        _ => {}
+    }));
+
+    let button_container_styles = Style {
+        height: StyleProp::Value(Units::Auto),
+        ..Default::default()
+    };
+
     let handle_button_events = Some(OnEvent::new(move |_, event| match event.event_type {
-        EventType::Click(..) => last_clicked.set(parent_id_move.unwrap()),
+        EventType::Click(..) => {
+            if parent_id_move.is_some() {
+                set_inspected(parent_id_move);
+            }
+        }
         _ => {}
     }));
 
     rsx! {
-        <Background styles={background_styles}>
-            {VecTracker::from(inspect_data.iter().map(|data| {
+        <>
+            {VecTracker::from(outlines.clone().into_iter().map(|outline| {
                 constructor! {
-                    <Text content={data.clone().to_string()} size={12.0} />
+                    <Background styles={Some(outline)} />
                 }
             }))}
-            <Button>
-                <Text content={"Go Up".into()} size={12.0} on_event={handle_button_events} />
-            </Button>
-        </Background>
+            <Background styles={panel_styles} on_event={handle_panel_events}>
+                <ScrollBox>
+                    {VecTracker::from(data.iter().map(|data| {
+                        constructor! {
+                            <Text content={data.clone().to_string()} size={12.0} />
+                        }
+                    }))}
+                </ScrollBox>
+                <Element styles={Some(button_container_styles)}>
+                    <Button on_event={handle_button_events}>
+                        <Text content={"Go Up".into()} size={12.0} />
+                    </Button>
+                </Element>
+            </Background>
+        </>
+    }
+}
+
+/// Resolves the pixel values of the given padding
+///
+/// Any padding that isn't given in pixels (i.e. stretched padding) is treated as zero.
+fn resolve_padding(padding: Edge<Units>) -> Space {
+    let resolve = |value: Units| match value {
+        Units::Pixels(value) => value,
+        _ => 0.0,
+    };
+
+    Space {
+        left: resolve(padding.left),
+        right: resolve(padding.right),
+        top: resolve(padding.top),
+        bottom: resolve(padding.bottom),
+    }
+}
+
+/// Formats the sides of a box model layer as a single line of text
+fn format_sides(label: &str, sides: &Space) -> String {
+    format!(
+        "{}: {} {} {} {}",
+        label, sides.top, sides.right, sides.bottom, sides.left
+    )
+}
+
+/// Returns the styles for an outline drawn around the given rect
+fn outline_styles(rect: &Rect, origin: (f32, f32), color: Color) -> Style {
+    Style {
+        background_color: StyleProp::Value(Color::TRANSPARENT),
+        border: StyleProp::Value(Edge::all(Units::Pixels(1.0))),
+        border_color: StyleProp::Value(color),
+        border_radius: StyleProp::Value(Corner::all(0.0)),
+        height: StyleProp::Value(Units::Pixels(rect.height.max(0.0))),
+        left: StyleProp::Value(Units::Pixels(rect.posx - origin.0)),
+        pointer_events: StyleProp::Value(PointerEvents::None),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        top: StyleProp::Value(Units::Pixels(rect.posy - origin.1)),
+        width: StyleProp::Value(Units::Pixels(rect.width.max(0.0))),
+        ..Default::default()
     }
 }
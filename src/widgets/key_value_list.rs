@@ -0,0 +1,175 @@
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    widget, OnEvent, OnLayout, TextProperties, VecTracker, WidgetProps, DEFAULT_FONT,
+};
+
+use crate::widgets::{use_theme, Element, Text};
+
+/// Props used by the [`KeyValueList`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct KeyValueListProps {
+    /// The label and value of each row, from top to bottom
+    pub items: Vec<(String, String)>,
+    /// If true, the labels are aligned to the right of their column, next to their values
+    pub right_align_labels: bool,
+    /// The font size (in pixels)
+    ///
+    /// Defaults to the theme's [`font_size`](crate::widgets::Theme::font_size).
+    pub size: Option<f32>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A widget that displays a list of labels and their values, such as the properties of an object
+///
+/// Each item is displayed as a row, with its label on the left and its value on the right. The
+/// labels share a column as wide as the widest label, so every value starts at the same position
+/// and fills the remaining width, wrapping onto more lines if needed. The labels are measured
+/// using the `font` style of this widget, which may be inherited (or the default font).
+///
+/// The labels use the theme's [`text_muted`](crate::widgets::Theme::text_muted) color and the
+/// values use its [`text`](crate::widgets::Theme::text) color.
+///
+/// # Props
+///
+/// __Type:__ [`KeyValueListProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::KeyValueList;
+///
+/// let items = vec![
+///     ("Name".to_string(), "Player".to_string()),
+///     ("Position".to_string(), "(12, 4)".to_string()),
+///     ("Health".to_string(), "100".to_string()),
+/// ];
+///
+/// rsx! {
+///     <KeyValueList items={items} right_align_labels={true} />
+/// }
+/// ```
+pub fn KeyValueList(props: KeyValueListProps) {
+    let KeyValueListProps {
+        items,
+        right_align_labels,
+        size,
+        ..
+    } = props.clone();
+    let theme = use_theme(context).unwrap_or_default();
+    let font_size = size.unwrap_or(theme.font_size);
+
+    // === Label Column === //
+    // The labels may inherit their font, which is only resolved once this widget has been rendered
+    let resolved_font = context
+        .get_node(&self.get_id())
+        .map(|node| node.resolved_styles.font);
+    let font = match props.styles.as_ref().map(|styles| &styles.font) {
+        Some(StyleProp::Value(font)) => font.clone(),
+        _ => match resolved_font {
+            Some(StyleProp::Value(font)) => font,
+            _ => DEFAULT_FONT.to_string(),
+        },
+    };
+    let properties = TextProperties {
+        font_size,
+        line_height: font_size * 1.2,
+        ..Default::default()
+    };
+    let mut label_width = Some(0.0_f32);
+    for (label, _) in items.iter() {
+        // The font may not be loaded yet, in which case this is re-rendered once it is
+        label_width = match context.measure_text(label, &font, properties) {
+            Some(measurement) => label_width.map(|width| width.max(measurement.size().0)),
+            None => None,
+        };
+    }
+    let label_width = match label_width {
+        // Rounded up so the widest label doesn't wrap
+        Some(width) => Units::Pixels(width.ceil()),
+        None => Units::Auto,
+    };
+
+    // === Styles === //
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                height: StyleProp::Value(Units::Auto),
+                layout_type: StyleProp::Value(LayoutType::Column),
+                row_between: StyleProp::Value(Units::Pixels(theme.spacing_small)),
+                ..Default::default()
+            }),
+    );
+
+    let row_styles = Style {
+        col_between: StyleProp::Value(Units::Pixels(theme.spacing_medium)),
+        height: StyleProp::Value(Units::Auto),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let label_cell_styles = Style {
+        height: StyleProp::Value(Units::Auto),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        width: StyleProp::Value(label_width),
+        ..Default::default()
+    };
+    let label_styles = Style {
+        color: StyleProp::Value(theme.text_muted),
+        left: StyleProp::Value(if right_align_labels {
+            Units::Stretch(1.0)
+        } else {
+            Units::Pixels(0.0)
+        }),
+        ..Default::default()
+    };
+    let value_cell_styles = Style {
+        height: StyleProp::Value(Units::Auto),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let value_styles = Style {
+        color: StyleProp::Value(theme.text),
+        ..Default::default()
+    };
+
+    // === Render === //
+    rsx! {
+        <>
+            {VecTracker::from(items.clone().into_iter().map(|(label, value)| {
+                constructor! {
+                    <Element styles={Some(row_styles.clone())}>
+                        <Element styles={Some(label_cell_styles.clone())}>
+                            <Text content={label} size={font_size} styles={Some(label_styles.clone())} />
+                        </Element>
+                        <Element styles={Some(value_cell_styles.clone())}>
+                            <Text content={value} size={font_size} styles={Some(value_styles.clone())} />
+                        </Element>
+                    </Element>
+                }
+            }))}
+        </>
+    }
+}
@@ -0,0 +1,228 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Edge, LayoutType, ResponsiveStyle, Style, StyleProp, Units},
+    use_state, widget, Children, EventType, Handler, OnEvent, OnLayout, ResizeEvent, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Background, Button, ButtonIcon, Clip, Element, If};
+
+/// The default width at which the panels are shown side by side (in pixels)
+const DEFAULT_BREAKPOINT: f32 = 600.0;
+/// The default width of the master panel when the panels are side by side (in pixels)
+const DEFAULT_MASTER_WIDTH: f32 = 280.0;
+/// The width of the divider between the panels (in pixels)
+const DIVIDER_WIDTH: f32 = 1.0;
+
+/// Props used by the [`MasterDetail`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct MasterDetailProps {
+    /// The content of the master panel, usually a list of items to choose from
+    pub master: Option<Children>,
+    /// The content of the detail panel, usually showing the selected item
+    pub detail: Option<Children>,
+    /// If true, an item is selected, so the detail panel is shown in the narrow layout
+    ///
+    /// In the wide layout, both panels are always shown.
+    pub selected: bool,
+    /// A callback for when the user navigates back to the master panel in the narrow layout
+    ///
+    /// This should clear the selection, so that [`selected`](Self::selected) becomes false.
+    pub on_back: Option<Handler>,
+    /// The width at which the panels are shown side by side (in pixels)
+    ///
+    /// Defaults to 600 pixels.
+    pub breakpoint: Option<f32>,
+    /// The width of the master panel when the panels are side by side
+    ///
+    /// Defaults to 280 pixels.
+    pub master_width: Option<Units>,
+    /// The text of the button used to navigate back in the narrow layout
+    ///
+    /// Defaults to "Back".
+    pub back_label: Option<String>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A two-panel layout with a list of items (the master) and the selected item (the detail)
+///
+/// While this widget is at least as wide as the [`breakpoint`](MasterDetailProps::breakpoint),
+/// the master panel is shown on the left and the detail panel fills the remaining width, separated
+/// by a divider. Below that width, only one panel is shown at a time, filling the widget: the
+/// master panel until an item is [`selected`](MasterDetailProps::selected), and then the detail
+/// panel along with a button to navigate back, which fires
+/// [`on_back`](MasterDetailProps::on_back).
+///
+/// The width is measured with [`on_resize`](crate::core::KayakContextRef::on_resize), so the
+/// layout only switches once resizing settles. The panel styles are selected using a
+/// [`ResponsiveStyle`].
+///
+/// # Props
+///
+/// __Type:__ [`MasterDetailProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, use_state, Children, Handler};
+/// # use kayak_ui::widgets::{MasterDetail, Text};
+///
+/// let (selected, set_selected, ..) = use_state!(Option::<usize>::None);
+/// let master = Children::new(move |parent_id, context| {
+///     rsx! {
+///         <Text content={"Inbox".to_string()} size={16.0} />
+///     }
+///     context.commit();
+/// });
+/// let detail = Children::new(move |parent_id, context| {
+///     rsx! {
+///         <Text content={format!("Message {:?}", selected)} size={16.0} />
+///     }
+///     context.commit();
+/// });
+/// let on_back = Handler::new(move |_| set_selected(None));
+///
+/// rsx! {
+///     <MasterDetail
+///         master={Some(master)}
+///         detail={Some(detail)}
+///         selected={selected.is_some()}
+///         on_back={Some(on_back)}
+///     />
+/// }
+/// ```
+pub fn MasterDetail(props: MasterDetailProps) {
+    let MasterDetailProps {
+        master,
+        detail,
+        selected,
+        on_back,
+        breakpoint,
+        master_width,
+        back_label,
+        ..
+    } = props.clone();
+    let theme = use_theme(context).unwrap_or_default();
+    let breakpoint = breakpoint.unwrap_or(DEFAULT_BREAKPOINT);
+    let master_width = master_width.unwrap_or(Units::Pixels(DEFAULT_MASTER_WIDTH));
+
+    // === Breakpoint === //
+    let master_styles = ResponsiveStyle::new(Style {
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    })
+    .with_breakpoint(
+        breakpoint,
+        Style {
+            width: StyleProp::Value(master_width),
+            ..Default::default()
+        },
+    );
+
+    // Only the breakpoint index is stored, so resizing within a layout doesn't re-render
+    let (breakpoint_index, set_breakpoint_index, ..) = use_state!(Option::<usize>::None);
+    let responsive = master_styles.clone();
+    context.on_resize(Some(Handler::new(move |event: ResizeEvent| {
+        let index = responsive.breakpoint_index(event.size.0);
+        if breakpoint_index != Some(index) {
+            set_breakpoint_index(Some(index));
+        }
+    })));
+    // Until the width is measured, assume there's enough room for both panels
+    let breakpoint_index = breakpoint_index.unwrap_or(1);
+    let is_wide = breakpoint_index > 0;
+
+    // === Styles === //
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                layout_type: StyleProp::Value(LayoutType::Row),
+                ..Default::default()
+            }),
+    );
+
+    let master_styles = master_styles.resolve_index(breakpoint_index);
+    let divider_styles = Style {
+        background_color: StyleProp::Value(theme.border),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        width: StyleProp::Value(Units::Pixels(DIVIDER_WIDTH)),
+        ..Default::default()
+    };
+    let detail_styles = Style {
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        layout_type: StyleProp::Value(LayoutType::Column),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let header_styles = Style {
+        height: StyleProp::Value(Units::Auto),
+        padding: StyleProp::Value(Edge::all(Units::Pixels(theme.spacing_small))),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let back_styles = Style {
+        padding_left: StyleProp::Value(Units::Pixels(theme.spacing_medium)),
+        padding_right: StyleProp::Value(Units::Pixels(theme.spacing_medium)),
+        width: StyleProp::Value(Units::Auto),
+        ..Default::default()
+    };
+    let on_back_click = OnEvent::new(move |_, event| {
+        if let EventType::Click(..) = event.event_type {
+            if let Some(ref on_back) = on_back {
+                on_back.call(());
+            }
+        }
+    });
+
+    let show_master = is_wide || !selected;
+    let show_detail = is_wide || selected;
+    let back_label = Some(back_label.unwrap_or_else(|| "Back".to_string()));
+    let back_icon = Some(ButtonIcon::Glyph("<".to_string()));
+
+    // === Render === //
+    rsx! {
+        <>
+            <If condition={show_master}>
+                <Clip styles={Some(master_styles)} children={master.clone()} />
+            </If>
+            <If condition={is_wide}>
+                <Background styles={Some(divider_styles)} />
+            </If>
+            <If condition={show_detail}>
+                <Element styles={Some(detail_styles)}>
+                    <If condition={!is_wide}>
+                        <Element styles={Some(header_styles)}>
+                            <Button
+                                label={back_label}
+                                leading_icon={back_icon}
+                                styles={Some(back_styles)}
+                                on_event={Some(on_back_click)}
+                            />
+                        </Element>
+                    </If>
+                    <Clip children={detail.clone()} />
+                </Element>
+            </If>
+        </>
+    }
+}
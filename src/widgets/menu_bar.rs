@@ -0,0 +1,476 @@
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{Corner, LayoutType, PositionType, Style, StyleProp, Units},
+    use_state, widget, Children, Color, CursorIcon, EventType, Handler, KeyCode, OnEvent, OnLayout,
+    VecTracker, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Background, If, Text};
+
+/// The height of the menu bar and of each menu item (in pixels)
+const ITEM_HEIGHT: f32 = 26.0;
+/// The width of each dropdown list (in pixels)
+const MENU_WIDTH: f32 = 200.0;
+
+/// A single entry within a [`MenuBar`]
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct MenuItem {
+    /// The string displayed for this item
+    pub label: String,
+    /// A callback for when the user selects this item
+    ///
+    /// This is not fired for items with a [`submenu`](Self::submenu), which instead open
+    /// their submenu when selected.
+    pub on_select: Option<Handler>,
+    /// The keyboard shortcut displayed alongside this item (e.g. `"Ctrl+S"`)
+    ///
+    /// This is for display purposes only.
+    pub shortcut: Option<String>,
+    /// The items of the menu opened by this item
+    pub submenu: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    /// Creates a new `MenuItem` with the given label and selection callback
+    pub fn new<T: Into<String>>(label: T, on_select: Option<Handler>) -> Self {
+        Self {
+            label: label.into(),
+            on_select,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the displayed keyboard shortcut of this item
+    pub fn with_shortcut<T: Into<String>>(mut self, shortcut: T) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Sets the submenu items of this item
+    pub fn with_submenu(mut self, submenu: Vec<MenuItem>) -> Self {
+        self.submenu = submenu;
+        self
+    }
+}
+
+/// Props used by the [`MenuBar`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct MenuBarProps {
+    /// The top-level menus displayed in the bar
+    ///
+    /// Each menu's [`submenu`](MenuItem::submenu) contains the items of its dropdown.
+    pub menus: Vec<MenuItem>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A widget that displays a bar of application menus
+///
+/// Clicking a menu opens its dropdown. Items with submenus open them to the side when hovered.
+/// While focused, the menus can also be navigated using the arrow keys, `Enter` to select, and
+/// `Escape` to close. Clicking anywhere outside of an open menu closes it.
+///
+/// # Props
+///
+/// __Type:__ [`MenuBarProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ❌        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{Handler, rsx};
+/// # use kayak_ui::widgets::{MenuBar, MenuItem};
+///
+/// let save = Handler::new(|_| println!("Saved!"));
+/// let menus = vec![
+///     MenuItem::new("File", None).with_submenu(vec![
+///         MenuItem::new("Save", Some(save)).with_shortcut("Ctrl+S"),
+///         MenuItem::new("Export", None).with_submenu(vec![
+///             MenuItem::new("PNG", None),
+///             MenuItem::new("JPEG", None),
+///         ]),
+///     ]),
+/// ];
+///
+/// rsx! {
+///     <MenuBar menus={menus} />
+/// }
+/// ```
+pub fn MenuBar(props: MenuBarProps) {
+    let MenuBarProps { menus, .. } = props.clone();
+
+    // === State === //
+    // The path to the currently highlighted item, where the first index is the open menu
+    // and each following index is the highlighted item in the list at that depth
+    let (path, set_path, ..) = use_state!(Vec::<usize>::new());
+    let set_path = Handler::new(set_path);
+
+    // === Events === //
+    let key_handler = {
+        let menus = menus.clone();
+        let path = path.clone();
+        let set_path = set_path.clone();
+        OnEvent::new(move |_, event| {
+            if let EventType::KeyDown(data) = event.event_type {
+                if let Some(next) = navigate(&menus, &path, data.key()) {
+                    set_path.call(next);
+                }
+            }
+        })
+    };
+    props.on_event = Some(key_handler.clone());
+    props.focusable = Some(true);
+
+    let theme = use_theme(context).unwrap_or_default();
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Quad),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                background_color: StyleProp::Value(theme.surface),
+                color: StyleProp::Value(theme.text),
+                height: StyleProp::Value(Units::Pixels(ITEM_HEIGHT)),
+                layout_type: StyleProp::Value(LayoutType::Row),
+                ..Default::default()
+            }),
+    );
+
+    let is_open = !path.is_empty();
+    let close = {
+        let set_path = set_path.clone();
+        Handler::new(move |_| set_path.call(Vec::new()))
+    };
+    context.on_click_outside(is_open.then(|| close.clone()));
+    context.on_escape(is_open.then(|| close));
+
+    rsx! {
+        <>
+            {VecTracker::from(menus.clone().into_iter().enumerate().map(|(index, menu)| {
+                let is_active = path.first() == Some(&index);
+
+                let set_path = set_path.clone();
+                let on_event = OnEvent::new(move |_, event| match event.event_type {
+                    EventType::Click(..) => {
+                        set_path.call(if is_active { Vec::new() } else { vec![index] });
+                    }
+                    EventType::MouseIn(..) if is_open && !is_active => {
+                        set_path.call(vec![index]);
+                    }
+                    _ => {}
+                });
+
+                let title_styles = Style {
+                    background_color: StyleProp::Value(if is_active {
+                        theme.primary
+                    } else {
+                        Color::TRANSPARENT
+                    }),
+                    color: StyleProp::Value(if is_active {
+                        theme.on_primary
+                    } else {
+                        theme.text
+                    }),
+                    cursor: CursorIcon::Hand.into(),
+                    height: StyleProp::Value(Units::Stretch(1.0)),
+                    padding_left: StyleProp::Value(Units::Pixels(10.0)),
+                    padding_right: StyleProp::Value(Units::Pixels(10.0)),
+                    width: StyleProp::Value(Units::Auto),
+                    ..Default::default()
+                };
+                let label_styles = Style {
+                    top: StyleProp::Value(Units::Stretch(1.0)),
+                    bottom: StyleProp::Value(Units::Stretch(1.0)),
+                    ..Default::default()
+                };
+                let list_styles = Style {
+                    left: StyleProp::Value(Units::Pixels(0.0)),
+                    top: StyleProp::Value(Units::Percentage(100.0)),
+                    ..Default::default()
+                };
+                let prefix = vec![index];
+                let list_path = path.clone();
+                let list_key_handler = key_handler.clone();
+
+                constructor! {
+                    <Background styles={Some(title_styles)} on_event={Some(on_event)}>
+                        <Text content={menu.label.clone()} size={14.0} styles={Some(label_styles)} />
+                        <If condition={is_active}>
+                            <MenuList
+                                items={menu.submenu.clone()}
+                                prefix={prefix.clone()}
+                                path={list_path.clone()}
+                                set_path={set_path.clone()}
+                                key_handler={Some(list_key_handler.clone())}
+                                styles={Some(list_styles)}
+                            />
+                        </If>
+                    </Background>
+                }
+            }))}
+        </>
+    }
+}
+
+/// Props used by the [`MenuList`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+struct MenuListProps {
+    /// The items displayed in this list
+    items: Vec<MenuItem>,
+    /// The keyboard handler of the owning [`MenuBar`]
+    key_handler: Option<OnEvent>,
+    /// The path to the currently highlighted item of the owning [`MenuBar`]
+    path: Vec<usize>,
+    /// The path to the item that opened this list
+    prefix: Vec<usize>,
+    /// Sets the path of the owning [`MenuBar`]
+    set_path: Handler<Vec<usize>>,
+    #[prop_field(Styles)]
+    styles: Option<Style>,
+    #[prop_field(Children)]
+    children: Option<Children>,
+    #[prop_field(OnEvent)]
+    on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    focusable: Option<bool>,
+}
+
+#[widget]
+/// A dropdown list of menu items, recursively displaying the highlighted item's submenu
+fn MenuList(props: MenuListProps) {
+    let MenuListProps {
+        items,
+        key_handler,
+        path,
+        prefix,
+        set_path,
+        ..
+    } = props.clone();
+    let submenu_key_handler = key_handler.clone();
+
+    // The list must be focusable so that clicking it keeps keyboard navigation working
+    props.on_event = Some(OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::KeyDown(..) => {
+            if let Some(ref key_handler) = key_handler {
+                key_handler.try_call(ctx, event);
+            }
+            // The owning menu bar would otherwise handle this key a second time
+            event.stop_propagation();
+        }
+        EventType::Click(..) => {
+            // Prevent the click from toggling the menu that owns this list
+            event.stop_propagation();
+        }
+        _ => {}
+    }));
+    props.focusable = Some(true);
+
+    let theme = use_theme(context).unwrap_or_default();
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Quad),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                background_color: StyleProp::Value(theme.background),
+                color: StyleProp::Value(theme.text),
+                border_radius: StyleProp::Value(Corner::all(4.0)),
+                height: StyleProp::Value(Units::Auto),
+                position_type: StyleProp::Value(PositionType::SelfDirected),
+                width: StyleProp::Value(Units::Pixels(MENU_WIDTH)),
+                ..Default::default()
+            }),
+    );
+
+    let depth = prefix.len();
+    let highlighted = path
+        .get(depth)
+        .copied()
+        .filter(|_| path.starts_with(&prefix));
+
+    rsx! {
+        <>
+            {VecTracker::from(items.clone().into_iter().enumerate().map(|(index, item)| {
+                let is_highlighted = highlighted == Some(index);
+                let has_submenu = !item.submenu.is_empty();
+                let is_submenu_open = is_highlighted && has_submenu;
+
+                let mut item_path = prefix.clone();
+                item_path.push(index);
+
+                let set_path = set_path.clone();
+                let on_select = item.on_select.clone();
+                let hovered_path = item_path.clone();
+                let on_event = OnEvent::new(move |_, event| match event.event_type {
+                    EventType::MouseIn(..) => {
+                        set_path.call(hovered_path.clone());
+                    }
+                    EventType::Click(..) => {
+                        event.stop_propagation();
+                        if has_submenu {
+                            set_path.call(hovered_path.clone());
+                        } else {
+                            set_path.call(Vec::new());
+                            if let Some(ref on_select) = on_select {
+                                on_select.call(());
+                            }
+                        }
+                    }
+                    _ => {}
+                });
+
+                let item_styles = Style {
+                    background_color: StyleProp::Value(if is_highlighted {
+                        theme.primary
+                    } else {
+                        Color::TRANSPARENT
+                    }),
+                    color: StyleProp::Value(if is_highlighted {
+                        theme.on_primary
+                    } else {
+                        theme.text
+                    }),
+                    border_radius: StyleProp::Value(Corner::all(4.0)),
+                    col_between: StyleProp::Value(Units::Pixels(8.0)),
+                    cursor: CursorIcon::Hand.into(),
+                    height: StyleProp::Value(Units::Pixels(ITEM_HEIGHT)),
+                    layout_type: StyleProp::Value(LayoutType::Row),
+                    padding_left: StyleProp::Value(Units::Pixels(10.0)),
+                    padding_right: StyleProp::Value(Units::Pixels(10.0)),
+                    ..Default::default()
+                };
+                let label_styles = Style {
+                    bottom: StyleProp::Value(Units::Stretch(1.0)),
+                    right: StyleProp::Value(Units::Stretch(1.0)),
+                    top: StyleProp::Value(Units::Stretch(1.0)),
+                    ..Default::default()
+                };
+                let hint_styles = Style {
+                    bottom: StyleProp::Value(Units::Stretch(1.0)),
+                    color: StyleProp::Value(theme.text_muted),
+                    top: StyleProp::Value(Units::Stretch(1.0)),
+                    ..Default::default()
+                };
+                let submenu_styles = Style {
+                    left: StyleProp::Value(Units::Percentage(100.0)),
+                    top: StyleProp::Value(Units::Pixels(0.0)),
+                    ..Default::default()
+                };
+                let submenu_path = path.clone();
+                let submenu_key_handler = submenu_key_handler.clone();
+                let hint = if has_submenu {
+                    ">".to_string()
+                } else {
+                    item.shortcut.clone().unwrap_or_default()
+                };
+
+                constructor! {
+                    <Background styles={Some(item_styles)} on_event={Some(on_event)}>
+                        <Text content={item.label.clone()} size={14.0} styles={Some(label_styles)} />
+                        <Text content={hint} size={12.0} styles={Some(hint_styles)} />
+                        <If condition={is_submenu_open}>
+                            <MenuList
+                                items={item.submenu.clone()}
+                                prefix={item_path.clone()}
+                                path={submenu_path.clone()}
+                                set_path={set_path.clone()}
+                                key_handler={submenu_key_handler.clone()}
+                                styles={Some(submenu_styles)}
+                            />
+                        </If>
+                    </Background>
+                }
+            }))}
+        </>
+    }
+}
+
+/// Returns the items of the list opened by the item at the given path
+fn items_at<'a>(menus: &'a [MenuItem], path: &[usize]) -> Option<&'a [MenuItem]> {
+    let mut items = menus;
+    for index in path {
+        items = &items.get(*index)?.submenu;
+    }
+    Some(items)
+}
+
+/// Returns the new path after pressing the given key, or `None` if the key does nothing
+fn navigate(menus: &[MenuItem], path: &[usize], key: KeyCode) -> Option<Vec<usize>> {
+    if menus.is_empty() {
+        return None;
+    }
+
+    let menu_count = menus.len();
+    let mut next = path.to_vec();
+
+    if path.is_empty() {
+        return match key {
+            KeyCode::Down | KeyCode::Return | KeyCode::Space => Some(vec![0]),
+            _ => None,
+        };
+    }
+
+    if path.len() == 1 {
+        // A menu is open but none of its items are highlighted
+        let item_count = items_at(menus, path)?.len();
+        match key {
+            KeyCode::Down | KeyCode::Return | KeyCode::Space if item_count > 0 => next.push(0),
+            KeyCode::Up if item_count > 0 => next.push(item_count - 1),
+            KeyCode::Left => next = vec![(path[0] + menu_count - 1) % menu_count],
+            KeyCode::Right => next = vec![(path[0] + 1) % menu_count],
+            _ => return None,
+        }
+        return Some(next);
+    }
+
+    let (highlighted, parent_path) = path.split_last()?;
+    let items = items_at(menus, parent_path)?;
+    let item = items.get(*highlighted)?;
+    let item_count = items.len();
+    let last = next.len() - 1;
+
+    match key {
+        KeyCode::Down => next[last] = (highlighted + 1) % item_count,
+        KeyCode::Up => next[last] = (highlighted + item_count - 1) % item_count,
+        KeyCode::Right if !item.submenu.is_empty() => next.push(0),
+        KeyCode::Right => next = vec![(path[0] + 1) % menu_count],
+        KeyCode::Left if path.len() > 2 => {
+            next.pop();
+        }
+        KeyCode::Left => next = vec![(path[0] + menu_count - 1) % menu_count],
+        KeyCode::Return | KeyCode::Space if !item.submenu.is_empty() => next.push(0),
+        KeyCode::Return | KeyCode::Space => {
+            if let Some(ref on_select) = item.on_select {
+                on_select.call(());
+            }
+            next.clear();
+        }
+        _ => return None,
+    }
+
+    Some(next)
+}
@@ -1,31 +1,87 @@
+mod animated_number;
 mod app;
+mod avatar;
 mod background;
+mod breadcrumb;
 mod button;
+mod carousel;
+mod chip;
 mod clip;
+mod color_picker;
+mod copy_button;
+mod date_picker;
+mod disabled;
 mod element;
+mod empty_state;
+mod error_boundary;
 mod fold;
 mod if_element;
 mod image;
 mod inspector;
+mod key_value_list;
+mod master_detail;
+mod menu_bar;
 mod nine_patch;
+mod pagination;
+mod panel;
+mod portal;
+mod rating;
 mod scroll;
+mod skeleton;
+mod sortable_list;
+mod spacer;
+mod spinner;
+mod static_element;
+mod stepper;
+mod table;
 mod text;
 mod text_box;
+mod theme;
+mod toast;
 mod tooltip;
+mod transition;
 mod window;
 
+pub use animated_number::*;
 pub use app::*;
+pub use avatar::*;
 pub use background::*;
+pub use breadcrumb::*;
 pub use button::*;
+pub use carousel::*;
+pub use chip::*;
 pub use clip::*;
+pub use color_picker::*;
+pub use copy_button::*;
+pub use date_picker::*;
+pub use disabled::*;
 pub use element::*;
+pub use empty_state::*;
+pub use error_boundary::*;
 pub use fold::*;
 pub use if_element::*;
 pub use image::*;
 pub use inspector::*;
+pub use key_value_list::*;
+pub use master_detail::*;
+pub use menu_bar::*;
 pub use nine_patch::*;
+pub use pagination::*;
+pub use panel::*;
+pub use portal::*;
+pub use rating::*;
 pub use scroll::*;
+pub use skeleton::*;
+pub use sortable_list::*;
+pub use spacer::*;
+pub use spinner::*;
+pub use static_element::*;
+pub use stepper::*;
+pub use table::*;
 pub use text::*;
 pub use text_box::*;
+pub use theme::*;
+pub use toast::*;
 pub use tooltip::*;
+pub use transition::*;
 pub use window::*;
@@ -40,8 +40,11 @@ pub struct NinePatchProps {
 ///
 /// * Stretching the edges (vertically for left/right and horizontally for top/bottom)
 /// * Preserving the corners
-/// * Scaling the center to fill the remaining space
+/// * Scaling the center to fill the remaining space (or tiling it, according to the
+///   [`slice_center`](Style::slice_center) style)
 ///
+/// The same can be done for an [`Image`](crate::widgets::Image) by setting its
+/// [`slice`](Style::slice) style.
 ///
 /// # Props
 ///
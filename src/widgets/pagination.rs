@@ -0,0 +1,203 @@
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    widget, Children, CursorIcon, EventType, OnChange, OnEvent, OnLayout, VecTracker, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Button, Text};
+
+/// The number of pages displayed on either side of the current page
+const SIBLING_COUNT: usize = 1;
+
+/// Props used by the [`Pagination`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct PaginationProps {
+    /// A callback for when the user selects a new page
+    ///
+    /// The handler is given the zero-based index of the selected page, which is always
+    /// within the range `[0, page_count - 1]`.
+    pub on_change: Option<OnChange<usize>>,
+    /// The zero-based index of the current page
+    pub page: usize,
+    /// The total number of pages
+    pub page_count: usize,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A widget used to navigate between the pages of paged content
+///
+/// Displays previous and next buttons along with the page numbers surrounding the current page.
+/// For large page counts, skipped page numbers are collapsed into ellipses.
+///
+/// # Props
+///
+/// __Type:__ [`PaginationProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{OnChange, rsx, use_state};
+/// # use kayak_ui::widgets::Pagination;
+///
+/// let (page, set_page, ..) = use_state!(0);
+/// let on_change = OnChange::new(move |page| {
+///     set_page(page);
+/// });
+///
+/// rsx! {
+///     <Pagination page={page} page_count={20} on_change={Some(on_change)} />
+/// }
+/// ```
+pub fn Pagination(props: PaginationProps) {
+    let PaginationProps {
+        on_change,
+        page,
+        page_count,
+        ..
+    } = props.clone();
+
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                col_between: StyleProp::Value(Units::Pixels(4.0)),
+                height: StyleProp::Value(Units::Auto),
+                layout_type: StyleProp::Value(LayoutType::Row),
+                width: StyleProp::Value(Units::Auto),
+                ..Default::default()
+            }),
+    );
+
+    let last_page = page_count.saturating_sub(1);
+    let page = page.min(last_page);
+    let theme = use_theme(context).unwrap_or_default();
+
+    // Each entry is the label to display and the page it navigates to (if enabled)
+    let mut entries: Vec<(String, Option<usize>, bool)> = Vec::new();
+    entries.push(("<".to_string(), page.checked_sub(1), false));
+    for item in page_window(page, page_count) {
+        match item {
+            Some(index) => entries.push(((index + 1).to_string(), Some(index), index == page)),
+            None => entries.push(("...".to_string(), None, false)),
+        }
+    }
+    entries.push((
+        ">".to_string(),
+        Some(page + 1).filter(|next| *next <= last_page && page_count > 0),
+        false,
+    ));
+
+    rsx! {
+        <>
+            {VecTracker::from(entries.clone().into_iter().map(|(label, target, is_current)| {
+                let is_enabled = target.is_some() && !is_current;
+                let on_change = on_change.clone();
+                let on_event = target.filter(|_| is_enabled).map(|target| {
+                    OnEvent::new(move |_, event| match event.event_type {
+                        EventType::Click(..) => {
+                            if let Some(ref on_change) = on_change {
+                                on_change.call(target.min(last_page));
+                            }
+                        }
+                        _ => {}
+                    })
+                });
+
+                let background_color = if is_current {
+                    theme.primary
+                } else {
+                    theme.surface
+                };
+                let button_styles = Style {
+                    background_color: StyleProp::Value(background_color),
+                    cursor: if is_enabled {
+                        CursorIcon::Hand.into()
+                    } else {
+                        CursorIcon::Default.into()
+                    },
+                    height: StyleProp::Value(Units::Pixels(28.0)),
+                    width: StyleProp::Value(Units::Pixels(32.0)),
+                    ..Default::default()
+                };
+                let text_color = if is_current {
+                    theme.on_primary
+                } else if is_enabled {
+                    theme.text
+                } else {
+                    theme.text_muted
+                };
+                let text_styles = Style {
+                    color: StyleProp::Value(text_color),
+                    top: StyleProp::Value(Units::Stretch(1.0)),
+                    bottom: StyleProp::Value(Units::Stretch(1.0)),
+                    ..Default::default()
+                };
+                let disabled = !is_enabled;
+
+                constructor! {
+                    <Button disabled={disabled} styles={Some(button_styles)} on_event={on_event}>
+                        <Text content={label} size={14.0} styles={Some(text_styles)} />
+                    </Button>
+                }
+            }))}
+        </>
+    }
+}
+
+/// Returns the page numbers to display for the given page, where `None` represents an ellipsis
+///
+/// The first and last pages are always included, along with the pages surrounding the current one.
+fn page_window(page: usize, page_count: usize) -> Vec<Option<usize>> {
+    if page_count == 0 {
+        return Vec::new();
+    }
+
+    let last_page = page_count - 1;
+    let start = page.saturating_sub(SIBLING_COUNT);
+    let end = (page + SIBLING_COUNT).min(last_page);
+
+    let mut window = Vec::new();
+    if start > 0 {
+        window.push(Some(0));
+        if start > 2 {
+            window.push(None);
+        } else if start == 2 {
+            window.push(Some(1));
+        }
+    }
+    window.extend((start..=end).map(Some));
+    if end < last_page {
+        if end + 2 < last_page {
+            window.push(None);
+        } else if end + 2 == last_page {
+            window.push(Some(last_page - 1));
+        }
+        window.push(Some(last_page));
+    }
+
+    window
+}
@@ -0,0 +1,193 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Corner, Edge, LayoutType, Style, StyleProp, Units},
+    widget, Children, OnChange, OnEvent, OnLayout, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Background, Clip, Element, Fold, If, Text};
+
+/// The width of the border around a panel and of the divider below its header (in pixels)
+const BORDER_WIDTH: f32 = 1.0;
+
+/// Props used by the [`Panel`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct PanelProps {
+    /// The string displayed in the header of the panel
+    ///
+    /// If `None`, the header is only displayed for [`collapsible`](Self::collapsible) panels.
+    pub title: Option<String>,
+    /// If true, the body of the panel can be collapsed by clicking its header
+    pub collapsible: bool,
+    /// The initial collapsed state of a [`collapsible`](Self::collapsible) panel
+    pub default_collapsed: bool,
+    /// Sets the controlled collapsed state of a [`collapsible`](Self::collapsible) panel
+    ///
+    /// If `None`, the collapsed state will be automatically handled internally.
+    pub collapsed: Option<bool>,
+    /// A callback for when the user collapses or expands the panel
+    ///
+    /// The handler is given the boolean value of the desired collapsed state.
+    pub on_toggle: Option<OnChange<bool>>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A bordered, rounded container with an optional titled header
+///
+/// The header is separated from the body by a divider. When the panel is
+/// [`collapsible`](PanelProps::collapsible), its header and body are displayed by a [`Fold`],
+/// so the header shows a disclosure indicator and toggles the body when clicked or, when focused,
+/// when pressing `Enter` or `Space`.
+///
+/// The colors and spacing all come from the current [`Theme`](crate::widgets::Theme).
+///
+/// # Props
+///
+/// __Type:__ [`PanelProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ✅        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{Panel, Text};
+///
+/// rsx! {
+///     <Panel title={Some("Settings".to_string())} collapsible={true}>
+///         <Text content={"Panel Content".to_string()} size={16.0} />
+///     </Panel>
+/// }
+/// ```
+pub fn Panel(props: PanelProps) {
+    let PanelProps {
+        title,
+        collapsible,
+        default_collapsed,
+        collapsed,
+        on_toggle,
+        ..
+    } = props.clone();
+    let theme = use_theme(context).unwrap_or_default();
+
+    // === State === //
+    // The fold manages the collapsed state, which is just the inverse of its open state
+    let open = collapsed.map(|collapsed| !collapsed);
+    let on_toggle = on_toggle.map(|callback| {
+        OnChange::new(move |open: bool| {
+            callback.call(!open);
+        })
+    });
+
+    // === Styles === //
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Quad),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                background_color: StyleProp::Value(theme.surface),
+                border: StyleProp::Value(Edge::all(Units::Pixels(BORDER_WIDTH))),
+                border_color: StyleProp::Value(theme.border),
+                border_radius: StyleProp::Value(Corner::all(theme.radius)),
+                height: StyleProp::Value(Units::Auto),
+                layout_type: StyleProp::Value(LayoutType::Column),
+                ..Default::default()
+            }),
+    );
+
+    let show_header = title.is_some() && !collapsible;
+    let header_styles = Style {
+        height: StyleProp::Value(Units::Auto),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        padding: StyleProp::Value(Edge::all(Units::Pixels(theme.spacing_medium))),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let title_styles = Style {
+        color: StyleProp::Value(theme.text),
+        ..Default::default()
+    };
+
+    let divider_styles = Style {
+        background_color: StyleProp::Value(theme.border),
+        height: StyleProp::Value(Units::Pixels(BORDER_WIDTH)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+
+    let body_styles = Style {
+        height: StyleProp::Value(Units::Auto),
+        padding: StyleProp::Value(Edge::all(Units::Pixels(theme.spacing_medium))),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+
+    // The fold's header and body share its padding, so the divider is spaced out from both
+    let fold_styles = Style {
+        color: StyleProp::Value(theme.text),
+        padding: StyleProp::Value(Edge::all(Units::Pixels(theme.spacing_medium))),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let fold_divider_styles = Style {
+        top: StyleProp::Value(Units::Pixels(theme.spacing_small)),
+        ..divider_styles.clone()
+    };
+    let fold_body_styles = Style {
+        height: StyleProp::Value(Units::Auto),
+        padding_top: StyleProp::Value(Units::Pixels(theme.spacing_medium)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+
+    let title = title.unwrap_or_default();
+    let font_size = theme.font_size;
+
+    // === Render === //
+    rsx! {
+        <>
+            <If condition={collapsible}>
+                <Fold
+                    label={title.clone()}
+                    default_open={!default_collapsed}
+                    open={open}
+                    on_toggle={on_toggle}
+                    styles={Some(fold_styles)}
+                >
+                    <Background styles={Some(fold_divider_styles)} />
+                    <Clip styles={Some(fold_body_styles)}>
+                        {children}
+                    </Clip>
+                </Fold>
+            </If>
+            <If condition={!collapsible}>
+                <If condition={show_header}>
+                    <Element styles={Some(header_styles)}>
+                        <Text content={title} size={font_size} styles={Some(title_styles)} />
+                    </Element>
+                    <Background styles={Some(divider_styles)} />
+                </If>
+                <Clip styles={Some(body_styles)}>
+                    {children}
+                </Clip>
+            </If>
+        </>
+    }
+}
@@ -0,0 +1,89 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{PositionType, Style, StyleProp, Units},
+    widget, Children, OnEvent, OnLayout, PointerEvents, WidgetProps,
+};
+
+/// Props used by the [`Portal`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct PortalProps {
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A widget that renders its children at the root of the widget tree, regardless of where it's
+/// declared
+///
+/// This allows overlays, such as tooltips, modals, and menus, to escape the clipping and layout of
+/// their ancestors. The portal covers the root widget and is drawn above everything else, so its
+/// children should be positioned relative to the root (which is usually the window).
+///
+/// Only the layout and rendering of the portal are moved: it's still a child of its declaring
+/// widget, so it inherits styles and consumes the providers of its ancestors as usual.
+///
+/// # Props
+///
+/// __Type:__ [`PortalProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ✅        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Styles
+///
+/// By default, the portal is self-directed and stretches to fill the root. It also only lets its
+/// children receive pointer events, so it doesn't block the widgets beneath it.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{Clip, Portal, Text};
+///
+/// rsx! {
+///     <Clip>
+///         <Portal>
+///             <Text content={"I'm not clipped!".to_string()} size={16.0} />
+///         </Portal>
+///     </Clip>
+/// }
+/// ```
+pub fn Portal(props: PortalProps) {
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                position_type: StyleProp::Value(PositionType::SelfDirected),
+                left: StyleProp::Value(Units::Pixels(0.0)),
+                top: StyleProp::Value(Units::Pixels(0.0)),
+                width: StyleProp::Value(Units::Stretch(1.0)),
+                height: StyleProp::Value(Units::Stretch(1.0)),
+                pointer_events: StyleProp::Value(PointerEvents::ChildrenOnly),
+                ..Default::default()
+            }),
+    );
+
+    context.set_portal(true);
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
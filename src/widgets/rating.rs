@@ -0,0 +1,193 @@
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, PositionType, Style, StyleProp, Units},
+    use_state, widget, Children, CursorIcon, EventType, OnChange, OnEvent, OnLayout, VecTracker,
+    WidgetProps,
+};
+
+use crate::widgets::{use_theme, Clip, Element, Text};
+
+/// The number of stars displayed when no valid `max` is given
+const DEFAULT_MAX: usize = 5;
+/// The size of each star (in pixels)
+const STAR_SIZE: f32 = 24.0;
+
+/// Props used by the [`Rating`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct RatingProps {
+    /// The number of stars to display
+    ///
+    /// A value of zero will be replaced by a default of 5 stars.
+    pub max: usize,
+    /// A callback for when the user clicks a star
+    ///
+    /// The handler is given the rating that was selected, from `1.0` up to [`max`](Self::max).
+    pub on_change: Option<OnChange<f32>>,
+    /// If true, the rating is only displayed and cannot be hovered or clicked
+    pub readonly: bool,
+    /// The character used to display each star
+    ///
+    /// Defaults to `"*"` since the default font does not include a star glyph. If the
+    /// font in use has one, `"★"` can be used instead.
+    pub symbol: Option<String>,
+    /// The current rating
+    ///
+    /// Fractional values will partially fill the last star.
+    pub value: f32,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A widget that displays a rating as a row of stars
+///
+/// Unless [`readonly`](RatingProps::readonly), hovering the stars previews the rating and clicking
+/// a star selects it.
+///
+/// # Props
+///
+/// __Type:__ [`RatingProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ❌        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{OnChange, rsx, use_state};
+/// # use kayak_ui::widgets::Rating;
+///
+/// let (rating, set_rating, ..) = use_state!(3.5);
+/// let on_change = OnChange::new(move |value| {
+///     set_rating(value);
+/// });
+///
+/// rsx! {
+///     <Rating value={rating} on_change={Some(on_change)} />
+/// }
+/// ```
+pub fn Rating(props: RatingProps) {
+    let RatingProps {
+        max,
+        on_change,
+        readonly,
+        symbol,
+        value,
+        ..
+    } = props.clone();
+
+    let max = if max > 0 { max } else { DEFAULT_MAX };
+    let symbol = symbol.unwrap_or_else(|| "*".to_string());
+
+    let (hover_value, set_hover_value, ..) = use_state!(Option::<f32>::None);
+    let hover_value = if readonly { None } else { hover_value };
+    let displayed_value = hover_value.unwrap_or(value).max(0.0).min(max as f32);
+    let theme = use_theme(context).unwrap_or_default();
+
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                col_between: StyleProp::Value(Units::Pixels(2.0)),
+                cursor: if readonly {
+                    StyleProp::Inherit
+                } else {
+                    CursorIcon::Hand.into()
+                },
+                height: StyleProp::Value(Units::Pixels(STAR_SIZE)),
+                layout_type: StyleProp::Value(LayoutType::Row),
+                width: StyleProp::Value(Units::Auto),
+                ..Default::default()
+            }),
+    );
+
+    let cloned_set_hover_value = set_hover_value.clone();
+    props.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
+        EventType::MouseOut(..) => {
+            cloned_set_hover_value(None);
+        }
+        _ => {}
+    }));
+
+    rsx! {
+        <>
+            {VecTracker::from((0..max).map(|index| {
+                let rating = (index + 1) as f32;
+                let fill = (displayed_value - index as f32).max(0.0).min(1.0);
+
+                let on_change = on_change.clone();
+                let set_hover_value = set_hover_value.clone();
+                let on_event = if readonly {
+                    None
+                } else {
+                    Some(OnEvent::new(move |_, event| match event.event_type {
+                        EventType::MouseIn(..) => {
+                            set_hover_value(Some(rating));
+                        }
+                        EventType::Click(..) => {
+                            if let Some(ref on_change) = on_change {
+                                on_change.call(rating);
+                            }
+                        }
+                        _ => {}
+                    }))
+                };
+
+                let star_styles = Style {
+                    width: StyleProp::Value(Units::Pixels(STAR_SIZE)),
+                    height: StyleProp::Value(Units::Pixels(STAR_SIZE)),
+                    ..Default::default()
+                };
+                let empty_styles = Style {
+                    color: StyleProp::Value(theme.text_muted),
+                    left: StyleProp::Value(Units::Pixels(0.0)),
+                    position_type: StyleProp::Value(PositionType::SelfDirected),
+                    top: StyleProp::Value(Units::Pixels(0.0)),
+                    ..Default::default()
+                };
+                let fill_styles = Style {
+                    height: StyleProp::Value(Units::Stretch(1.0)),
+                    left: StyleProp::Value(Units::Pixels(0.0)),
+                    position_type: StyleProp::Value(PositionType::SelfDirected),
+                    top: StyleProp::Value(Units::Pixels(0.0)),
+                    width: StyleProp::Value(Units::Percentage(fill * 100.0)),
+                    ..Default::default()
+                };
+                let filled_styles = Style {
+                    color: StyleProp::Value(theme.primary),
+                    ..Default::default()
+                };
+                let empty_symbol = symbol.clone();
+                let filled_symbol = symbol.clone();
+
+                constructor! {
+                    <Element styles={Some(star_styles)} on_event={on_event}>
+                        <Text content={empty_symbol} size={STAR_SIZE} styles={Some(empty_styles)} />
+                        <Clip styles={Some(fill_styles)}>
+                            <Text content={filled_symbol} size={STAR_SIZE} styles={Some(filled_styles)} />
+                        </Clip>
+                    </Element>
+                }
+            }))}
+        </>
+    }
+}
@@ -2,16 +2,55 @@ use crate::core::{
     render_command::RenderCommand,
     rsx,
     styles::{PositionType, Style, Units},
-    use_state, widget, Bound, EventType, MutableBound, OnEvent, WidgetProps,
+    use_state, widget, Bound, EventType, Handler, MutableBound, OnEvent, WidgetProps,
 };
 use kayak_core::layout_cache::Rect;
 use kayak_core::styles::{Corner, Edge};
 use kayak_core::Color;
 
+use crate::widgets::transition::ease_out;
 use crate::widgets::{Background, Clip};
 
 use super::{map_range, ScrollContext};
 
+/// How long an auto-hiding scrollbar stays visible after scrolling (in seconds)
+const AUTO_HIDE_DELAY: f32 = 1.0;
+/// How long an auto-hiding scrollbar takes to fade out (in seconds)
+const AUTO_HIDE_DURATION: f32 = 0.3;
+
+/// Where the scrollbars of a [`ScrollBox`](crate::widgets::ScrollBox) are placed
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ScrollbarPlacement {
+    /// Places the scrollbars beside the content, reserving a gutter for them
+    Inset,
+    /// Draws the scrollbars over the edges of the content, without reserving any space
+    Overlay,
+}
+
+impl Default for ScrollbarPlacement {
+    fn default() -> Self {
+        ScrollbarPlacement::Inset
+    }
+}
+
+/// The appearance of the scrollbars of a [`ScrollBox`](crate::widgets::ScrollBox)
+///
+/// This can be given to a scroll box directly or to all of them through the
+/// [`Theme`](crate::widgets::Theme). Any unset value falls back to the default look.
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct ScrollbarStyle {
+    /// The color of the scrollbar thumb
+    pub thumb_color: Option<Color>,
+    /// The color of the scrollbar track
+    pub track_color: Option<Color>,
+    /// The thickness of the scrollbar (in pixels)
+    pub thickness: Option<f32>,
+    /// If true, the scrollbar fades out when not scrolling, hovered, or dragged
+    pub auto_hide: bool,
+    /// Where the scrollbar is placed
+    pub placement: ScrollbarPlacement,
+}
+
 /// Props used by the [`ScrollBar`] widget
 #[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
 pub struct ScrollBarProps {
@@ -29,6 +68,10 @@ pub struct ScrollBarProps {
     pub track_color: Option<Color>,
     /// The styles of the scrollbar track
     pub track_styles: Option<Style>,
+    /// If true, fades out the scrollbar when not scrolling, hovered, or dragged
+    pub auto_hide: bool,
+    /// Where the scrollbar is placed within its scroll box
+    pub placement: ScrollbarPlacement,
     #[prop_field(Styles)]
     styles: Option<Style>,
 }
@@ -48,6 +91,9 @@ pub struct ScrollBarProps {
 /// This is the track along which the thumb moves. Clicking anywhere in the track will move
 /// the thumb to the clicked position and update the scroll offset.
 ///
+/// When [`auto_hide`](ScrollBarProps::auto_hide) is set, the scrollbar fades out shortly after
+/// the scroll offset stops changing, and reappears while hovered or dragged.
+///
 /// # Props
 ///
 /// __Type:__ [`ScrollBarProps`]
@@ -122,24 +168,83 @@ pub fn ScrollBar(props: ScrollBarProps) {
         (0.0, 100.0 - thumb_size_percent * 100.0),
     );
 
+    // === States === //
+    // A state determining whether we are currently dragging the thumb
+    let (is_dragging, set_is_dragging, ..) = use_state!(false);
+    // A state containing the UI coordinates of the initial click.
+    // This is used to get the difference from the current cursor coordinates, so that
+    // we can calculate how much the thumb should move.
+    let (start_pos, set_start_pos, ..) = use_state!((0.0, 0.0));
+    // A state containing the scroll offsets when initially clicked.
+    // This is used in conjunction with `start_pos` to calculate the actual scrolled amount.
+    let (start_offset, set_start_offset, ..) = use_state!((0.0, 0.0));
+    // A state containing whether the cursor is over the scrollbar
+    let (is_hovered, set_is_hovered, ..) = use_state!(false);
+    // A state containing the last seen scroll progress and the time since it was last changed
+    let (last_scrolled, set_last_scrolled, ..) =
+        use_state!((percent_scrolled, Option::<f32>::None));
+
+    // === Auto Hide === //
+    let mut is_hiding = false;
+    let opacity = if !props.auto_hide || is_dragging || is_hovered {
+        1.0
+    } else if last_scrolled.0 != percent_scrolled {
+        set_last_scrolled((percent_scrolled, Some(0.0)));
+        1.0
+    } else if let Some(elapsed) = last_scrolled.1 {
+        let progress = ((elapsed - AUTO_HIDE_DELAY) / AUTO_HIDE_DURATION).clamp(0.0, 1.0);
+        is_hiding = progress < 1.0;
+        ease_out(1.0 - progress)
+    } else {
+        0.0
+    };
+    // Keep ticking until fully hidden
+    let tick_set_last_scrolled = set_last_scrolled.clone();
+    context.on_tick(is_hiding.then(|| {
+        Handler::new(move |delta_time: f32| {
+            let (scrolled, elapsed) = last_scrolled;
+            tick_set_last_scrolled((scrolled, elapsed.map(|elapsed| elapsed + delta_time)))
+        })
+    }));
+
     // === Styles === //
-    props.styles = Some(
-        Style::default().with_style(Style {
-            render_command: RenderCommand::Layout.into(),
-            width: if horizontal {
-                Units::Stretch(1.0)
-            } else {
-                Units::Pixels(thickness)
-            }
-            .into(),
-            height: if horizontal {
-                Units::Pixels(thickness)
-            } else {
-                Units::Stretch(1.0)
-            }
-            .into(),
+    let placement_styles = match props.placement {
+        ScrollbarPlacement::Inset => Style::default(),
+        ScrollbarPlacement::Overlay if horizontal => Style {
+            position_type: PositionType::SelfDirected.into(),
+            left: Units::Pixels(0.0).into(),
+            top: Units::Stretch(1.0).into(),
+            bottom: Units::Pixels(0.0).into(),
+            ..Default::default()
+        },
+        ScrollbarPlacement::Overlay => Style {
+            position_type: PositionType::SelfDirected.into(),
+            top: Units::Pixels(0.0).into(),
+            left: Units::Stretch(1.0).into(),
+            right: Units::Pixels(0.0).into(),
             ..Default::default()
-        }),
+        },
+    };
+    props.styles = Some(
+        Style::default()
+            .with_style(placement_styles)
+            .with_style(Style {
+                render_command: RenderCommand::Layout.into(),
+                opacity: opacity.into(),
+                width: if horizontal {
+                    Units::Stretch(1.0)
+                } else {
+                    Units::Pixels(thickness)
+                }
+                .into(),
+                height: if horizontal {
+                    Units::Pixels(thickness)
+                } else {
+                    Units::Stretch(1.0)
+                }
+                .into(),
+                ..Default::default()
+            }),
     );
 
     let mut track_style = Style::default()
@@ -165,7 +270,7 @@ pub fn ScrollBar(props: ScrollBarProps) {
         .with_style(Style {
             background_color: thumb_color.into(),
             border_radius: Corner::all(thickness / 2.0).into(),
-            border: Edge::all(1.0).into(),
+            border: Edge::all(Units::Pixels(1.0)).into(),
             border_color: border_color.into(),
             ..Default::default()
         });
@@ -198,22 +303,12 @@ pub fn ScrollBar(props: ScrollBarProps) {
         });
     }
 
-    // === States === //
-    // A state determining whether we are currently dragging the thumb
-    let (is_dragging, set_is_dragging, ..) = use_state!(false);
-    // A state containing the UI coordinates of the initial click.
-    // This is used to get the difference from the current cursor coordinates, so that
-    // we can calculate how much the thumb should move.
-    let (start_pos, set_start_pos, ..) = use_state!((0.0, 0.0));
-    // A state containing the scroll offsets when initially clicked.
-    // This is used in conjunction with `start_pos` to calculate the actual scrolled amount.
-    let (start_offset, set_start_offset, ..) = use_state!((0.0, 0.0));
-
     // === Events === //
     let on_track_event = OnEvent::new(move |ctx, event| match event.event_type {
         EventType::MouseDown(data) => {
             // --- Capture Cursor --- //
-            ctx.capture_cursor(event.current_target);
+            // The capture ends on its own once the mouse is released
+            ctx.capture_pointer();
             set_start_pos((data.position.0, data.position.1));
             set_is_dragging(true);
 
@@ -264,10 +359,12 @@ pub fn ScrollBar(props: ScrollBarProps) {
             scroll_ctx.set(old);
             set_start_offset(offset)
         }
-        EventType::MouseUp(..) => {
-            // --- Release Cursor --- //
-            ctx.release_cursor(event.current_target);
-            set_is_dragging(false);
+        EventType::MouseUp(..) => set_is_dragging(false),
+        EventType::MouseIn(..) => set_is_hovered(true),
+        EventType::MouseOut(..) => {
+            set_is_hovered(false);
+            // Restart the auto-hide delay from when the cursor left
+            set_last_scrolled((percent_scrolled, Some(0.0)));
         }
         EventType::Hover(data) if is_dragging => {
             // --- Move Thumb --- //
@@ -8,9 +8,11 @@ use crate::core::{
 use kayak_core::styles::LayoutType;
 use kayak_core::{Color, GeometryChanged, OnLayout};
 
-use crate::widgets::{Clip, Element, If};
+use crate::widgets::{use_theme, Clip, Element, If};
 
-use super::{ScrollBar, ScrollContent, ScrollContext, ScrollMode};
+use super::{
+    ScrollBar, ScrollContent, ScrollContext, ScrollMode, ScrollbarPlacement, ScrollbarStyle,
+};
 
 /// Props used by the [`ScrollBox`] widget
 #[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
@@ -30,15 +32,25 @@ pub struct ScrollBoxProps {
     pub hide_horizontal: bool,
     /// If true, hides the vertical scrollbar
     pub hide_vertical: bool,
+    /// The appearance of the scrollbars
+    ///
+    /// Defaults to the scrollbar style of the current [`Theme`](crate::widgets::Theme).
+    pub scrollbar_style: Option<ScrollbarStyle>,
     /// The thickness of the scrollbar
+    ///
+    /// This takes precedence over the thickness given by the scrollbar style.
     pub scrollbar_thickness: Option<f32>,
     /// The step to scroll by when `ScrollUnit::Line`
     pub scroll_line: Option<f32>,
     /// The color of the scrollbar thumb
+    ///
+    /// This takes precedence over the color given by the scrollbar style.
     pub thumb_color: Option<Color>,
     /// The styles of the scrollbar thumb
     pub thumb_styles: Option<Style>,
     /// The color of the scrollbar track
+    ///
+    /// This takes precedence over the color given by the scrollbar style.
     pub track_color: Option<Color>,
     /// The styles of the scrollbar track
     pub track_styles: Option<Style>,
@@ -53,6 +65,9 @@ pub struct ScrollBoxProps {
 #[widget]
 /// A widget that creates a scrollable area for overflowing content
 ///
+/// The look of the scrollbars can be configured with a [`ScrollbarStyle`], given either through the
+/// [`scrollbar_style`](ScrollBoxProps::scrollbar_style) prop or the current theme.
+///
 /// # Props
 ///
 /// __Type:__ [`ScrollBoxProps`]
@@ -72,11 +87,18 @@ pub fn ScrollBox(props: ScrollBoxProps) {
     let hide_horizontal = props.hide_horizontal;
     let hide_vertical = props.hide_vertical;
     let mode = props.mode;
-    let scrollbar_thickness = props.scrollbar_thickness.unwrap_or(10.0);
+    let theme = use_theme(context).unwrap_or_default();
+    let scrollbar_style = props.scrollbar_style.unwrap_or(theme.scrollbar);
+    let scrollbar_thickness = props
+        .scrollbar_thickness
+        .or(scrollbar_style.thickness)
+        .unwrap_or(10.0);
     let scroll_line = props.scroll_line.unwrap_or(16.0);
-    let thumb_color = props.thumb_color;
+    let thumb_color = props.thumb_color.or(scrollbar_style.thumb_color);
     let thumb_styles = props.thumb_styles.clone();
-    let track_color = props.track_color;
+    let track_color = props.track_color.or(scrollbar_style.track_color);
+    let auto_hide = scrollbar_style.auto_hide;
+    let placement = scrollbar_style.placement;
     let track_styles = props.track_styles.clone();
 
     // === Scroll === //
@@ -98,9 +120,19 @@ pub fn ScrollBox(props: ScrollBoxProps) {
     let hide_vertical = hide_vertical || !always_show_scrollbar && scrollable_height < f32::EPSILON;
 
     {
+        // Overlay scrollbars are drawn over the content, so they don't need a gutter
+        let is_overlay = placement == ScrollbarPlacement::Overlay;
         let mut next = scroll_ctx.get();
-        next.pad_x = if hide_vertical { 0.0 } else { vert_thickness };
-        next.pad_y = if hide_horizontal { 0.0 } else { hori_thickness };
+        next.pad_x = if hide_vertical || is_overlay {
+            0.0
+        } else {
+            vert_thickness
+        };
+        next.pad_y = if hide_horizontal || is_overlay {
+            0.0
+        } else {
+            hori_thickness
+        };
 
         if next.pad_x != scroll.pad_x || next.pad_y != scroll.pad_y {
             scroll_ctx.set(next);
@@ -206,6 +238,8 @@ pub fn ScrollBox(props: ScrollBoxProps) {
                             thumb_styles={thumb_styles}
                             track_color={track_color}
                             track_styles={track_styles}
+                            auto_hide={auto_hide}
+                            placement={placement}
                         />
                     </If>
                 </Element>
@@ -217,6 +251,8 @@ pub fn ScrollBox(props: ScrollBoxProps) {
                         thumb_styles={thumb_styles}
                         track_color={track_color}
                         track_styles={track_styles}
+                        auto_hide={auto_hide}
+                        placement={placement}
                     />
                 </If>
             </Element>
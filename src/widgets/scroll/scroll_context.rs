@@ -56,6 +56,16 @@ impl ScrollContext {
         }
     }
 
+    /// The width of the scrollbox itself
+    pub fn scrollbox_width(&self) -> f32 {
+        self.scrollbox_width
+    }
+
+    /// The height of the scrollbox itself
+    pub fn scrollbox_height(&self) -> f32 {
+        self.scrollbox_height
+    }
+
     /// The total amount that can be scrolled along the x-axis
     pub fn scrollable_width(&self) -> f32 {
         (self.content_width() - self.scrollbox_width).max(0.0)
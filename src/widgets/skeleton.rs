@@ -0,0 +1,236 @@
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{Corner, LayoutType, PositionType, Style, StyleProp, Transform2D, Units},
+    use_state, widget, Handler, OnEvent, OnLayout, PointerEvents, VecTracker, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Background, Clip, Element, If};
+
+/// The time it takes the shimmer to sweep across the window once (in seconds)
+const SHIMMER_PERIOD: f32 = 1.5;
+/// The width of the shimmer (in pixels)
+const SHIMMER_WIDTH: f32 = 160.0;
+/// The number of strips the shimmer is made of, which approximate a gradient
+const SHIMMER_STRIPS: usize = 8;
+/// The opacity at the center of the shimmer
+const SHIMMER_OPACITY: f32 = 0.12;
+
+/// The shape of a [`Skeleton`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SkeletonShape {
+    /// A rectangle with the theme's rounded corners, such as for lines of text or images
+    Rect,
+    /// A circle (or ellipse), such as for avatars
+    Circle,
+}
+
+impl Default for SkeletonShape {
+    fn default() -> Self {
+        Self::Rect
+    }
+}
+
+/// Props used by the [`Skeleton`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct SkeletonProps {
+    /// The width of the placeholder
+    ///
+    /// Defaults to filling the parent, or to the height for circles.
+    pub width: Option<Units>,
+    /// The height of the placeholder
+    ///
+    /// Defaults to the height of a line of text, using the theme's
+    /// [`font_size`](crate::widgets::Theme::font_size).
+    pub height: Option<Units>,
+    /// The shape of the placeholder
+    pub shape: SkeletonShape,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A placeholder displayed in place of content that's still loading
+///
+/// The placeholder is a block of the theme's [`border`](crate::widgets::Theme::border) color with
+/// a soft shimmer sweeping across it from left to right. The shimmer moves across the whole window
+/// rather than each skeleton, so several skeletons (such as a circle next to a few lines, to mimic
+/// a card) shimmer together as if they were a single surface. Since the shimmer is clipped to the
+/// rectangular bounds of the placeholder, circles brighten as it passes over their center instead.
+///
+/// The shimmer is advanced on every [tick](crate::core::KayakContextRef::on_tick), so skeletons
+/// should be removed once the content has loaded. While the user
+/// [prefers reduced motion](crate::core::KayakContextRef::prefers_reduced_motion), the shimmer is
+/// left out and only the plain placeholder is displayed.
+///
+/// # Props
+///
+/// __Type:__ [`SkeletonProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, styles::{LayoutType, Style, StyleProp, Units}};
+/// # use kayak_ui::widgets::{Element, Skeleton, SkeletonShape};
+///
+/// let card_styles = Style {
+///     col_between: StyleProp::Value(Units::Pixels(12.0)),
+///     height: StyleProp::Value(Units::Auto),
+///     layout_type: StyleProp::Value(LayoutType::Row),
+///     ..Default::default()
+/// };
+/// let lines_styles = Style {
+///     row_between: StyleProp::Value(Units::Pixels(8.0)),
+///     height: StyleProp::Value(Units::Auto),
+///     ..Default::default()
+/// };
+///
+/// rsx! {
+///     <Element styles={Some(card_styles)}>
+///         <Skeleton shape={SkeletonShape::Circle} height={Some(Units::Pixels(48.0))} />
+///         <Element styles={Some(lines_styles)}>
+///             <Skeleton />
+///             <Skeleton width={Some(Units::Percentage(60.0))} />
+///         </Element>
+///     </Element>
+/// }
+/// ```
+pub fn Skeleton(props: SkeletonProps) {
+    let SkeletonProps {
+        width,
+        height,
+        shape,
+        ..
+    } = props.clone();
+    let theme = use_theme(context).unwrap_or_default();
+
+    let height = height.unwrap_or(Units::Pixels((theme.font_size * 1.2).round()));
+    let width = match (width, shape) {
+        (Some(width), _) => width,
+        (None, SkeletonShape::Circle) => height,
+        (None, SkeletonShape::Rect) => Units::Stretch(1.0),
+    };
+
+    // === Shimmer === //
+    let has_shimmer = !context.prefers_reduced_motion();
+    // Starting from the context's elapsed time keeps every skeleton's shimmer in sync
+    let now = context.elapsed();
+    let (elapsed, set_elapsed, ..) = use_state!(now);
+    context.on_tick(
+        has_shimmer.then(|| Handler::new(move |delta_time: f32| set_elapsed(elapsed + delta_time))),
+    );
+    let layout = context.get_layout(&self.get_id()).copied();
+    let (posx, size) = layout
+        .map(|layout| (layout.posx, (layout.width, layout.height)))
+        .unwrap_or_default();
+    let sweep_width = context
+        .window_size()
+        .map(|(window_width, _)| window_width)
+        .unwrap_or(size.0);
+    let progress = (elapsed % SHIMMER_PERIOD) / SHIMMER_PERIOD;
+    // The left edge of the shimmer relative to this widget, starting just outside the window
+    let shimmer_left = progress * (sweep_width + SHIMMER_WIDTH) - SHIMMER_WIDTH - posx;
+
+    let radius = match shape {
+        SkeletonShape::Rect => theme.radius,
+        SkeletonShape::Circle => size.0.min(size.1) / 2.0,
+    };
+
+    // === Styles === //
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Quad),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                background_color: StyleProp::Value(theme.border),
+                border_radius: StyleProp::Value(Corner::all(radius)),
+                height: StyleProp::Value(height),
+                pointer_events: StyleProp::Value(PointerEvents::None),
+                width: StyleProp::Value(width),
+                ..Default::default()
+            }),
+    );
+
+    let has_glow = has_shimmer && shape == SkeletonShape::Circle;
+    let has_sweep = has_shimmer && shape == SkeletonShape::Rect;
+    let fill_styles = Style {
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        left: StyleProp::Value(Units::Pixels(0.0)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        top: StyleProp::Value(Units::Pixels(0.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    };
+    let band_styles = Style {
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        transform: StyleProp::Value(Transform2D::from_translation(shimmer_left, 0.0)),
+        width: StyleProp::Value(Units::Pixels(SHIMMER_WIDTH)),
+        ..Default::default()
+    };
+    // Circles can't clip the shimmer to their shape, so they brighten as it passes their center
+    let center = size.0 / 2.0 - shimmer_left;
+    let glow_styles = Style {
+        background_color: StyleProp::Value(theme.text),
+        border_radius: StyleProp::Value(Corner::all(radius)),
+        opacity: StyleProp::Value(shimmer_opacity(center / SHIMMER_WIDTH)),
+        ..fill_styles.clone()
+    };
+    let strip_width = SHIMMER_WIDTH / SHIMMER_STRIPS as f32;
+    let strip_styles = move |strip: usize| Style {
+        background_color: StyleProp::Value(theme.text),
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        opacity: StyleProp::Value(shimmer_opacity(
+            (strip as f32 + 0.5) / SHIMMER_STRIPS as f32,
+        )),
+        width: StyleProp::Value(Units::Pixels(strip_width)),
+        ..Default::default()
+    };
+
+    rsx! {
+        <>
+            <If condition={has_glow}>
+                <Background styles={Some(glow_styles)} />
+            </If>
+            <If condition={has_sweep}>
+                <Clip styles={Some(fill_styles)}>
+                    <Element styles={Some(band_styles)}>
+                        {VecTracker::from((0..SHIMMER_STRIPS).map(|strip| {
+                            let strip_styles = strip_styles(strip);
+                            constructor! {
+                                <Background styles={Some(strip_styles)} />
+                            }
+                        }))}
+                    </Element>
+                </Clip>
+            </If>
+        </>
+    }
+}
+
+/// Returns the opacity of the shimmer at the given position across it (from `0.0` to `1.0`)
+///
+/// The shimmer fades in and out smoothly, peaking at its center.
+fn shimmer_opacity(position: f32) -> f32 {
+    if !(0.0..=1.0).contains(&position) {
+        return 0.0;
+    }
+    SHIMMER_OPACITY * (position * std::f32::consts::PI).sin().powi(2)
+}
@@ -0,0 +1,318 @@
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{Corner, Edge, PositionType, Style, StyleProp, Units},
+    use_state, widget, Accessibility, CursorIcon, EventType, Handler, OnChange, OnEvent, OnLayout,
+    Role, VecTracker, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Background, Element, If, Text, Theme};
+
+/// The default height of each item (in pixels)
+const DEFAULT_ITEM_HEIGHT: f32 = 32.0;
+/// The space left between neighboring items (in pixels)
+const ITEM_GAP: f32 = 4.0;
+/// The duration of the animation played when items shift to make room (in seconds)
+const SHIFT_DURATION: f32 = 0.15;
+
+/// Props used by the [`SortableList`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct SortableListProps {
+    /// The labels of the items, in their current order
+    ///
+    /// Each label is also used as the key of its item, so they should be unique.
+    pub items: Vec<String>,
+    /// A callback fired with the original and new index of an item once it's dropped in a new
+    /// position
+    ///
+    /// The list doesn't reorder itself, so this should be used to update
+    /// [`items`](Self::items).
+    pub on_reorder: Option<OnChange<(usize, usize)>>,
+    /// The height of each item (in pixels)
+    ///
+    /// Defaults to 32 pixels.
+    pub item_height: Option<f32>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+/// An in-progress drag of an item in a [`SortableList`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct SortDrag {
+    /// The index of the dragged item
+    from: usize,
+    /// The index the item will be dropped at
+    to: usize,
+    /// The distance from the top of the item to the cursor when the drag started
+    grab: f32,
+    /// The position of the cursor, relative to the top of the list
+    cursor: f32,
+}
+
+impl SortDrag {
+    /// Returns the slot the item at the given index is displayed in, making room for the drop
+    fn slot(&self, index: usize) -> usize {
+        if self.from < index && index <= self.to {
+            index - 1
+        } else if self.to <= index && index < self.from {
+            index + 1
+        } else {
+            index
+        }
+    }
+}
+
+#[widget]
+/// A widget that displays a list of items that can be reordered by dragging them
+///
+/// Pressing an item picks it up, and it then follows the cursor until released. While dragging, a
+/// placeholder gap is left where the item will be dropped, and the neighboring items are animated
+/// (unless the user
+/// [prefers reduced motion](crate::core::KayakContextRef::prefers_reduced_motion)) as they shift to
+/// make room. Releasing the item calls [`on_reorder`](SortableListProps::on_reorder) with its
+/// original and new index, if it moved.
+///
+/// The cursor is captured for the duration of the drag, so the item can be dragged past the ends
+/// of the list (where it's kept at the first or last position). Items are keyed by their label,
+/// so their state is kept as they're reordered.
+///
+/// # Props
+///
+/// __Type:__ [`SortableListProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, OnChange};
+/// # use kayak_ui::widgets::SortableList;
+///
+/// let items = vec!["First".to_string(), "Second".to_string(), "Third".to_string()];
+/// let on_reorder = OnChange::new(|(from, to): (usize, usize)| {
+///     println!("Moved item {} to {}", from, to);
+/// });
+///
+/// rsx! {
+///     <SortableList items={items} on_reorder={Some(on_reorder)} />
+/// }
+/// ```
+pub fn SortableList(props: SortableListProps) {
+    let SortableListProps {
+        items,
+        on_reorder,
+        item_height,
+        ..
+    } = props.clone();
+    let item_height = item_height.unwrap_or(DEFAULT_ITEM_HEIGHT);
+    let count = items.len();
+    context.set_accessibility(Some(Accessibility::new(Role::List)));
+
+    let (drag, set_drag, ..) = use_state!(Option::<SortDrag>::None);
+    // The elapsed time (in seconds) and starting item positions of the current shift animation
+    let (shift, set_shift, ..) = use_state!(Option::<(f32, Vec<f32>)>::None);
+
+    // === Positions === //
+    let target_tops: Vec<f32> = (0..count)
+        .map(|index| match drag {
+            Some(drag) if drag.from == index => drag.to as f32 * item_height,
+            Some(drag) => drag.slot(index) as f32 * item_height,
+            None => index as f32 * item_height,
+        })
+        .collect();
+
+    let has_motion = !context.prefers_reduced_motion();
+    let is_shifting = has_motion
+        && shift
+            .as_ref()
+            .map_or(false, |(elapsed, _)| *elapsed < SHIFT_DURATION);
+    let tick_shift = shift.clone();
+    let tick_set_shift = set_shift.clone();
+    context.on_tick(is_shifting.then(|| {
+        Handler::new(move |delta_time: f32| {
+            if let Some((elapsed, ref from_tops)) = tick_shift {
+                tick_set_shift(Some((elapsed + delta_time, from_tops.clone())));
+            }
+        })
+    }));
+    let tops: Vec<f32> = match shift {
+        Some((elapsed, ref from_tops)) => {
+            let progress = if has_motion {
+                (elapsed / SHIFT_DURATION).min(1.0)
+            } else {
+                1.0
+            };
+            let amount = 1.0 - (1.0 - progress).powi(3);
+            target_tops
+                .iter()
+                .enumerate()
+                .map(|(index, to)| {
+                    let from = from_tops.get(index).copied().unwrap_or(*to);
+                    from + (to - from) * amount
+                })
+                .collect()
+        }
+        None => target_tops,
+    };
+
+    // === Events === //
+    let drag_tops = tops.clone();
+    let drag_handler = OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::MouseDown(data) => {
+            let list_top = match ctx.get_layout(&event.current_target) {
+                Some(layout) => layout.posy,
+                None => return,
+            };
+            let cursor = data.position.1 - list_top;
+            if cursor < 0.0 || count == 0 {
+                return;
+            }
+            let index = ((cursor / item_height) as usize).min(count - 1);
+            ctx.capture_pointer();
+            set_drag(Some(SortDrag {
+                from: index,
+                to: index,
+                grab: cursor - index as f32 * item_height,
+                cursor,
+            }));
+        }
+        EventType::Hover(data) => {
+            let (drag, list_top) = match (drag, ctx.get_layout(&event.current_target)) {
+                (Some(drag), Some(layout)) => (drag, layout.posy),
+                _ => return,
+            };
+            let cursor = data.position.1 - list_top;
+            // The item is dropped in whichever slot its center is over
+            let center = cursor - drag.grab + item_height / 2.0;
+            let to = ((center.max(0.0) / item_height) as usize).min(count.saturating_sub(1));
+            if to != drag.to {
+                set_shift(Some((0.0, drag_tops.clone())));
+            }
+            set_drag(Some(SortDrag { to, cursor, ..drag }));
+        }
+        EventType::MouseUp(..) => {
+            if let Some(drag) = drag {
+                set_drag(None);
+                set_shift(None);
+                if drag.from != drag.to {
+                    if let Some(ref on_reorder) = on_reorder {
+                        on_reorder.call((drag.from, drag.to));
+                    }
+                }
+            }
+        }
+        _ => {}
+    });
+
+    // === Styles === //
+    let theme = use_theme(context).unwrap_or_default();
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                width: StyleProp::Value(Units::Stretch(1.0)),
+                height: StyleProp::Value(Units::Auto),
+                ..Default::default()
+            })
+            .with_style(&props.styles),
+    );
+
+    let list_styles = Style {
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Pixels(count as f32 * item_height)),
+        cursor: StyleProp::Value(if drag.is_some() {
+            CursorIcon::Grabbing
+        } else {
+            CursorIcon::Grab
+        }),
+        ..Default::default()
+    };
+
+    let text_styles = Style {
+        color: StyleProp::Value(theme.text),
+        cursor: StyleProp::Inherit,
+        ..Default::default()
+    };
+
+    // === Overlay === //
+    // The dragged item follows the cursor, kept within the ends of the list
+    let max_top = count.saturating_sub(1) as f32 * item_height;
+    let overlay = drag.and_then(|drag| {
+        let label = items.get(drag.from)?.clone();
+        let top = (drag.cursor - drag.grab).clamp(0.0, max_top);
+        let styles = Style {
+            background_color: StyleProp::Value(theme.overlay),
+            border_color: StyleProp::Value(theme.primary),
+            border: StyleProp::Value(Edge::all(Units::Pixels(1.0))),
+            ..item_styles(&theme, top, item_height)
+        };
+        Some((label, styles))
+    });
+    let is_dragging = overlay.is_some();
+    let (overlay_label, overlay_styles) = overlay.unwrap_or_default();
+
+    // === Render === //
+    let font_size = theme.font_size;
+    let line_height = item_height - ITEM_GAP;
+    rsx! {
+        <Element styles={Some(list_styles)} on_event={Some(drag_handler)}>
+            {VecTracker::from(items.iter().enumerate().map(|(index, item)| {
+                let is_placeholder = drag.map_or(false, |drag| drag.from == index);
+                let styles = if is_placeholder {
+                    Style {
+                        background_color: StyleProp::Value(theme.background),
+                        border_color: StyleProp::Value(theme.border),
+                        border: StyleProp::Value(Edge::all(Units::Pixels(1.0))),
+                        ..item_styles(&theme, tops[index], item_height)
+                    }
+                } else {
+                    item_styles(&theme, tops[index], item_height)
+                };
+                let label = item.clone();
+                let text_styles = text_styles.clone();
+
+                constructor! {
+                    <Background key={item} styles={Some(styles)}>
+                        <If condition={!is_placeholder}>
+                            <Text styles={Some(text_styles)} line_height={Some(line_height)} size={font_size} content={label} />
+                        </If>
+                    </Background>
+                }
+            }))}
+            <If condition={is_dragging}>
+                <Background styles={Some(overlay_styles)}>
+                    <Text styles={Some(text_styles)} line_height={Some(line_height)} size={font_size} content={overlay_label} />
+                </Background>
+            </If>
+        </Element>
+    }
+}
+
+/// Returns the styles for an item displayed at the given position within the list
+fn item_styles(theme: &Theme, top: f32, item_height: f32) -> Style {
+    Style {
+        background_color: StyleProp::Value(theme.surface),
+        border_radius: StyleProp::Value(Corner::all(theme.radius)),
+        cursor: StyleProp::Inherit,
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        left: StyleProp::Value(Units::Pixels(0.0)),
+        top: StyleProp::Value(Units::Pixels(top)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        height: StyleProp::Value(Units::Pixels(item_height - ITEM_GAP)),
+        padding_left: StyleProp::Value(Units::Pixels(theme.spacing_medium)),
+        ..Default::default()
+    }
+}
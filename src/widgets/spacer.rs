@@ -0,0 +1,125 @@
+use crate::core::{
+    render_command::RenderCommand,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, Handler, OnLayout, WidgetProps,
+};
+
+/// Props used by the [`Spacer`] widget
+#[derive(WidgetProps, Debug, PartialEq, Clone)]
+pub struct SpacerProps {
+    /// The minimum size of the spacer along the main axis of its parent
+    ///
+    /// The spacer never shrinks below this size, even if there's no space remaining.
+    pub basis: Option<Units>,
+    /// The share of the remaining space this spacer consumes, relative to other stretched siblings
+    ///
+    /// Defaults to `1.0`.
+    pub grow: f32,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+impl Default for SpacerProps {
+    fn default() -> Self {
+        Self {
+            basis: None,
+            grow: 1.0,
+            styles: None,
+            on_layout: None,
+        }
+    }
+}
+
+#[widget]
+/// A widget that consumes the remaining space along the main axis of its parent
+///
+/// This can be used to push content apart, such as to place items at both ends of a toolbar. In a
+/// [`Row`](LayoutType::Row), the spacer stretches horizontally, while in a
+/// [`Column`](LayoutType::Column) it stretches vertically. It takes up no space along the other
+/// axis. Multiple spacers share the remaining space according to their `grow` factors.
+///
+/// # Props
+///
+/// __Type:__ [`SpacerProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ❌        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, styles::{LayoutType, Style, StyleProp}};
+/// # use kayak_ui::widgets::{Button, Element, Spacer, Text};
+///
+/// let toolbar_styles = Style {
+///     layout_type: StyleProp::Value(LayoutType::Row),
+///     ..Default::default()
+/// };
+///
+/// rsx! {
+///     <Element styles={Some(toolbar_styles)}>
+///         <Text content={"Title".to_string()} size={16.0} />
+///         <Spacer />
+///         <Button>
+///             <Text content={"Close".to_string()} size={16.0} />
+///         </Button>
+///     </Element>
+/// }
+/// ```
+pub fn Spacer(props: SpacerProps) {
+    let grow = Units::Stretch(props.grow.max(0.0));
+    let basis = props
+        .basis
+        .map(StyleProp::Value)
+        .unwrap_or(StyleProp::Default);
+
+    // The main axis is only known once the parent has been laid out at least once
+    let layout_type = context
+        .get_valid_parent(self.get_id())
+        .and_then(|parent| context.get_node(&parent))
+        .map(|parent| parent.resolved_styles.layout_type.resolve_or_default());
+    // Render again on the next frame until the parent's layout type is known
+    let (attempts, set_attempts, ..) = use_state!(0u32);
+    context.on_tick(
+        layout_type
+            .is_none()
+            .then(|| Handler::new(move |_: f32| set_attempts(attempts + 1))),
+    );
+
+    let axis_styles = match layout_type {
+        Some(LayoutType::Row) => Style {
+            width: StyleProp::Value(grow),
+            min_width: basis,
+            height: StyleProp::Value(Units::Pixels(0.0)),
+            ..Default::default()
+        },
+        Some(LayoutType::Column) => Style {
+            height: StyleProp::Value(grow),
+            min_height: basis,
+            width: StyleProp::Value(Units::Pixels(0.0)),
+            ..Default::default()
+        },
+        _ => Style {
+            width: StyleProp::Value(grow),
+            height: StyleProp::Value(grow),
+            ..Default::default()
+        },
+    };
+
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(axis_styles),
+    );
+}
@@ -0,0 +1,119 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Corner, LayoutType, Style, StyleProp, Units},
+    use_state, widget, Color, Handler, OnLayout, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Background};
+
+/// The time it takes for the pulse to travel across all dots (in seconds)
+const PERIOD: f32 = 1.0;
+/// The number of dots in a spinner
+const DOT_COUNT: usize = 3;
+/// The opacity of a dot that isn't pulsing
+const MIN_OPACITY: f32 = 0.3;
+
+/// Props used by the [`Spinner`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct SpinnerProps {
+    /// The height of the spinner (in pixels)
+    ///
+    /// Defaults to the theme's [`font_size`](crate::widgets::Theme::font_size).
+    pub size: Option<f32>,
+    /// The color of the dots
+    ///
+    /// Defaults to the theme's [`text`](crate::widgets::Theme::text) color.
+    pub color: Option<Color>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A widget that indicates that something is in progress
+///
+/// This is drawn as a row of dots that pulse one after another. The spinner is twice as wide as
+/// its [`size`](SpinnerProps::size) and is advanced on every
+/// [tick](crate::core::KayakContextRef::on_tick) while it's mounted.
+///
+/// # Props
+///
+/// __Type:__ [`SpinnerProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ❌        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::Spinner;
+///
+/// rsx! {
+///     <Spinner size={Some(24.0)} />
+/// }
+/// ```
+pub fn Spinner(props: SpinnerProps) {
+    let theme = use_theme(context).unwrap_or_default();
+    let size = props.size.unwrap_or(theme.font_size).max(0.0);
+    let color = props.color.unwrap_or(theme.text);
+
+    let (elapsed, set_elapsed, ..) = use_state!(0.0);
+    context.on_tick(Some(Handler::new(move |delta_time: f32| {
+        set_elapsed(elapsed + delta_time)
+    })));
+
+    let dot_size = size * 0.5;
+    let gap = size * 0.25;
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                col_between: StyleProp::Value(Units::Pixels(gap)),
+                height: StyleProp::Value(Units::Pixels(size)),
+                layout_type: StyleProp::Value(LayoutType::Row),
+                width: StyleProp::Value(Units::Pixels(
+                    dot_size * DOT_COUNT as f32 + gap * (DOT_COUNT - 1) as f32,
+                )),
+                ..Default::default()
+            }),
+    );
+
+    let dot_styles = |index: usize| {
+        // Each dot lags behind the previous one, so the pulse appears to travel along the row
+        let phase = (elapsed / PERIOD - index as f32 / DOT_COUNT as f32).rem_euclid(1.0);
+        let pulse = (phase * std::f32::consts::TAU).cos() * 0.5 + 0.5;
+        Style {
+            background_color: StyleProp::Value(color),
+            border_radius: StyleProp::Value(Corner::all(dot_size / 2.0)),
+            bottom: StyleProp::Value(Units::Stretch(1.0)),
+            height: StyleProp::Value(Units::Pixels(dot_size)),
+            opacity: StyleProp::Value(MIN_OPACITY + (1.0 - MIN_OPACITY) * pulse),
+            top: StyleProp::Value(Units::Stretch(1.0)),
+            width: StyleProp::Value(Units::Pixels(dot_size)),
+            ..Default::default()
+        }
+    };
+    let first_styles = dot_styles(0);
+    let second_styles = dot_styles(1);
+    let third_styles = dot_styles(2);
+
+    rsx! {
+        <>
+            <Background styles={Some(first_styles)} />
+            <Background styles={Some(second_styles)} />
+            <Background styles={Some(third_styles)} />
+        </>
+    }
+}
@@ -0,0 +1,73 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp},
+    widget, Children, OnEvent, OnLayout, WidgetProps,
+};
+
+/// Props used by the [`Static`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct StaticProps {
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A widget that caches the layout of its children, for content that rarely changes
+///
+/// The children are laid out once, and that layout is reused until one of them renders with
+/// different styles, content, or children, or until the size of this widget changes. Moving this
+/// widget moves the cached layout along with it. Wrapping large, mostly-static regions (such as
+/// toolbars, sidebars, and other chrome) in this widget saves laying them out whenever something
+/// else in the app changes.
+///
+/// See [`KayakContextRef::set_static`](crate::core::KayakContextRef::set_static) for the details.
+///
+/// # Props
+///
+/// __Type:__ [`StaticProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ✅        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{Static, Text};
+///
+/// rsx! {
+///     <Static>
+///         <Text content={"This layout is only calculated once".to_string()} size={16.0} />
+///     </Static>
+/// }
+/// ```
+pub fn Static(props: StaticProps) {
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles),
+    );
+
+    context.set_static(true);
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
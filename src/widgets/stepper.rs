@@ -0,0 +1,190 @@
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{Corner, LayoutType, Style, StyleProp, Units},
+    widget, Children, CursorIcon, EventType, OnChange, OnEvent, OnLayout, VecTracker, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Background, Element, If, Text};
+
+/// The size of each step indicator (in pixels)
+const INDICATOR_SIZE: f32 = 24.0;
+
+/// Props used by the [`Stepper`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct StepperProps {
+    /// The zero-based index of the active step
+    ///
+    /// All steps before this one are considered completed.
+    pub active: usize,
+    /// A callback for when the user clicks a completed step
+    ///
+    /// The handler is given the zero-based index of the clicked step. If `None`, steps
+    /// will not be clickable.
+    pub on_step_change: Option<OnChange<usize>>,
+    /// The labels of each step, in order
+    pub steps: Vec<String>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A widget that displays the progress through a multi-step flow
+///
+/// Each step is shown as a numbered indicator, connected to the previous step by a line.
+/// Completed and active steps (and the lines leading to them) are highlighted.
+///
+/// # Props
+///
+/// __Type:__ [`StepperProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, use_state, OnChange};
+/// # use kayak_ui::widgets::Stepper;
+///
+/// let (active, set_active, ..) = use_state!(2);
+/// let on_step_change = OnChange::new(move |step| {
+///     set_active(step);
+/// });
+///
+/// let steps = vec!["Account".to_string(), "Profile".to_string(), "Confirm".to_string()];
+///
+/// rsx! {
+///     <Stepper steps={steps} active={active} on_step_change={Some(on_step_change)} />
+/// }
+/// ```
+pub fn Stepper(props: StepperProps) {
+    let StepperProps {
+        active,
+        on_step_change,
+        steps,
+        ..
+    } = props.clone();
+
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                col_between: StyleProp::Value(Units::Pixels(8.0)),
+                height: StyleProp::Value(Units::Pixels(INDICATOR_SIZE)),
+                layout_type: StyleProp::Value(LayoutType::Row),
+                ..Default::default()
+            }),
+    );
+
+    let theme = use_theme(context).unwrap_or_default();
+
+    rsx! {
+        <>
+            {VecTracker::from(steps.clone().into_iter().enumerate().map(|(index, label)| {
+                let is_highlighted = index <= active;
+                let is_clickable = index < active && on_step_change.is_some();
+                let (indicator_color, connector_color, number_color) = if is_highlighted {
+                    (theme.primary, theme.primary, theme.on_primary)
+                } else {
+                    (theme.surface, theme.border, theme.text)
+                };
+
+                let on_step_change = on_step_change.clone();
+                let on_event = if is_clickable {
+                    Some(OnEvent::new(move |_, event| match event.event_type {
+                        EventType::Click(..) => {
+                            if let Some(ref on_step_change) = on_step_change {
+                                on_step_change.call(index);
+                            }
+                        }
+                        _ => {}
+                    }))
+                } else {
+                    None
+                };
+
+                let has_connector = index > 0;
+                let step_styles = Style {
+                    col_between: StyleProp::Value(Units::Pixels(8.0)),
+                    cursor: if is_clickable {
+                        CursorIcon::Hand.into()
+                    } else {
+                        StyleProp::Inherit
+                    },
+                    layout_type: StyleProp::Value(LayoutType::Row),
+                    width: if has_connector {
+                        StyleProp::Value(Units::Stretch(1.0))
+                    } else {
+                        StyleProp::Value(Units::Auto)
+                    },
+                    ..Default::default()
+                };
+                let connector_styles = Style {
+                    background_color: StyleProp::Value(connector_color),
+                    bottom: StyleProp::Value(Units::Stretch(1.0)),
+                    height: StyleProp::Value(Units::Pixels(2.0)),
+                    top: StyleProp::Value(Units::Stretch(1.0)),
+                    width: StyleProp::Value(Units::Stretch(1.0)),
+                    ..Default::default()
+                };
+                let indicator_styles = Style {
+                    background_color: StyleProp::Value(indicator_color),
+                    border_radius: StyleProp::Value(Corner::all(INDICATOR_SIZE / 2.0)),
+                    height: StyleProp::Value(Units::Pixels(INDICATOR_SIZE)),
+                    width: StyleProp::Value(Units::Pixels(INDICATOR_SIZE)),
+                    ..Default::default()
+                };
+                let number_styles = Style {
+                    color: StyleProp::Value(number_color),
+                    top: StyleProp::Value(Units::Stretch(1.0)),
+                    right: StyleProp::Value(Units::Stretch(1.0)),
+                    bottom: StyleProp::Value(Units::Stretch(1.0)),
+                    left: StyleProp::Value(Units::Stretch(1.0)),
+                    ..Default::default()
+                };
+                let label_styles = Style {
+                    color: StyleProp::Value(if is_highlighted {
+                        theme.text
+                    } else {
+                        theme.text_muted
+                    }),
+                    top: StyleProp::Value(Units::Stretch(1.0)),
+                    bottom: StyleProp::Value(Units::Stretch(1.0)),
+                    ..Default::default()
+                };
+                let number = (index + 1).to_string();
+
+                constructor! {
+                    <Element styles={Some(step_styles)} on_event={on_event}>
+                        <If condition={has_connector}>
+                            <Background styles={Some(connector_styles)} />
+                        </If>
+                        <Background styles={Some(indicator_styles)}>
+                            <Text content={number} size={14.0} styles={Some(number_styles)} />
+                        </Background>
+                        <Text content={label} size={14.0} styles={Some(label_styles)} />
+                    </Element>
+                }
+            }))}
+        </>
+    }
+}
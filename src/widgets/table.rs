@@ -0,0 +1,410 @@
+use std::ops::Range;
+
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{LayoutType, Style, StyleProp, Units},
+    use_state, widget, Children, Color, CursorIcon, EventType, OnChange, OnEvent, OnLayout,
+    VecTracker, WidgetProps,
+};
+
+use crate::widgets::{use_theme, Background, Element, If, ScrollBox, ScrollContext, Text};
+
+/// The default height of each row (in pixels)
+const DEFAULT_ROW_HEIGHT: f32 = 28.0;
+/// The horizontal padding within each cell (in pixels)
+const CELL_PADDING: f32 = 8.0;
+
+/// The definition of a single column in a [`Table`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    /// The text displayed in the header of this column
+    pub header: String,
+    /// If true, clicking this column's header will fire the table's sort callback
+    pub sortable: bool,
+    /// The width of this column
+    ///
+    /// This is applied to the header and every cell in the column, keeping them aligned.
+    pub width: Units,
+}
+
+impl Column {
+    /// Creates a new, non-sortable column
+    pub fn new<S: Into<String>>(header: S, width: Units) -> Self {
+        Self {
+            header: header.into(),
+            sortable: false,
+            width,
+        }
+    }
+
+    /// Makes this column sortable
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+}
+
+/// The direction a [`Table`] column is sorted in
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// Returns the opposite direction
+    pub fn reversed(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+}
+
+/// Props used by the [`Table`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct TableProps {
+    /// The columns of the table, in order
+    pub columns: Vec<Column>,
+    /// A callback for when the user clicks a row
+    ///
+    /// The handler is given the zero-based index of the clicked row.
+    pub on_row_click: Option<OnChange<usize>>,
+    /// A callback for when the user clicks the header of a sortable column
+    ///
+    /// The handler is given the zero-based index of the column and the requested direction.
+    /// The table does not reorder its rows itself: it's up to the handler to sort the data
+    /// and pass the sorted rows back in.
+    pub on_sort: Option<OnChange<(usize, SortDirection)>>,
+    /// The height of each row (in pixels)
+    ///
+    /// Defaults to 28px.
+    pub row_height: Option<f32>,
+    /// The rows of the table, with one cell per column
+    pub rows: Vec<Vec<String>>,
+    /// If true, alternating rows are given a different background color
+    pub striped: bool,
+    /// If true, only the rows currently scrolled into view will be rendered
+    ///
+    /// This is recommended for large tables. The table body is placed in a [`ScrollBox`],
+    /// so the table should be given a fixed or stretched height.
+    pub virtualize: bool,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+/// Props used by the [`TableBody`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+struct TableBodyProps {
+    pub columns: Vec<Column>,
+    pub on_row_click: Option<OnChange<usize>>,
+    pub row_height: f32,
+    pub rows: Vec<Vec<String>>,
+    pub striped: bool,
+    pub virtualize: bool,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+    #[prop_field(Focusable)]
+    pub focusable: Option<bool>,
+}
+
+#[widget]
+/// A widget that displays data in rows and aligned columns
+///
+/// # Props
+///
+/// __Type:__ [`TableProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ❌        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ✅        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, styles::Units};
+/// # use kayak_ui::widgets::{Column, Table};
+///
+/// let columns = vec![
+///     Column::new("Name", Units::Stretch(1.0)).sortable(),
+///     Column::new("Level", Units::Pixels(80.0)),
+/// ];
+/// let rows = vec![
+///     vec!["Alice".to_string(), "12".to_string()],
+///     vec!["Bob".to_string(), "7".to_string()],
+/// ];
+///
+/// rsx! {
+///     <Table columns={columns} rows={rows} striped={true} />
+/// }
+/// ```
+pub fn Table(props: TableProps) {
+    let TableProps {
+        columns,
+        on_row_click,
+        on_sort,
+        row_height,
+        rows,
+        striped,
+        virtualize,
+        ..
+    } = props.clone();
+    let row_height = row_height.unwrap_or(DEFAULT_ROW_HEIGHT);
+
+    // === State === //
+    let (sort, set_sort, ..) = use_state!(Option::<(usize, SortDirection)>::None);
+
+    // === Styles === //
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                height: StyleProp::Value(if virtualize {
+                    Units::Stretch(1.0)
+                } else {
+                    Units::Auto
+                }),
+                ..Default::default()
+            }),
+    );
+
+    let theme = use_theme(context).unwrap_or_default();
+    let header_styles = Style {
+        background_color: StyleProp::Value(theme.surface),
+        color: StyleProp::Value(theme.text),
+        height: StyleProp::Value(Units::Pixels(row_height)),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        ..Default::default()
+    };
+
+    // === Render === //
+    rsx! {
+        <>
+            <Background styles={Some(header_styles)}>
+                {VecTracker::from(columns.clone().into_iter().enumerate().map(|(index, column)| {
+                    let direction = match sort {
+                        Some((sorted, direction)) if sorted == index => Some(direction),
+                        _ => None,
+                    };
+                    let on_event = if column.sortable {
+                        let set_sort = set_sort.clone();
+                        let on_sort = on_sort.clone();
+                        Some(OnEvent::new(move |_, event| match event.event_type {
+                            EventType::Click(..) => {
+                                let direction = direction
+                                    .map(SortDirection::reversed)
+                                    .unwrap_or(SortDirection::Ascending);
+                                set_sort(Some((index, direction)));
+                                if let Some(ref on_sort) = on_sort {
+                                    on_sort.call((index, direction));
+                                }
+                            }
+                            _ => {}
+                        }))
+                    } else {
+                        None
+                    };
+
+                    let content = match direction {
+                        Some(SortDirection::Ascending) => format!("{} ^", column.header),
+                        Some(SortDirection::Descending) => format!("{} v", column.header),
+                        None => column.header,
+                    };
+                    let cell_styles = Style {
+                        cursor: if column.sortable {
+                            CursorIcon::Hand.into()
+                        } else {
+                            StyleProp::Inherit
+                        },
+                        ..cell_styles(column.width)
+                    };
+
+                    constructor! {
+                        <Element styles={Some(cell_styles)} on_event={on_event}>
+                            <Text content={content} size={14.0} styles={Some(text_styles())} />
+                        </Element>
+                    }
+                }))}
+            </Background>
+            <If condition={virtualize}>
+                <ScrollBox>
+                    <TableBody
+                        columns={columns}
+                        on_row_click={on_row_click}
+                        row_height={row_height}
+                        rows={rows}
+                        striped={striped}
+                        virtualize={true}
+                    />
+                </ScrollBox>
+            </If>
+            <If condition={!virtualize}>
+                <TableBody
+                    columns={columns}
+                    on_row_click={on_row_click}
+                    row_height={row_height}
+                    rows={rows}
+                    striped={striped}
+                    virtualize={false}
+                />
+            </If>
+        </>
+    }
+}
+
+#[widget]
+/// The body rows of a [`Table`]
+///
+/// When virtualized, this widget must be placed within a [`ScrollBox`] so that it can determine
+/// which rows are currently in view.
+fn TableBody(props: TableBodyProps) {
+    let TableBodyProps {
+        columns,
+        on_row_click,
+        row_height,
+        rows,
+        striped,
+        virtualize,
+        ..
+    } = props.clone();
+
+    let visible = if virtualize {
+        let scroll = context
+            .create_consumer::<ScrollContext>()
+            .map(|scroll_ctx| scroll_ctx.get())
+            .unwrap_or_default();
+        visible_rows(&scroll, rows.len(), row_height)
+    } else {
+        0..rows.len()
+    };
+
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles)
+            .with_style(Style {
+                height: StyleProp::Value(Units::Auto),
+                ..Default::default()
+            }),
+    );
+
+    let theme = use_theme(context).unwrap_or_default();
+
+    // Spacers stand in for the rows that aren't rendered so the scrollable height stays correct
+    let before_styles = spacer_styles(visible.start as f32 * row_height);
+    let after_styles = spacer_styles((rows.len() - visible.end) as f32 * row_height);
+
+    rsx! {
+        <>
+            <Element styles={Some(before_styles)} />
+            {VecTracker::from(rows[visible.clone()].to_vec().into_iter().zip(visible.clone()).map(|(row, index)| {
+                let on_event = on_row_click.clone().map(|on_row_click| {
+                    OnEvent::new(move |_, event| match event.event_type {
+                        EventType::Click(..) => {
+                            on_row_click.call(index);
+                        }
+                        _ => {}
+                    })
+                });
+
+                let row_styles = Style {
+                    background_color: StyleProp::Value(if striped && index % 2 == 1 {
+                        theme.input
+                    } else {
+                        Color::TRANSPARENT
+                    }),
+                    color: StyleProp::Value(theme.text),
+                    cursor: if on_event.is_some() {
+                        CursorIcon::Hand.into()
+                    } else {
+                        StyleProp::Inherit
+                    },
+                    height: StyleProp::Value(Units::Pixels(row_height)),
+                    layout_type: StyleProp::Value(LayoutType::Row),
+                    ..Default::default()
+                };
+                let row_columns = columns.clone();
+
+                constructor! {
+                    <Background styles={Some(row_styles)} on_event={on_event}>
+                        {VecTracker::from(row.clone().into_iter().zip(row_columns.clone()).map(|(cell, column)| {
+                            let cell_styles = cell_styles(column.width);
+                            constructor! {
+                                <Element styles={Some(cell_styles)}>
+                                    <Text content={cell} size={14.0} styles={Some(text_styles())} />
+                                </Element>
+                            }
+                        }))}
+                    </Background>
+                }
+            }))}
+            <Element styles={Some(after_styles)} />
+        </>
+    }
+}
+
+/// Returns the range of rows visible within the given scroll area
+///
+/// An extra row is included on either side so that partially visible rows are still rendered.
+fn visible_rows(scroll: &ScrollContext, total: usize, row_height: f32) -> Range<usize> {
+    let row_height = row_height.max(1.0);
+    let offset = -scroll.scroll_y();
+    let start = ((offset / row_height).floor().max(0.0) as usize).min(total);
+    let count = (scroll.scrollbox_height() / row_height).ceil() as usize + 2;
+    start.saturating_sub(1)..(start + count).min(total)
+}
+
+/// Returns the styles for a header or body cell in the given column
+fn cell_styles(width: Units) -> Style {
+    Style {
+        height: StyleProp::Value(Units::Stretch(1.0)),
+        padding_left: StyleProp::Value(Units::Pixels(CELL_PADDING)),
+        padding_right: StyleProp::Value(Units::Pixels(CELL_PADDING)),
+        width: StyleProp::Value(width),
+        ..Default::default()
+    }
+}
+
+/// Returns the styles for the text within a cell
+fn text_styles() -> Style {
+    Style {
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        ..Default::default()
+    }
+}
+
+/// Returns the styles for an empty element of the given height
+fn spacer_styles(height: f32) -> Style {
+    Style {
+        height: StyleProp::Value(Units::Pixels(height)),
+        ..Default::default()
+    }
+}
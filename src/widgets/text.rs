@@ -1,7 +1,9 @@
+use std::ops::Range;
+
 use crate::core::{
     render_command::RenderCommand,
     styles::{Style, StyleProp},
-    widget, CursorIcon, OnEvent, OnLayout, WidgetProps,
+    widget, Accessibility, Color, CursorIcon, OnEvent, OnLayout, Role, WidgetProps,
 };
 
 /// Props used by the [`Text`] widget
@@ -13,8 +15,18 @@ pub struct TextProps {
     ///
     /// The given font must already be loaded into the [`KayakContext`](kayak_core::KayakContext)
     pub font: Option<String>,
+    /// The ranges of chars (not bytes) to draw a background color behind, like a marker pen
+    ///
+    /// A range that wraps onto multiple lines is highlighted on each of those lines. Overlapping
+    /// ranges are drawn in order, so later ones appear on top.
+    pub highlights: Vec<(Range<usize>, Color)>,
     /// The height of a line of text (currently in pixels)
     pub line_height: Option<f32>,
+    /// Whether to apply kerning and common ligatures (such as "fi")
+    ///
+    /// Set this to `Some(false)` for monospace or code text. This _will_ override the
+    /// `font_shaping` style.
+    pub shaping: Option<bool>,
     /// If true, displays the default text cursor when hovered.
     ///
     /// This _will_ override the `cursor` style.
@@ -38,7 +50,9 @@ impl Default for TextProps {
         Self {
             content: String::new(),
             font: None,
+            highlights: Vec::new(),
             line_height: None,
+            shaping: None,
             show_cursor: false,
             size: -1.0,
             styles: None,
@@ -52,6 +66,13 @@ impl Default for TextProps {
 #[widget]
 /// A widget that renders plain text
 ///
+/// Text with a [`direction`](Style::direction) of [`Direction::Rtl`](crate::core::styles::Direction::Rtl)
+/// is aligned to the right. This only affects alignment: characters are still laid out from left to
+/// right.
+///
+/// Parts of the text can be marked using [`highlights`](TextProps::highlights), such as to show
+/// the matches of a search.
+///
 /// # Props
 ///
 /// __Type:__ [`TextProps`]
@@ -64,10 +85,23 @@ impl Default for TextProps {
 /// | `on_layout` | ✅        |
 /// | `focusable` | ✅        |
 ///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, Color};
+/// # use kayak_ui::widgets::Text;
+///
+/// let highlights = vec![(6..11, Color::new(1.0, 0.85, 0.2, 0.5))];
+///
+/// rsx! {
+///     <Text content={"Hello world!".to_string()} size={16.0} highlights={highlights} />
+/// }
+/// ```
 pub fn Text(props: TextProps) {
     let mut styles = Style {
         render_command: StyleProp::Value(RenderCommand::Text {
             content: props.content.clone(),
+            highlights: props.highlights.clone(),
         }),
         ..Default::default()
     };
@@ -84,6 +118,12 @@ pub fn Text(props: TextProps) {
     if let Some(line_height) = props.line_height {
         styles.line_height = StyleProp::Value(line_height);
     }
+    if let Some(shaping) = props.shaping {
+        styles.font_shaping = StyleProp::Value(shaping);
+    }
 
     props.styles = Some(styles.with_style(&props.styles));
+    context.set_accessibility(Some(
+        Accessibility::new(Role::Label).with_name(props.content.clone()),
+    ));
 }
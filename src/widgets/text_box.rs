@@ -1,23 +1,45 @@
 use crate::core::{
+    next_word_boundary, prev_word_boundary,
     render_command::RenderCommand,
     rsx,
-    styles::{Corner, Style, Units},
-    widget, Bound, Children, Color, EventType, MutableBound, OnEvent, WidgetProps,
+    styles::{Corner, PositionType, Style, StyleProp, Units},
+    widget, Accessibility, Bound, Children, Color, EventType, Handler, KeyCode, MutableBound,
+    OnChange, OnEvent, Role, TextProperties, WidgetProps, DEFAULT_FONT,
 };
 use kayak_core::{CursorIcon, OnLayout};
-use std::sync::{Arc, RwLock};
 
-use crate::widgets::{Background, Clip, Text};
+use crate::widgets::{use_theme, Background, Clip, Text};
+
+/// The default time the caret stays visible (or hidden) while blinking (in seconds)
+const DEFAULT_BLINK_INTERVAL: f32 = 0.53;
+/// The font size of the text (in pixels)
+const FONT_SIZE: f32 = 14.0;
+/// The line height of the text (in pixels)
+const LINE_HEIGHT: f32 = 22.0;
+/// The horizontal padding between the edge of the field and the text (in pixels)
+const PADDING: f32 = 5.0;
+/// The default maximum number of edits that can be undone
+const DEFAULT_UNDO_DEPTH: usize = 100;
 
 /// Props used by the [`TextBox`] widget
 #[derive(Default, Debug, PartialEq, Clone)]
 pub struct TextBoxProps {
     /// If true, prevents the widget from being focused (and consequently edited)
+    ///
+    /// Text boxes within a [`Disabled`](crate::widgets::Disabled) widget are also disabled.
     pub disabled: bool,
+    /// The time the caret stays visible (or hidden) while blinking (in seconds)
+    ///
+    /// Defaults to 0.53 seconds. A value of `0.0` keeps the caret solid.
+    pub caret_blink_interval: Option<f32>,
     /// A callback for when the text value was changed
-    pub on_change: Option<OnChange>,
+    pub on_change: Option<OnChange<ChangeEvent>>,
     /// The text to display when the user input is empty
     pub placeholder: Option<String>,
+    /// The maximum number of edits that can be undone
+    ///
+    /// Defaults to 100.
+    pub undo_depth: Option<usize>,
     /// The user input
     ///
     /// This is a controlled state. You _must_ set this to the value to you wish to be displayed.
@@ -61,33 +83,108 @@ pub struct ChangeEvent {
     pub value: String,
 }
 
-#[derive(Clone)]
-pub struct OnChange(pub Arc<RwLock<dyn FnMut(ChangeEvent) + Send + Sync + 'static>>);
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Focus(pub bool);
 
-impl OnChange {
-    pub fn new<F: FnMut(ChangeEvent) + Send + Sync + 'static>(f: F) -> OnChange {
-        OnChange(Arc::new(RwLock::new(f)))
-    }
+/// The position of the caret within a [`TextBox`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Caret {
+    /// The char index the caret is placed before
+    index: usize,
+    /// Whether the caret is shown during the current blink, which it always is once moved
+    visible: bool,
 }
 
-impl PartialEq for OnChange {
-    fn eq(&self, _other: &Self) -> bool {
-        true
+impl Caret {
+    fn new(index: usize) -> Self {
+        Self {
+            index,
+            visible: true,
+        }
     }
 }
 
-impl std::fmt::Debug for OnChange {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("OnChange").finish()
-    }
+/// The value and caret position of a [`TextBox`] before or after an edit
+#[derive(Debug, Clone, PartialEq)]
+struct EditSnapshot {
+    value: String,
+    caret: usize,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Focus(pub bool);
+/// The undo and redo stacks of a [`TextBox`]
+#[derive(Debug, Default, Clone, PartialEq)]
+struct EditHistory {
+    undo: Vec<EditSnapshot>,
+    redo: Vec<EditSnapshot>,
+    /// Whether the last edit was typing a char, which the next typed char is merged into
+    is_typing: bool,
+}
+
+impl EditHistory {
+    /// Records an edit, given the state from before it was made
+    ///
+    /// Consecutive typed chars are coalesced into a single step. Only the newest `max_depth` steps
+    /// are kept.
+    fn record(&mut self, before: EditSnapshot, is_typing: bool, max_depth: usize) {
+        if !(is_typing && self.is_typing) {
+            self.undo.push(before);
+            if self.undo.len() > max_depth {
+                self.undo.drain(..self.undo.len() - max_depth);
+            }
+        }
+        self.is_typing = is_typing;
+        self.redo.clear();
+    }
+
+    /// Prevents the next typed char from being merged into the current step
+    fn end_typing(&mut self) {
+        self.is_typing = false;
+    }
+
+    /// Returns the state to restore when undoing, given the current state
+    fn undo(&mut self, current: EditSnapshot) -> Option<EditSnapshot> {
+        let previous = self.undo.pop()?;
+        self.redo.push(current);
+        self.is_typing = false;
+        Some(previous)
+    }
+
+    /// Returns the state to restore when redoing, given the current state
+    fn redo(&mut self, current: EditSnapshot) -> Option<EditSnapshot> {
+        let next = self.redo.pop()?;
+        self.undo.push(current);
+        self.is_typing = false;
+        Some(next)
+    }
+}
 
 #[widget]
 /// A widget that displays a text input field
 ///
+/// While focused, a blinking caret marks where typed text is inserted. The caret can be moved
+/// with the arrow keys or by clicking within the text, and stays solid while typing.
+///
+/// # Keyboard
+///
+/// | Key                              | Action                                       |
+/// | :------------------------------- | :------------------------------------------- |
+/// | `Left` / `Right`                 | Moves the caret by one char                  |
+/// | `Ctrl+Left` / `Ctrl+Right`       | Moves the caret to the previous/next word    |
+/// | `Home` / `End`                   | Moves the caret to the start/end of the line |
+/// | `Backspace` / `Delete`           | Removes the char before/after the caret      |
+/// | `Ctrl+Backspace` / `Ctrl+Delete` | Removes the word before/after the caret      |
+/// | `Ctrl+Z`                         | Undoes the last edit                         |
+/// | `Ctrl+Y` / `Ctrl+Shift+Z`        | Redoes the last undone edit                  |
+/// | `Ctrl+C`                         | Copies the value to the clipboard            |
+/// | `Ctrl+V`                         | Pastes the clipboard text at the caret       |
+///
+/// Consecutively typed chars are undone together, and undoing also restores the caret position.
+/// Copying and pasting use the [clipboard](crate::core::KayakContextRef::clipboard) provided by
+/// the integration, and do nothing if there isn't one. Pasted text is inserted on a single line.
+///
+/// Words are found using [`next_word_boundary`](crate::core::next_word_boundary), which splits
+/// the text wherever it changes between whitespace, punctuation, and letters or digits.
+///
 /// # Props
 ///
 /// __Type:__ [`TextBoxProps`]
@@ -102,12 +199,18 @@ pub struct Focus(pub bool);
 ///
 pub fn TextBox(props: TextBoxProps) {
     let TextBoxProps {
+        caret_blink_interval,
         on_change,
         placeholder,
+        undo_depth,
         value,
         ..
     } = props.clone();
+    let blink_interval = caret_blink_interval
+        .unwrap_or(DEFAULT_BLINK_INTERVAL)
+        .max(0.0);
 
+    let theme = use_theme(context).unwrap_or_default();
     props.styles = Some(
         Style::default()
             // Required styles
@@ -128,52 +231,241 @@ pub fn TextBox(props: TextBoxProps) {
     );
 
     let background_styles = Style {
-        background_color: Color::new(0.176, 0.196, 0.215, 1.0).into(),
-        border_radius: Corner::all(5.0).into(),
+        background_color: theme.input.into(),
+        border_radius: Corner::all(theme.radius).into(),
         height: Units::Pixels(26.0).into(),
-        padding_left: Units::Pixels(5.0).into(),
-        padding_right: Units::Pixels(5.0).into(),
+        padding_left: Units::Pixels(PADDING).into(),
+        padding_right: Units::Pixels(PADDING).into(),
         ..Default::default()
     };
 
+    context.set_disabled(props.disabled);
+    let accessibility = Accessibility::new(Role::TextBox).with_value(value.clone());
+    context.set_accessibility(Some(match placeholder {
+        Some(ref placeholder) => accessibility.with_name(placeholder.clone()),
+        None => accessibility,
+    }));
+
     let has_focus = context.create_state(Focus(false)).unwrap();
+    // Typed keys should reach this widget instead of triggering shortcuts
+    context.capture_keys(true);
 
+    let char_count = value.chars().count();
+    let caret = context.create_state(Caret::new(char_count)).unwrap();
+    let caret_index = caret.get().index.min(char_count);
+    let history = context.create_state(EditHistory::default()).unwrap();
+    let undo_depth = undo_depth.unwrap_or(DEFAULT_UNDO_DEPTH);
+
+    // === Caret Layout === //
+    // The text may inherit its font, which is only resolved once this widget has been rendered
+    let resolved_styles = context
+        .get_node(&self.get_id())
+        .map(|node| node.resolved_styles)
+        .unwrap_or_default();
+    let font = match props.styles.as_ref().map(|styles| &styles.font) {
+        Some(StyleProp::Value(font)) => font.clone(),
+        _ => match resolved_styles.font {
+            StyleProp::Value(font) => font,
+            _ => DEFAULT_FONT.to_string(),
+        },
+    };
+    let shaping = match props.styles.as_ref().map(|styles| &styles.font_shaping) {
+        Some(StyleProp::Value(shaping)) => *shaping,
+        _ => match resolved_styles.font_shaping {
+            StyleProp::Value(shaping) => shaping,
+            _ => true,
+        },
+    };
+    let measurement = context
+        .measure_text(
+            &value,
+            &font,
+            TextProperties {
+                font_size: FONT_SIZE,
+                line_height: LINE_HEIGHT,
+                shaping,
+                ..Default::default()
+            },
+        )
+        .unwrap_or_default();
+    let (caret_x, _) = measurement.caret_position(caret_index);
+
+    let is_focused = has_focus.get().0 && !props.disabled;
+    let is_caret_visible = if is_focused && blink_interval > 0.0 {
+        // Every render restarts the delay, so the caret stays solid while the user is typing
+        let blink_caret = caret.clone();
+        context.set_timeout(
+            "caret_blink",
+            blink_interval,
+            Handler::new(move |_| {
+                let current = blink_caret.get();
+                blink_caret.set(Caret {
+                    visible: !current.visible,
+                    ..current
+                });
+            }),
+        );
+        caret.get().visible
+    } else {
+        context.clear_timeout("caret_blink");
+        is_focused
+    };
+
+    // === Events === //
     let mut current_value = value.clone();
     let cloned_on_change = on_change.clone();
     let cloned_has_focus = has_focus.clone();
+    let cloned_caret = caret.clone();
+    let cloned_history = history.clone();
+
+    props.on_event = Some(OnEvent::new(move |ctx, event| {
+        let char_count = current_value.chars().count();
+        let index = cloned_caret.get().index.min(char_count);
+        let before = EditSnapshot {
+            value: current_value.clone(),
+            caret: index,
+        };
+        let mut history = cloned_history.get();
+
+        match event.event_type {
+            EventType::CharInput { c } => {
+                if !cloned_has_focus.get().0 {
+                    return;
+                }
+                if is_backspace(c) {
+                    // Chars don't include the modifiers, so they're read from the context instead
+                    let start = if ctx.keyboard_modifiers().is_ctrl_pressed {
+                        prev_word_boundary(&current_value, index)
+                    } else {
+                        index.saturating_sub(1)
+                    };
+                    if start < index {
+                        remove_chars(&mut current_value, start..index);
+                        history.record(before.clone(), false, undo_depth);
+                        cloned_caret.set(Caret::new(start));
+                    }
+                } else if !c.is_control() {
+                    current_value.insert(byte_index(&current_value, index), c);
+                    history.record(before.clone(), true, undo_depth);
+                    cloned_caret.set(Caret::new(index + 1));
+                }
+            }
+            EventType::KeyDown(evt) => {
+                let is_ctrl_pressed = evt.is_ctrl_pressed();
+                let restored = match evt.key() {
+                    KeyCode::Z if is_ctrl_pressed && evt.is_shift_pressed() => {
+                        history.redo(before.clone())
+                    }
+                    KeyCode::Z if is_ctrl_pressed => history.undo(before.clone()),
+                    KeyCode::Y if is_ctrl_pressed => history.redo(before.clone()),
+                    KeyCode::C if is_ctrl_pressed => {
+                        // The whole value is copied, since there's no selection
+                        let _ = ctx.clipboard().set_text(&current_value);
+                        None
+                    }
+                    KeyCode::V if is_ctrl_pressed => {
+                        if let Ok(text) = ctx.clipboard().get_text() {
+                            let text = text.chars().filter(|c| !c.is_control()).collect::<String>();
+                            if !text.is_empty() {
+                                current_value.insert_str(byte_index(&current_value, index), &text);
+                                history.record(before.clone(), false, undo_depth);
+                                cloned_caret.set(Caret::new(index + text.chars().count()));
+                            }
+                        }
+                        None
+                    }
+                    KeyCode::Delete => {
+                        let end = if is_ctrl_pressed {
+                            next_word_boundary(&current_value, index)
+                        } else {
+                            (index + 1).min(char_count)
+                        };
+                        if end > index {
+                            remove_chars(&mut current_value, index..end);
+                            history.record(before.clone(), false, undo_depth);
+                            cloned_caret.set(Caret::new(index));
+                        }
+                        None
+                    }
+                    key => {
+                        let next_index = match key {
+                            KeyCode::Left if is_ctrl_pressed => {
+                                prev_word_boundary(&current_value, index)
+                            }
+                            KeyCode::Left => index.saturating_sub(1),
+                            KeyCode::Right if is_ctrl_pressed => {
+                                next_word_boundary(&current_value, index)
+                            }
+                            KeyCode::Right => (index + 1).min(char_count),
+                            // The field is a single line, so that line spans the whole value
+                            KeyCode::Home => 0,
+                            KeyCode::End => char_count,
+                            _ => return,
+                        };
+                        history.end_typing();
+                        cloned_caret.set(Caret::new(next_index));
+                        None
+                    }
+                };
 
-    props.on_event = Some(OnEvent::new(move |_, event| match event.event_type {
-        EventType::CharInput { c } => {
-            if !cloned_has_focus.get().0 {
-                return;
+                if let Some(restored) = restored {
+                    current_value = restored.value;
+                    cloned_caret.set(Caret::new(restored.caret));
+                }
             }
-            if is_backspace(c) {
-                if !current_value.is_empty() {
-                    current_value.truncate(current_value.len() - 1);
+            EventType::MouseDown(data) => {
+                // Place the caret at the grapheme boundary nearest to the cursor
+                if let Some(layout) = ctx.get_layout(&event.current_target) {
+                    let x = data.position.0 - layout.posx - PADDING;
+                    history.end_typing();
+                    cloned_caret.set(Caret::new(measurement.char_index_at(x, 0.0)));
                 }
-            } else if !c.is_control() {
-                current_value.push(c);
             }
+            EventType::Focus => {
+                cloned_has_focus.set(Focus(true));
+                // Start blinking from a visible caret, even if it was hidden when last blurred
+                cloned_caret.set(Caret::new(index));
+            }
+            EventType::Blur => cloned_has_focus.set(Focus(false)),
+            _ => {}
+        }
+
+        cloned_history.set(history);
+        if current_value != before.value {
             if let Some(on_change) = cloned_on_change.as_ref() {
-                if let Ok(mut on_change) = on_change.0.write() {
-                    on_change(ChangeEvent {
-                        value: current_value.clone(),
-                    });
-                }
+                on_change.call(ChangeEvent {
+                    value: current_value.clone(),
+                });
             }
         }
-        EventType::Focus => cloned_has_focus.set(Focus(true)),
-        EventType::Blur => cloned_has_focus.set(Focus(false)),
-        _ => {}
     }));
 
     let text_styles = if value.is_empty() || (has_focus.get().0 && value.is_empty()) {
         Style {
-            color: Color::new(0.5, 0.5, 0.5, 1.0).into(),
+            color: theme.text_muted.into(),
             ..Style::default()
         }
     } else {
-        Style::default()
+        Style {
+            color: theme.text.into(),
+            ..Style::default()
+        }
+    };
+
+    let caret_styles = Style {
+        background_color: if is_caret_visible {
+            theme.text
+        } else {
+            Color::TRANSPARENT
+        }
+        .into(),
+        position_type: PositionType::SelfDirected.into(),
+        left: Units::Pixels(caret_x).into(),
+        top: Units::Stretch(1.0).into(),
+        bottom: Units::Stretch(1.0).into(),
+        width: Units::Pixels(1.0).into(),
+        height: Units::Pixels(FONT_SIZE + 2.0).into(),
+        ..Default::default()
     };
 
     let value = if value.is_empty() {
@@ -187,10 +479,11 @@ pub fn TextBox(props: TextBoxProps) {
             <Clip>
                 <Text
                     content={value}
-                    size={14.0}
-                    line_height={Some(22.0)}
+                    size={FONT_SIZE}
+                    line_height={Some(LINE_HEIGHT)}
                     styles={Some(text_styles)}
                 />
+                <Background styles={Some(caret_styles)} />
             </Clip>
         </Background>
     }
@@ -202,3 +495,67 @@ pub fn TextBox(props: TextBoxProps) {
 fn is_backspace(c: char) -> bool {
     c == '\u{8}' || c == '\u{7f}'
 }
+
+/// Removes the chars within the given range of char indices
+fn remove_chars(value: &mut String, chars: std::ops::Range<usize>) {
+    let start = byte_index(value, chars.start);
+    let end = byte_index(value, chars.end);
+    value.replace_range(start..end, "");
+}
+
+/// Returns the byte index of the char at the given char index (or the length of the string)
+fn byte_index(value: &str, char_index: usize) -> usize {
+    value
+        .char_indices()
+        .nth(char_index)
+        .map(|(index, _)| index)
+        .unwrap_or(value.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChangeEvent, TextBox};
+    use crate::core::{
+        render,
+        styles::{Style, StyleProp, Units},
+        Index, InputEvent, KayakContext, KeyCode, OnChange, TestHarness,
+    };
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn should_delete_words_with_ctrl_backspace_sent_before_the_key_press() {
+        let values = Arc::new(Mutex::new(Vec::new()));
+        let recorded = values.clone();
+        let mut context = KayakContext::new();
+        {
+            let context = &mut context;
+            let styles = Style {
+                width: StyleProp::Value(Units::Pixels(200.0)),
+                ..Default::default()
+            };
+            let on_change = OnChange::new(move |event: ChangeEvent| {
+                recorded.lock().unwrap().push(event.value);
+            });
+            render! {
+                <TextBox value={"hello world".to_string()} on_change={Some(on_change)} styles={Some(styles)} />
+            }
+        }
+
+        let mut harness = TestHarness::new(context);
+        harness.click(10.0, 10.0);
+        harness.press(KeyCode::End);
+        harness.send(vec![InputEvent::Keyboard {
+            key: KeyCode::LControl,
+            is_pressed: true,
+        }]);
+        // The char for the backspace arrives before the key press itself
+        harness.send(vec![
+            InputEvent::CharEvent { c: '\u{8}' },
+            InputEvent::Keyboard {
+                key: KeyCode::Back,
+                is_pressed: true,
+            },
+        ]);
+        assert_eq!(vec!["hello ".to_string()], *values.lock().unwrap());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::core::{
+    rsx, styles::Style, widget, Bound, Children, Color, KayakContextRef, MutableBound, OnEvent,
+    OnLayout, WidgetProps,
+};
+use crate::widgets::ScrollbarStyle;
+
+/// A set of design tokens used to style widgets
+///
+/// Widgets can access the theme provided by the nearest [`ThemeProvider`] using [`use_theme`].
+/// The default theme is [`Theme::dark`], which matches the look of the built-in widgets when no
+/// theme is provided.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    /// The name of the theme
+    pub name: String,
+
+    // === Colors === //
+    /// The color used for the background of windows and other large areas
+    pub background: Color,
+    /// The color used for raised surfaces, such as buttons and title bars
+    pub surface: Color,
+    /// The color used for floating elements, such as tooltips
+    pub overlay: Color,
+    /// The color used for the background of input fields
+    pub input: Color,
+    /// The color used for borders and dividers
+    pub border: Color,
+    /// The accent color used for active or selected elements
+    pub primary: Color,
+    /// The color used for text displayed over the [`primary`](Self::primary) color
+    pub on_primary: Color,
+    /// The color used for regular text
+    pub text: Color,
+    /// The color used for secondary text, such as placeholders
+    pub text_muted: Color,
+
+    // === Spacing === //
+    /// A small amount of spacing (in pixels)
+    pub spacing_small: f32,
+    /// A medium amount of spacing (in pixels)
+    pub spacing_medium: f32,
+    /// A large amount of spacing (in pixels)
+    pub spacing_large: f32,
+    /// The default corner radius (in pixels)
+    pub radius: f32,
+
+    // === Typography === //
+    /// The font size used for small text, such as tooltips (in pixels)
+    pub font_size_small: f32,
+    /// The font size used for regular text (in pixels)
+    pub font_size: f32,
+    /// The font size used for headings (in pixels)
+    pub font_size_large: f32,
+
+    // === Scrollbars === //
+    /// The default appearance of scrollbars
+    pub scrollbar: ScrollbarStyle,
+}
+
+impl Theme {
+    /// A dark theme, matching the default look of the built-in widgets
+    pub fn dark() -> Self {
+        Self {
+            name: "Dark".to_string(),
+            background: Color::new(0.125, 0.125, 0.125, 1.0),
+            surface: Color::new(0.0781, 0.0898, 0.101, 1.0),
+            overlay: Color::new(0.13, 0.15, 0.17, 0.85),
+            input: Color::new(0.176, 0.196, 0.215, 1.0),
+            border: Color::new(0.0781, 0.0898, 0.101, 1.0),
+            primary: Color::new(0.239, 0.42, 0.87, 1.0),
+            on_primary: Color::WHITE,
+            text: Color::WHITE,
+            text_muted: Color::new(0.5, 0.5, 0.5, 1.0),
+            spacing_small: 5.0,
+            spacing_medium: 10.0,
+            spacing_large: 20.0,
+            radius: 5.0,
+            font_size_small: 12.0,
+            font_size: 16.0,
+            font_size_large: 24.0,
+            scrollbar: ScrollbarStyle {
+                thumb_color: Some(Color::new(0.2981, 0.3098, 0.321, 0.95)),
+                track_color: Some(Color::new(0.1581, 0.1758, 0.191, 0.15)),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// A light theme
+    pub fn light() -> Self {
+        Self {
+            name: "Light".to_string(),
+            background: Color::new(0.96, 0.96, 0.96, 1.0),
+            surface: Color::new(0.87, 0.88, 0.9, 1.0),
+            overlay: Color::new(0.2, 0.2, 0.22, 0.9),
+            input: Color::WHITE,
+            border: Color::new(0.75, 0.76, 0.78, 1.0),
+            primary: Color::new(0.2, 0.38, 0.84, 1.0),
+            on_primary: Color::WHITE,
+            text: Color::new(0.1, 0.1, 0.12, 1.0),
+            text_muted: Color::new(0.45, 0.45, 0.48, 1.0),
+            scrollbar: ScrollbarStyle {
+                thumb_color: Some(Color::new(0.62, 0.63, 0.65, 0.95)),
+                track_color: Some(Color::new(0.75, 0.76, 0.78, 0.3)),
+                ..Default::default()
+            },
+            ..Self::dark()
+        }
+    }
+
+    /// A dark theme with stronger contrast between text, surfaces, and borders
+    ///
+    /// This suits users who
+    /// [prefer high contrast](crate::core::KayakContextRef::prefers_high_contrast), and can be
+    /// given as the [`high_contrast`](ThemeProviderProps::high_contrast) theme of a
+    /// [`ThemeProvider`].
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "High Contrast".to_string(),
+            background: Color::BLACK,
+            surface: Color::new(0.1, 0.1, 0.1, 1.0),
+            overlay: Color::new(0.0, 0.0, 0.0, 0.95),
+            input: Color::BLACK,
+            border: Color::WHITE,
+            primary: Color::new(1.0, 0.85, 0.0, 1.0),
+            on_primary: Color::BLACK,
+            text: Color::WHITE,
+            text_muted: Color::new(0.85, 0.85, 0.85, 1.0),
+            scrollbar: ScrollbarStyle {
+                thumb_color: Some(Color::WHITE),
+                track_color: Some(Color::new(0.3, 0.3, 0.3, 1.0)),
+                ..Default::default()
+            },
+            ..Self::dark()
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// Props used by the [`ThemeProvider`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct ThemeProviderProps {
+    /// The theme provided to all descendants
+    ///
+    /// Changing this prop will update the provided theme, re-rendering any widget using it.
+    pub theme: Theme,
+    /// The theme provided instead of [`theme`](Self::theme) when the user prefers high contrast
+    ///
+    /// See [`KayakContextRef::prefers_high_contrast`].
+    pub high_contrast: Option<Theme>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A widget that provides a [`Theme`] to its descendants
+///
+/// Descendants can read the theme using [`use_theme`]. The theme can be swapped at runtime either
+/// by changing the `theme` prop or by setting the value of the provided `Binding<Theme>`, obtained
+/// by calling `context.create_consumer::<Theme>()` from any descendant.
+///
+/// Providers can be nested, in which case widgets use the theme of their nearest provider.
+///
+/// If given a [`high_contrast`](ThemeProviderProps::high_contrast) theme, it's provided instead
+/// while the user [prefers high contrast](KayakContextRef::prefers_high_contrast), switching back
+/// and forth as that preference changes.
+///
+/// # Props
+///
+/// __Type:__ [`ThemeProviderProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ✅        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::rsx;
+/// # use kayak_ui::widgets::{Button, Theme, ThemeProvider};
+///
+/// rsx! {
+///     <ThemeProvider theme={Theme::light()} high_contrast={Some(Theme::high_contrast())}>
+///         <Button />
+///     </ThemeProvider>
+/// }
+/// ```
+pub fn ThemeProvider(props: ThemeProviderProps) {
+    let ThemeProviderProps {
+        theme,
+        high_contrast,
+        ..
+    } = props.clone();
+    let theme = match high_contrast {
+        Some(high_contrast) if context.prefers_high_contrast() => high_contrast,
+        _ => theme,
+    };
+
+    let provider = context.create_provider(theme.clone());
+    let previous_theme = context.create_state(theme.clone()).unwrap();
+    if previous_theme.get() != theme {
+        // The prop changed: push the new theme to all consumers
+        previous_theme.set(theme.clone());
+        provider.set(theme);
+    }
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
+
+/// Returns the theme provided by the nearest [`ThemeProvider`], if any
+///
+/// The current widget is bound to the theme, so it will re-render whenever the theme changes.
+/// Widgets should fall back to [`Theme::default`] when no theme is provided.
+pub fn use_theme(context: &mut KayakContextRef) -> Option<Theme> {
+    let theme = context.create_consumer::<Theme>()?;
+    context.bind(&theme);
+    Some(theme.get())
+}
@@ -0,0 +1,429 @@
+use crate::core::{
+    constructor,
+    render_command::RenderCommand,
+    rsx,
+    styles::{AlignItems, Corner, Edge, LayoutType, PositionType, Style, StyleProp, Units},
+    use_state, widget, Accessibility, Binding, Bound, Children, EventType, Handler,
+    KayakContextRef, MutableBound, OnEvent, OnLayout, Role, VecTracker, WidgetProps,
+};
+
+use crate::widgets::{
+    use_theme, Background, Button, Element, If, Portal, Text, Transition, TransitionPreset,
+};
+
+/// The key of the timeout used to dismiss a toast
+const DISMISS_TIMEOUT: &str = "dismiss";
+/// The default time a toast is displayed for (in seconds)
+const DEFAULT_DURATION: f32 = 4.0;
+/// The default number of toasts displayed at once
+const DEFAULT_MAX_VISIBLE: usize = 3;
+/// The distance toasts slide up from as they appear (in pixels)
+const ENTER_OFFSET: f32 = 10.0;
+
+/// A short message displayed by a [`ToastProvider`], such as the result of an action
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Toast {
+    /// The text to display
+    pub message: String,
+    /// The label of a button displayed next to the message and its handler
+    ///
+    /// The handler receives the events of the button, including a
+    /// [`Click`](crate::core::EventType::Click) when it's activated. Clicking the button also
+    /// dismisses the toast.
+    pub action: Option<(String, OnEvent)>,
+    /// The time the toast is displayed for before it's dismissed (in seconds)
+    ///
+    /// Defaults to 4 seconds. This only counts the time the toast is actually displayed and not
+    /// hovered, so it doesn't run out while the toast is queued or being read.
+    pub duration: Option<f32>,
+}
+
+impl Toast {
+    /// Creates a toast with the given message, no action, and the default duration
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        Self {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a button with the given label that calls the handler when it's clicked
+    pub fn with_action<S: Into<String>>(mut self, label: S, handler: OnEvent) -> Self {
+        self.action = Some((label.into(), handler));
+        self
+    }
+
+    /// Sets the time the toast is displayed for (in seconds)
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+}
+
+/// The toasts of a [`ToastProvider`], in the order they were shown
+///
+/// Every provider creates a consumable `Binding<ToastQueue>`, which can be obtained using
+/// [`use_toasts`]. The provider displays the oldest toasts, up to its
+/// [`max_visible`](ToastProviderProps::max_visible) count, and the rest wait in the queue until
+/// one of those is dismissed.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ToastQueue {
+    /// The ID given to the next toast
+    next_id: usize,
+    /// The toasts and their IDs, from oldest to newest
+    toasts: Vec<(usize, Toast)>,
+}
+
+impl ToastQueue {
+    /// Adds a toast to the end of the queue, returning its ID
+    pub fn push(&mut self, toast: Toast) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.toasts.push((id, toast));
+        id
+    }
+
+    /// Removes the toast with the given ID, returning it if it was still queued or displayed
+    pub fn dismiss(&mut self, id: usize) -> Option<Toast> {
+        let index = self
+            .toasts
+            .iter()
+            .position(|(toast_id, _)| *toast_id == id)?;
+        Some(self.toasts.remove(index).1)
+    }
+
+    /// Removes every toast
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// Returns the toasts and their IDs, from oldest to newest
+    pub fn toasts(&self) -> &[(usize, Toast)] {
+        &self.toasts
+    }
+
+    /// Returns the number of toasts, including those waiting to be displayed
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Returns true if there are no toasts
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+/// Props used by the [`ToastProvider`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct ToastProviderProps {
+    /// The number of toasts displayed at once
+    ///
+    /// Defaults to 3. Any other toasts are queued until a slot frees up.
+    pub max_visible: Option<usize>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A widget that provides a queue of [`Toast`] messages to its descendants and displays them
+///
+/// Descendants show a toast by pushing it onto the [`ToastQueue`] returned by [`use_toasts`]. The
+/// toasts are stacked at the bottom center of the window, oldest first, and each one is dismissed
+/// automatically once its [`duration`](Toast::duration) has passed. Hovering a toast pauses its
+/// timer until the cursor leaves it. At most [`max_visible`](ToastProviderProps::max_visible)
+/// toasts are displayed at a time, so later ones wait in the queue until a slot frees up.
+///
+/// Toasts fade in using a [`Transition`] and are rendered in a [`Portal`], so they aren't clipped
+/// by the provider. Each one is announced to assistive technologies as a
+/// [`Status`](crate::core::Role::Status).
+///
+/// # Props
+///
+/// __Type:__ [`ToastProviderProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ✅        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, widget, EventType, MutableBound, Bound, OnEvent};
+/// # use kayak_ui::widgets::{use_toasts, Button, Toast, ToastProvider};
+///
+/// #[widget]
+/// fn SaveButton() {
+///   let toasts = use_toasts(context).unwrap();
+///   let on_event = OnEvent::new(move |_, event| {
+///     if let EventType::Click(..) = event.event_type {
+///       let on_undo = OnEvent::new(|_, event| {
+///         if let EventType::Click(..) = event.event_type {
+///           println!("Undone!");
+///         }
+///       });
+///       let mut queue = toasts.get();
+///       queue.push(Toast::new("Saved").with_action("Undo", on_undo));
+///       toasts.set(queue);
+///     }
+///   });
+///   rsx! {
+///     <Button label={Some("Save".to_string())} on_event={Some(on_event)} />
+///   }
+/// }
+///
+/// rsx! {
+///     <ToastProvider max_visible={Some(2)}>
+///         <SaveButton />
+///     </ToastProvider>
+/// }
+/// ```
+pub fn ToastProvider(props: ToastProviderProps) {
+    let ToastProviderProps { max_visible, .. } = props.clone();
+    let theme = use_theme(context).unwrap_or_default();
+    let queue = context.create_provider(ToastQueue::default());
+
+    props.styles = Some(
+        Style::default()
+            .with_style(Style {
+                render_command: StyleProp::Value(RenderCommand::Layout),
+                ..Default::default()
+            })
+            .with_style(&props.styles),
+    );
+
+    let stack_styles = Style {
+        bottom: StyleProp::Value(Units::Pixels(theme.spacing_large)),
+        height: StyleProp::Value(Units::Auto),
+        layout_type: StyleProp::Value(LayoutType::Column),
+        left: StyleProp::Value(Units::Stretch(1.0)),
+        position_type: StyleProp::Value(PositionType::SelfDirected),
+        right: StyleProp::Value(Units::Stretch(1.0)),
+        row_between: StyleProp::Value(Units::Pixels(theme.spacing_medium)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        width: StyleProp::Value(Units::Auto),
+        ..Default::default()
+    };
+    let visible: Vec<(usize, Toast)> = queue
+        .get()
+        .toasts()
+        .iter()
+        .take(max_visible.unwrap_or(DEFAULT_MAX_VISIBLE))
+        .cloned()
+        .collect();
+
+    rsx! {
+        <>
+            <Element>
+                {children}
+            </Element>
+            <Portal>
+                <Element styles={Some(stack_styles)}>
+                    {VecTracker::from(visible.clone().into_iter().map(|(id, toast)| {
+                        let queue = queue.clone();
+                        let on_dismiss = Handler::new(move |_| {
+                            let mut state = queue.get();
+                            if state.dismiss(id).is_some() {
+                                queue.set(state);
+                            }
+                        });
+                        constructor! {
+                            <ToastItem key={id} id={id} toast={toast} on_dismiss={Some(on_dismiss)} />
+                        }
+                    }))}
+                </Element>
+            </Portal>
+        </>
+    }
+}
+
+/// Returns the queue of toasts provided by the nearest [`ToastProvider`], if any
+///
+/// Unlike [`use_theme`], this doesn't bind the current widget, since widgets showing toasts
+/// rarely need to re-render when the queue changes.
+pub fn use_toasts(context: &mut KayakContextRef) -> Option<Binding<ToastQueue>> {
+    context.create_consumer::<ToastQueue>()
+}
+
+/// The dismissal timer of a displayed [`Toast`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct ToastTimer {
+    /// The ID of the toast the timer belongs to
+    id: usize,
+    /// The time left before the toast is dismissed, as of when the timer was last paused
+    remaining: f32,
+    /// The [elapsed](KayakContextRef::elapsed) time at which the timer last started running, or
+    /// `None` if it isn't running
+    started_at: Option<f32>,
+    /// Whether the toast is hovered, pausing the timer
+    is_paused: bool,
+}
+
+/// Props used by the [`ToastItem`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+struct ToastItemProps {
+    /// The ID of the toast within its queue
+    id: usize,
+    /// The toast to display
+    toast: Toast,
+    /// A callback for when the toast should be removed from its queue
+    on_dismiss: Option<Handler>,
+    #[prop_field(Styles)]
+    styles: Option<Style>,
+    #[prop_field(OnEvent)]
+    on_event: Option<OnEvent>,
+}
+
+#[widget]
+/// Displays a single toast of a [`ToastProvider`] and dismisses it once its time runs out
+fn ToastItem(props: ToastItemProps) {
+    let ToastItemProps {
+        id,
+        toast,
+        on_dismiss,
+        ..
+    } = props.clone();
+    let Toast {
+        message,
+        action,
+        duration,
+    } = toast;
+    let theme = use_theme(context).unwrap_or_default();
+    let duration = duration.unwrap_or(DEFAULT_DURATION);
+
+    // === Timer === //
+    let (timer, set_timer, ..) = use_state!(ToastTimer {
+        id,
+        remaining: duration,
+        started_at: None,
+        is_paused: false,
+    });
+    let mut next_timer = timer;
+    if next_timer.id != id {
+        // This slot now displays a newer toast, so its timer starts over
+        next_timer = ToastTimer {
+            id,
+            remaining: duration,
+            started_at: None,
+            is_paused: false,
+        };
+    }
+    if !next_timer.is_paused && next_timer.started_at.is_none() {
+        // Replaces the timeout of the toast previously displayed here, if any
+        let on_timeout = on_dismiss.clone();
+        context.set_timeout(
+            DISMISS_TIMEOUT,
+            next_timer.remaining,
+            Handler::new(move |_| {
+                if let Some(ref on_dismiss) = on_timeout {
+                    on_dismiss.call(());
+                }
+            }),
+        );
+        next_timer.started_at = Some(context.elapsed());
+    }
+    if next_timer != timer {
+        set_timer(next_timer);
+    }
+
+    // === Events === //
+    let set_hover_timer = set_timer.clone();
+    props.on_event = Some(OnEvent::new(move |ctx, event| match event.event_type {
+        EventType::PointerEnter(..) => {
+            ctx.clear_timeout(DISMISS_TIMEOUT);
+            let running_for = next_timer
+                .started_at
+                .map(|started_at| ctx.elapsed() - started_at)
+                .unwrap_or_default();
+            set_hover_timer(ToastTimer {
+                remaining: (next_timer.remaining - running_for).max(0.0),
+                started_at: None,
+                is_paused: true,
+                ..next_timer
+            });
+        }
+        EventType::PointerLeave(..) => {
+            set_hover_timer(ToastTimer {
+                is_paused: false,
+                ..next_timer
+            });
+        }
+        _ => {}
+    }));
+
+    let has_action = action.is_some();
+    let (action_label, action_handler) = match action {
+        Some((label, handler)) => (Some(label), Some(handler)),
+        None => (None, None),
+    };
+    let on_action = OnEvent::new(move |ctx, event| {
+        if let Some(ref handler) = action_handler {
+            handler.try_call(ctx, event);
+        }
+        if let EventType::Click(..) = event.event_type {
+            if let Some(ref on_dismiss) = on_dismiss {
+                on_dismiss.call(());
+            }
+        }
+    });
+
+    // === Accessibility === //
+    context.set_accessibility(Some(
+        Accessibility::new(Role::Status).with_name(message.clone()),
+    ));
+
+    // === Styles === //
+    props.styles = Some(Style {
+        height: StyleProp::Value(Units::Auto),
+        render_command: StyleProp::Value(RenderCommand::Layout),
+        width: StyleProp::Value(Units::Auto),
+        ..Default::default()
+    });
+    let background_styles = Style {
+        align_items: StyleProp::Value(AlignItems::Center),
+        background_color: StyleProp::Value(theme.overlay),
+        border_radius: StyleProp::Value(Corner::all(theme.radius)),
+        col_between: StyleProp::Value(Units::Pixels(theme.spacing_medium)),
+        height: StyleProp::Value(Units::Auto),
+        layout_type: StyleProp::Value(LayoutType::Row),
+        padding: StyleProp::Value(Edge::new(
+            Units::Pixels(theme.spacing_small),
+            Units::Pixels(theme.spacing_medium),
+            Units::Pixels(theme.spacing_small),
+            Units::Pixels(theme.spacing_large),
+        )),
+        width: StyleProp::Value(Units::Auto),
+        ..Default::default()
+    };
+    let message_styles = Style {
+        color: StyleProp::Value(theme.text),
+        ..Default::default()
+    };
+    let action_styles = Style {
+        padding_left: StyleProp::Value(Units::Pixels(theme.spacing_medium)),
+        padding_right: StyleProp::Value(Units::Pixels(theme.spacing_medium)),
+        width: StyleProp::Value(Units::Auto),
+        ..Default::default()
+    };
+    let font_size = theme.font_size;
+
+    rsx! {
+        <Transition visible={true} enter={TransitionPreset::FadeSlide(0.0, ENTER_OFFSET)}>
+            <Background styles={Some(background_styles)}>
+                <Text content={message} size={font_size} styles={Some(message_styles)} />
+                <If condition={has_action}>
+                    <Button label={action_label} styles={Some(action_styles)} on_event={Some(on_action)} />
+                </If>
+            </Background>
+        </Transition>
+    }
+}
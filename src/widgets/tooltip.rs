@@ -1,60 +1,248 @@
 use crate::core::{
+    constructor,
     render_command::RenderCommand,
     rsx,
     styles::{PositionType, Style, StyleProp, Units},
-    widget, Bound, Children, Color, EventType, MutableBound, OnEvent, WidgetProps,
+    use_state, widget, Accessibility, Binding, Bound, Children, EventType, Handler, Index,
+    KayakContextRef, Layout, MutableBound, OnEvent, OnLayout, Role, VecTracker, WidgetProps,
 };
 use std::sync::Arc;
 
-use crate::widgets::{Background, Clip, Element, If, Text};
+use crate::widgets::{use_theme, Background, Clip, Element, Portal, Text, Transition};
+
+/// The side of an anchor on which a tooltip is placed
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Placement {
+    /// Centered above the anchor
+    Top,
+    /// Centered below the anchor
+    Bottom,
+    /// Centered to the left of the anchor
+    Left,
+    /// Centered to the right of the anchor
+    Right,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Self::Top
+    }
+}
+
+impl Placement {
+    /// Returns the point on the edge of the given layout that a tooltip with this placement is anchored to
+    pub fn anchor_of(&self, layout: &Layout) -> (f32, f32) {
+        let center = (
+            layout.posx + layout.width / 2.0,
+            layout.posy + layout.height / 2.0,
+        );
+        match self {
+            Self::Top => (center.0, layout.posy),
+            Self::Bottom => (center.0, layout.posy + layout.height),
+            Self::Left => (layout.posx, center.1),
+            Self::Right => (layout.posx + layout.width, center.1),
+        }
+    }
+}
+
+/// Controls what a [`TooltipConsumer`] anchors its tooltip to
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TooltipAnchor {
+    /// The tooltip follows the cursor
+    Cursor,
+    /// The tooltip is placed on the given side of the consumer's own layout
+    ///
+    /// This is better suited for fixed UI elements, such as icon buttons.
+    Element(Placement),
+}
+
+impl Default for TooltipAnchor {
+    fn default() -> Self {
+        Self::Cursor
+    }
+}
 
 /// Data provided by a [`TooltipProvider`] used to control a tooltip
 #[derive(Clone, PartialEq, Debug, Default)]
 pub struct TooltipData {
     /// The anchor coordinates in pixels (x, y)
     pub anchor: (f32, f32),
+    /// The side of the anchor on which to place the tooltip
+    ///
+    /// If `None`, the tooltip is placed next to the anchor, on the side facing away from the
+    /// nearest edges of the provider.
+    pub placement: Option<Placement>,
+    /// The gap between the anchor and the tooltip in pixels (x, y)
+    ///
+    /// If `None`, the provider's [`padding`](TooltipProviderProps::padding) is used.
+    pub offset: Option<(f32, f32)>,
     /// The size of the tooltip in pixels (width, height)
+    ///
+    /// If `None`, the provider's [`default_size`](TooltipProviderProps::default_size) is used.
     pub size: Option<(f32, f32)>,
+    /// The styles applied on top of the provider's tooltip styles
+    pub styles: Option<Style>,
     /// The text to display
     pub text: String,
     /// Whether the tooltip is visible or not
     pub visible: bool,
+    /// The ID of the widget the tooltip describes, such as the [`TooltipConsumer`] that shows it
+    ///
+    /// This is used to associate the tooltip with that widget for assistive technologies.
+    pub owner: Option<Index>,
+    /// Whether the tooltip is pinned open by a [`pinnable`](TooltipConsumerProps::pinnable)
+    /// consumer
+    ///
+    /// A pinned tooltip isn't moved or hidden when the cursor leaves its consumer, and isn't taken
+    /// over by other consumers.
+    pub pinned: bool,
+}
+
+/// The consumer whose tooltip the mouse is currently pressed on, if any
+///
+/// This is tracked by the provider, so a consumer can tell a click on its tooltip apart from a
+/// click outside of it.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+struct TooltipPress(Option<Index>);
+
+/// The tooltips displayed by a [`TooltipProvider`] that allows [`multiple`](TooltipProviderProps::multiple) tooltips
+///
+/// Every provider creates a consumable `Binding<TooltipCollection>`. It's only used when `multiple`
+/// is true, in which case it replaces the provider's single [`TooltipData`].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TooltipCollection {
+    /// Whether the provider displays multiple tooltips at once
+    pub multiple: bool,
+    /// The time a tooltip stays visible after the cursor leaves its consumer (in seconds)
+    pub hide_delay: f32,
+    /// The tooltips to display, keyed by the ID of the widget that owns them
+    pub tooltips: Vec<(Index, TooltipData)>,
+}
+
+impl TooltipCollection {
+    /// Returns the tooltip with the given key
+    pub fn get(&self, key: Index) -> Option<&TooltipData> {
+        self.tooltips
+            .iter()
+            .find(|(id, _)| *id == key)
+            .map(|(_, tooltip)| tooltip)
+    }
+
+    /// Inserts the tooltip with the given key, replacing any existing one in place
+    pub fn insert(&mut self, key: Index, tooltip: TooltipData) {
+        match self.tooltips.iter_mut().find(|(id, _)| *id == key) {
+            Some((_, existing)) => *existing = tooltip,
+            None => self.tooltips.push((key, tooltip)),
+        }
+    }
+
+    /// Removes the tooltip with the given key, returning it if it existed
+    pub fn remove(&mut self, key: Index) -> Option<TooltipData> {
+        let index = self.tooltips.iter().position(|(id, _)| *id == key)?;
+        Some(self.tooltips.remove(index).1)
+    }
 }
 
 /// Props used by the [`TooltipProvider`] widget
-#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+#[derive(WidgetProps, Debug, PartialEq, Clone)]
 pub struct TooltipProviderProps {
+    /// The size of tooltips whose consumer doesn't give one, in pixels (width, height)
+    ///
+    /// If `None`, a size of `(150.0, 18.0)` is used.
+    pub default_size: Option<(f32, f32)>,
+    /// Whether each [`TooltipConsumer`] may display its own tooltip at the same time
+    ///
+    /// By default, a single tooltip is shared by all consumers.
+    pub multiple: bool,
+    /// The time a tooltip stays visible after the cursor leaves its consumer (in seconds)
+    ///
+    /// This keeps the tooltip from flickering off and on as the cursor crosses a gap between two
+    /// consumers, since the next consumer takes it over before it's hidden.
+    /// If `None`, a delay of 0.05 seconds is used.
+    pub hide_delay: Option<f32>,
+    /// The gap between the anchor and tooltips whose consumer doesn't give an
+    /// [`offset`](TooltipConsumerProps::offset), in pixels (x, y)
+    ///
+    /// Defaults to `(10.0, 5.0)`.
+    pub padding: (f32, f32),
     /// The position of the containing rect (used to layout the tooltip)
-    pub position: (f32, f32),
+    ///
+    /// If `None`, the position is measured from the provider's own layout.
+    pub position: Option<(f32, f32)>,
     /// The size of the containing rect (used to layout the tooltip)
-    pub size: (f32, f32),
+    ///
+    /// If `None`, the size is measured from the provider's own layout.
+    pub size: Option<(f32, f32)>,
     #[prop_field(Styles)]
     pub styles: Option<Style>,
     #[prop_field(Children)]
     pub children: Option<Children>,
     #[prop_field(OnEvent)]
     pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
 }
 
-/// Props used by the [`TooltipProvider`] widget
+impl Default for TooltipProviderProps {
+    fn default() -> Self {
+        Self {
+            default_size: None,
+            multiple: false,
+            hide_delay: None,
+            padding: DEFAULT_OFFSET,
+            position: None,
+            size: None,
+            styles: None,
+            children: None,
+            on_event: None,
+            on_layout: None,
+        }
+    }
+}
+
+/// Props used by the [`TooltipConsumer`] widget
 #[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
 pub struct TooltipConsumerProps {
     /// The position at which to anchor the tooltip (in pixels)
     ///
-    /// If `None`, the tooltip will follow the cursor
+    /// If `None`, the tooltip is anchored according to the [`anchor_mode`](Self::anchor_mode).
+    /// This is ignored when anchoring to the element.
     pub anchor: Option<(f32, f32)>,
+    /// Controls whether the tooltip follows the cursor or is placed relative to this widget
+    ///
+    /// Defaults to [`TooltipAnchor::Cursor`].
+    pub anchor_mode: TooltipAnchor,
+    /// The gap between the anchor and the tooltip in pixels (x, y)
+    ///
+    /// When following the cursor, this is only applied while the tooltip is placed below or to the
+    /// right of the anchor. When anchored to the element, only the component along the axis of the
+    /// [`Placement`] is used.
+    /// If `None`, the provider's [`padding`](TooltipProviderProps::padding) is used.
+    pub offset: Option<(f32, f32)>,
     /// The size of the tooltip
     ///
-    /// If `None`, the tooltip will be automatically sized
+    /// If `None`, the provider's [`default_size`](TooltipProviderProps::default_size) is used.
     pub size: Option<(f32, f32)>,
+    /// If true, clicking this widget (or its tooltip) pins the tooltip open
+    ///
+    /// A pinned tooltip stays in place when the cursor leaves this widget, so its content can be
+    /// reached, turning it into a lightweight popover. Clicking anywhere else unpins and hides it.
+    pub pinnable: bool,
     /// The text to display in the tooltip
     pub text: String,
+    /// The styles of this consumer's tooltip
+    ///
+    /// These are applied on top of the styles given to the [`TooltipProvider`], which allows a
+    /// single tooltip to be restyled (such as giving an error tooltip a red `background_color`).
+    pub tooltip_styles: Option<Style>,
     #[prop_field(Styles)]
     pub styles: Option<Style>,
     #[prop_field(Children)]
     pub children: Option<Children>,
     #[prop_field(OnEvent)]
     pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
 }
 
 #[widget]
@@ -63,6 +251,22 @@ pub struct TooltipConsumerProps {
 /// This widget creates a single tooltip that can be controlled by any descendant [`TooltipConsumer`],
 /// or by creating a consumer for [`TooltipData`]
 ///
+/// If [`multiple`](TooltipProviderProps::multiple) is true, each consumer instead displays its own
+/// tooltip, allowing several to be shown at once. These are stored in a [`TooltipCollection`].
+///
+/// Tooltips fade in and out using a [`Transition`]. In multiple mode, only the fade in is played,
+/// since a consumer's tooltip is removed from the collection as soon as it's hidden.
+///
+/// When the cursor leaves a consumer, its tooltip is only hidden after the
+/// [`hide_delay`](TooltipProviderProps::hide_delay), so moving straight onto another consumer hands
+/// the tooltip over without hiding it in between.
+///
+/// The tooltip is positioned and kept within the bounds of this widget's own measured layout, as
+/// well as those of the window (when the integration provides its size). These bounds update
+/// automatically as the window is resized. The `position` and `size` props can be used to override
+/// the bounds of the provider. The `default_size` and
+/// `padding` props set the size and anchor gap of every tooltip whose consumer doesn't set its own.
+///
 /// # Props
 ///
 /// __Type:__ [`TooltipProviderProps`]
@@ -72,13 +276,14 @@ pub struct TooltipConsumerProps {
 /// | `children`  | ✅        |
 /// | `styles`    | ✅        |
 /// | `on_event`  | ✅        |
-/// | `on_layout` | ❌        |
+/// | `on_layout` | ✅        |
 /// | `focusable` | ❌        |
 ///
 /// # Styles
 ///
 /// This widget accepts all styles and affects the actual tooltip container. The `background_color`
-/// and `color` styles, however, apply directly to the tooltip itself.
+/// and `color` styles, however, apply directly to the tooltip itself. Each consumer can override
+/// these using its [`tooltip_styles`](TooltipConsumerProps::tooltip_styles).
 ///
 /// # Examples
 ///
@@ -89,7 +294,7 @@ pub struct TooltipConsumerProps {
 /// fn MyWidget() {
 ///   rsx! {
 ///     <>
-///         <TooltipProvider size={Some(1280.0, 720.0)}>
+///         <TooltipProvider size={Some((1280.0, 720.0))}>
 ///             // ...
 ///             <TooltipConsumer text={"Tooltip A".to_string()}>
 ///                 // ...
@@ -104,90 +309,262 @@ pub struct TooltipConsumerProps {
 /// }
 /// ```
 pub fn TooltipProvider(props: TooltipProviderProps) {
-    let TooltipProviderProps { position, size, .. } = props;
-    const WIDTH: f32 = 150.0;
-    const HEIGHT: f32 = 18.0;
-    const PADDING: (f32, f32) = (10.0, 5.0);
+    let TooltipProviderProps {
+        default_size,
+        multiple,
+        hide_delay,
+        padding,
+        position,
+        size,
+        on_event,
+        on_layout,
+        ..
+    } = props.clone();
 
+    let theme = use_theme(context).unwrap_or_default();
     let tooltip = context.create_provider(TooltipData::default());
-    let TooltipData {
-        anchor,
-        size: tooltip_size,
-        text,
-        visible,
-        ..
-    } = tooltip.get();
-    let tooltip_size = tooltip_size.unwrap_or((WIDTH, HEIGHT));
+    let collection = context.create_provider(TooltipCollection::default());
+    if collection.get().multiple != multiple {
+        let mut state = collection.get();
+        state.multiple = multiple;
+        state.tooltips.clear();
+        collection.set(state);
+    }
+    let hide_delay = hide_delay.unwrap_or(DEFAULT_HIDE_DELAY);
+    if collection.get().hide_delay != hide_delay {
+        let mut state = collection.get();
+        state.hide_delay = hide_delay;
+        collection.set(state);
+    }
+    context.create_provider(TooltipPress::default());
+
+    // === Unmounting === //
+    // A pinned tooltip is only unpinned by its consumer, so it's hidden here once that consumer is
+    // removed. This is checked whenever the provider renders or is pressed.
+    hide_unmounted(context, &tooltip, &collection);
+    let (cloned_tooltip, cloned_collection) = (tooltip.clone(), collection.clone());
+    props.on_event = Some(OnEvent::new(move |ctx, event| {
+        if let EventType::MouseDown(..) = event.event_type {
+            hide_unmounted(ctx, &cloned_tooltip, &cloned_collection);
+        }
+        if let Some(ref on_event) = on_event {
+            on_event.try_call(ctx, event);
+        }
+    }));
+    // Presses outside of the provider are also outside of all its consumers, which would unpin
+    // their tooltips anyway
+    let has_pinned = tooltip.get().pinned
+        || collection
+            .get()
+            .tooltips
+            .iter()
+            .any(|(_, state)| state.pinned);
+    let on_click_outside = has_pinned.then(|| {
+        let (tooltip, collection) = (tooltip.clone(), collection.clone());
+        Handler::new(move |_| unpin_tooltips(&tooltip, &collection))
+    });
+    context.on_click_outside(on_click_outside);
+
+    let tooltips: Vec<TooltipData> = if multiple {
+        collection
+            .get()
+            .tooltips
+            .into_iter()
+            .map(|(_, tooltip)| tooltip)
+            .filter(|tooltip| tooltip.visible)
+            .collect()
+    } else {
+        // Hidden tooltips are still rendered so they can play their exit transition
+        vec![tooltip.get()]
+    };
+
+    // === Layout === //
+    let (layout, set_layout, ..) = use_state!(Layout::default());
+    props.on_layout = Some(OnLayout::new(move |ctx, evt| {
+        set_layout(evt.layout);
+        if let Some(ref on_layout) = on_layout {
+            on_layout.try_call(ctx, evt);
+        }
+    }));
 
+    // === Styles === //
     props.styles = Some(
         Style::default()
             .with_style(Style {
-                left: StyleProp::Value(Units::Pixels(position.0)),
-                top: StyleProp::Value(Units::Pixels(position.1)),
+                left: position
+                    .map(|position| StyleProp::Value(Units::Pixels(position.0)))
+                    .unwrap_or_default(),
+                top: position
+                    .map(|position| StyleProp::Value(Units::Pixels(position.1)))
+                    .unwrap_or_default(),
                 ..Default::default()
             })
             .with_style(&props.styles)
             .with_style(Style {
-                width: StyleProp::Value(Units::Pixels(size.0)),
-                height: StyleProp::Value(Units::Pixels(size.1)),
+                width: size
+                    .map(|size| StyleProp::Value(Units::Pixels(size.0)))
+                    .unwrap_or_default(),
+                height: size
+                    .map(|size| StyleProp::Value(Units::Pixels(size.1)))
+                    .unwrap_or_default(),
                 ..Default::default()
             }),
     );
 
-    let base_styles = props.styles.clone().unwrap();
-    let mut tooltip_styles = Style {
-        position_type: StyleProp::Value(PositionType::SelfDirected),
-        background_color: StyleProp::select(&[
-            &base_styles.background_color,
-            &Color::new(0.13, 0.15, 0.17, 0.85).into(),
-        ])
-        .clone(),
-        width: StyleProp::Value(Units::Pixels(tooltip_size.0)),
-        height: StyleProp::Value(Units::Pixels(tooltip_size.1)),
-        ..Style::default()
+    // Explicitly given props take precedence over the measured layout
+    let position = position.unwrap_or_else(|| layout.pos());
+    let size = size.unwrap_or((layout.width, layout.height));
+    // Tooltips should also stay within the window, which may be smaller than the provider
+    let size = match context.window_size() {
+        Some(window_size) => (
+            size.0.min(window_size.0 - position.0).max(0.0),
+            size.1.min(window_size.1 - position.1).max(0.0),
+        ),
+        None => size,
     };
 
-    if anchor.0 < size.0 / 2.0 {
-        tooltip_styles.left = StyleProp::Value(Units::Pixels(anchor.0 + PADDING.0));
-    } else {
-        // TODO: Replace with `right` (currently not working properly)
-        tooltip_styles.left = StyleProp::Value(Units::Pixels(anchor.0 - tooltip_size.0));
-    }
-
-    if anchor.1 < size.1 / 2.0 {
-        tooltip_styles.top = StyleProp::Value(Units::Pixels(anchor.1 + PADDING.1));
-    } else {
-        // TODO: Replace with `bottom` (currently not working properly)
-        tooltip_styles.top = StyleProp::Value(Units::Pixels(anchor.1 - tooltip_size.1));
-    }
-
-    let text_styles = Style {
-        width: StyleProp::Value(Units::Pixels(tooltip_size.0)),
-        height: StyleProp::Value(Units::Pixels(tooltip_size.1)),
-        color: StyleProp::select(&[&base_styles.color, &Color::WHITE.into()]).clone(),
-        ..Style::default()
-    };
+    let base_styles = props.styles.clone().unwrap();
+    let background_color =
+        StyleProp::select(&[&base_styles.background_color, &theme.overlay.into()]).clone();
+    let color = StyleProp::select(&[&base_styles.color, &theme.text.into()]).clone();
+    let font_size = theme.font_size_small;
+    let default_size = default_size.unwrap_or((WIDTH, HEIGHT));
 
     rsx! {
         <>
             <Element>
                 {children}
             </Element>
-            <If condition={visible}>
-                <Background styles={Some(tooltip_styles)}>
-                    <Clip>
-                        <Text content={text} size={12.0} styles={Some(text_styles)} />
-                    </Clip>
-                </Background>
-            </If>
+            <Portal>
+                {VecTracker::from(tooltips.clone().into_iter().map(|tooltip| {
+                    let tooltip_size = tooltip.size.unwrap_or(default_size);
+                    let (left, top) = position_tooltip(&tooltip, tooltip_size, padding, position, size);
+                    // Tooltips are rendered in a portal, so they're positioned relative to the root
+                    let (left, top) = (left + position.0, top + position.1);
+                    let transition_styles = Style {
+                        position_type: StyleProp::Value(PositionType::SelfDirected),
+                        left: StyleProp::Value(Units::Pixels(left)),
+                        top: StyleProp::Value(Units::Pixels(top)),
+                        width: StyleProp::Value(Units::Pixels(tooltip_size.0)),
+                        height: StyleProp::Value(Units::Pixels(tooltip_size.1)),
+                        ..Style::default()
+                    };
+                    let consumer_styles = tooltip.styles.clone().unwrap_or_default();
+                    let tooltip_styles = Style::default()
+                        .with_style(Style {
+                            width: StyleProp::Value(Units::Pixels(tooltip_size.0)),
+                            height: StyleProp::Value(Units::Pixels(tooltip_size.1)),
+                            ..Style::default()
+                        })
+                        .with_style(&consumer_styles)
+                        .with_style(Style {
+                            background_color: background_color.clone(),
+                            ..Style::default()
+                        });
+                    let text_styles = Style {
+                        width: StyleProp::Value(Units::Pixels(tooltip_size.0)),
+                        height: StyleProp::Value(Units::Pixels(tooltip_size.1)),
+                        color: StyleProp::select(&[&consumer_styles.color, &color]).clone(),
+                        ..Style::default()
+                    };
+
+                    constructor! {
+                        <Transition visible={tooltip.visible} styles={Some(transition_styles)}>
+                            <TooltipSemantics tooltip={tooltip.clone()}>
+                                <Background styles={Some(tooltip_styles)}>
+                                    <Clip>
+                                        <Text content={tooltip.text.clone()} size={font_size} styles={Some(text_styles)} />
+                                    </Clip>
+                                </Background>
+                            </TooltipSemantics>
+                        </Transition>
+                    }
+                }))}
+            </Portal>
         </>
     }
 }
 
+/// The default width of a tooltip (in pixels)
+const WIDTH: f32 = 150.0;
+/// The default height of a tooltip (in pixels)
+const HEIGHT: f32 = 18.0;
+/// The default gap between a tooltip and its anchor (in pixels)
+const DEFAULT_OFFSET: (f32, f32) = (10.0, 5.0);
+/// The minimum time between moving a tooltip that follows the cursor (in seconds)
+const HOVER_INTERVAL: f32 = 1.0 / 60.0;
+/// The default time a tooltip stays visible after the cursor leaves its consumer (in seconds)
+const DEFAULT_HIDE_DELAY: f32 = 0.05;
+/// The key of the timeout used to hide a consumer's tooltip once the cursor leaves it
+const HIDE_TIMEOUT: &str = "hide_tooltip";
+
+/// Returns the position of a tooltip relative to the bounds of its provider
+///
+/// The anchor of the tooltip is given in window coordinates, so it's made relative to the
+/// provider's `position` first. The `padding` is used if the tooltip doesn't have its own offset.
+fn position_tooltip(
+    tooltip: &TooltipData,
+    tooltip_size: (f32, f32),
+    padding: (f32, f32),
+    position: (f32, f32),
+    size: (f32, f32),
+) -> (f32, f32) {
+    let anchor = (tooltip.anchor.0 - position.0, tooltip.anchor.1 - position.1);
+    let offset = tooltip.offset.unwrap_or(padding);
+
+    if let Some(placement) = tooltip.placement {
+        let (left, top) = match placement {
+            Placement::Top => (
+                anchor.0 - tooltip_size.0 / 2.0,
+                anchor.1 - tooltip_size.1 - offset.1,
+            ),
+            Placement::Bottom => (anchor.0 - tooltip_size.0 / 2.0, anchor.1 + offset.1),
+            Placement::Left => (
+                anchor.0 - tooltip_size.0 - offset.0,
+                anchor.1 - tooltip_size.1 / 2.0,
+            ),
+            Placement::Right => (anchor.0 + offset.0, anchor.1 - tooltip_size.1 / 2.0),
+        };
+        // Keep the tooltip within the bounds of the provider
+        return (
+            left.min(size.0 - tooltip_size.0).max(0.0),
+            top.min(size.1 - tooltip_size.1).max(0.0),
+        );
+    }
+
+    let left = if anchor.0 < size.0 / 2.0 {
+        anchor.0 + offset.0
+    } else {
+        // TODO: Replace with `right` (currently not working properly)
+        anchor.0 - tooltip_size.0
+    };
+
+    let top = if anchor.1 < size.1 / 2.0 {
+        anchor.1 + offset.1
+    } else {
+        // TODO: Replace with `bottom` (currently not working properly)
+        anchor.1 - tooltip_size.1
+    };
+
+    (left, top)
+}
+
 #[widget]
 /// A widget that consumes the [`TooltipData`] from a [`TooltipProvider`], providing a
 /// convenient way to apply a tooltip over its children.
 ///
+/// By default, the tooltip follows the cursor. Setting the [`anchor_mode`](TooltipConsumerProps::anchor_mode)
+/// to [`TooltipAnchor::Element`] instead places it on one side of this widget's measured layout.
+///
+/// The tooltip is also shown while a descendant of this widget is focused, so it's available when
+/// navigating with the keyboard. It's then anchored to this widget (above it, unless another
+/// [`Placement`] is given). For assistive technologies, this widget is a [`Role::Group`] and the
+/// tooltip is a [`Role::Tooltip`] that describes it. While shown by focus, the tooltip text is also
+/// the group's description, so it's announced along with the focused widget.
+///
+/// If [`pinnable`](TooltipConsumerProps::pinnable) is true, clicking this widget or its tooltip
+/// pins the tooltip open until the user clicks anywhere else (see [`TooltipData::pinned`]).
+///
 /// # Props
 ///
 /// __Type:__ [`TooltipConsumerProps`]
@@ -197,6 +574,7 @@ pub fn TooltipProvider(props: TooltipProviderProps) {
 /// | `children`  | ✅        |
 /// | `styles`    | ✅        |
 /// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
 /// | `focusable` | ❌        |
 ///
 /// # Examples
@@ -207,12 +585,15 @@ pub fn TooltipProvider(props: TooltipProviderProps) {
 /// fn MyWidget() {
 ///   rsx! {
 ///     <>
-///         <TooltipProvider size={Some(1280.0, 720.0)}>
+///         <TooltipProvider size={Some((1280.0, 720.0))}>
 ///             // ...
 ///             <TooltipConsumer text={"Tooltip A".to_string()}>
 ///                 // ...
 ///             </TooltipConsumer>
-///             <TooltipConsumer text={"Tooltip B".to_string()}>
+///             <TooltipConsumer
+///                 text={"Tooltip B".to_string()}
+///                 anchor_mode={TooltipAnchor::Element(Placement::Top)}
+///             >
 ///                 // ...
 ///             </TooltipConsumer>
 ///             // ...
@@ -223,7 +604,15 @@ pub fn TooltipProvider(props: TooltipProviderProps) {
 /// ```
 pub fn TooltipConsumer(props: TooltipConsumerProps) {
     let TooltipConsumerProps {
-        anchor, size, text, ..
+        anchor,
+        anchor_mode,
+        offset,
+        pinnable,
+        size,
+        text,
+        tooltip_styles,
+        on_layout,
+        ..
     } = props.clone();
     props.styles = Some(
         Style::default()
@@ -242,29 +631,284 @@ pub fn TooltipConsumer(props: TooltipConsumerProps) {
     let data = context
         .create_consumer::<TooltipData>()
         .expect("TooltipConsumer requires TooltipProvider as an ancestor");
+    let collection = context
+        .create_consumer::<TooltipCollection>()
+        .expect("TooltipConsumer requires TooltipProvider as an ancestor");
+    let press = context
+        .create_consumer::<TooltipPress>()
+        .expect("TooltipConsumer requires TooltipProvider as an ancestor");
+    let key = parent_id.unwrap_or_default();
+
+    // === Layout === //
+    let (layout, set_layout, ..) = use_state!(Layout::default());
+    props.on_layout = Some(OnLayout::new(move |ctx, evt| {
+        set_layout(evt.layout);
+        if let Some(ref on_layout) = on_layout {
+            on_layout.try_call(ctx, evt);
+        }
+    }));
+
+    let placement = match anchor_mode {
+        TooltipAnchor::Cursor => None,
+        TooltipAnchor::Element(placement) => Some(placement),
+    };
+    let element_anchor = placement.map(|placement| placement.anchor_of(&layout));
+
+    // === Hovering === //
+    // Pointer over events are sent on every mouse move, so only this consumer's anchor is
+    // throttled. It's merged into the current tooltip once applied, so it never overwrites changes
+    // made in the meantime (such as another consumer's tooltip being hidden).
+    let hover_anchor = context.create_state(Option::<(f32, f32)>::None).unwrap();
+    if let Some(anchor) = hover_anchor.get() {
+        hover_anchor.set(None);
+        if owned_tooltip(&data, &collection, key).map_or(false, |state| {
+            state.visible && !state.pinned && state.anchor != anchor
+        }) {
+            update_tooltip(&data, &collection, key, |state| state.anchor = anchor);
+        }
+    }
+
+    // === Focus === //
+    // The tooltip is also shown while a descendant is focused (such as when tabbing through a
+    // toolbar), in which case it's always placed next to this widget
+    let (is_focused, set_is_focused, ..) = use_state!(false);
+    let focus_placement = placement.unwrap_or_default();
+    let focus_anchor = focus_placement.anchor_of(&layout);
+    // While its tooltip is shown by focus, the text describes this widget for assistive technologies
+    let accessibility = Accessibility::new(Role::Group);
+    context.set_accessibility(Some(if is_focused {
+        accessibility.with_description(text.clone())
+    } else {
+        accessibility
+    }));
+
+    // === Pinning === //
+    let on_click_outside = pinnable.then(|| {
+        let (data, collection) = (data.clone(), collection.clone());
+        Handler::new(move |_| {
+            let state = match owned_tooltip(&data, &collection, key) {
+                Some(state) => state,
+                None => return,
+            };
+            if press.get().0 == Some(key) {
+                // The press landed on the tooltip itself, which pins it as well
+                press.set(TooltipPress::default());
+                update_tooltip(&data, &collection, key, |state| state.pinned = true);
+            } else if state.pinned {
+                update_tooltip(&data, &collection, key, |state| state.pinned = false);
+                hide_tooltip(&data, &collection, key);
+            }
+        })
+    });
+    context.on_click_outside(on_click_outside);
 
     let text = Arc::new(text);
-    props.on_event = Some(OnEvent::new(move |ctx, event| match event.event_type {
-        EventType::MouseIn(..) => {
-            let mut state = data.get();
+    props.on_event = Some(OnEvent::new(move |ctx, event| {
+        let show = |state: &mut TooltipData| {
             state.visible = true;
+            state.owner = Some(key);
             state.text = (*text).clone();
             state.size = size;
+            // Always replaced, so styles from a previous consumer never carry over
+            state.styles = tooltip_styles.clone();
+            state.placement = placement;
+            state.offset = offset;
+            if let Some(element_anchor) = element_anchor {
+                state.anchor = element_anchor;
+            }
+        };
+
+        match event.event_type {
+            EventType::FocusIn => {
+                set_is_focused(true);
+                ctx.cancel_throttled(&hover_anchor);
+                update_tooltip(&data, &collection, key, |state| {
+                    if state.pinned {
+                        return;
+                    }
+                    state.visible = true;
+                    state.owner = Some(key);
+                    state.text = (*text).clone();
+                    state.size = size;
+                    state.styles = tooltip_styles.clone();
+                    state.placement = Some(focus_placement);
+                    state.offset = offset;
+                    state.anchor = focus_anchor;
+                });
+            }
+            EventType::FocusOut => {
+                // Focus may have only moved to another descendant
+                if !ctx.contains_focus(event.current_target) {
+                    set_is_focused(false);
+                    hide_tooltip(&data, &collection, key);
+                }
+            }
+            EventType::PointerEnter(..) => {
+                ctx.clear_timeout(HIDE_TIMEOUT);
+                ctx.cancel_throttled(&hover_anchor);
+                update_tooltip(&data, &collection, key, |state| {
+                    if !state.pinned {
+                        show(state);
+                    }
+                });
+            }
+            EventType::Click(..) if pinnable => {
+                ctx.clear_timeout(HIDE_TIMEOUT);
+                // Pinned tooltips stay in place, so any held back anchor is dropped
+                ctx.cancel_throttled(&hover_anchor);
+                update_tooltip(&data, &collection, key, |state| {
+                    if state.owner != Some(key) {
+                        show(state);
+                    }
+                    state.pinned = true;
+                });
+            }
+            EventType::PointerOver(..) if element_anchor.is_none() => {
+                let anchor = anchor.unwrap_or(ctx.last_mouse_position());
+                ctx.set_throttled(&hover_anchor, Some(anchor), HOVER_INTERVAL);
+            }
+            EventType::PointerLeave(..) => {
+                // Make sure a held back anchor update doesn't show the tooltip again
+                ctx.cancel_throttled(&hover_anchor);
+                let hide_delay = collection.get().hide_delay;
+                if hide_delay > 0.0 {
+                    // Hiding is held back, so another consumer entered right after can take it
+                    // over
+                    let (data, collection) = (data.clone(), collection.clone());
+                    ctx.set_timeout(
+                        HIDE_TIMEOUT,
+                        hide_delay,
+                        Handler::new(move |_| hide_tooltip(&data, &collection, key)),
+                    );
+                } else {
+                    hide_tooltip(&data, &collection, key);
+                }
+            }
+            _ => {}
+        }
+    }));
+
+    rsx! {
+        <>
+            {children}
+        </>
+    }
+}
+
+/// Hides the tooltip owned by the consumer with the given key
+fn hide_tooltip(data: &Binding<TooltipData>, collection: &Binding<TooltipCollection>, key: Index) {
+    let mut tooltips = collection.get();
+    if tooltips.multiple {
+        if tooltips.get(key).map_or(false, |state| state.pinned) {
+            return;
+        }
+        tooltips.remove(key);
+        collection.set(tooltips);
+    } else {
+        let mut state = data.get();
+        // Only hide the tooltip if this consumer still owns it (and hasn't pinned it)
+        // Otherwise, it was picked up by another consumer and should be kept visible
+        if state.owner == Some(key) && !state.pinned {
+            state.visible = false;
             data.set(state);
         }
-        EventType::Hover(..) => {
-            let mut state = data.get();
-            state.anchor = anchor.unwrap_or(ctx.last_mouse_position());
+    }
+}
+
+/// Hides the tooltips whose consumer is no longer in the widget tree, even if they're pinned
+fn hide_unmounted(
+    context: &KayakContextRef,
+    data: &Binding<TooltipData>,
+    collection: &Binding<TooltipCollection>,
+) {
+    let mut tooltips = collection.get();
+    if tooltips.multiple {
+        let count = tooltips.tooltips.len();
+        tooltips
+            .tooltips
+            .retain(|(key, _)| context.is_mounted(*key));
+        if tooltips.tooltips.len() != count {
+            collection.set(tooltips);
+        }
+    } else {
+        let mut state = data.get();
+        if state.visible
+            && state
+                .owner
+                .map_or(false, |owner| !context.is_mounted(owner))
+        {
+            state.visible = false;
+            state.pinned = false;
             data.set(state);
         }
-        EventType::MouseOut(..) => {
-            let mut state = data.get();
-            // Set hidden only if the tooltip's text matches this consumer's
-            // Otherwise, it likely got picked up by another widget and should be kept visible
-            state.visible = false || state.text != *text;
+    }
+}
+
+/// Unpins and hides every pinned tooltip
+fn unpin_tooltips(data: &Binding<TooltipData>, collection: &Binding<TooltipCollection>) {
+    let mut tooltips = collection.get();
+    if tooltips.multiple {
+        let count = tooltips.tooltips.len();
+        tooltips.tooltips.retain(|(_, state)| !state.pinned);
+        if tooltips.tooltips.len() != count {
+            collection.set(tooltips);
+        }
+    } else {
+        let mut state = data.get();
+        if state.pinned {
+            state.visible = false;
+            state.pinned = false;
             data.set(state);
         }
-        _ => {}
+    }
+}
+
+/// Props used by the [`TooltipSemantics`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+struct TooltipSemanticsProps {
+    /// The tooltip being displayed
+    tooltip: TooltipData,
+    #[prop_field(Children)]
+    children: Option<Children>,
+    #[prop_field(OnEvent)]
+    on_event: Option<OnEvent>,
+}
+
+#[widget]
+/// Marks a displayed tooltip with the [`Role::Tooltip`] role
+///
+/// The tooltip is named by its text and describes its [`owner`](TooltipData::owner). Hidden
+/// tooltips (which are still rendered while fading out) are left out of the accessibility tree.
+///
+/// Presses on the tooltip are also tracked in the provider's [`TooltipPress`], so clicking a
+/// pinned tooltip doesn't unpin it.
+fn TooltipSemantics(props: TooltipSemanticsProps) {
+    let TooltipData {
+        owner,
+        text,
+        visible,
+        ..
+    } = props.tooltip.clone();
+
+    let press = context.create_consumer::<TooltipPress>();
+    if let (Some(press), Some(owner)) = (press, owner) {
+        props.on_event = Some(OnEvent::new(move |_, event| {
+            let pressed = match event.event_type {
+                EventType::MouseDown(..) if visible => Some(owner),
+                EventType::MouseDown(..) | EventType::MouseUp(..) => None,
+                _ => return,
+            };
+            if press.get().0 != pressed {
+                press.set(TooltipPress(pressed));
+            }
+        }));
+    }
+    context.set_accessibility(visible.then(|| {
+        let accessibility = Accessibility::new(Role::Tooltip).with_name(text);
+        match owner {
+            Some(owner) => accessibility.with_describes(owner),
+            None => accessibility,
+        }
     }));
 
     rsx! {
@@ -273,3 +917,96 @@ pub fn TooltipConsumer(props: TooltipConsumerProps) {
         </>
     }
 }
+
+/// Returns the tooltip owned by the consumer with the given key, if it has one
+fn owned_tooltip(
+    data: &Binding<TooltipData>,
+    collection: &Binding<TooltipCollection>,
+    key: Index,
+) -> Option<TooltipData> {
+    let tooltips = collection.get();
+    if tooltips.multiple {
+        tooltips.get(key).cloned()
+    } else {
+        Some(data.get()).filter(|state| state.owner == Some(key))
+    }
+}
+
+/// Updates the tooltip owned by the consumer with the given key
+///
+/// This updates the consumer's own entry in the [`TooltipCollection`] if the provider allows
+/// multiple tooltips, or the shared [`TooltipData`] otherwise.
+fn update_tooltip<F: FnOnce(&mut TooltipData)>(
+    data: &Binding<TooltipData>,
+    collection: &Binding<TooltipCollection>,
+    key: Index,
+    update: F,
+) {
+    let mut tooltips = collection.get();
+    if tooltips.multiple {
+        let mut state = tooltips.get(key).cloned().unwrap_or_default();
+        update(&mut state);
+        tooltips.insert(key, state);
+        collection.set(tooltips);
+    } else {
+        let mut state = data.get();
+        update(&mut state);
+        data.set(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TooltipConsumer, TooltipData, TooltipProvider, HOVER_INTERVAL};
+    use crate::core::{
+        render,
+        styles::{Style, StyleProp, Units},
+        widget, Binding, Bound, Index, KayakContext, TestHarness,
+    };
+    use crate::widgets::Element;
+
+    #[widget]
+    /// Exposes the tooltip of the nearest provider as a global, so it can be checked in tests
+    fn TooltipProbe() {
+        if let Some(tooltip) = context.create_consumer::<TooltipData>() {
+            context.set_global(tooltip);
+        }
+    }
+
+    #[test]
+    fn should_stay_pinned_once_held_back_hovers_are_applied() {
+        let mut context = KayakContext::new();
+        {
+            let context = &mut context;
+            let styles = Style {
+                width: StyleProp::Value(Units::Pixels(100.0)),
+                height: StyleProp::Value(Units::Pixels(100.0)),
+                ..Default::default()
+            };
+            render! {
+                <TooltipProvider size={Some((400.0, 400.0))}>
+                    <TooltipConsumer text={"Pinned".to_string()} pinnable={true}>
+                        <Element styles={Some(styles)}>
+                            <TooltipProbe />
+                        </Element>
+                    </TooltipConsumer>
+                </TooltipProvider>
+            }
+        }
+
+        let mut harness = TestHarness::new(context);
+        harness.hover(10.0, 10.0);
+        // Moving again right away holds back the new anchor
+        harness.click(20.0, 20.0);
+
+        std::thread::sleep(std::time::Duration::from_secs_f32(HOVER_INTERVAL));
+        harness.render();
+        let tooltip = harness
+            .context
+            .get_global::<Binding<TooltipData>>()
+            .unwrap()
+            .get();
+        assert!(tooltip.visible);
+        assert!(tooltip.pinned);
+    }
+}
@@ -0,0 +1,259 @@
+use crate::core::{
+    render_command::RenderCommand,
+    rsx,
+    styles::{Style, StyleProp, Units},
+    use_state, widget, Children, Handler, OnEvent, OnLayout, WidgetProps,
+};
+
+/// The default duration of a transition (in seconds)
+const DEFAULT_DURATION: f32 = 0.15;
+
+/// An animation played when a [`Transition`] is shown or hidden
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransitionPreset {
+    /// Shows or hides the content instantly
+    None,
+    /// Fades the content in from (or out to) fully transparent
+    Fade,
+    /// Slides the content in from (or out to) the given offset (in pixels)
+    Slide(f32, f32),
+    /// Combines [`Fade`](Self::Fade) and [`Slide`](Self::Slide)
+    FadeSlide(f32, f32),
+}
+
+impl Default for TransitionPreset {
+    fn default() -> Self {
+        Self::Fade
+    }
+}
+
+impl TransitionPreset {
+    /// Returns the styles applied to a transition that is `amount` of the way to being fully shown
+    ///
+    /// Slides are applied on top of the `left` and `top` of the given styles.
+    fn styles(&self, amount: f32, base: &Style) -> Style {
+        let slide = |base: &StyleProp<Units>, offset: f32| match base {
+            StyleProp::Value(Units::Pixels(value)) => {
+                StyleProp::Value(Units::Pixels(value + offset * (1.0 - amount)))
+            }
+            StyleProp::Value(_) => base.clone(),
+            _ => StyleProp::Value(Units::Pixels(offset * (1.0 - amount))),
+        };
+
+        match *self {
+            Self::None => Style::default(),
+            Self::Fade => Style {
+                opacity: StyleProp::Value(amount),
+                ..Default::default()
+            },
+            Self::Slide(x, y) => Style {
+                left: slide(&base.left, x),
+                top: slide(&base.top, y),
+                ..Default::default()
+            },
+            Self::FadeSlide(x, y) => Style {
+                opacity: StyleProp::Value(amount),
+                left: slide(&base.left, x),
+                top: slide(&base.top, y),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// The current phase of a [`Transition`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+///
+/// The animating phases hold the time spent in them so far (in seconds).
+enum TransitionPhase {
+    Hidden,
+    Entering(f32),
+    Visible,
+    Exiting(f32),
+}
+
+/// Props used by the [`Transition`] widget
+#[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
+pub struct TransitionProps {
+    /// If true, the children will be shown, otherwise they will be hidden
+    pub visible: bool,
+    /// The animation played when the children are shown
+    ///
+    /// Defaults to [`TransitionPreset::Fade`].
+    pub enter: TransitionPreset,
+    /// The animation played when the children are hidden
+    ///
+    /// Defaults to the [`enter`](Self::enter) animation.
+    pub exit: Option<TransitionPreset>,
+    /// The duration of each animation (in seconds)
+    ///
+    /// Defaults to 0.15 seconds.
+    pub duration: Option<f32>,
+    #[prop_field(Styles)]
+    pub styles: Option<Style>,
+    #[prop_field(Children)]
+    pub children: Option<Children>,
+    #[prop_field(OnEvent)]
+    pub on_event: Option<OnEvent>,
+    #[prop_field(OnLayout)]
+    pub on_layout: Option<OnLayout>,
+}
+
+#[widget]
+/// A widget that animates its children as they are shown and hidden
+///
+/// This works like [`If`](crate::widgets::If), except that the children are animated in when
+/// [`visible`](TransitionProps::visible) becomes true and animated out when it becomes false. While
+/// the exit animation plays, the children are kept mounted and only removed once it completes.
+/// Toggling `visible` in the middle of an animation reverses it from its current point.
+///
+/// Only the children are kept mounted during the exit animation, so the `Transition` itself must
+/// stay in the tree. Wrapping it in an `If` (or otherwise removing it) unmounts it immediately,
+/// without playing the exit animation, so use `visible` to hide the children instead.
+///
+/// The animation is advanced on every [tick](crate::core::KayakContextRef::on_tick). If the user
+/// [prefers reduced motion](crate::core::KayakContextRef::prefers_reduced_motion), the children
+/// are shown and hidden immediately instead.
+///
+/// # Props
+///
+/// __Type:__ [`TransitionProps`]
+///
+/// | Common Prop | Accepted |
+/// | :---------: | :------: |
+/// | `children`  | ✅        |
+/// | `styles`    | ✅        |
+/// | `on_event`  | ✅        |
+/// | `on_layout` | ✅        |
+/// | `focusable` | ❌        |
+///
+/// # Styles
+///
+/// This widget accepts all styles and affects the container wrapping the children. Slide animations
+/// offset its `left` and `top` styles (when given in pixels), and fade animations set its `opacity`.
+///
+/// # Examples
+///
+/// ```
+/// # use kayak_ui::core::{rsx, widget, use_state};
+/// # use kayak_ui::widgets::{Text, Transition, TransitionPreset};
+///
+/// #[widget]
+/// fn MyWidget() {
+///   let (is_open, ..) = use_state!(true);
+///   rsx! {
+///     <Transition visible={is_open} enter={TransitionPreset::FadeSlide(0.0, -10.0)}>
+///         <Text content={"Hello!".into()} />
+///     </Transition>
+///   }
+/// }
+/// ```
+pub fn Transition(props: TransitionProps) {
+    let TransitionProps {
+        visible,
+        enter,
+        exit,
+        duration,
+        ..
+    } = props.clone();
+    let duration = if context.prefers_reduced_motion() {
+        0.0
+    } else {
+        duration.unwrap_or(DEFAULT_DURATION).max(0.0)
+    };
+    let exit = exit.unwrap_or(enter);
+
+    let (phase, set_phase, ..) = use_state!(if visible {
+        TransitionPhase::Entering(0.0)
+    } else {
+        TransitionPhase::Hidden
+    });
+
+    let progress = |elapsed: f32| {
+        if duration > 0.0 {
+            (elapsed / duration).min(1.0)
+        } else {
+            1.0
+        }
+    };
+    // Starts a new animation that picks up where the current one (at the given progress) left off
+    let reverse = |progress: f32| (1.0 - progress) * duration;
+
+    let next_phase = match phase {
+        TransitionPhase::Hidden if visible => TransitionPhase::Entering(0.0),
+        TransitionPhase::Visible if !visible => TransitionPhase::Exiting(0.0),
+        TransitionPhase::Entering(start) if !visible => {
+            TransitionPhase::Exiting(reverse(progress(start)))
+        }
+        TransitionPhase::Exiting(start) if visible => {
+            TransitionPhase::Entering(reverse(progress(start)))
+        }
+        TransitionPhase::Entering(start) if progress(start) >= 1.0 => TransitionPhase::Visible,
+        TransitionPhase::Exiting(start) if progress(start) >= 1.0 => TransitionPhase::Hidden,
+        phase => phase,
+    };
+    if next_phase != phase {
+        set_phase(next_phase);
+    }
+    let tick_set_phase = set_phase.clone();
+    context.on_tick(match next_phase {
+        TransitionPhase::Entering(elapsed) => Some(Handler::new(move |delta_time: f32| {
+            tick_set_phase(TransitionPhase::Entering(elapsed + delta_time))
+        })),
+        TransitionPhase::Exiting(elapsed) => Some(Handler::new(move |delta_time: f32| {
+            tick_set_phase(TransitionPhase::Exiting(elapsed + delta_time))
+        })),
+        _ => None,
+    });
+
+    // The styles applied below are written back into the props, so the styles given by the parent
+    // are kept separately to avoid compounding slides every time this widget re-renders itself
+    let (styles, set_styles, ..) = use_state!((Option::<Style>::None, Option::<Style>::None));
+    let (given_styles, applied_styles) = styles;
+    let base_styles = if props.styles == applied_styles {
+        given_styles.clone()
+    } else {
+        props.styles.clone()
+    };
+    let (preset, amount) = match next_phase {
+        TransitionPhase::Hidden => (TransitionPreset::None, 0.0),
+        TransitionPhase::Visible => (enter, 1.0),
+        TransitionPhase::Entering(start) => (enter, ease_out(progress(start))),
+        TransitionPhase::Exiting(start) => (exit, ease_out(1.0 - progress(start))),
+    };
+
+    props.styles = if next_phase == TransitionPhase::Hidden {
+        Some(Style {
+            render_command: StyleProp::Value(RenderCommand::Empty),
+            ..Default::default()
+        })
+    } else {
+        let base_styles = base_styles.clone().unwrap_or_default();
+        Some(
+            Style::default()
+                .with_style(Style {
+                    render_command: StyleProp::Value(RenderCommand::Layout),
+                    ..Default::default()
+                })
+                // The animated styles are applied on top of the given ones
+                .with_style(preset.styles(amount, &base_styles))
+                .with_style(&base_styles),
+        )
+    };
+    if (&base_styles, &props.styles) != (&given_styles, &applied_styles) {
+        set_styles((base_styles, props.styles.clone()));
+    }
+
+    if next_phase != TransitionPhase::Hidden {
+        rsx! {
+            <>
+                {children}
+            </>
+        }
+    }
+}
+
+/// Eases the given progress (from `0.0` to `1.0`) so that animations settle gently
+pub(crate) fn ease_out(progress: f32) -> f32 {
+    1.0 - (1.0 - progress).powi(3)
+}
@@ -1,22 +1,60 @@
 use crate::core::{
-    color::Color,
+    constructor,
     render_command::RenderCommand,
     rsx,
-    styles::{Corner, Edge, PositionType, Style, StyleProp, Units},
-    use_state, widget, Children, EventType, OnEvent, WidgetProps,
+    styles::{Corner, Edge, LayoutType, PositionType, Style, StyleProp, Units},
+    use_state, widget, Accessibility, Children, EventType, Handler, OnChange, OnEvent, Role,
+    VecTracker, WidgetProps,
 };
 use kayak_core::{CursorIcon, OnLayout};
 
-use crate::widgets::{Background, Clip, Element, Text};
+use crate::widgets::{use_theme, Background, Button, Clip, Element, If, Text};
+
+/// The smallest size a window can be resized to (in pixels)
+const MIN_SIZE: (f32, f32) = (100.0, 50.0);
+/// The thickness of the zones along the edges of a window used to resize it (in pixels)
+const RESIZE_HANDLE_SIZE: f32 = 6.0;
+/// How close to an edge of its parent a window must be released to snap to it (in pixels)
+const SNAP_DISTANCE: f32 = 20.0;
+/// The duration of the animation played when a window snaps to an edge (in seconds)
+const SNAP_DURATION: f32 = 0.15;
+/// The height of a window's title bar (in pixels)
+const TITLE_BAR_HEIGHT: f32 = 24.0;
+/// The height of a minimized window, which only shows its title bar (in pixels)
+///
+/// This is the height of the title bar, plus the window's border and padding around it.
+const MINIMIZED_HEIGHT: f32 = TITLE_BAR_HEIGHT + 2.0 * 4.0 + 2.0 * 5.0;
 
 /// Props used by the [`Window`] widget
 #[derive(WidgetProps, Default, Debug, PartialEq, Clone)]
 pub struct WindowProps {
     /// If true, allows the window to be draggable by its title bar
     pub draggable: bool,
-    /// The position at which to display the window in pixels
+    /// If true, allows the window to be resized by dragging its edges and corners
+    pub resizable: bool,
+    /// If true, a dragged window released near an edge of its parent docks to fill that half (or
+    /// quadrant, near a corner) of the parent
+    pub snap: bool,
+    /// A callback fired whenever the window is docked or undocked
+    pub on_dock: Option<OnChange<WindowDock>>,
+    /// If true, renders minimize and maximize/restore buttons in the title bar, as well as a close
+    /// button if [`on_close`](Self::on_close) is given
+    pub controls: bool,
+    /// A callback fired with whether the window is minimized whenever it's minimized or restored
+    pub on_minimize: Option<OnChange<bool>>,
+    /// A callback fired with whether the window is maximized whenever it's maximized or restored
+    pub on_maximize: Option<OnChange<bool>>,
+    /// A callback fired when the close button is clicked
+    ///
+    /// The window doesn't remove itself, so this should be used to stop rendering it.
+    pub on_close: Option<Handler>,
+    /// A callback fired with the new position of the window whenever it's dragged or resized
+    pub on_move: Option<OnChange<(f32, f32)>>,
+    /// A callback fired with the new size of the window whenever it's resized
+    pub on_resize: Option<OnChange<(f32, f32)>>,
+    /// The initial position at which to display the window in pixels
     pub position: (f32, f32),
-    /// The size of the window in pixels
+    /// The initial size of the window in pixels
     pub size: (f32, f32),
     /// The text to display in the window's title bar
     pub title: String,
@@ -32,9 +70,228 @@ pub struct WindowProps {
     pub focusable: Option<bool>,
 }
 
+/// The area of its parent that a [`Window`] is docked to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowDock {
+    /// The window isn't docked
+    Floating,
+    /// The left half of the parent
+    Left,
+    /// The right half of the parent
+    Right,
+    /// The top half of the parent
+    Top,
+    /// The bottom half of the parent
+    Bottom,
+    /// The top-left quadrant of the parent
+    TopLeft,
+    /// The top-right quadrant of the parent
+    TopRight,
+    /// The bottom-left quadrant of the parent
+    BottomLeft,
+    /// The bottom-right quadrant of the parent
+    BottomRight,
+}
+
+impl Default for WindowDock {
+    fn default() -> Self {
+        Self::Floating
+    }
+}
+
+impl WindowDock {
+    /// Returns the dock for a window released with the cursor at the given position, relative to a
+    /// parent of the given size
+    fn at(cursor: (f32, f32), bounds: (f32, f32)) -> Self {
+        let left = cursor.0 <= SNAP_DISTANCE;
+        let top = cursor.1 <= SNAP_DISTANCE;
+        let right = cursor.0 >= bounds.0 - SNAP_DISTANCE;
+        let bottom = cursor.1 >= bounds.1 - SNAP_DISTANCE;
+        match (left, top, right, bottom) {
+            (true, true, ..) => Self::TopLeft,
+            (_, true, true, _) => Self::TopRight,
+            (true, _, _, true) => Self::BottomLeft,
+            (_, _, true, true) => Self::BottomRight,
+            (true, ..) => Self::Left,
+            (_, true, ..) => Self::Top,
+            (_, _, true, _) => Self::Right,
+            (.., true) => Self::Bottom,
+            _ => Self::Floating,
+        }
+    }
+
+    /// Returns the position and size of a window docked this way within a parent of the given size
+    ///
+    /// Returns `None` for [`WindowDock::Floating`].
+    pub fn rect(&self, bounds: (f32, f32)) -> Option<((f32, f32), (f32, f32))> {
+        let (width, height) = bounds;
+        let (half_width, half_height) = (width / 2.0, height / 2.0);
+        let rect = match self {
+            Self::Floating => return None,
+            Self::Left => ((0.0, 0.0), (half_width, height)),
+            Self::Right => ((half_width, 0.0), (half_width, height)),
+            Self::Top => ((0.0, 0.0), (width, half_height)),
+            Self::Bottom => ((0.0, half_height), (width, half_height)),
+            Self::TopLeft => ((0.0, 0.0), (half_width, half_height)),
+            Self::TopRight => ((half_width, 0.0), (half_width, half_height)),
+            Self::BottomLeft => ((0.0, half_height), (half_width, half_height)),
+            Self::BottomRight => ((half_width, half_height), (half_width, half_height)),
+        };
+        Some(rect)
+    }
+}
+
+/// The edges of a [`Window`] moved by one of its resize handles
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct ResizeEdges {
+    left: bool,
+    top: bool,
+    right: bool,
+    bottom: bool,
+}
+
+impl ResizeEdges {
+    /// The edges of every resize handle: the four sides followed by the four corners
+    const HANDLES: [Self; 8] = [
+        Self::new(true, false, false, false),
+        Self::new(false, true, false, false),
+        Self::new(false, false, true, false),
+        Self::new(false, false, false, true),
+        Self::new(true, true, false, false),
+        Self::new(false, true, true, false),
+        Self::new(false, false, true, true),
+        Self::new(true, false, false, true),
+    ];
+
+    const fn new(left: bool, top: bool, right: bool, bottom: bool) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// Returns the cursor shown while hovering the handle for these edges
+    fn cursor(&self) -> CursorIcon {
+        match (self.left, self.top, self.right, self.bottom) {
+            (true, true, ..) | (_, _, true, true) => CursorIcon::NwseResize,
+            (_, true, true, _) | (true, _, _, true) => CursorIcon::NeswResize,
+            (true, ..) | (_, _, true, _) => CursorIcon::EwResize,
+            _ => CursorIcon::NsResize,
+        }
+    }
+
+    /// Returns the styles placing the handle for these edges within the window
+    fn styles(&self) -> Style {
+        let handle = Units::Pixels(RESIZE_HANDLE_SIZE);
+        // Along each axis, a handle either hugs one side or spans the edge between the corners
+        let place = |start: bool, end: bool| match (start, end) {
+            (true, _) => (Units::Pixels(0.0), handle, Units::Stretch(1.0)),
+            (_, true) => (Units::Stretch(1.0), handle, Units::Pixels(0.0)),
+            _ => (handle, Units::Stretch(1.0), handle),
+        };
+        let (left, width, right) = place(self.left, self.right);
+        let (top, height, bottom) = place(self.top, self.bottom);
+
+        Style {
+            cursor: StyleProp::Value(self.cursor()),
+            position_type: StyleProp::Value(PositionType::SelfDirected),
+            left: StyleProp::Value(left),
+            width: StyleProp::Value(width),
+            right: StyleProp::Value(right),
+            top: StyleProp::Value(top),
+            height: StyleProp::Value(height),
+            bottom: StyleProp::Value(bottom),
+            ..Style::default()
+        }
+    }
+}
+
+/// An in-progress resize of a [`Window`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ResizeDrag {
+    edges: ResizeEdges,
+    /// The position of the cursor when the resize started
+    start_mouse: (f32, f32),
+    /// The position of the window when the resize started
+    start_pos: (f32, f32),
+    /// The size of the window when the resize started
+    start_size: (f32, f32),
+}
+
+impl ResizeDrag {
+    /// Returns the position and size of the window with the cursor at the given position
+    ///
+    /// The window is kept at or above its minimum size and, if given, within the bounds of its parent.
+    fn resize(&self, mouse: (f32, f32), bounds: Option<(f32, f32)>) -> ((f32, f32), (f32, f32)) {
+        let (x, width) = Self::resize_axis(
+            self.start_pos.0,
+            self.start_size.0,
+            mouse.0 - self.start_mouse.0,
+            (self.edges.left, self.edges.right),
+            MIN_SIZE.0,
+            bounds.map(|bounds| bounds.0),
+        );
+        let (y, height) = Self::resize_axis(
+            self.start_pos.1,
+            self.start_size.1,
+            mouse.1 - self.start_mouse.1,
+            (self.edges.top, self.edges.bottom),
+            MIN_SIZE.1,
+            bounds.map(|bounds| bounds.1),
+        );
+        ((x, y), (width, height))
+    }
+
+    fn resize_axis(
+        start: f32,
+        size: f32,
+        delta: f32,
+        (is_start, is_end): (bool, bool),
+        min_size: f32,
+        bound: Option<f32>,
+    ) -> (f32, f32) {
+        let end = start + size;
+        if is_start {
+            let mut start = (start + delta).min(end - min_size);
+            if bound.is_some() {
+                start = start.max(0.0);
+            }
+            (start, end - start)
+        } else if is_end {
+            let mut end = (end + delta).max(start + min_size);
+            if let Some(bound) = bound {
+                end = end.min(bound.max(start + min_size));
+            }
+            (start, end - start)
+        } else {
+            (start, size)
+        }
+    }
+}
+
 #[widget]
 /// A widget that renders a window-like container element
 ///
+/// If [`draggable`](WindowProps::draggable) is true, the window can be moved by dragging its title
+/// bar. If [`resizable`](WindowProps::resizable) is true, it can be resized by dragging its edges
+/// and corners, down to a minimum size of 100x50 pixels. In both cases, the window is kept within
+/// the bounds of its parent.
+///
+/// If [`snap`](WindowProps::snap) is also true, releasing the title bar near an edge of the parent
+/// docks the window to that half of the parent (or quadrant, near a corner), animating it into
+/// place (unless the user
+/// [prefers reduced motion](crate::core::KayakContextRef::prefers_reduced_motion)). Dragging a
+/// docked window away restores its floating size.
+///
+/// If [`controls`](WindowProps::controls) is true, the title bar contains buttons to minimize the
+/// window (collapsing it to its title bar), maximize it to fill its parent, and restore it to its
+/// exact rect from before it was maximized. A maximized window can't be dragged or resized.
+///
+/// The `position` and `size` props only set the initial rect of the window. Use the `on_move` and
+/// `on_resize` callbacks to track it as it's moved and resized.
+///
 /// # Props
 ///
 /// __Type:__ [`WindowProps`]
@@ -50,30 +307,134 @@ pub struct WindowProps {
 pub fn Window(props: WindowProps) {
     let WindowProps {
         draggable,
+        resizable,
+        snap,
+        on_dock,
+        controls,
+        on_minimize,
+        on_maximize,
+        on_close,
+        on_move,
+        on_resize,
         position,
         size,
         title,
         ..
     } = props.clone();
+    context.set_accessibility(Some(
+        Accessibility::new(Role::Window).with_name(title.clone()),
+    ));
 
     let (is_dragging, set_is_dragging, ..) = use_state!(false);
     let (offset, set_offset, ..) = use_state!((0.0, 0.0));
+    // The position of the window when the current drag started
+    let (drag_start, set_drag_start, ..) = use_state!(position);
     let (pos, set_pos, ..) = use_state!(position);
+    let (size, set_size, ..) = use_state!(size);
+    let (resizing, set_resizing, ..) = use_state!(Option::<ResizeDrag>::None);
+
+    let (docked, set_docked, ..) = use_state!(WindowDock::Floating);
+    // The size of the window before it was docked
+    let (floating_size, set_floating_size, ..) = use_state!(size);
+    // The time spent in the current snap animation, and the position and size it started from
+    let (snap_animation, set_snap_animation, ..) =
+        use_state!(Option::<(f32, (f32, f32), (f32, f32))>::None);
+
+    // The parent, which the window is kept within
+    let parent_layout = parent_id
+        .and_then(|id| context.get_valid_parent(id))
+        .and_then(|parent| context.get_layout(&parent))
+        .copied();
+    let bounds = parent_layout.map(|layout| (layout.width, layout.height));
+
+    let (is_minimized, set_is_minimized, ..) = use_state!(false);
+    let (is_maximized, set_is_maximized, ..) = use_state!(false);
+    // The position and size of the window before it was maximized
+    let (restore_rect, set_restore_rect, ..) = use_state!((pos, size));
 
     let drag_handler = if draggable {
+        let set_pos = set_pos.clone();
+        let set_size = set_size.clone();
+        let set_snap_animation = set_snap_animation.clone();
+        let on_move = on_move.clone();
+        let on_resize = on_resize.clone();
         Some(OnEvent::new(move |ctx, event| match event.event_type {
             EventType::MouseDown(data) => {
-                ctx.capture_cursor(event.current_target);
+                if is_maximized {
+                    return;
+                }
+                ctx.capture_pointer();
                 set_is_dragging(true);
+                set_drag_start(pos);
                 set_offset((pos.0 - data.position.0, pos.1 - data.position.1));
             }
-            EventType::MouseUp(..) => {
-                ctx.release_cursor(event.current_target);
+            EventType::MouseUp(data) => {
                 set_is_dragging(false);
+                if !is_dragging || pos == drag_start {
+                    // Only a window that was actually dragged snaps, not one that was just clicked
+                    return;
+                }
+
+                let parent_layout = match parent_layout {
+                    Some(parent_layout) if snap => parent_layout,
+                    _ => return,
+                };
+                let cursor = (
+                    data.position.0 - parent_layout.posx,
+                    data.position.1 - parent_layout.posy,
+                );
+                let dock = WindowDock::at(cursor, (parent_layout.width, parent_layout.height));
+                if let Some((dock_pos, dock_size)) =
+                    dock.rect((parent_layout.width, parent_layout.height))
+                {
+                    if docked == WindowDock::Floating {
+                        set_floating_size(size);
+                    }
+                    set_snap_animation(Some((0.0, pos, size)));
+                    set_pos(dock_pos);
+                    set_size(dock_size);
+                    set_docked(dock);
+                    if let Some(ref on_dock) = on_dock {
+                        on_dock.call(dock);
+                    }
+                    if let Some(ref on_move) = on_move {
+                        on_move.call(dock_pos);
+                    }
+                    if let Some(ref on_resize) = on_resize {
+                        on_resize.call(dock_size);
+                    }
+                }
             }
             EventType::Hover(data) => {
                 if is_dragging {
-                    set_pos((offset.0 + data.position.0, offset.1 + data.position.1));
+                    let (mut offset, mut size) = (offset, size);
+                    if docked != WindowDock::Floating {
+                        // Dragging away restores the floating size, keeping the cursor at the same
+                        // relative point along the title bar
+                        offset.0 *= floating_size.0 / size.0.max(1.0);
+                        size = floating_size;
+                        set_offset(offset);
+                        set_size(size);
+                        set_docked(WindowDock::Floating);
+                        if let Some(ref on_dock) = on_dock {
+                            on_dock.call(WindowDock::Floating);
+                        }
+                        if let Some(ref on_resize) = on_resize {
+                            on_resize.call(size);
+                        }
+                    }
+
+                    let mut next_pos = (offset.0 + data.position.0, offset.1 + data.position.1);
+                    if let Some(bounds) = bounds {
+                        next_pos.0 = next_pos.0.min(bounds.0 - size.0).max(0.0);
+                        next_pos.1 = next_pos.1.min(bounds.1 - size.1).max(0.0);
+                    }
+                    if next_pos != pos {
+                        set_pos(next_pos);
+                        if let Some(ref on_move) = on_move {
+                            on_move.call(next_pos);
+                        }
+                    }
                 }
             }
             _ => {}
@@ -82,19 +443,112 @@ pub fn Window(props: WindowProps) {
         None
     };
 
+    let mut window_controls: Vec<(&'static str, Handler)> = Vec::new();
+    if controls {
+        let set_is_minimized = set_is_minimized.clone();
+        let minimize = Handler::new(move |_| {
+            set_is_minimized(!is_minimized);
+            if let Some(ref on_minimize) = on_minimize {
+                on_minimize.call(!is_minimized);
+            }
+        });
+        window_controls.push(("_", minimize));
+
+        let set_pos = set_pos.clone();
+        let set_size = set_size.clone();
+        let set_snap_animation = set_snap_animation.clone();
+        let on_move = on_move.clone();
+        let on_resize = on_resize.clone();
+        let toggle_maximize = Handler::new(move |_| {
+            let (next_pos, next_size) = if is_maximized {
+                restore_rect
+            } else if let Some(bounds) = bounds {
+                set_restore_rect((pos, size));
+                ((0.0, 0.0), bounds)
+            } else {
+                return;
+            };
+
+            set_snap_animation(Some((0.0, pos, size)));
+            set_pos(next_pos);
+            set_size(next_size);
+            set_is_maximized(!is_maximized);
+            set_is_minimized(false);
+            if let Some(ref on_maximize) = on_maximize {
+                on_maximize.call(!is_maximized);
+            }
+            if let Some(ref on_move) = on_move {
+                on_move.call(next_pos);
+            }
+            if let Some(ref on_resize) = on_resize {
+                on_resize.call(next_size);
+            }
+        });
+        window_controls.push((if is_maximized { "v" } else { "^" }, toggle_maximize));
+
+        if let Some(on_close) = on_close {
+            window_controls.push(("x", on_close));
+        }
+    }
+
+    // While snapping, the window is displayed between its previous and docked rects
+    let has_motion = !context.prefers_reduced_motion();
+    let mut is_snapping = false;
+    let (display_pos, mut display_size) = match snap_animation {
+        Some((elapsed, from_pos, from_size)) => {
+            let progress = if has_motion {
+                (elapsed / SNAP_DURATION).min(1.0)
+            } else {
+                1.0
+            };
+            if progress >= 1.0 {
+                set_snap_animation(None);
+            } else {
+                is_snapping = true;
+            }
+            let amount = 1.0 - (1.0 - progress).powi(3);
+            let lerp = |from: (f32, f32), to: (f32, f32)| {
+                (
+                    from.0 + (to.0 - from.0) * amount,
+                    from.1 + (to.1 - from.1) * amount,
+                )
+            };
+            (lerp(from_pos, pos), lerp(from_size, size))
+        }
+        None => (pos, size),
+    };
+    let tick_set_snap_animation = set_snap_animation.clone();
+    context.on_tick(is_snapping.then(|| {
+        Handler::new(move |delta_time: f32| {
+            if let Some((elapsed, from_pos, from_size)) = snap_animation {
+                tick_set_snap_animation(Some((elapsed + delta_time, from_pos, from_size)));
+            }
+        })
+    }));
+    if is_minimized {
+        display_size.1 = MINIMIZED_HEIGHT;
+    }
+
+    let resize_handles = if resizable && !is_minimized && !is_maximized {
+        ResizeEdges::HANDLES.to_vec()
+    } else {
+        Vec::new()
+    };
+
+    let theme = use_theme(context).unwrap_or_default();
     props.styles = Some(Style {
-        background_color: StyleProp::Value(Color::new(0.125, 0.125, 0.125, 1.0)),
-        border_color: StyleProp::Value(Color::new(0.0781, 0.0898, 0.101, 1.0)),
-        border: StyleProp::Value(Edge::all(4.0)),
-        border_radius: StyleProp::Value(Corner::all(5.0)),
+        background_color: StyleProp::Value(theme.background),
+        border_color: StyleProp::Value(theme.border),
+        border: StyleProp::Value(Edge::all(Units::Pixels(4.0))),
+        border_radius: StyleProp::Value(Corner::all(theme.radius)),
         render_command: StyleProp::Value(RenderCommand::Quad),
         position_type: StyleProp::Value(PositionType::SelfDirected),
-        left: StyleProp::Value(Units::Pixels(pos.0)),
-        top: StyleProp::Value(Units::Pixels(pos.1)),
-        width: StyleProp::Value(Units::Pixels(size.0)),
-        height: StyleProp::Value(Units::Pixels(size.1)),
-        max_width: StyleProp::Value(Units::Pixels(size.0)),
-        max_height: StyleProp::Value(Units::Pixels(size.1)),
+        left: StyleProp::Value(Units::Pixels(display_pos.0)),
+        top: StyleProp::Value(Units::Pixels(display_pos.1)),
+        width: StyleProp::Value(Units::Pixels(display_size.0)),
+        height: StyleProp::Value(Units::Pixels(display_size.1)),
+        max_width: StyleProp::Value(Units::Pixels(display_size.0)),
+        max_height: StyleProp::Value(Units::Pixels(display_size.1)),
         ..props.styles.clone().unwrap_or_default()
     });
 
@@ -102,8 +556,8 @@ pub fn Window(props: WindowProps) {
         padding: StyleProp::Value(Edge::all(Units::Pixels(5.0))),
         width: StyleProp::Value(Units::Stretch(1.0)),
         height: StyleProp::Value(Units::Stretch(1.0)),
-        max_width: StyleProp::Value(Units::Pixels(size.0)),
-        max_height: StyleProp::Value(Units::Pixels(size.1)),
+        max_width: StyleProp::Value(Units::Pixels(display_size.0)),
+        max_height: StyleProp::Value(Units::Pixels(display_size.1)),
         ..Style::default()
     };
 
@@ -118,10 +572,11 @@ pub fn Window(props: WindowProps) {
     };
 
     let title_background_styles = Style {
-        background_color: StyleProp::Value(Color::new(0.0781, 0.0898, 0.101, 1.0)),
-        border_radius: StyleProp::Value(Corner::all(5.0)),
+        background_color: StyleProp::Value(theme.surface),
+        border_radius: StyleProp::Value(Corner::all(theme.radius)),
         cursor: cursor.into(),
-        height: StyleProp::Value(Units::Pixels(24.0)),
+        height: StyleProp::Value(Units::Pixels(TITLE_BAR_HEIGHT)),
+        layout_type: StyleProp::Value(LayoutType::Row),
         width: StyleProp::Value(Units::Stretch(1.0)),
         left: StyleProp::Value(Units::Pixels(0.0)),
         right: StyleProp::Value(Units::Pixels(0.0)),
@@ -132,7 +587,25 @@ pub fn Window(props: WindowProps) {
     };
 
     let title_text_styles = Style {
+        color: StyleProp::Value(theme.text),
         height: StyleProp::Value(Units::Pixels(25.0)),
+        width: StyleProp::Value(Units::Stretch(1.0)),
+        cursor: StyleProp::Inherit,
+        ..Style::default()
+    };
+
+    let control_styles = Style {
+        border_radius: StyleProp::Value(Corner::all(theme.radius)),
+        height: StyleProp::Value(Units::Pixels(18.0)),
+        width: StyleProp::Value(Units::Pixels(18.0)),
+        top: StyleProp::Value(Units::Stretch(1.0)),
+        bottom: StyleProp::Value(Units::Stretch(1.0)),
+        right: StyleProp::Value(Units::Pixels(4.0)),
+        ..Style::default()
+    };
+
+    let control_text_styles = Style {
+        color: StyleProp::Value(theme.text),
         cursor: StyleProp::Inherit,
         ..Style::default()
     };
@@ -144,13 +617,77 @@ pub fn Window(props: WindowProps) {
 
     let title = title.clone();
     rsx! {
-        <Clip styles={Some(clip_styles)}>
-            <Background on_event={drag_handler} styles={Some(title_background_styles)}>
-                <Text styles={Some(title_text_styles)} size={16.0} content={title} />
-            </Background>
-            <Element styles={Some(content_styles)}>
-                {children}
-            </Element>
-        </Clip>
+        <>
+            <Clip styles={Some(clip_styles)}>
+                <Background on_event={drag_handler} styles={Some(title_background_styles)}>
+                    <Text styles={Some(title_text_styles)} size={16.0} content={title} />
+                    {VecTracker::from(window_controls.into_iter().map(|(label, on_click)| {
+                        let control_events = OnEvent::new(move |_, event| match event.event_type {
+                            // Keep the title bar from starting (or ending) a drag
+                            EventType::MouseDown(..) | EventType::MouseUp(..) => {
+                                event.stop_propagation()
+                            }
+                            EventType::Click(..) => {
+                                event.stop_propagation();
+                                on_click.call(());
+                            }
+                            _ => {}
+                        });
+
+                        constructor! {
+                            <Button styles={Some(control_styles.clone())} on_event={Some(control_events)}>
+                                <Text styles={Some(control_text_styles.clone())} size={14.0} content={label.to_string()} />
+                            </Button>
+                        }
+                    }))}
+                </Background>
+                <If condition={!is_minimized}>
+                    <Element styles={Some(content_styles)}>
+                        {children}
+                    </Element>
+                </If>
+            </Clip>
+            {VecTracker::from(resize_handles.into_iter().map(|edges| {
+                let on_move = on_move.clone();
+                let on_resize = on_resize.clone();
+                let set_pos = set_pos.clone();
+                let set_size = set_size.clone();
+                let set_resizing = set_resizing.clone();
+                let handle_events = OnEvent::new(move |ctx, event| match event.event_type {
+                    EventType::MouseDown(data) => {
+                        ctx.capture_pointer();
+                        set_resizing(Some(ResizeDrag {
+                            edges,
+                            start_mouse: data.position,
+                            start_pos: pos,
+                            start_size: size,
+                        }));
+                    }
+                    EventType::MouseUp(..) => set_resizing(None),
+                    EventType::Hover(data) => {
+                        if let Some(drag) = resizing {
+                            let (next_pos, next_size) = drag.resize(data.position, bounds);
+                            if next_pos != pos {
+                                set_pos(next_pos);
+                                if let Some(ref on_move) = on_move {
+                                    on_move.call(next_pos);
+                                }
+                            }
+                            if next_size != size {
+                                set_size(next_size);
+                                if let Some(ref on_resize) = on_resize {
+                                    on_resize.call(next_size);
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                });
+
+                constructor! {
+                    <Element styles={Some(edges.styles())} on_event={Some(handle_events)} />
+                }
+            }))}
+        </>
     }
 }